@@ -0,0 +1,302 @@
+//! Validation baseline for legacy specks
+//!
+//! A baseline records the issues a speck already has, so that validation
+//! fails only on issues introduced later. Entries are identified by file,
+//! rule code, anchor and a hash of the offending source line (or of the
+//! message when the issue has no line), never by line number, so edits
+//! elsewhere in the file do not invalidate them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SpecksError;
+use crate::validator::{Severity, ValidationResult};
+
+/// Baseline file name inside `.specks/`
+pub const BASELINE_FILE: &str = "validation-baseline.json";
+
+/// Current baseline format version
+const BASELINE_VERSION: u32 = 1;
+
+/// One recorded issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Speck path relative to the project root, with forward slashes
+    pub file: String,
+    /// Rule code (e.g., "W004", "P001")
+    pub code: String,
+    /// Anchor the issue refers to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// Hash of the offending line's content, or of the message
+    pub hash: String,
+    /// Message at the time the baseline was written (informational)
+    pub message: String,
+}
+
+/// Code, anchor and hash: what matches an entry to an issue within a file
+type EntryKey = (String, Option<String>, String);
+
+/// A set of recorded issues, stored as `.specks/validation-baseline.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: vec![],
+        }
+    }
+}
+
+/// Outcome of filtering one file's result through the baseline
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BaselineMatch {
+    /// Number of issues and diagnostics hidden because they are recorded
+    pub baselined: usize,
+    /// Recorded entries for the file that no longer occur
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Load a baseline; `Ok(None)` if the file does not exist
+    pub fn load(path: &Path) -> Result<Option<Self>, SpecksError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(SpecksError::Io(e)),
+        };
+        let baseline: Baseline =
+            serde_json::from_str(&content).map_err(|e| SpecksError::InvalidBaseline {
+                path: path.display().to_string(),
+                reason: e.to_string(),
+            })?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(SpecksError::InvalidBaseline {
+                path: path.display().to_string(),
+                reason: format!("unsupported version {}", baseline.version),
+            });
+        }
+        Ok(Some(baseline))
+    }
+
+    /// Write the baseline as pretty-printed JSON, entries sorted
+    pub fn save(&self, path: &Path) -> Result<(), SpecksError> {
+        let mut sorted = self.clone();
+        sorted.entries.sort_by(|a, b| {
+            (&a.file, &a.code, &a.anchor, &a.hash).cmp(&(&b.file, &b.code, &b.anchor, &b.hash))
+        });
+        let json = serde_json::to_string_pretty(&sorted)
+            .map_err(|e| SpecksError::Config(e.to_string()))?;
+        fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    /// Replace the entries of `file` with the issues of `result`
+    pub fn record(&mut self, file: &str, content: &str, result: &ValidationResult) {
+        self.entries.retain(|e| e.file != file);
+        self.entries.extend(entries_for(file, content, result));
+    }
+
+    /// Remove recorded issues from `result` and report stale entries
+    ///
+    /// Each entry hides at most one issue. `result.valid` is recomputed from
+    /// the issues that remain.
+    pub fn apply(&self, file: &str, content: &str, result: &mut ValidationResult) -> BaselineMatch {
+        let mut remaining: HashMap<EntryKey, Vec<&BaselineEntry>> = HashMap::new();
+        for entry in self.entries.iter().filter(|e| e.file == file) {
+            let key = (entry.code.clone(), entry.anchor.clone(), entry.hash.clone());
+            remaining.entry(key).or_default().push(entry);
+        }
+
+        let lines: Vec<&str> = content.lines().collect();
+        let mut baselined = 0;
+        let mut consume = |code: &str, anchor: Option<&str>, hash: String| {
+            let key = (code.to_string(), anchor.map(str::to_string), hash);
+            let matched = remaining
+                .get_mut(&key)
+                .and_then(|entries| entries.pop())
+                .is_some();
+            if matched {
+                baselined += 1;
+            }
+            !matched
+        };
+
+        result.issues.retain(|issue| {
+            let hash = issue_hash(&lines, &issue.code, issue.line, &issue.message);
+            consume(&issue.code, issue.anchor.as_deref(), hash)
+        });
+        result.diagnostics.retain(|diagnostic| {
+            let hash = issue_hash(
+                &lines,
+                &diagnostic.code,
+                Some(diagnostic.line),
+                &diagnostic.message,
+            );
+            consume(&diagnostic.code, None, hash)
+        });
+        result.valid = !result.issues.iter().any(|i| i.severity == Severity::Error);
+
+        let mut fixed: Vec<BaselineEntry> = remaining
+            .into_values()
+            .flatten()
+            .map(|entry| (*entry).clone())
+            .collect();
+        fixed.sort_by(|a, b| (&a.code, &a.anchor).cmp(&(&b.code, &b.anchor)));
+        BaselineMatch { baselined, fixed }
+    }
+
+    /// Recorded entries for files not in `files` (e.g., deleted specks)
+    pub fn entries_outside(&self, files: &[String]) -> Vec<BaselineEntry> {
+        self.entries
+            .iter()
+            .filter(|e| !files.contains(&e.file))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Baseline entries for every issue and diagnostic in `result`
+fn entries_for(file: &str, content: &str, result: &ValidationResult) -> Vec<BaselineEntry> {
+    let lines: Vec<&str> = content.lines().collect();
+    let issues = result.issues.iter().map(|issue| BaselineEntry {
+        file: file.to_string(),
+        code: issue.code.clone(),
+        anchor: issue.anchor.clone(),
+        hash: issue_hash(&lines, &issue.code, issue.line, &issue.message),
+        message: issue.message.clone(),
+    });
+    let diagnostics = result.diagnostics.iter().map(|diagnostic| BaselineEntry {
+        file: file.to_string(),
+        code: diagnostic.code.clone(),
+        anchor: None,
+        hash: issue_hash(
+            &lines,
+            &diagnostic.code,
+            Some(diagnostic.line),
+            &diagnostic.message,
+        ),
+        message: diagnostic.message.clone(),
+    });
+    issues.chain(diagnostics).collect()
+}
+
+/// Hash of the trimmed source line an issue points at, or of its message
+fn issue_hash(lines: &[&str], code: &str, line: Option<usize>, message: &str) -> String {
+    let text = line
+        .and_then(|l| l.checked_sub(1))
+        .and_then(|i| lines.get(i))
+        .map_or(message, |l| l.trim());
+    format!("{:016x}", fnv1a(format!("{}\0{}", code, text).as_bytes()))
+}
+
+/// 64-bit FNV-1a, stable across platforms and Rust versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_speck;
+    use crate::validator::validate_speck;
+
+    const LEGACY: &str = r#"## Phase 1.0: Legacy {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | 2026-02-04 |
+
+#### Step 0: Setup {#step-0}
+
+**Commit:** `feat: setup`
+
+**Tasks:**
+- [ ] Task
+"#;
+
+    fn validate(content: &str) -> ValidationResult {
+        validate_speck(&parse_speck(content).unwrap())
+    }
+
+    #[test]
+    fn test_fnv1a_known_values() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_baseline_hides_recorded_issues_across_line_moves() {
+        let mut baseline = Baseline::default();
+        baseline.record(".specks/specks-l.md", LEGACY, &validate(LEGACY));
+        assert!(!baseline.entries.is_empty());
+        assert!(baseline.entries.iter().any(|e| e.code == "W004"));
+
+        // Lines shift, but the recorded issues are still recognized
+        let shifted = LEGACY.replace("#### Step 0", "Intro text.\n\n#### Step 0");
+        let mut result = validate(&shifted);
+        let count = result.issues.len() + result.diagnostics.len();
+        let matched = baseline.apply(".specks/specks-l.md", &shifted, &mut result);
+        assert_eq!(matched.baselined, count);
+        assert!(matched.fixed.is_empty());
+        assert!(result.issues.is_empty());
+    }
+
+    #[test]
+    fn test_baseline_reports_new_and_fixed_issues() {
+        let mut baseline = Baseline::default();
+        baseline.record(".specks/specks-l.md", LEGACY, &validate(LEGACY));
+
+        // Adding tests fixes W004; a bogus status is new
+        let edited = LEGACY
+            .replace("- [ ] Task\n", "- [ ] Task\n\n**Tests:**\n- [ ] Test\n")
+            .replace("| Status | active |", "| Status | bogus |");
+        let mut result = validate(&edited);
+        let matched = baseline.apply(".specks/specks-l.md", &edited, &mut result);
+
+        assert!(matched.fixed.iter().any(|e| e.code == "W004"));
+        assert!(result.issues.iter().any(|i| i.code == "E003"));
+        assert!(result.issues.iter().all(|i| i.code != "W004"));
+        assert!(!result.valid);
+
+        // Entries of other files are untouched
+        let mut other = validate(&edited);
+        let matched = baseline.apply(".specks/specks-other.md", &edited, &mut other);
+        assert_eq!(matched, BaselineMatch::default());
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join(BASELINE_FILE);
+        assert!(Baseline::load(&path).unwrap().is_none());
+
+        let mut baseline = Baseline::default();
+        baseline.record(".specks/specks-l.md", LEGACY, &validate(LEGACY));
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap().unwrap();
+        assert_eq!(loaded.entries.len(), baseline.entries.len());
+        assert!(loaded.entries.iter().all(|e| !e.hash.is_empty()));
+
+        fs::write(&path, "{ not json").unwrap();
+        let err = Baseline::load(&path).unwrap_err();
+        assert_eq!(err.code(), "E044");
+    }
+}
@@ -50,6 +50,11 @@ pub struct IssueDetails {
     pub notes: Option<String>,
     #[serde(default)]
     pub close_reason: Option<String>,
+    /// When the issue was closed (ISO 8601)
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
     #[serde(default)]
     pub metadata: Option<serde_json::Value>,
 }
@@ -1146,6 +1151,8 @@ mod tests {
             acceptance_criteria: Some("Acceptance content".to_string()),
             notes: Some("Notes content".to_string()),
             close_reason: Some("Completed".to_string()),
+            closed_at: None,
+            labels: vec![],
             metadata: Some(serde_json::json!({"key": "value"})),
         };
 
@@ -0,0 +1,378 @@
+//! Multi-file specks
+//!
+//! A large speck can move deep dives and reference material into companion
+//! files next to it in `.specks/`. Companions listed in the `Includes`
+//! metadata row are part of the speck: their anchors and labels count as
+//! defined, and they are not listed as specks of their own. Any other file
+//! named by a cross-file reference (`specks-auth-reference.md#t03-error-codes`)
+//! is loaded only to resolve that reference.
+//!
+//! Steps can also depend on steps of other specks
+//! (`**Depends on:** specks-auth.md#step-3`). Those specks are loaded the same
+//! way, following their own cross-speck dependencies in turn, so that cycles
+//! spanning several specks can be found.
+
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::parser::{parse_speck, patterns};
+use crate::types::{Companion, Speck};
+
+/// Anchor reference, optionally qualified by a file:
+/// `#t03-error-codes` or `specks-auth-reference.md#t03-error-codes`
+/// (an optional leading `./` is allowed)
+pub(crate) static FILE_ANCHOR_REF: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:(?:\./)?\b([A-Za-z0-9_][A-Za-z0-9_.-]*\.md))?#([a-z0-9-]+)").unwrap()
+});
+
+/// A plain `.md` file name, as [`FILE_ANCHOR_REF`] accepts in references:
+/// no directories, no `..`, not absolute
+static COMPANION_FILE_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z0-9_][A-Za-z0-9_.-]*\.md$").unwrap());
+
+/// Whether an `Includes` entry names a file next to the speck that may be
+/// loaded as a companion
+pub fn is_companion_file_name(name: &str) -> bool {
+    COMPANION_FILE_NAME.is_match(name)
+}
+
+/// Files a speck pulls in: its `Includes`, every file named by a cross-file
+/// reference in a References line, then every speck it depends on, in
+/// first-seen order
+///
+/// `Includes` entries that are not plain `.md` file names are skipped.
+pub fn referenced_files(speck: &Speck) -> Vec<String> {
+    let mut files: Vec<String> = speck
+        .metadata
+        .includes
+        .iter()
+        .filter(|f| is_companion_file_name(f))
+        .cloned()
+        .collect();
+
+    let references = speck.steps.iter().flat_map(|step| {
+        std::iter::once(&step.references)
+            .chain(step.all_substeps().into_iter().map(|s| &s.references))
+    });
+    for refs in references.flatten() {
+        for cap in FILE_ANCHOR_REF.captures_iter(refs) {
+            let Some(file) = cap.get(1).map(|m| m.as_str()) else {
+                continue;
+            };
+            if !files.iter().any(|f| f == file) {
+                files.push(file.to_string());
+            }
+        }
+    }
+
+    for file in dependency_files(&speck.step_dependencies()) {
+        if !files.contains(&file) {
+            files.push(file);
+        }
+    }
+
+    files
+}
+
+/// Split a cross-speck dependency (`specks-auth.md#step-3`) into file and
+/// anchor; `None` for a step of the same speck
+pub fn external_dependency(dep: &str) -> Option<(&str, &str)> {
+    dep.split_once('#')
+}
+
+/// Files named by cross-speck dependencies, in first-seen order
+fn dependency_files(dependencies: &[(String, Vec<String>)]) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+    for dep in dependencies.iter().flat_map(|(_, deps)| deps) {
+        if let Some((file, _)) = external_dependency(dep) {
+            if !files.iter().any(|f| f == file) {
+                files.push(file.to_string());
+            }
+        }
+    }
+    files
+}
+
+/// Load the companion files of a speck from `dir` (the speck's directory)
+///
+/// Unreadable files are still recorded, with `found: false`, so validation
+/// can report them. A speck never loads itself as a companion. Specks reached
+/// through cross-speck dependencies are loaded transitively.
+pub fn load_companions(speck: &mut Speck, dir: &Path) {
+    let own_name = speck
+        .path
+        .as_deref()
+        .and_then(|p| Path::new(p).file_name())
+        .and_then(|n| n.to_str())
+        .map(|n| n.to_string());
+
+    let mut pending = referenced_files(speck);
+    while !pending.is_empty() {
+        let file = pending.remove(0);
+        if own_name.as_deref() == Some(file.as_str()) || speck.companion(&file).is_some() {
+            continue;
+        }
+
+        let included = speck.metadata.includes.contains(&file);
+        let companion = match fs::read_to_string(dir.join(&file)) {
+            Ok(content) => {
+                let parsed = parse_speck(&content).unwrap_or_default();
+                Companion {
+                    file,
+                    included,
+                    found: true,
+                    dependencies: parsed.step_dependencies(),
+                    anchors: parsed.anchors,
+                    labels: parsed.labels,
+                    raw_content: content,
+                }
+            }
+            Err(_) => Companion {
+                file,
+                included,
+                found: false,
+                ..Default::default()
+            },
+        };
+        pending.extend(dependency_files(&companion.dependencies));
+        speck.companions.push(companion);
+    }
+}
+
+/// A step of another speck that a cross-speck dependency points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalStep {
+    /// Speck file name (e.g., "specks-auth.md")
+    pub file: String,
+    /// Step or substep anchor, without `#`
+    pub anchor: String,
+    /// Step number (e.g., "3", "2.1")
+    pub number: String,
+    /// Step title
+    pub title: String,
+    /// Bead ID, if the other speck has been synced
+    pub bead_id: Option<String>,
+    /// Completed checkbox items, substeps included
+    pub done: usize,
+    /// Total checkbox items, substeps included
+    pub total: usize,
+}
+
+impl ExternalStep {
+    /// Whether every checkbox item of the step is checked
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.done == self.total
+    }
+}
+
+/// Read the step a cross-speck dependency points at from the speck in `dir`
+///
+/// Returns `None` if `dep` is not qualified by a file, or if the file or the
+/// step does not exist.
+pub fn resolve_external_step(dir: &Path, dep: &str) -> Option<ExternalStep> {
+    let (file, anchor) = external_dependency(dep)?;
+    let content = fs::read_to_string(dir.join(file)).ok()?;
+    let speck = parse_speck(&content).ok()?;
+    let external =
+        |number: &str, title: &str, bead_id: &Option<String>, (done, total)| ExternalStep {
+            file: file.to_string(),
+            anchor: anchor.to_string(),
+            number: number.to_string(),
+            title: title.to_string(),
+            bead_id: bead_id.clone(),
+            done,
+            total,
+        };
+    speck.steps.iter().find_map(|step| {
+        if step.anchor == anchor {
+            return Some(external(
+                &step.number,
+                &step.title,
+                &step.bead_id,
+                step.rollup_counts(),
+            ));
+        }
+        step.all_substeps()
+            .into_iter()
+            .find(|s| s.anchor == anchor)
+            .map(|s| external(&s.number, &s.title, &s.bead_id, s.rollup_counts()))
+    })
+}
+
+/// File names listed in a speck's `Includes` metadata row
+///
+/// A cheap scan of the raw content, used to hide companions when listing specks.
+pub fn included_files(content: &str) -> Vec<String> {
+    let mut in_metadata_table = false;
+    for line in content.lines() {
+        if patterns::METADATA_HEADER.is_match(line) {
+            in_metadata_table = true;
+            continue;
+        }
+        if !in_metadata_table {
+            continue;
+        }
+        if !line.starts_with('|') {
+            break;
+        }
+        if let Some(caps) = patterns::METADATA_ROW.captures(line) {
+            if caps
+                .get(1)
+                .unwrap()
+                .as_str()
+                .eq_ignore_ascii_case("includes")
+            {
+                return caps
+                    .get(2)
+                    .unwrap()
+                    .as_str()
+                    .split(',')
+                    .map(|f| f.trim().trim_matches('`').trim_start_matches("./"))
+                    .filter(|f| !f.is_empty())
+                    .map(|f| f.to_string())
+                    .collect();
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// Every file-qualified anchor reference in raw speck content, as
+/// `(file, anchor)` pairs in first-seen order
+///
+/// Used to find which anchors of a speck other specks depend on.
+pub fn cross_file_refs(content: &str) -> Vec<(String, String)> {
+    let mut refs: Vec<(String, String)> = Vec::new();
+    for cap in FILE_ANCHOR_REF.captures_iter(content) {
+        let Some(file) = cap.get(1) else {
+            continue;
+        };
+        let pair = (file.as_str().to_string(), cap[2].to_string());
+        if !refs.contains(&pair) {
+            refs.push(pair);
+        }
+    }
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN: &str = r#"## Phase 1.0: Auth {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | 2026-02-03 |
+| Includes | specks-auth-reference.md |
+
+#### Step 0: Tokens {#step-0}
+
+**References:** specks-auth-reference.md#t03-error-codes, ./specks-auth-deepdives.md#flows
+
+**Tasks:**
+- [ ] Task
+"#;
+
+    const REFERENCE: &str = r#"## Auth Reference {#auth-reference}
+
+### Error codes {#t03-error-codes}
+
+**Table T03: Error codes** {#t03-table}
+
+| Code | Meaning |
+|------|---------|
+"#;
+
+    #[test]
+    fn test_referenced_files_includes_then_references() {
+        let speck = parse_speck(MAIN).unwrap();
+        assert_eq!(speck.metadata.includes, vec!["specks-auth-reference.md"]);
+        assert_eq!(
+            referenced_files(&speck),
+            vec!["specks-auth-reference.md", "specks-auth-deepdives.md"]
+        );
+    }
+
+    #[test]
+    fn test_referenced_files_skips_paths_in_includes() {
+        let content = MAIN.replace(
+            "| Includes | specks-auth-reference.md |",
+            "| Includes | ../../etc/passwd, /etc/hosts.md, sub/notes.md, notes.txt, specks-auth-reference.md |",
+        );
+        let speck = parse_speck(&content).unwrap();
+        assert_eq!(speck.metadata.includes.len(), 5);
+        assert_eq!(
+            referenced_files(&speck),
+            vec!["specks-auth-reference.md", "specks-auth-deepdives.md"]
+        );
+        assert!(is_companion_file_name("specks-auth-reference.md"));
+        assert!(!is_companion_file_name("..md"));
+        assert!(!is_companion_file_name("C:\\specks\\x.md"));
+    }
+
+    #[test]
+    fn test_cross_file_refs_skips_local_anchors() {
+        let refs = cross_file_refs(MAIN);
+        assert_eq!(
+            refs,
+            vec![
+                (
+                    "specks-auth-reference.md".to_string(),
+                    "t03-error-codes".to_string()
+                ),
+                ("specks-auth-deepdives.md".to_string(), "flows".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_companions_and_extract_across_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("specks-auth-reference.md"), REFERENCE).unwrap();
+
+        let mut speck = parse_speck(MAIN).unwrap();
+        speck.path = Some(dir.path().join("specks-auth.md").display().to_string());
+        load_companions(&mut speck, dir.path());
+
+        assert_eq!(speck.companions.len(), 2);
+        let reference = speck.companion("specks-auth-reference.md").unwrap();
+        assert!(reference.included && reference.found);
+        assert!(
+            reference
+                .anchors
+                .iter()
+                .any(|a| a.name == "t03-error-codes")
+        );
+        assert_eq!(reference.labels[0].id, "T03");
+
+        let deepdives = speck.companion("specks-auth-deepdives.md").unwrap();
+        assert!(!deepdives.included && !deepdives.found);
+
+        let section = speck
+            .extract_section_by_anchor("specks-auth-reference.md#t03-error-codes")
+            .unwrap();
+        assert!(section.contains("Table T03"));
+
+        // Plain anchors fall through to included companions
+        assert!(speck.extract_section_by_anchor("t03-error-codes").is_some());
+        assert!(
+            speck
+                .extract_section_by_anchor("specks-auth-deepdives.md#flows")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_included_files_scans_metadata_only() {
+        assert_eq!(included_files(MAIN), vec!["specks-auth-reference.md"]);
+        assert!(included_files(REFERENCE).is_empty());
+    }
+}
@@ -1,10 +1,12 @@
 //! Configuration handling for specks
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::error::SpecksError;
+use crate::validator::{CustomCheck, CustomRule, RuleSetting, StepField, rule_description};
 
 /// Specks configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -25,6 +27,10 @@ pub struct SpecksConfig {
     #[serde(default)]
     pub show_info: bool,
 
+    /// Per-rule validation settings
+    #[serde(default)]
+    pub validation: ValidationRulesConfig,
+
     /// Naming settings
     #[serde(default)]
     pub naming: NamingConfig,
@@ -34,6 +40,46 @@ pub struct SpecksConfig {
     pub beads: BeadsConfig,
 }
 
+/// Per-rule validation configuration (`[specks.validation]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ValidationRulesConfig {
+    /// Rule code to "off", "info", "warning" or "error" (e.g. `W004 = "off"`)
+    #[serde(default)]
+    pub rules: BTreeMap<String, String>,
+
+    /// Project-defined rules (`[[specks.validation.custom]]`)
+    #[serde(default)]
+    pub custom: Vec<CustomRuleConfig>,
+}
+
+/// A project-defined rule as written in config
+///
+/// `kind` selects the check and which of the other keys it uses:
+/// - `required-anchor`: `anchor`
+/// - `required-metadata`: `field`
+/// - `step-pattern`: `field` (a step field) and `pattern` (a regex)
+/// - `max-tasks`: `max`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomRuleConfig {
+    /// Code reported for the rule (e.g. "C001")
+    pub code: String,
+    /// Which check to run
+    pub kind: String,
+    /// "info", "warning" or "error"
+    #[serde(default = "default_custom_severity")]
+    pub severity: String,
+    /// Message reported for the rule's issues
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<usize>,
+}
+
 /// Naming configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NamingConfig {
@@ -61,6 +107,14 @@ pub struct BeadsConfig {
     #[serde(default = "default_bd_path")]
     pub bd_path: String,
 
+    /// Issue tracker backend: "bd" (beads CLI), "file" (.specks/tracker/) or "github"
+    #[serde(default = "default_tracker")]
+    pub tracker: String,
+
+    /// ID prefix for issues created by the file tracker
+    #[serde(default = "default_tracker_prefix")]
+    pub tracker_prefix: String,
+
     /// Update titles on sync
     #[serde(default)]
     pub update_title: bool,
@@ -85,9 +139,37 @@ pub struct BeadsConfig {
     #[serde(default = "default_pull_checkbox_mode")]
     pub pull_checkbox_mode: String,
 
-    /// Warn on conflict during pull
+    /// Warn when `pull --fields` finds a field changed on both sides
     #[serde(default = "default_pull_warn")]
     pub pull_warn_on_conflict: bool,
+
+    /// Orphan bead handling on sync: "report", "close" or "relink"
+    #[serde(default = "default_orphans")]
+    pub orphans: String,
+
+    /// GitHub Issues tracker settings
+    #[serde(default)]
+    pub github: GitHubConfig,
+}
+
+/// GitHub Issues tracker configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubConfig {
+    /// REST API base URL
+    #[serde(default = "default_github_api_url")]
+    pub api_url: String,
+
+    /// Repository as "owner/name"
+    #[serde(default)]
+    pub repo: String,
+
+    /// Root bead mapping: "issue" (tracking issue) or "milestone"
+    #[serde(default = "default_github_root")]
+    pub root: String,
+}
+
+fn default_custom_severity() -> String {
+    "warning".to_string()
 }
 
 fn default_validation_level() -> String {
@@ -114,6 +196,26 @@ fn default_bd_path() -> String {
     "bd".to_string()
 }
 
+fn default_tracker() -> String {
+    "bd".to_string()
+}
+
+fn default_tracker_prefix() -> String {
+    "sp".to_string()
+}
+
+fn default_orphans() -> String {
+    "report".to_string()
+}
+
+fn default_github_api_url() -> String {
+    "https://api.github.com".to_string()
+}
+
+fn default_github_root() -> String {
+    "issue".to_string()
+}
+
 fn default_root_issue_type() -> String {
     "epic".to_string()
 }
@@ -135,6 +237,7 @@ impl Default for SpecksConfig {
         Self {
             validation_level: default_validation_level(),
             show_info: false,
+            validation: ValidationRulesConfig::default(),
             naming: NamingConfig::default(),
             beads: BeadsConfig::default(),
         }
@@ -156,6 +259,8 @@ impl Default for BeadsConfig {
             enabled: default_beads_enabled(),
             validate_bead_ids: default_validate_bead_ids(),
             bd_path: default_bd_path(),
+            tracker: default_tracker(),
+            tracker_prefix: default_tracker_prefix(),
             update_title: false,
             update_body: false,
             prune_deps: false,
@@ -163,8 +268,142 @@ impl Default for BeadsConfig {
             substeps: default_substeps(),
             pull_checkbox_mode: default_pull_checkbox_mode(),
             pull_warn_on_conflict: default_pull_warn(),
+            orphans: default_orphans(),
+            github: GitHubConfig::default(),
+        }
+    }
+}
+
+impl Default for GitHubConfig {
+    fn default() -> Self {
+        Self {
+            api_url: default_github_api_url(),
+            repo: String::new(),
+            root: default_github_root(),
+        }
+    }
+}
+
+impl ValidationRulesConfig {
+    /// Parse the configured rule settings
+    pub fn rule_settings(&self) -> Result<HashMap<String, RuleSetting>, SpecksError> {
+        self.rules
+            .iter()
+            .map(|(code, value)| {
+                RuleSetting::parse(value)
+                    .map(|setting| (code.to_uppercase(), setting))
+                    .ok_or_else(|| {
+                        SpecksError::Config(format!(
+                            "invalid setting '{}' for rule {} (must be off, info, warning or error)",
+                            value, code
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+impl ValidationRulesConfig {
+    /// Parse the configured custom rules
+    pub fn custom_rules(&self) -> Result<Vec<CustomRule>, SpecksError> {
+        self.custom.iter().map(CustomRuleConfig::to_rule).collect()
+    }
+}
+
+impl CustomRuleConfig {
+    /// Check the rule's keys and build it
+    fn to_rule(&self) -> Result<CustomRule, SpecksError> {
+        let invalid = |reason: String| {
+            SpecksError::Config(format!("invalid custom rule {}: {}", self.code, reason))
+        };
+        let required = |value: &Option<String>, key: &str| {
+            value
+                .clone()
+                .filter(|v| !v.trim().is_empty())
+                .ok_or_else(|| invalid(format!("kind {} requires `{}`", self.kind, key)))
+        };
+
+        let code = self.code.trim().to_uppercase();
+        if code.is_empty() || code.contains(char::is_whitespace) {
+            return Err(invalid("code must be a single word".to_string()));
+        }
+        if rule_description(&code).is_some() {
+            return Err(invalid("code is a built-in rule".to_string()));
+        }
+        let severity = match RuleSetting::parse(&self.severity) {
+            Some(RuleSetting::Severity(severity)) => severity,
+            _ => {
+                return Err(invalid(format!(
+                    "severity '{}' must be info, warning or error",
+                    self.severity
+                )));
+            }
+        };
+
+        let check = match self.kind.as_str() {
+            "required-anchor" => CustomCheck::RequiredAnchor(
+                required(&self.anchor, "anchor")?
+                    .trim_start_matches('#')
+                    .to_string(),
+            ),
+            "required-metadata" => CustomCheck::RequiredMetadata(required(&self.field, "field")?),
+            "step-pattern" => {
+                let name = required(&self.field, "field")?;
+                let field = StepField::parse(&name).ok_or_else(|| {
+                    invalid(format!(
+                        "unknown step field '{}' (must be title, commit, references, depends_on, tasks, tests or checkpoints)",
+                        name
+                    ))
+                })?;
+                let pattern = required(&self.pattern, "pattern")?;
+                let pattern = regex::Regex::new(&pattern)
+                    .map_err(|e| invalid(format!("invalid pattern {:?}: {}", pattern, e)))?;
+                CustomCheck::StepPattern { field, pattern }
+            }
+            "max-tasks" => CustomCheck::MaxTasks(
+                self.max
+                    .ok_or_else(|| invalid("kind max-tasks requires `max`".to_string()))?,
+            ),
+            other => {
+                return Err(invalid(format!(
+                    "unknown kind '{}' (must be required-anchor, required-metadata, step-pattern or max-tasks)",
+                    other
+                )));
+            }
+        };
+
+        Ok(CustomRule {
+            code,
+            severity,
+            message: self.message.clone(),
+            check,
+        })
+    }
+}
+
+impl NamingConfig {
+    /// Check a speck name (without prefix or extension) against `name_pattern`
+    pub fn validate_name(&self, name: &str) -> Result<(), SpecksError> {
+        let pattern = regex::Regex::new(&self.name_pattern).map_err(|e| {
+            SpecksError::Config(format!(
+                "invalid naming.name_pattern {:?}: {}",
+                self.name_pattern, e
+            ))
+        })?;
+        if pattern.is_match(name) {
+            Ok(())
+        } else {
+            Err(SpecksError::InvalidSpeckName {
+                name: name.to_string(),
+                pattern: self.name_pattern.clone(),
+            })
         }
     }
+
+    /// File name for a speck with this name ("auth" -> "specks-auth.md")
+    pub fn file_name(&self, name: &str) -> String {
+        format!("{}{}.md", self.prefix, name)
+    }
 }
 
 impl Config {
@@ -223,7 +462,8 @@ pub fn find_project_root_from(start: PathBuf) -> Result<PathBuf, SpecksError> {
 
 /// Find all speck files in the .specks directory
 ///
-/// Per [D03], speck files match `specks-*.md` except reserved files.
+/// Per [D03], speck files match `specks-*.md` except reserved files. Companion
+/// files listed in another speck's `Includes` row are skipped.
 pub fn find_specks(project_root: &Path) -> Result<Vec<PathBuf>, SpecksError> {
     let specks_dir = project_root.join(".specks");
     if !specks_dir.is_dir() {
@@ -247,6 +487,18 @@ pub fn find_specks(project_root: &Path) -> Result<Vec<PathBuf>, SpecksError> {
         }
     }
 
+    // Companion files included by another speck are part of that speck
+    let included: HashSet<String> = specks
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|content| crate::companion::included_files(&content))
+        .collect();
+    specks.retain(|path| {
+        path.file_name()
+            .and_then(|n| n.to_str())
+            .is_none_or(|name| !included.contains(name))
+    });
+
     // Sort by filename for consistent ordering
     specks.sort();
     Ok(specks)
@@ -299,6 +551,27 @@ mod tests {
         assert_eq!(speck_name_from_path(Path::new("specks-.md")), None);
     }
 
+    #[test]
+    fn test_find_specks_skips_included_companions() {
+        let temp = tempfile::tempdir().unwrap();
+        let specks_dir = temp.path().join(".specks");
+        fs::create_dir(&specks_dir).unwrap();
+        fs::write(
+            specks_dir.join("specks-auth.md"),
+            "| Field | Value |\n|------|-------|\n| Includes | specks-auth-reference.md |\n",
+        )
+        .unwrap();
+        fs::write(
+            specks_dir.join("specks-auth-reference.md"),
+            "## Reference\n",
+        )
+        .unwrap();
+        fs::write(specks_dir.join("specks-skeleton.md"), "").unwrap();
+
+        let specks = find_specks(temp.path()).unwrap();
+        assert_eq!(specks, vec![specks_dir.join("specks-auth.md")]);
+    }
+
     #[test]
     fn test_default_config() {
         let config = Config::default();
@@ -307,4 +580,103 @@ mod tests {
         assert_eq!(config.specks.naming.prefix, "specks-");
         assert!(config.specks.beads.enabled);
     }
+
+    #[test]
+    fn test_validation_rule_settings() {
+        let config: Config =
+            toml::from_str("[specks.validation.rules]\nW004 = \"off\"\nw011 = \"error\"\n")
+                .unwrap();
+        let rules = config.specks.validation.rule_settings().unwrap();
+        assert_eq!(rules["W004"], RuleSetting::Off);
+        assert_eq!(
+            rules["W011"],
+            RuleSetting::Severity(crate::validator::Severity::Error)
+        );
+
+        let bad: Config = toml::from_str("[specks.validation.rules]\nW004 = \"loud\"\n").unwrap();
+        assert!(bad.specks.validation.rule_settings().is_err());
+    }
+
+    #[test]
+    fn test_custom_rules_config() {
+        let config: Config = toml::from_str(
+            r#"
+[[specks.validation.custom]]
+code = "c001"
+kind = "step-pattern"
+field = "commit"
+pattern = '^(feat|fix|chore)(\(.+\))?: '
+severity = "error"
+message = "Commit must use a conventional prefix"
+
+[[specks.validation.custom]]
+code = "C002"
+kind = "max-tasks"
+max = 8
+message = "Too many tasks"
+"#,
+        )
+        .unwrap();
+        let rules = config.specks.validation.custom_rules().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].code, "C001");
+        assert_eq!(rules[0].severity, crate::validator::Severity::Error);
+        assert!(matches!(
+            &rules[0].check,
+            CustomCheck::StepPattern { field: StepField::Commit, pattern } if pattern.is_match("fix(cli): x")
+        ));
+        // Severity defaults to warning
+        assert_eq!(rules[1].severity, crate::validator::Severity::Warning);
+        assert!(matches!(rules[1].check, CustomCheck::MaxTasks(8)));
+
+        let invalid = |body: &str| {
+            let config: Config =
+                toml::from_str(&format!("[[specks.validation.custom]]\n{}", body)).unwrap();
+            config
+                .specks
+                .validation
+                .custom_rules()
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(
+            invalid("code = \"W004\"\nkind = \"max-tasks\"\nmax = 1\nmessage = \"m\"")
+                .contains("built-in")
+        );
+        assert!(
+            invalid("code = \"C1\"\nkind = \"required-anchor\"\nmessage = \"m\"")
+                .contains("requires `anchor`")
+        );
+        assert!(invalid(
+            "code = \"C1\"\nkind = \"step-pattern\"\nfield = \"body\"\npattern = \"x\"\nmessage = \"m\""
+        )
+        .contains("unknown step field"));
+        assert!(invalid(
+            "code = \"C1\"\nkind = \"step-pattern\"\nfield = \"title\"\npattern = \"(\"\nmessage = \"m\""
+        )
+        .contains("invalid pattern"));
+        assert!(
+            invalid("code = \"C1\"\nkind = \"nope\"\nmessage = \"m\"").contains("unknown kind")
+        );
+    }
+
+    #[test]
+    fn test_naming_validate_name() {
+        let naming = NamingConfig::default();
+        assert!(naming.validate_name("user-auth").is_ok());
+        assert_eq!(
+            naming.validate_name("User Auth").unwrap_err().code(),
+            "E040"
+        );
+        assert_eq!(naming.file_name("user-auth"), "specks-user-auth.md");
+
+        let bad = NamingConfig {
+            name_pattern: "[".to_string(),
+            ..NamingConfig::default()
+        };
+        assert!(matches!(
+            bad.validate_name("x"),
+            Err(SpecksError::Config(_))
+        ));
+    }
 }
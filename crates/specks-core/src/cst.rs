@@ -0,0 +1,947 @@
+//! Lossless concrete syntax tree for speck files
+//!
+//! [`parse_speck`](crate::parse_speck) produces a lossy semantic model. The
+//! [`SpeckTree`] defined here instead keeps the original source and classifies
+//! every line of it. Headings, metadata rows, checkboxes, `**Bead:**` and
+//! `**Depends on:**` lines carry byte spans for their editable parts.
+//!
+//! Serializing an unedited tree reproduces the input byte-for-byte, and every
+//! edit rewrites only the bytes of the node it touches. Commands that mutate
+//! speck files (`beads sync`, `beads link`, `beads pull`) go through the edit
+//! API here rather than patching `raw_content` themselves.
+
+use std::sync::LazyLock;
+
+use crate::parser::{patterns, split_beads_hints};
+use crate::types::CheckpointKind;
+
+/// Checkbox item, allowing indentation (nested list items are still checkboxes)
+static CHECKBOX_ITEM: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"^\s*-\s+\[([ xX~!-])\]\s*(.*)$").unwrap());
+
+/// A half-open byte range `[start, end)` into the tree's source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Create a new span
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Number of bytes covered by the span
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Whether the span covers no bytes
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The slice of `source` covered by this span
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start..self.end]
+    }
+
+    fn shift(self, by: usize) -> Self {
+        Self::new(self.start + by, self.end + by)
+    }
+}
+
+/// Classification of a single source line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CstNode {
+    /// Empty or whitespace-only line
+    Blank,
+    /// Any line without further structure
+    Text,
+    /// A code fence, or a line inside a fenced code block
+    Code,
+    /// A `---` thematic break
+    Rule,
+    /// A Markdown heading, with an optional explicit `{#anchor}`
+    Heading {
+        level: usize,
+        title: Span,
+        anchor: Option<Span>,
+    },
+    /// A row of the Plan Metadata table
+    MetadataRow {
+        /// Field name, trimmed
+        field: Span,
+        /// Value, trimmed (empty span at the cell end when the value is blank)
+        value: Span,
+        /// Everything between the value cell's pipes, including padding
+        cell: Span,
+    },
+    /// A `- [ ] item` line
+    Checkbox {
+        /// The single state character between the brackets
+        state: Span,
+        /// The item text after the brackets
+        text: Span,
+        /// The `**Tasks:**`/`**Tests:**`/`**Checkpoint:**` list this item belongs to
+        section: Option<CheckpointKind>,
+    },
+    /// A `**Bead:** \`id\`` line
+    Bead {
+        /// The bead ID between the backticks
+        id: Span,
+    },
+    /// A `**Depends on:** #a, #b` line
+    DependsOn {
+        /// Each referenced anchor name, without the leading `#`
+        refs: Vec<Span>,
+    },
+}
+
+/// A source line together with its classification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CstLine {
+    /// Line number (1-indexed)
+    pub number: usize,
+    /// Line content, excluding the line terminator
+    pub span: Span,
+    /// The line terminator (`\n`, `\r\n`, or empty on an unterminated last line)
+    pub eol: Span,
+    pub node: CstNode,
+}
+
+/// The lines belonging to one execution step (or substep)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepBlock {
+    /// Step number as written (e.g. "2" or "2.1")
+    pub number: String,
+    /// Explicit anchor, or the `step-N[-M]` default the parser assigns
+    pub anchor: String,
+    /// Index of the step heading in [`SpeckTree::lines`]
+    pub heading: usize,
+    /// Index one past the last line of the block (next heading, `---`, or EOF)
+    pub end: usize,
+}
+
+/// Lossless, span-annotated view of a speck file with an edit API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeckTree {
+    source: String,
+    lines: Vec<CstLine>,
+}
+
+impl SpeckTree {
+    /// Build a tree from speck source. Never fails: unknown content is kept as text.
+    pub fn parse(source: &str) -> Self {
+        Self::from_string(source.to_string())
+    }
+
+    fn from_string(source: String) -> Self {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        let mut in_code_block = false;
+        let mut in_metadata_table = false;
+        let mut section: Option<CheckpointKind> = None;
+
+        for (i, raw) in source.split_inclusive('\n').enumerate() {
+            let content = raw
+                .strip_suffix('\n')
+                .map(|s| s.strip_suffix('\r').unwrap_or(s))
+                .unwrap_or(raw);
+            let span = Span::new(offset, offset + content.len());
+            let eol = Span::new(span.end, offset + raw.len());
+            offset += raw.len();
+
+            let node = if content.trim().starts_with("```") {
+                in_code_block = !in_code_block;
+                CstNode::Code
+            } else if in_code_block {
+                CstNode::Code
+            } else {
+                classify(content, span.start, &mut in_metadata_table, &mut section)
+            };
+
+            lines.push(CstLine {
+                number: i + 1,
+                span,
+                eol,
+                node,
+            });
+        }
+
+        Self { source, lines }
+    }
+
+    /// The (possibly edited) source text
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Consume the tree, returning the (possibly edited) source text
+    pub fn into_string(self) -> String {
+        self.source
+    }
+
+    /// All lines in source order
+    pub fn lines(&self) -> &[CstLine] {
+        &self.lines
+    }
+
+    /// The text covered by a span
+    pub fn text(&self, span: Span) -> &str {
+        span.text(&self.source)
+    }
+
+    /// Execution steps and substeps in document order
+    pub fn steps(&self) -> Vec<StepBlock> {
+        let mut steps: Vec<StepBlock> = Vec::new();
+        for (idx, line) in self.lines.iter().enumerate() {
+            if !matches!(line.node, CstNode::Heading { .. }) {
+                continue;
+            }
+            if let Some(last) = steps.last_mut() {
+                if last.end == self.lines.len() {
+                    last.end = idx;
+                }
+            }
+            if let Some(caps) = patterns::STEP_HEADER.captures(self.text(line.span)) {
+                let number = caps.get(1).unwrap().as_str().to_string();
+                let anchor = caps
+                    .get(3)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_else(|| format!("step-{}", number.replace('.', "-")));
+                steps.push(StepBlock {
+                    number,
+                    anchor,
+                    heading: idx,
+                    end: self.lines.len(),
+                });
+            }
+        }
+
+        // A step block also ends at the first thematic break after its heading
+        for step in &mut steps {
+            if let Some(pos) = self.lines[step.heading + 1..step.end]
+                .iter()
+                .position(|l| l.node == CstNode::Rule)
+            {
+                step.end = step.heading + 1 + pos;
+            }
+        }
+        steps
+    }
+
+    /// Find a step or substep by anchor
+    pub fn step(&self, anchor: &str) -> Option<StepBlock> {
+        self.steps().into_iter().find(|s| s.anchor == anchor)
+    }
+
+    /// Index of the Plan Metadata row whose field matches `field` (case-insensitive)
+    pub fn metadata_row(&self, field: &str) -> Option<usize> {
+        self.lines.iter().position(|l| match l.node {
+            CstNode::MetadataRow { field: f, .. } => self.text(f).eq_ignore_ascii_case(field),
+            _ => false,
+        })
+    }
+
+    /// Replace the bytes covered by `span` with `text`, re-deriving the tree.
+    ///
+    /// Everything outside `span` is preserved byte-for-byte.
+    pub fn replace(&mut self, span: Span, text: &str) {
+        let mut source = std::mem::take(&mut self.source);
+        source.replace_range(span.start..span.end, text);
+        *self = Self::from_string(source);
+    }
+
+    /// Replace several non-overlapping spans at once, re-deriving the tree once.
+    ///
+    /// Spans refer to the source before any of the edits.
+    pub fn replace_all(&mut self, mut edits: Vec<(Span, String)>) {
+        if edits.is_empty() {
+            return;
+        }
+        edits.sort_by_key(|(span, _)| std::cmp::Reverse(span.start));
+        let mut source = std::mem::take(&mut self.source);
+        for (span, text) in edits {
+            source.replace_range(span.start..span.end, &text);
+        }
+        *self = Self::from_string(source);
+    }
+
+    /// Set the `**Bead:**` line of the step with the given anchor.
+    ///
+    /// An existing bead ID is replaced in place. Otherwise a new `**Bead:**`
+    /// line is inserted after `**Depends on:**` (or after the heading when
+    /// the step has no dependencies). Returns false if the step does not exist.
+    pub fn set_step_bead(&mut self, anchor: &str, bead_id: &str) -> bool {
+        let Some(step) = self.step(anchor) else {
+            return false;
+        };
+        let body = step.heading + 1..step.end;
+
+        let existing = self.lines[body.clone()].iter().find_map(|l| match l.node {
+            CstNode::Bead { id } => Some(id),
+            _ => None,
+        });
+        if let Some(id) = existing {
+            self.replace(id, bead_id);
+            return true;
+        }
+
+        let after = self.lines[body.clone()]
+            .iter()
+            .position(|l| matches!(l.node, CstNode::DependsOn { .. }))
+            .map(|pos| body.start + pos)
+            .unwrap_or(step.heading);
+
+        self.insert_paragraph_after(after, &format!("**Bead:** `{bead_id}`"));
+        true
+    }
+
+    /// Set the title of the step with the given anchor, keeping its number
+    /// and anchor. Returns true if the title changed.
+    pub fn set_step_title(&mut self, anchor: &str, title: &str) -> bool {
+        let Some(step) = self.step(anchor) else {
+            return false;
+        };
+        let line = self.lines[step.heading].span;
+        let Some(current) = patterns::STEP_HEADER
+            .captures(self.text(line))
+            .and_then(|caps| caps.get(2))
+        else {
+            return false;
+        };
+        if current.as_str() == title {
+            return false;
+        }
+        let span = Span::new(current.start(), current.end()).shift(line.start);
+        self.replace(span, title);
+        true
+    }
+
+    /// Set one `key=value` pair of a step's `**Beads:**` hints line.
+    ///
+    /// Other pairs keep their order; `None` removes the key, and the whole
+    /// line once no pairs are left. A missing line is inserted after the
+    /// step's `**Bead:**` line (or `**Depends on:**`, or the heading).
+    /// Returns true if the source changed.
+    pub fn set_step_hint(&mut self, anchor: &str, key: &str, value: Option<&str>) -> bool {
+        let Some(step) = self.step(anchor) else {
+            return false;
+        };
+        let body = step.heading + 1..step.end;
+        let hints_line = body
+            .clone()
+            .find(|&idx| patterns::BEADS_HINTS.is_match(self.text(self.lines[idx].span)));
+
+        let Some(idx) = hints_line else {
+            let Some(value) = value else {
+                return false;
+            };
+            let after = body
+                .clone()
+                .rev()
+                .find(|&idx| {
+                    matches!(
+                        self.lines[idx].node,
+                        CstNode::Bead { .. } | CstNode::DependsOn { .. }
+                    )
+                })
+                .unwrap_or(step.heading);
+            self.insert_paragraph_after(after, &format!("**Beads:** {}={}", key, value));
+            return true;
+        };
+
+        let line = self.lines[idx].span;
+        let text = self.text(line);
+        let mut pairs: Vec<(String, String)> =
+            split_beads_hints(text.trim_start_matches("**Beads:**"))
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+        let existing = pairs.iter().position(|(k, _)| k.eq_ignore_ascii_case(key));
+        match (existing, value) {
+            (Some(pos), Some(value)) => pairs[pos].1 = value.to_string(),
+            (Some(pos), None) => {
+                pairs.remove(pos);
+            }
+            (None, Some(value)) => pairs.push((key.to_string(), value.to_string())),
+            (None, None) => return false,
+        }
+
+        if pairs.is_empty() {
+            // Drop the line, and the blank line before it if one follows too
+            let mut start = line.start;
+            let next_blank = self
+                .lines
+                .get(idx + 1)
+                .is_none_or(|next| next.node == CstNode::Blank);
+            if idx > 0 && self.lines[idx - 1].node == CstNode::Blank && next_blank {
+                start = self.lines[idx - 1].span.start;
+            }
+            self.replace(Span::new(start, self.lines[idx].eol.end), "");
+            return true;
+        }
+
+        let pairs: Vec<String> = pairs.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        let new_text = format!("**Beads:** {}", pairs.join(", "));
+        if new_text == text {
+            return false;
+        }
+        self.replace(line, &new_text);
+        true
+    }
+
+    /// Insert `text` as its own paragraph after line `after`
+    pub(crate) fn insert_paragraph_after(&mut self, after: usize, text: &str) {
+        let eol = self.line_ending();
+        let mut text = format!("{eol}{eol}{text}");
+        if self
+            .lines
+            .get(after + 1)
+            .is_some_and(|next| next.node != CstNode::Blank)
+        {
+            text.push_str(eol);
+        }
+        let at = self.lines[after].span.end;
+        self.replace(Span::new(at, at), &text);
+    }
+
+    /// Set the Beads Root row of the Plan Metadata table.
+    ///
+    /// An existing row has its value cell replaced; otherwise a row is appended
+    /// to the table. Returns false if the speck has no metadata table.
+    pub fn set_beads_root(&mut self, bead_id: &str) -> bool {
+        let value_text = format!("`{}`", bead_id);
+
+        if let Some(idx) = self.metadata_row("Beads Root") {
+            if let CstNode::MetadataRow { value, cell, .. } = self.lines[idx].node {
+                if value.is_empty() {
+                    self.replace(cell, &format!(" {} ", value_text));
+                } else {
+                    self.replace(value, &value_text);
+                }
+            }
+            return true;
+        }
+
+        let Some(first) = self
+            .lines
+            .iter()
+            .position(|l| matches!(l.node, CstNode::MetadataRow { .. }))
+        else {
+            return false;
+        };
+        let last = self.lines[first..]
+            .iter()
+            .take_while(|l| matches!(l.node, CstNode::MetadataRow { .. }))
+            .count()
+            + first
+            - 1;
+
+        let at = self.lines[last].span.end;
+        let row = format!("{}| Beads Root | {} |", self.line_ending(), value_text);
+        self.replace(Span::new(at, at), &row);
+        true
+    }
+
+    /// Set the checkbox on line `index` to checked (`[x]`) or unchecked (`[ ]`).
+    ///
+    /// Returns true if the line is a checkbox and its state changed.
+    pub fn set_checkbox(&mut self, index: usize, checked: bool) -> bool {
+        let Some(CstNode::Checkbox { state, .. }) = self.lines.get(index).map(|l| &l.node) else {
+            return false;
+        };
+        let state = *state;
+        let current = self.text(state);
+        let new_state = if checked { "x" } else { " " };
+        if current.eq_ignore_ascii_case(new_state) {
+            return false;
+        }
+        self.replace(state, new_state);
+        true
+    }
+
+    /// Check every unchecked box in a step whose section passes `include`.
+    ///
+    /// Returns the number of checkboxes that changed.
+    pub fn check_step_boxes(
+        &mut self,
+        anchor: &str,
+        include: impl Fn(Option<CheckpointKind>) -> bool,
+    ) -> usize {
+        let Some(step) = self.step(anchor) else {
+            return 0;
+        };
+        let targets: Vec<usize> = (step.heading + 1..step.end)
+            .filter(|&idx| match &self.lines[idx].node {
+                CstNode::Checkbox { state, section, .. } => {
+                    self.text(*state) == " " && include(*section)
+                }
+                _ => false,
+            })
+            .collect();
+
+        targets
+            .into_iter()
+            .filter(|&idx| self.set_checkbox(idx, true))
+            .count()
+    }
+
+    /// Line terminator used by the source (CRLF if the first line uses it)
+    pub(crate) fn line_ending(&self) -> &'static str {
+        match self.lines.first() {
+            Some(line) if self.text(line.eol) == "\r\n" => "\r\n",
+            _ => "\n",
+        }
+    }
+}
+
+/// Classify a line outside of code blocks, tracking table and list context
+fn classify(
+    line: &str,
+    base: usize,
+    in_metadata_table: &mut bool,
+    section: &mut Option<CheckpointKind>,
+) -> CstNode {
+    if line.trim().is_empty() {
+        *in_metadata_table = false;
+        return CstNode::Blank;
+    }
+
+    if patterns::METADATA_HEADER.is_match(line) {
+        *in_metadata_table = true;
+        return CstNode::Text;
+    }
+    if *in_metadata_table {
+        if !line.starts_with('|') {
+            *in_metadata_table = false;
+        } else {
+            return metadata_row(line, base).unwrap_or(CstNode::Text);
+        }
+    }
+
+    if line.trim() == "---" {
+        *section = None;
+        return CstNode::Rule;
+    }
+
+    if let Some(caps) = patterns::SECTION_HEADER.captures(line) {
+        *section = None;
+        let span = |m: regex::Match<'_>| Span::new(m.start(), m.end()).shift(base);
+        return CstNode::Heading {
+            level: caps.get(1).unwrap().as_str().len(),
+            title: span(caps.get(2).unwrap()),
+            anchor: caps.get(3).map(span),
+        };
+    }
+
+    if let Some(caps) = patterns::BEAD_LINE.captures(line) {
+        let id = caps.get(1).unwrap();
+        return CstNode::Bead {
+            id: Span::new(id.start(), id.end()).shift(base),
+        };
+    }
+
+    if let Some(caps) = patterns::DEPENDS_ON.captures(line) {
+        let deps = caps.get(1).unwrap();
+        let refs = patterns::ANCHOR_REF
+            .captures_iter(deps.as_str())
+            .map(|c| {
+                let name = c.get(1).unwrap();
+                Span::new(name.start(), name.end()).shift(base + deps.start())
+            })
+            .collect();
+        return CstNode::DependsOn { refs };
+    }
+
+    if let Some(caps) = CHECKBOX_ITEM.captures(line) {
+        let state = caps.get(1).unwrap();
+        let text = caps.get(2).unwrap();
+        return CstNode::Checkbox {
+            state: Span::new(state.start(), state.end()).shift(base),
+            text: Span::new(text.start(), text.end()).shift(base),
+            section: *section,
+        };
+    }
+
+    if line.starts_with("**Tasks:**") {
+        *section = Some(CheckpointKind::Task);
+    } else if line.starts_with("**Tests:**") {
+        *section = Some(CheckpointKind::Test);
+    } else if line.starts_with("**Checkpoint:**") || line.starts_with("**Checkpoints:**") {
+        *section = Some(CheckpointKind::Checkpoint);
+    } else if line.starts_with("**") && line.contains(":**") {
+        *section = None;
+    }
+
+    CstNode::Text
+}
+
+/// Split a `| field | value |` row into spans; None for separator or malformed rows
+fn metadata_row(line: &str, base: usize) -> Option<CstNode> {
+    let pipes: Vec<usize> = line.match_indices('|').map(|(i, _)| i).take(3).collect();
+    if pipes.len() < 3 {
+        return None;
+    }
+    let field = trimmed(line, pipes[0] + 1, pipes[1]);
+    if field.text(line).contains("---") {
+        return None;
+    }
+    let cell = Span::new(pipes[1] + 1, pipes[2]);
+    let mut value = trimmed(line, cell.start, cell.end);
+    if value.is_empty() {
+        value = Span::new(cell.end, cell.end);
+    }
+    Some(CstNode::MetadataRow {
+        field: field.shift(base),
+        value: value.shift(base),
+        cell: cell.shift(base),
+    })
+}
+
+/// Span of `line[start..end]` with surrounding whitespace removed
+fn trimmed(line: &str, start: usize, end: usize) -> Span {
+    let slice = &line[start..end];
+    let lead = slice.len() - slice.trim_start().len();
+    let trail = slice.len() - slice.trim_end().len();
+    if lead == slice.len() {
+        return Span::new(start, start);
+    }
+    Span::new(start + lead, end - trail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"## Phase 1.0: Test {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | draft |
+| Last updated | 2026-02-03 |
+
+---
+
+#### Step 0: Setup {#step-0}
+
+**Commit:** `feat: setup`
+
+**Tasks:**
+- [ ] Task one
+- [X] Task two
+
+**Checkpoint:**
+- [ ] Build passes
+
+---
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0
+
+**Bead:** `bd-old1`
+
+**Commit:** `feat: build`
+
+```markdown
+#### Step 9: Not a step {#step-9}
+- [ ] not a checkbox
+```
+"#;
+
+    fn kinds(tree: &SpeckTree) -> Vec<&CstNode> {
+        tree.lines().iter().map(|l| &l.node).collect()
+    }
+
+    #[test]
+    fn test_round_trip_is_byte_identical() {
+        for source in [
+            SAMPLE,
+            "",
+            "no newline at end",
+            "line one\r\n\r\n#### Step 0: X {#step-0}\r\n",
+            "trailing\n\n\n",
+        ] {
+            let tree = SpeckTree::parse(source);
+            assert_eq!(tree.as_str(), source);
+            let rebuilt: String = tree
+                .lines()
+                .iter()
+                .map(|l| format!("{}{}", tree.text(l.span), tree.text(l.eol)))
+                .collect();
+            assert_eq!(rebuilt, source);
+        }
+    }
+
+    #[test]
+    fn test_node_spans() {
+        let tree = SpeckTree::parse(SAMPLE);
+
+        let heading = tree
+            .lines()
+            .iter()
+            .find_map(|l| match l.node {
+                CstNode::Heading {
+                    level: 4,
+                    title,
+                    anchor,
+                } => Some((title, anchor)),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(tree.text(heading.0), "Step 0: Setup");
+        assert_eq!(tree.text(heading.1.unwrap()), "step-0");
+
+        let owner = tree.metadata_row("owner").unwrap();
+        match tree.lines()[owner].node {
+            CstNode::MetadataRow { field, value, .. } => {
+                assert_eq!(tree.text(field), "Owner");
+                assert_eq!(tree.text(value), "Test");
+            }
+            ref other => panic!("expected metadata row, got {:?}", other),
+        }
+
+        let deps = kinds(&tree)
+            .into_iter()
+            .find_map(|n| match n {
+                CstNode::DependsOn { refs } => Some(refs.clone()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(tree.text(deps[0]), "step-0");
+
+        let bead = kinds(&tree)
+            .into_iter()
+            .find_map(|n| match n {
+                CstNode::Bead { id } => Some(*id),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(tree.text(bead), "bd-old1");
+    }
+
+    #[test]
+    fn test_checkbox_sections() {
+        let tree = SpeckTree::parse(SAMPLE);
+        let boxes: Vec<_> = tree
+            .lines()
+            .iter()
+            .filter_map(|l| match l.node {
+                CstNode::Checkbox {
+                    state,
+                    text,
+                    section,
+                } => Some((tree.text(state), tree.text(text), section)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            boxes,
+            vec![
+                (" ", "Task one", Some(CheckpointKind::Task)),
+                ("X", "Task two", Some(CheckpointKind::Task)),
+                (" ", "Build passes", Some(CheckpointKind::Checkpoint)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_blocks_are_opaque() {
+        let tree = SpeckTree::parse(SAMPLE);
+        assert!(tree.step("step-9").is_none());
+        let anchors: Vec<_> = tree.steps().into_iter().map(|s| s.anchor).collect();
+        assert_eq!(anchors, vec!["step-0", "step-1"]);
+    }
+
+    #[test]
+    fn test_step_blocks_end_at_rule_or_heading() {
+        let content =
+            "#### Step 1: A {#step-1}\n\ntext\n\n##### Step 1.1: B\n\nmore\n\n---\n\nafter\n";
+        let tree = SpeckTree::parse(content);
+        let steps = tree.steps();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].anchor, "step-1");
+        assert_eq!(steps[0].end, 4);
+        assert_eq!(steps[1].number, "1.1");
+        assert_eq!(steps[1].anchor, "step-1-1");
+        assert_eq!(tree.lines()[steps[1].end].node, CstNode::Rule);
+    }
+
+    #[test]
+    fn test_set_step_bead_replaces_existing() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        assert!(tree.set_step_bead("step-1", "bd-new1"));
+        assert_eq!(
+            tree.as_str(),
+            SAMPLE.replace("`bd-old1`", "`bd-new1`"),
+            "only the bead ID should change"
+        );
+    }
+
+    #[test]
+    fn test_set_step_bead_inserts_after_heading() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        assert!(tree.set_step_bead("step-0", "bd-abc"));
+        assert_eq!(
+            tree.as_str(),
+            SAMPLE.replace(
+                "{#step-0}\n\n**Commit:**",
+                "{#step-0}\n\n**Bead:** `bd-abc`\n\n**Commit:**"
+            )
+        );
+    }
+
+    #[test]
+    fn test_set_step_title_keeps_number_and_anchor() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        assert!(tree.set_step_title("step-1", "Build it all"));
+        assert!(!tree.set_step_title("step-1", "Build it all"));
+        assert!(!tree.set_step_title("step-7", "Missing"));
+        assert_eq!(
+            tree.as_str(),
+            SAMPLE.replace(
+                "#### Step 1: Build {#step-1}",
+                "#### Step 1: Build it all {#step-1}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_set_step_hint_inserts_updates_and_removes() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        assert!(tree.set_step_hint("step-1", "priority", Some("1")));
+        assert_eq!(
+            tree.as_str(),
+            SAMPLE.replace(
+                "**Bead:** `bd-old1`\n",
+                "**Bead:** `bd-old1`\n\n**Beads:** priority=1\n"
+            )
+        );
+
+        assert!(tree.set_step_hint("step-1", "labels", Some("api,backend")));
+        assert!(tree.set_step_hint("step-1", "priority", Some("3")));
+        assert!(!tree.set_step_hint("step-1", "priority", Some("3")));
+        assert!(
+            tree.as_str()
+                .contains("**Beads:** priority=3, labels=api,backend\n")
+        );
+
+        assert!(tree.set_step_hint("step-1", "priority", None));
+        assert!(tree.set_step_hint("step-1", "labels", None));
+        assert!(!tree.set_step_hint("step-1", "labels", None));
+        assert_eq!(tree.as_str(), SAMPLE);
+    }
+
+    #[test]
+    fn test_set_step_bead_inserts_after_depends_on() {
+        let content = "#### Step 1: B {#step-1}\n\n**Depends on:** #step-0\n**Commit:** `x`\n";
+        let mut tree = SpeckTree::parse(content);
+        assert!(tree.set_step_bead("step-1", "bd-1"));
+        assert_eq!(
+            tree.as_str(),
+            "#### Step 1: B {#step-1}\n\n**Depends on:** #step-0\n\n**Bead:** `bd-1`\n\n**Commit:** `x`\n"
+        );
+    }
+
+    #[test]
+    fn test_set_step_bead_default_anchor_and_crlf() {
+        let content = "#### Step 2: No anchor\r\n\r\ntext\r\n";
+        let mut tree = SpeckTree::parse(content);
+        assert!(tree.set_step_bead("step-2", "bd-2"));
+        assert_eq!(
+            tree.as_str(),
+            "#### Step 2: No anchor\r\n\r\n**Bead:** `bd-2`\r\n\r\ntext\r\n"
+        );
+    }
+
+    #[test]
+    fn test_set_step_bead_missing_step() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        assert!(!tree.set_step_bead("step-42", "bd-1"));
+        assert_eq!(tree.as_str(), SAMPLE);
+    }
+
+    #[test]
+    fn test_set_beads_root_appends_row() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        assert!(tree.set_beads_root("bd-root"));
+        assert_eq!(
+            tree.as_str(),
+            SAMPLE.replace(
+                "| Last updated | 2026-02-03 |\n",
+                "| Last updated | 2026-02-03 |\n| Beads Root | `bd-root` |\n"
+            )
+        );
+        // Updating again rewrites the value rather than adding another row
+        assert!(tree.set_beads_root("bd-root2"));
+        assert_eq!(tree.as_str().matches("Beads Root").count(), 1);
+        assert!(tree.as_str().contains("| Beads Root | `bd-root2` |"));
+    }
+
+    #[test]
+    fn test_set_beads_root_replaces_placeholder_and_empty_cell() {
+        let placeholder = "| Field | Value |\n|---|---|\n| Beads Root | *(optional)* |\n";
+        let mut tree = SpeckTree::parse(placeholder);
+        assert!(tree.set_beads_root("bd-r"));
+        assert_eq!(
+            tree.as_str(),
+            "| Field | Value |\n|---|---|\n| Beads Root | `bd-r` |\n"
+        );
+
+        let empty = "| Field | Value |\n|---|---|\n| Beads Root ||\n";
+        let mut tree = SpeckTree::parse(empty);
+        assert!(tree.set_beads_root("bd-r"));
+        assert_eq!(
+            tree.as_str(),
+            "| Field | Value |\n|---|---|\n| Beads Root | `bd-r` |\n"
+        );
+    }
+
+    #[test]
+    fn test_set_beads_root_without_metadata_table() {
+        let mut tree = SpeckTree::parse("## Phase 1.0: X\n");
+        assert!(!tree.set_beads_root("bd-r"));
+        assert_eq!(tree.as_str(), "## Phase 1.0: X\n");
+    }
+
+    #[test]
+    fn test_check_step_boxes_by_section() {
+        let mut tree = SpeckTree::parse(SAMPLE);
+        let count = tree.check_step_boxes("step-0", |s| s == Some(CheckpointKind::Checkpoint));
+        assert_eq!(count, 1);
+        assert_eq!(
+            tree.as_str(),
+            SAMPLE.replace("- [ ] Build passes", "- [x] Build passes")
+        );
+
+        let count = tree.check_step_boxes("step-0", |_| true);
+        assert_eq!(count, 1);
+        assert!(tree.as_str().contains("- [x] Task one"));
+        assert!(tree.as_str().contains("- [X] Task two"));
+        assert!(tree.as_str().contains("- [ ] not a checkbox"));
+    }
+
+    #[test]
+    fn test_check_step_boxes_leaves_marked_items() {
+        let content = "#### Step 0: X {#step-0}\n\n**Checkpoint:**\n- [-] Skipped -- n/a\n- [~] Blocked\n- [!] Failed\n- [ ] Open\n";
+        let mut tree = SpeckTree::parse(content);
+        assert_eq!(tree.check_step_boxes("step-0", |_| true), 1);
+        assert_eq!(tree.as_str(), content.replace("- [ ] Open", "- [x] Open"));
+    }
+
+    #[test]
+    fn test_set_checkbox() {
+        let mut tree = SpeckTree::parse("- [x] done\n- [ ] todo\n");
+        assert!(tree.set_checkbox(0, false));
+        assert!(!tree.set_checkbox(1, false));
+        assert!(!tree.set_checkbox(5, true));
+        assert_eq!(tree.as_str(), "- [ ] done\n- [ ] todo\n");
+    }
+}
@@ -193,6 +193,33 @@ pub enum SpecksError {
     /// E037: Init failed during worktree creation
     #[error("E037: Init failed: {reason}")]
     InitFailed { reason: String },
+
+    // === Formatting errors (E038) ===
+    /// E038: Formatting would change the parsed speck
+    #[error("E038: Formatting would change speck semantics: {reason}")]
+    FormatChangedSemantics { reason: String },
+
+    // === Scaffolding errors (E040-E043) ===
+    /// E040: Speck name does not match the configured naming pattern
+    #[error("E040: Invalid speck name: {name} (must match {pattern})")]
+    InvalidSpeckName { name: String, pattern: String },
+
+    /// E041: Template uses variables that have no value
+    #[error("E041: Template variables not set: {names}")]
+    TemplateVariableMissing { names: String },
+
+    /// E042: Template not found
+    #[error("E042: Template not found: {name}")]
+    TemplateNotFound { name: String },
+
+    /// E043: Speck file already exists
+    #[error("E043: Speck already exists: {path}")]
+    SpeckAlreadyExists { path: String },
+
+    // === Baseline errors (E044) ===
+    /// E044: Validation baseline file cannot be read or parsed
+    #[error("E044: Invalid validation baseline {path}: {reason}")]
+    InvalidBaseline { path: String, reason: String },
 }
 
 impl SpecksError {
@@ -239,6 +266,12 @@ impl SpecksError {
             SpecksError::BeadsSyncFailed { .. } => "E035",
             SpecksError::BeadCommitFailed { .. } => "E036",
             SpecksError::InitFailed { .. } => "E037",
+            SpecksError::FormatChangedSemantics { .. } => "E038",
+            SpecksError::InvalidSpeckName { .. } => "E040",
+            SpecksError::TemplateVariableMissing { .. } => "E041",
+            SpecksError::TemplateNotFound { .. } => "E042",
+            SpecksError::SpeckAlreadyExists { .. } => "E043",
+            SpecksError::InvalidBaseline { .. } => "E044",
         }
     }
 
@@ -317,6 +350,12 @@ impl SpecksError {
             SpecksError::BeadsSyncFailed { .. } => 10,   // Beads sync failed (exit code 10 per S02)
             SpecksError::BeadCommitFailed { .. } => 11, // Bead commit failed (exit code 11 per S02)
             SpecksError::InitFailed { .. } => 12,       // Init failed (exit code 12)
+            SpecksError::FormatChangedSemantics { .. } => 1, // Formatter refused to rewrite
+            SpecksError::InvalidSpeckName { .. } => 1,  // Name fails naming.name_pattern
+            SpecksError::TemplateVariableMissing { .. } => 1, // Unset template variable
+            SpecksError::TemplateNotFound { .. } => 2,  // Template file errors
+            SpecksError::SpeckAlreadyExists { .. } => 3, // Refuses to overwrite
+            SpecksError::InvalidBaseline { .. } => 4,   // Like a configuration error
         }
     }
 }
@@ -0,0 +1,421 @@
+//! Estimate rollups and actual time spent
+//!
+//! Steps are estimated with `estimate_minutes` in their `**Beads:**` hints; a
+//! step without one counts the sum of its substeps' estimates. Estimates roll
+//! up over the whole speck, over the unfinished steps, and along the critical
+//! path of the step dependencies.
+//!
+//! Actual time comes from completion times: implementation log entry dates
+//! and bead close times. Steps are implemented one after another, so a step
+//! took the time from the previous completion in the speck to its own; the
+//! first completed step has no known start.
+
+use std::collections::HashMap;
+
+use crate::graph::{CriticalPath, StepGraph};
+use crate::session::parse_iso8601;
+use crate::types::Speck;
+
+/// One entry of the implementation log
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Step anchor as logged (e.g., "#step-3")
+    pub step: String,
+    /// Entry date (ISO 8601)
+    pub date: String,
+    /// Bead ID, if one was recorded
+    pub bead: Option<String>,
+    /// Paths listed under "Files changed"
+    pub files: Vec<String>,
+}
+
+/// Parse the entries of an implementation log, in file order
+///
+/// Entries start with a frontmatter block holding at least `step:` and
+/// `date:`; anything else in the log is skipped.
+pub fn parse_log_entries(content: &str) -> Vec<LogEntry> {
+    let lines: Vec<&str> = content.lines().collect();
+    let starts_entry = |i: usize| {
+        lines[i].trim() == "---" && lines.get(i + 1).is_some_and(|l| l.starts_with("step:"))
+    };
+
+    let mut entries = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if !starts_entry(i) {
+            i += 1;
+            continue;
+        }
+
+        let mut entry = LogEntry::default();
+        i += 1;
+        while i < lines.len() && lines[i].trim() != "---" {
+            if let Some((key, value)) = lines[i].split_once(':') {
+                let value = value.trim().to_string();
+                match key.trim() {
+                    "step" => entry.step = value,
+                    "date" => entry.date = value,
+                    "bead" => entry.bead = Some(value),
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+        i += 1;
+
+        let mut in_files = false;
+        while i < lines.len() && !starts_entry(i) {
+            let line = lines[i].trim();
+            if line == "**Files changed:**" {
+                in_files = true;
+            } else if let Some(file) = line.strip_prefix("- ").filter(|_| in_files) {
+                entry.files.push(file.to_string());
+            } else if !line.is_empty() {
+                in_files = false;
+            }
+            i += 1;
+        }
+
+        if !entry.step.is_empty() && !entry.date.is_empty() {
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+/// Completion times of a speck's steps from implementation log entries, in
+/// seconds since the Unix epoch keyed by step anchor (without `#`)
+///
+/// An entry counts when it records the bead of a step, or when it names a
+/// step's anchor and lists `speck_file` (the speck's file name) among the
+/// changed files. A step logged more than once takes its latest entry.
+pub fn log_completions(
+    speck: &Speck,
+    speck_file: &str,
+    entries: &[LogEntry],
+) -> HashMap<String, i64> {
+    let mut completions: HashMap<String, i64> = HashMap::new();
+    for entry in entries {
+        let Some(time) = parse_iso8601(&entry.date) else {
+            continue;
+        };
+        let names_speck = entry.files.iter().any(|f| {
+            f.trim_matches('`')
+                .rsplit(['/', '\\'])
+                .next()
+                .is_some_and(|name| name == speck_file)
+        });
+        let step = speck.steps.iter().find(|step| {
+            let same_bead = entry.bead.is_some() && entry.bead == step.bead_id;
+            same_bead || (names_speck && entry.step.trim_start_matches('#') == step.anchor)
+        });
+        if let Some(step) = step {
+            let latest = completions.entry(step.anchor.clone()).or_insert(time);
+            *latest = (*latest).max(time);
+        }
+    }
+    completions
+}
+
+/// Completion times of a speck's steps from bead close times
+/// (`bead ID -> ISO 8601`), keyed by step anchor (without `#`)
+pub fn bead_completions(
+    speck: &Speck,
+    closed_at: &HashMap<String, String>,
+) -> HashMap<String, i64> {
+    speck
+        .steps
+        .iter()
+        .filter_map(|step| {
+            let closed = closed_at.get(step.bead_id.as_ref()?)?;
+            Some((step.anchor.clone(), parse_iso8601(closed)?))
+        })
+        .collect()
+}
+
+/// Estimate and actual time of one step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepTime {
+    /// Step anchor, without `#`
+    pub anchor: String,
+    /// The step's estimate, or the sum of its substeps' estimates
+    pub estimate_minutes: Option<u32>,
+    /// Whether the step is complete (all items checked, or a known completion)
+    pub done: bool,
+    /// Completion time, in seconds since the Unix epoch
+    pub completed_at: Option<i64>,
+    /// Minutes since the previous completion in the speck
+    pub actual_minutes: Option<u32>,
+}
+
+/// Estimates and actual time of a whole speck
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EstimateRollup {
+    /// Per step, in document order
+    pub steps: Vec<StepTime>,
+    /// Sum of all step estimates
+    pub total_minutes: u32,
+    /// Sum of the estimates of unfinished steps
+    pub remaining_minutes: u32,
+    /// Steps without an estimate
+    pub unestimated_steps: usize,
+    /// Critical path through all steps
+    pub critical_path: Option<CriticalPath>,
+    /// Critical path through the unfinished steps
+    pub remaining_critical_path: Option<CriticalPath>,
+}
+
+impl EstimateRollup {
+    /// Whether any step has an estimate
+    pub fn has_estimates(&self) -> bool {
+        self.steps.iter().any(|s| s.estimate_minutes.is_some())
+    }
+
+    /// Actual and estimated minutes over the steps that have both
+    pub fn actual_vs_estimate(&self) -> Option<(u32, u32)> {
+        let timed: Vec<(u32, u32)> = self
+            .steps
+            .iter()
+            .filter_map(|s| Some((s.actual_minutes?, s.estimate_minutes?)))
+            .collect();
+        if timed.is_empty() {
+            return None;
+        }
+        Some(timed.iter().fold((0, 0), |(a, e), (actual, estimate)| {
+            (a + actual, e + estimate)
+        }))
+    }
+
+    /// Actual time over estimated time so far (above 1.0 means slower)
+    pub fn pace(&self) -> Option<f64> {
+        let (actual, estimate) = self.actual_vs_estimate()?;
+        (estimate > 0).then(|| f64::from(actual) / f64::from(estimate))
+    }
+
+    /// Expected completion time: `now` plus the remaining critical path,
+    /// scaled by the pace so far; `None` when nothing estimated remains
+    pub fn eta(&self, now: i64) -> Option<i64> {
+        let minutes = self.remaining_critical_path.as_ref()?.minutes;
+        if minutes == 0 {
+            return None;
+        }
+        let scaled = f64::from(minutes) * self.pace().unwrap_or(1.0);
+        Some(now + (scaled * 60.0).round() as i64)
+    }
+}
+
+/// Roll up the estimates of a speck, with actual time from `completions`
+/// (seconds since the Unix epoch keyed by step anchor)
+pub fn rollup_estimates(speck: &Speck, completions: &HashMap<String, i64>) -> EstimateRollup {
+    let graph = StepGraph::from_speck(speck);
+    let mut steps: Vec<StepTime> = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            let completed_at = completions.get(&node.step.anchor).copied();
+            StepTime {
+                anchor: node.step.anchor.clone(),
+                estimate_minutes: node.estimate_minutes,
+                done: node.step.is_complete() || completed_at.is_some(),
+                completed_at,
+                actual_minutes: None,
+            }
+        })
+        .collect();
+
+    let mut finished: Vec<(i64, usize)> = steps
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| Some((s.completed_at?, i)))
+        .collect();
+    finished.sort();
+    for pair in finished.windows(2) {
+        let ((previous, _), (time, index)) = (pair[0], pair[1]);
+        let minutes = ((time - previous) as f64 / 60.0).round();
+        steps[index].actual_minutes = Some(minutes as u32);
+    }
+
+    let done: Vec<bool> = steps.iter().map(|s| s.done).collect();
+    let sum = |filter: &dyn Fn(&StepTime) -> bool| -> u32 {
+        steps
+            .iter()
+            .filter(|s| filter(s))
+            .filter_map(|s| s.estimate_minutes)
+            .sum()
+    };
+
+    EstimateRollup {
+        total_minutes: sum(&|_| true),
+        remaining_minutes: sum(&|s| !s.done),
+        unestimated_steps: steps
+            .iter()
+            .filter(|s| s.estimate_minutes.is_none())
+            .count(),
+        critical_path: graph.critical_path(),
+        remaining_critical_path: graph.remaining_critical_path(&done),
+        steps,
+    }
+}
+
+/// Format minutes as a short duration ("45m", "2h", "1h 30m")
+pub fn format_duration(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_speck;
+
+    const SPECK: &str = r#"## Phase 1.0: Estimates {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Bead:** `bd-est.1`
+
+**Beads:** estimate_minutes=30
+
+**Tasks:**
+- [x] Create module
+
+#### Step 1: Model {#step-1}
+
+**Depends on:** #step-0
+
+**Beads:** estimate_minutes=60
+
+**Tasks:**
+- [x] Add model
+
+#### Step 2: Api {#step-2}
+
+**Depends on:** #step-1
+
+**Beads:** estimate_minutes=90
+
+**Tasks:**
+- [ ] Add endpoints
+
+#### Step 3: Docs {#step-3}
+
+**Depends on:** #step-0
+
+**Tasks:**
+- [ ] Write docs
+"#;
+
+    const LOG: &str = r#"# Specks Implementation Log
+
+Entries are sorted newest-first.
+
+---
+
+---
+step: #step-1
+date: 2026-02-13T03:30:00Z
+---
+
+## #step-1: Model
+
+**Files changed:**
+- .specks/specks-est.md
+
+---
+
+---
+step: #step-1
+date: 2026-02-13T02:00:00Z
+---
+
+## #step-1: Model (other speck)
+
+**Files changed:**
+- .specks/specks-other.md
+
+---
+
+---
+step: #step-0
+date: 2026-02-13T02:00:00Z
+bead: bd-est.1
+---
+
+## #step-0: Setup
+
+---
+"#;
+
+    #[test]
+    fn test_parse_log_entries() {
+        let entries = parse_log_entries(LOG);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].step, "#step-1");
+        assert_eq!(entries[0].date, "2026-02-13T03:30:00Z");
+        assert_eq!(entries[0].files, vec![".specks/specks-est.md"]);
+        assert_eq!(entries[2].bead.as_deref(), Some("bd-est.1"));
+        assert!(entries[2].files.is_empty());
+    }
+
+    #[test]
+    fn test_log_completions_match_speck_or_bead() {
+        let speck = parse_speck(SPECK).unwrap();
+        let completions = log_completions(&speck, "specks-est.md", &parse_log_entries(LOG));
+        assert_eq!(completions.len(), 2);
+        assert_eq!(
+            completions["step-0"],
+            parse_iso8601("2026-02-13T02:00:00Z").unwrap()
+        );
+        assert_eq!(
+            completions["step-1"],
+            parse_iso8601("2026-02-13T03:30:00Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bead_completions() {
+        let speck = parse_speck(SPECK).unwrap();
+        let closed_at = HashMap::from([
+            ("bd-est.1".to_string(), "2026-02-13T02:00:00Z".to_string()),
+            ("bd-est.9".to_string(), "2026-02-13T04:00:00Z".to_string()),
+        ]);
+        let completions = bead_completions(&speck, &closed_at);
+        assert_eq!(completions.len(), 1);
+        assert!(completions.contains_key("step-0"));
+    }
+
+    #[test]
+    fn test_rollup_estimates() {
+        let speck = parse_speck(SPECK).unwrap();
+        let completions = log_completions(&speck, "specks-est.md", &parse_log_entries(LOG));
+        let rollup = rollup_estimates(&speck, &completions);
+
+        assert!(rollup.has_estimates());
+        assert_eq!(rollup.total_minutes, 180);
+        assert_eq!(rollup.remaining_minutes, 90);
+        assert_eq!(rollup.unestimated_steps, 1);
+        assert_eq!(rollup.critical_path.as_ref().unwrap().steps, vec![0, 1, 2]);
+        assert_eq!(rollup.critical_path.as_ref().unwrap().minutes, 180);
+        let remaining = rollup.remaining_critical_path.as_ref().unwrap();
+        assert_eq!(remaining.steps, vec![2]);
+        assert_eq!(remaining.minutes, 90);
+
+        // Step 0 has no known start; Step 1 took 90 minutes against 60
+        assert_eq!(rollup.steps[0].actual_minutes, None);
+        assert_eq!(rollup.steps[1].actual_minutes, Some(90));
+        assert_eq!(rollup.actual_vs_estimate(), Some((90, 60)));
+        assert_eq!(rollup.pace(), Some(1.5));
+        assert_eq!(rollup.eta(0), Some(135 * 60));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(45), "45m");
+        assert_eq!(format_duration(120), "2h");
+        assert_eq!(format_duration(90), "1h 30m");
+    }
+}
@@ -0,0 +1,702 @@
+//! Autofix for mechanically fixable diagnostics
+//!
+//! [`fix_speck`] applies fixes that need no judgment:
+//! - P001: step headers get a capital `Step`, a colon, 3-6 `#`s and an anchor
+//! - P005/E005: invalid anchors are normalized (`{#Step_0}` becomes `{#step-0}`)
+//!   and every `#anchor` reference in the file is renamed with them
+//! - P007: malformed commit lines become ``**Commit:** `message` ``
+//! - W009: steps without a commit line get one derived from the step title
+//! - W006: a placeholder `Last updated` date is set to today
+//!
+//! Code blocks are left untouched. A rename is skipped if the normalized
+//! anchor is already taken.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::cst::{CstLine, CstNode, SpeckTree};
+use crate::error::SpecksError;
+use crate::parser::{parse_speck, patterns};
+
+/// Step number and title inside a near-miss step heading ("step 1 setup")
+static LOOSE_STEP_TITLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^step\s+(\d+(?:\.\d+)*)\s*:?\s*(.*)$").unwrap());
+
+/// Metadata placeholder value, e.g. `<YYYY-MM-DD>`
+static PLACEHOLDER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^<[^>]+>$").unwrap());
+
+/// A single fix applied to a speck
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fix {
+    /// Diagnostic or rule code the fix resolves (e.g., "P001")
+    pub code: String,
+    /// Line number (1-indexed) in the original file
+    pub line: usize,
+    /// What was changed
+    pub message: String,
+}
+
+/// Result of [`fix_speck`]
+#[derive(Debug, Clone, Default)]
+pub struct FixResult {
+    /// The fixed content (equal to the input if nothing was fixed)
+    pub content: String,
+    /// Fixes applied, in line order
+    pub fixes: Vec<Fix>,
+}
+
+impl FixResult {
+    /// Whether any fix was applied
+    pub fn changed(&self) -> bool {
+        !self.fixes.is_empty()
+    }
+}
+
+/// Apply all safe fixes to a speck
+///
+/// `today` (`YYYY-MM-DD`) fills a placeholder `Last updated` field. Edits go
+/// through [`SpeckTree`], so code blocks and line endings are treated exactly
+/// as the parser treats them.
+pub fn fix_speck(content: &str, today: &str) -> Result<FixResult, SpecksError> {
+    let mut tree = SpeckTree::parse(content);
+    let mut fixes = Vec::new();
+
+    // Anchor renames first, so header fixes see the final anchor names
+    let renames = anchor_renames(&tree, &mut fixes);
+    if !renames.is_empty() {
+        let edits = prose_lines(&tree)
+            .filter_map(|line| {
+                let text = tree.text(line.span);
+                let renamed = rename_references(text, &renames);
+                (renamed != text).then_some((line.span, renamed))
+            })
+            .collect();
+        tree.replace_all(edits);
+    }
+
+    let mut defined: HashSet<String> = prose_lines(&tree)
+        .flat_map(|line| {
+            patterns::ANCHOR
+                .captures_iter(tree.text(line.span))
+                .map(|c| c[1].to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut edits = Vec::new();
+    for line in prose_lines(&tree) {
+        let text = tree.text(line.span);
+        let fixed = match line.node {
+            CstNode::MetadataRow { field, value, .. } => {
+                fix_last_updated(tree.text(field), tree.text(value), today)
+                    .map(|f| (value, f, "W006", "Set Last updated to today"))
+            }
+            CstNode::Heading {
+                level,
+                title,
+                anchor,
+            } if patterns::NEAR_MISS_STEP.is_match(text)
+                && !patterns::STEP_HEADER.is_match(text) =>
+            {
+                let anchor = anchor.map(|a| tree.text(a));
+                fix_step_header(level, tree.text(title), anchor, &mut defined)
+                    .map(|f| (line.span, f, "P001", "Normalized step header"))
+            }
+            CstNode::Text
+                if patterns::NEAR_MISS_COMMIT.is_match(text)
+                    && !patterns::COMMIT_LINE.is_match(text) =>
+            {
+                fix_commit_line(text).map(|f| (line.span, f, "P007", "Normalized commit line"))
+            }
+            _ => None,
+        };
+
+        if let Some((span, new_text, code, message)) = fixed {
+            let mut new_line = text.to_string();
+            new_line.replace_range(
+                span.start - line.span.start..span.end - line.span.start,
+                &new_text,
+            );
+            fixes.push(Fix {
+                code: code.to_string(),
+                line: line.number,
+                message: format!("{}: {} -> {}", message, text.trim(), new_line.trim()),
+            });
+            edits.push((span, new_text));
+        }
+    }
+    tree.replace_all(edits);
+
+    insert_missing_commit_lines(&mut tree, &mut fixes)?;
+
+    fixes.sort_by_key(|f| f.line);
+    Ok(FixResult {
+        content: tree.into_string(),
+        fixes,
+    })
+}
+
+/// Lines outside fenced code blocks
+fn prose_lines(tree: &SpeckTree) -> impl Iterator<Item = &CstLine> {
+    tree.lines()
+        .iter()
+        .filter(|line| line.node != CstNode::Code)
+}
+
+/// Normalize an anchor name: lowercase, `_` and spaces become `-`
+fn normalize_anchor(name: &str) -> String {
+    let mut out = String::new();
+    for c in name.trim().chars() {
+        let c = match c {
+            '_' | ' ' | '.' => '-',
+            c => c.to_ascii_lowercase(),
+        };
+        if c.is_ascii_lowercase() || c.is_ascii_digit() || (c == '-' && !out.ends_with('-')) {
+            out.push(c);
+        }
+    }
+    out.trim_matches('-').to_string()
+}
+
+/// Collect `old -> new` renames for invalid anchors (P005/E005)
+fn anchor_renames(tree: &SpeckTree, fixes: &mut Vec<Fix>) -> Vec<(String, String)> {
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut invalid: Vec<(String, usize)> = Vec::new();
+
+    for line in prose_lines(tree) {
+        for cap in patterns::INVALID_ANCHOR.captures_iter(tree.text(line.span)) {
+            let name = cap[1].to_string();
+            if patterns::VALID_ANCHOR.is_match(&name) {
+                taken.insert(name);
+            } else if !invalid.iter().any(|(n, _)| *n == name) {
+                invalid.push((name, line.number));
+            }
+        }
+    }
+
+    let mut renames = Vec::new();
+    for (old, line) in invalid {
+        let new = normalize_anchor(&old);
+        if new.is_empty() || !patterns::VALID_ANCHOR.is_match(&new) || !taken.insert(new.clone()) {
+            continue;
+        }
+        // Only a stray leading hyphen is an E005; anything else is P005
+        let code = if old
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            "E005"
+        } else {
+            "P005"
+        };
+        fixes.push(Fix {
+            code: code.to_string(),
+            line,
+            message: format!("Renamed anchor #{} to #{} (and its references)", old, new),
+        });
+        renames.push((old, new));
+    }
+    renames
+}
+
+/// Rename `#old` to `#new` wherever it is a whole reference in this file
+///
+/// `other.md#old` points into another file and is left alone.
+fn rename_references(line: &str, renames: &[(String, String)]) -> String {
+    let mut line = line.to_string();
+    for (old, new) in renames {
+        let needle = format!("#{}", old);
+        let mut out = String::with_capacity(line.len());
+        let mut rest = line.as_str();
+        let mut consumed = 0;
+        while let Some(pos) = rest.find(&needle) {
+            let start = consumed + pos;
+            let end = start + needle.len();
+            let before = line[..start].chars().next_back();
+            let after = line[end..].chars().next();
+            let whole = !before.is_some_and(|c| c.is_ascii_alphanumeric() || "#._-".contains(c))
+                && !after.is_some_and(|c| c.is_ascii_alphanumeric() || "_-".contains(c));
+
+            out.push_str(&rest[..pos]);
+            if whole {
+                out.push('#');
+                out.push_str(new);
+            } else {
+                out.push_str(&needle);
+            }
+            rest = &rest[pos + needle.len()..];
+            consumed = end;
+        }
+        out.push_str(rest);
+        line = out;
+    }
+    line
+}
+
+/// P001: rewrite a near-miss step heading in strict form
+///
+/// `title` and `anchor` are the heading's parts as split by the tree.
+fn fix_step_header(
+    level: usize,
+    title: &str,
+    anchor: Option<&str>,
+    defined: &mut HashSet<String>,
+) -> Option<String> {
+    let caps = LOOSE_STEP_TITLE.captures(title)?;
+    let number = &caps[1];
+    let title = caps[2].trim();
+    // An anchor the rename left invalid stays in the title: leave it to a human
+    if title.is_empty() || title.contains("{#") {
+        return None;
+    }
+
+    let depth = number.matches('.').count();
+    let level = if (3..=6).contains(&level) {
+        level
+    } else {
+        (4 + depth).min(6)
+    };
+
+    let anchor = match anchor {
+        Some(anchor) => anchor.to_string(),
+        None => {
+            let anchor = format!("step-{}", number.replace('.', "-"));
+            if !defined.insert(anchor.clone()) {
+                return None;
+            }
+            anchor
+        }
+    };
+
+    let fixed = format!(
+        "{} Step {}: {} {{#{}}}",
+        "#".repeat(level),
+        number,
+        title,
+        anchor
+    );
+    patterns::STEP_HEADER.is_match(&fixed).then_some(fixed)
+}
+
+/// P007: rewrite a near-miss commit line in strict form
+fn fix_commit_line(text: &str) -> Option<String> {
+    let prefix = patterns::NEAR_MISS_COMMIT.find(text)?;
+    let message = text[prefix.end()..]
+        .trim_start_matches('*')
+        .trim()
+        .trim_matches('`')
+        .trim();
+    if message.is_empty() || message.contains('`') {
+        return None;
+    }
+    Some(format!("**Commit:** `{}`", message))
+}
+
+/// W006: the new value of a placeholder Last updated field
+fn fix_last_updated(field: &str, value: &str, today: &str) -> Option<String> {
+    (field.eq_ignore_ascii_case("last updated") && PLACEHOLDER.is_match(value))
+        .then(|| today.to_string())
+}
+
+/// W009: add a commit line to every step and substep without one
+///
+/// The line goes after the heading and any `**Depends on:**`, `**Bead:**`
+/// or comment lines that directly follow it.
+fn insert_missing_commit_lines(
+    tree: &mut SpeckTree,
+    fixes: &mut Vec<Fix>,
+) -> Result<(), SpecksError> {
+    let speck = parse_speck(tree.as_str())?;
+
+    let mut missing: Vec<(usize, String)> = Vec::new();
+    for step in &speck.steps {
+        if step.commit_message.is_none() {
+            missing.push((step.line, step.title.clone()));
+        }
+        for substep in step.all_substeps() {
+            if substep.commit_message.is_none() {
+                missing.push((substep.line, substep.title.clone()));
+            }
+        }
+    }
+    // Bottom-up, so the line numbers of steps still to do stay valid
+    missing.sort_by_key(|(line, _)| std::cmp::Reverse(*line));
+
+    for (line, title) in missing {
+        let Some(step) = tree
+            .steps()
+            .into_iter()
+            .find(|s| tree.lines()[s.heading].number == line)
+        else {
+            continue;
+        };
+
+        let mut after = step.heading;
+        for (idx, body) in tree.lines()[..step.end]
+            .iter()
+            .enumerate()
+            .skip(step.heading + 1)
+        {
+            let text = tree.text(body.span).trim();
+            match body.node {
+                CstNode::Blank => continue,
+                CstNode::DependsOn { .. } | CstNode::Bead { .. } => after = idx,
+                CstNode::Text if text.starts_with("<!--") || text.starts_with("**Beads:**") => {
+                    after = idx
+                }
+                _ => break,
+            }
+        }
+
+        let commit = format!("**Commit:** `{}`", commit_message_for(&title));
+        tree.insert_paragraph_after(after, &commit);
+        fixes.push(Fix {
+            code: "W009".to_string(),
+            line,
+            message: format!("Added {}", commit),
+        });
+    }
+    Ok(())
+}
+
+/// Conventional commit message for a step title ("Add Login" -> "feat: add login")
+fn commit_message_for(title: &str) -> String {
+    let mut chars = title.chars();
+    let subject: String = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+    format!("feat: {}", subject.replace('`', ""))
+}
+
+/// Render a unified diff between two versions of a file
+///
+/// Returns an empty string if the contents are equal.
+pub fn unified_diff(path: &str, before: &str, after: &str) -> String {
+    const CONTEXT: usize = 3;
+
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    if old == new {
+        return String::new();
+    }
+
+    let ops = edit_script(&old, &new);
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let mut idx = 0;
+    while idx < ops.len() {
+        let Some(first_change) = ops[idx..].iter().position(|op| op.0 != ' ') else {
+            break;
+        };
+        let start = (idx + first_change).saturating_sub(CONTEXT).max(idx);
+
+        // Extend the hunk until a run of more than 2 * CONTEXT unchanged lines
+        let mut end = idx + first_change;
+        let mut unchanged = 0;
+        let mut k = end;
+        while k < ops.len() {
+            if ops[k].0 == ' ' {
+                unchanged += 1;
+                if unchanged > 2 * CONTEXT {
+                    break;
+                }
+            } else {
+                unchanged = 0;
+                end = k;
+            }
+            k += 1;
+        }
+        let end = (end + CONTEXT + 1).min(ops.len());
+
+        let hunk = &ops[start..end];
+        let old_count = hunk.iter().filter(|op| op.0 != '+').count();
+        let new_count = hunk.iter().filter(|op| op.0 != '-').count();
+        let old_start = if old_count == 0 {
+            hunk[0].1
+        } else {
+            hunk[0].1 + 1
+        };
+        let new_start = if new_count == 0 {
+            hunk[0].2
+        } else {
+            hunk[0].2 + 1
+        };
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start, old_count, new_start, new_count
+        ));
+        for &(op, i, j) in hunk {
+            let text = if op == '+' { new[j] } else { old[i] };
+            out.push(op);
+            out.push_str(text);
+            out.push('\n');
+        }
+        idx = end;
+    }
+    out
+}
+
+/// Shortest edit script between two line lists (Myers' algorithm)
+///
+/// Returns `(' ' | '-' | '+', old index, new index)` per line. The common
+/// prefix and suffix are matched up front, and the search keeps only the
+/// diagonals reached so far, so memory grows with the size of the change
+/// rather than with the product of the file lengths.
+fn edit_script(old: &[&str], new: &[&str]) -> Vec<(char, usize, usize)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+    let (n, m) = (a.len() as isize, b.len() as isize);
+
+    // Furthest x reached on each diagonal k = x - y, recorded before each round
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    'search: for d in 0..=max as isize {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let idx = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    // Walk back from the end, one round at a time
+    let mut middle: Vec<(char, usize, usize)> = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| v[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            (at(prev_k), at(prev_k) - prev_k)
+        };
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            middle.push((' ', x as usize, y as usize));
+        }
+        if d > 0 {
+            if x == prev_x {
+                middle.push(('+', x as usize, prev_y as usize));
+            } else {
+                middle.push(('-', prev_x as usize, y as usize));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    middle.reverse();
+
+    let mut ops: Vec<(char, usize, usize)> = (0..prefix).map(|i| (' ', i, i)).collect();
+    ops.extend(
+        middle
+            .into_iter()
+            .map(|(op, i, j)| (op, i + prefix, j + prefix)),
+    );
+    let (old_end, new_end) = (old.len() - suffix, new.len() - suffix);
+    ops.extend((0..suffix).map(|i| (' ', old_end + i, new_end + i)));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BROKEN: &str = r#"## Phase 1.0: Fixes {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | <YYYY-MM-DD> |
+
+### Design {#Design_Notes}
+
+See (#Design_Notes) and specks-other.md#Design_Notes.
+
+### step 0: setup {#step-0}
+
+commit: feat: setup
+
+**Tasks:**
+- [ ] Task
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0
+
+**References:** (#Design_Notes)
+
+**Tasks:**
+- [ ] Task
+
+```
+commit: not a real commit line {#Not_An_Anchor}
+```
+"#;
+
+    #[test]
+    fn test_fix_speck_applies_each_fix() {
+        let result = fix_speck(BROKEN, "2026-10-16").unwrap();
+        let codes: Vec<&str> = result.fixes.iter().map(|f| f.code.as_str()).collect();
+        assert_eq!(codes, vec!["W006", "P005", "P001", "P007", "W009"]);
+
+        let fixed = &result.content;
+        assert!(fixed.contains("| Last updated | 2026-10-16 |"));
+        assert!(fixed.contains("### Design {#design-notes}"));
+        assert!(fixed.contains("See (#design-notes) and specks-other.md#Design_Notes."));
+        assert!(fixed.contains("**References:** (#design-notes)"));
+        assert!(fixed.contains("### Step 0: setup {#step-0}"));
+        assert!(fixed.contains("**Commit:** `feat: setup`"));
+        assert!(
+            fixed.contains(
+                "**Depends on:** #step-0\n\n**Commit:** `feat: build`\n\n**References:**"
+            )
+        );
+        // Code blocks are untouched
+        assert!(fixed.contains("commit: not a real commit line {#Not_An_Anchor}"));
+
+        let speck = parse_speck(fixed).unwrap();
+        assert!(speck.diagnostics.is_empty(), "{:?}", speck.diagnostics);
+        assert_eq!(speck.steps.len(), 2);
+    }
+
+    #[test]
+    fn test_fix_speck_is_idempotent() {
+        let once = fix_speck(BROKEN, "2026-10-16").unwrap();
+        let twice = fix_speck(&once.content, "2026-10-17").unwrap();
+        assert!(!twice.changed(), "{:?}", twice.fixes);
+        assert_eq!(twice.content, once.content);
+    }
+
+    #[test]
+    fn test_fix_speck_skips_rename_onto_existing_anchor() {
+        let content = "### A {#design}\n\n### B {#Design}\n\nSee #Design.\n";
+        let result = fix_speck(content, "2026-10-16").unwrap();
+        assert!(!result.changed());
+        assert_eq!(result.content, content);
+    }
+
+    #[test]
+    fn test_fix_speck_follows_tree_on_crlf_and_code() {
+        let content = "## Phase 1.0: Fixes {#phase-1}\r\n\r\n#### step 0 Setup\r\n\r\n\
+                       ```rust\r\n\
+                       commit: kept as is\r\n\
+                       ```\r\n\r\n\
+                       commit: feat: setup\r\n";
+        let result = fix_speck(content, "2026-10-16").unwrap();
+        let codes: Vec<&str> = result.fixes.iter().map(|f| f.code.as_str()).collect();
+        assert_eq!(codes, vec!["P001", "P007"]);
+        assert_eq!(
+            result.content,
+            "## Phase 1.0: Fixes {#phase-1}\r\n\r\n#### Step 0: Setup {#step-0}\r\n\r\n\
+             ```rust\r\n\
+             commit: kept as is\r\n\
+             ```\r\n\r\n\
+             **Commit:** `feat: setup`\r\n"
+        );
+
+        let content = "#### Step 0: Setup {#step-0}\r\n\r\n**Tasks:**\r\n- [ ] Task\r\n";
+        let result = fix_speck(content, "2026-10-16").unwrap();
+        assert_eq!(
+            result.content,
+            "#### Step 0: Setup {#step-0}\r\n\r\n**Commit:** `feat: setup`\r\n\r\n\
+             **Tasks:**\r\n- [ ] Task\r\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_step_header_levels_and_anchor() {
+        let mut defined = HashSet::new();
+        assert_eq!(
+            fix_step_header(2, "Step 2.1 Sub", None, &mut defined).as_deref(),
+            Some("##### Step 2.1: Sub {#step-2-1}")
+        );
+        // Anchor already used elsewhere: leave the header for a human
+        assert_eq!(
+            fix_step_header(2, "step 2.1: Again", None, &mut defined),
+            None
+        );
+        assert_eq!(
+            fix_step_header(3, "step 3 Own", Some("own"), &mut defined).as_deref(),
+            Some("### Step 3: Own {#own}")
+        );
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let before = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let after = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+        let diff = unified_diff(".specks/specks-x.md", before, after);
+        assert_eq!(
+            diff,
+            "--- a/.specks/specks-x.md\n+++ b/.specks/specks-x.md\n\
+             @@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n\
+             @@ -8,3 +8,4 @@\n h\n i\n j\n+k\n"
+        );
+        assert!(unified_diff("x", before, before).is_empty());
+    }
+
+    #[test]
+    fn test_edit_script_is_minimal() {
+        let cases: [(&str, &str, usize); 5] = [
+            ("abcabba", "cbabac", 5),
+            ("", "abc", 3),
+            ("abc", "", 3),
+            ("abcdef", "abXdef", 2),
+            ("xaxbxc", "abc", 3),
+        ];
+        for (before, after, edits) in cases {
+            let old: Vec<&str> = before.split_terminator("").skip(1).collect();
+            let new: Vec<&str> = after.split_terminator("").skip(1).collect();
+            let ops = edit_script(&old, &new);
+            assert_eq!(ops.iter().filter(|op| op.0 != ' ').count(), edits);
+
+            // Replaying the script turns old into new
+            let mut replayed = Vec::new();
+            for &(op, i, j) in &ops {
+                match op {
+                    ' ' => {
+                        assert_eq!(old[i], new[j]);
+                        replayed.push(old[i]);
+                    }
+                    '+' => replayed.push(new[j]),
+                    _ => {}
+                }
+            }
+            assert_eq!(replayed, new, "{} -> {}", before, after);
+        }
+    }
+}
@@ -0,0 +1,440 @@
+//! Canonical formatting for speck files
+//!
+//! [`format_speck`] rewrites a speck into canonical form:
+//! - Plan Metadata table columns are padded to a common width
+//! - Headings use a single space after the `#`s and before the `{#anchor}`
+//! - `**Depends on:**` references follow step order in the document
+//! - Checkboxes are written `- [ ] text` / `- [x] text` (marks like `[-]` are kept)
+//! - `**Beads:**` hints use the order type, priority, labels, estimate_minutes
+//! - Headings and `---` rules are surrounded by exactly one blank line, and
+//!   runs of blank lines collapse to one
+//!
+//! Code blocks are left untouched. Formatting never changes meaning: the
+//! result is re-parsed and compared against the original, and
+//! [`SpecksError::FormatChangedSemantics`] is returned if they differ.
+
+use std::collections::HashMap;
+
+use crate::cst::{CstNode, SpeckTree};
+use crate::error::SpecksError;
+use crate::parser::{parse_speck, patterns, split_beads_hints};
+use crate::types::Speck;
+
+/// Canonical order of `**Beads:**` hint keys (unknown keys sort last)
+const HINT_KEY_ORDER: &[&str] = &["type", "priority", "labels", "estimate_minutes", "estimate"];
+
+/// How a formatted line participates in blank-line normalization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineClass {
+    Blank,
+    /// Heading or thematic break: wants a blank line on both sides
+    Block,
+    /// Inside a code fence: emitted verbatim
+    Code,
+    Other,
+}
+
+/// Format a speck into canonical form.
+///
+/// Returns an error if the formatted output would parse to a different speck.
+pub fn format_speck(content: &str) -> Result<String, SpecksError> {
+    let tree = SpeckTree::parse(content);
+    let formatted = join_lines(&format_lines(&tree), tree.line_ending());
+
+    let before = parse_speck(content)?;
+    let after = parse_speck(&formatted)?;
+    if semantic_view(&before)? != semantic_view(&after)? {
+        return Err(SpecksError::FormatChangedSemantics {
+            reason: "re-parsing the formatted speck produced a different result".to_string(),
+        });
+    }
+
+    Ok(formatted)
+}
+
+/// Whether `content` is already in canonical form
+pub fn is_formatted(content: &str) -> Result<bool, SpecksError> {
+    Ok(format_speck(content)? == content)
+}
+
+/// Produce the canonical text of each line, before blank-line normalization
+fn format_lines(tree: &SpeckTree) -> Vec<(String, LineClass)> {
+    let step_order: HashMap<String, usize> = tree
+        .steps()
+        .into_iter()
+        .enumerate()
+        .map(|(i, step)| (step.anchor, i))
+        .collect();
+
+    let lines = tree.lines();
+    let mut out = Vec::with_capacity(lines.len());
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = &lines[i];
+        let text = tree.text(line.span);
+
+        match &line.node {
+            CstNode::Code => out.push((text.to_string(), LineClass::Code)),
+            CstNode::Blank => out.push((String::new(), LineClass::Blank)),
+            CstNode::Rule => out.push(("---".to_string(), LineClass::Block)),
+            CstNode::Heading {
+                level,
+                title,
+                anchor,
+            } => {
+                let mut heading = format!("{} {}", "#".repeat(*level), tree.text(*title));
+                if let Some(anchor) = anchor {
+                    heading.push_str(&format!(" {{#{}}}", tree.text(*anchor)));
+                }
+                out.push((heading, LineClass::Block));
+            }
+            CstNode::Checkbox {
+                state, text: item, ..
+            } if !item.is_empty() => {
+                let indent = &text[..text.find('-').unwrap_or(0)];
+                let state = tree.text(*state).to_lowercase();
+                out.push((
+                    format!("{}- [{}] {}", indent, state, tree.text(*item)),
+                    LineClass::Other,
+                ));
+            }
+            CstNode::DependsOn { refs } => {
+                out.push((
+                    format_depends_on(tree, text, refs, &step_order),
+                    LineClass::Other,
+                ));
+            }
+            CstNode::Text if patterns::METADATA_HEADER.is_match(text) => {
+                let end = lines[i..]
+                    .iter()
+                    .position(|l| !tree.text(l.span).starts_with('|'))
+                    .map_or(lines.len(), |pos| i + pos);
+                let rows: Vec<&str> = lines[i..end].iter().map(|l| tree.text(l.span)).collect();
+                for row in format_table(&rows) {
+                    out.push((row, LineClass::Other));
+                }
+                i = end;
+                continue;
+            }
+            CstNode::Text if patterns::BEADS_HINTS.is_match(text) => {
+                out.push((format_beads_hints(text), LineClass::Other));
+            }
+            _ => out.push((text.to_string(), LineClass::Other)),
+        }
+        i += 1;
+    }
+
+    out
+}
+
+/// Collapse blank runs and put exactly one blank line around headings and rules
+fn join_lines(lines: &[(String, LineClass)], eol: &str) -> String {
+    let mut result: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut last: Option<LineClass> = None;
+    let mut need_blank = false;
+
+    for (text, class) in lines {
+        match class {
+            LineClass::Blank => {
+                if last.is_some_and(|c| c != LineClass::Blank) {
+                    result.push("");
+                    last = Some(LineClass::Blank);
+                }
+                continue;
+            }
+            LineClass::Block => {
+                if last.is_some_and(|c| c != LineClass::Blank) {
+                    result.push("");
+                }
+            }
+            LineClass::Code | LineClass::Other => {
+                if need_blank && last != Some(LineClass::Blank) {
+                    result.push("");
+                }
+            }
+        }
+        result.push(text);
+        need_blank = *class == LineClass::Block;
+        last = Some(*class);
+    }
+
+    while result.last() == Some(&"") {
+        result.pop();
+    }
+    if result.is_empty() {
+        return String::new();
+    }
+    let mut formatted = result.join(eol);
+    formatted.push_str(eol);
+    formatted
+}
+
+/// Align a two-column `| Field | Value |` table; other shapes are left as-is
+fn format_table(rows: &[&str]) -> Vec<String> {
+    let verbatim = || rows.iter().map(|r| r.to_string()).collect();
+
+    let mut cells: Vec<(String, String)> = Vec::with_capacity(rows.len());
+    let mut separator = None;
+    for (idx, row) in rows.iter().enumerate() {
+        let row = row.trim_end();
+        let Some(inner) = row.strip_prefix('|').and_then(|r| r.strip_suffix('|')) else {
+            return verbatim();
+        };
+        let parts: Vec<&str> = inner.split('|').map(str::trim).collect();
+        if parts.len() != 2 {
+            return verbatim();
+        }
+        if parts
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c == '-' || c == ':'))
+        {
+            separator = Some(idx);
+        }
+        cells.push((parts[0].to_string(), parts[1].to_string()));
+    }
+
+    let width = |f: fn(&(String, String)) -> &String| {
+        cells
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| Some(*idx) != separator)
+            .map(|(_, c)| f(c).chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(3)
+    };
+    let field_width = width(|c| &c.0);
+    let value_width = width(|c| &c.1);
+
+    cells
+        .iter()
+        .enumerate()
+        .map(|(idx, (field, value))| {
+            if Some(idx) == separator {
+                format!(
+                    "|{}|{}|",
+                    "-".repeat(field_width + 2),
+                    "-".repeat(value_width + 2)
+                )
+            } else {
+                format!("| {:<field_width$} | {:<value_width$} |", field, value)
+            }
+        })
+        .collect()
+}
+
+/// Rewrite a `**Depends on:**` line with refs in document step order.
+///
+/// Lines carrying anything besides comma-separated anchor refs are kept as-is.
+fn format_depends_on(
+    tree: &SpeckTree,
+    line: &str,
+    refs: &[crate::cst::Span],
+    step_order: &HashMap<String, usize>,
+) -> String {
+    let rest = line.trim_start_matches("**Depends on:**");
+    let leftover = patterns::ANCHOR_REF.replace_all(rest, "");
+    if refs.is_empty() || leftover.chars().any(|c| c != ',' && !c.is_whitespace()) {
+        return line.to_string();
+    }
+
+    let mut names: Vec<&str> = refs.iter().map(|r| tree.text(*r)).collect();
+    names.sort_by_key(|name| step_order.get(*name).copied().unwrap_or(usize::MAX));
+    let refs: Vec<String> = names.iter().map(|n| format!("#{}", n)).collect();
+    format!("**Depends on:** {}", refs.join(", "))
+}
+
+/// Rewrite a `**Beads:**` hints line with keys in canonical order
+fn format_beads_hints(line: &str) -> String {
+    let hints = line.trim_start_matches("**Beads:**");
+    let mut pairs = split_beads_hints(hints);
+    if pairs.is_empty() {
+        return line.to_string();
+    }
+    pairs.sort_by_key(|(key, _)| {
+        let key = key.to_lowercase();
+        HINT_KEY_ORDER
+            .iter()
+            .position(|k| *k == key)
+            .unwrap_or(HINT_KEY_ORDER.len())
+    });
+    let pairs: Vec<String> = pairs.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    format!("**Beads:** {}", pairs.join(", "))
+}
+
+/// The parts of a speck that formatting must preserve.
+///
+/// Line numbers and raw content are expected to change; dependency order is
+/// not semantically meaningful.
+fn semantic_view(speck: &Speck) -> Result<serde_json::Value, SpecksError> {
+    fn strip(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.remove("line");
+                map.remove("raw_content");
+                if let Some(serde_json::Value::Array(deps)) = map.get_mut("depends_on") {
+                    deps.sort_by_key(|d| d.to_string());
+                }
+                map.values_mut().for_each(strip);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(strip),
+            _ => {}
+        }
+    }
+
+    let mut value =
+        serde_json::to_value(speck).map_err(|e| SpecksError::FormatChangedSemantics {
+            reason: format!("could not compare the formatted speck: {}", e),
+        })?;
+    strip(&mut value);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSY: &str = r#"
+
+## Phase 1.0: Test   {#phase-1}
+**Purpose:** Test formatting.
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | draft |
+| Last updated | 2026-02-03 |
+---
+
+
+#### Step 0: Setup{#step-0}
+**Commit:** `feat: setup`
+
+**Tasks:**
+-  [X]   Task one
+- [ ] Task two
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-2,  #step-0
+
+**Beads:** labels=backend,api, estimate_minutes=30, type=task, priority=1
+
+```text
+-  [X]   left alone
+
+
+```
+
+#### Step 2: Ship {#step-2}
+
+**Depends on:** #step-0
+"#;
+
+    const CANONICAL: &str = r#"## Phase 1.0: Test {#phase-1}
+
+**Purpose:** Test formatting.
+
+### Plan Metadata {#plan-metadata}
+
+| Field        | Value      |
+|--------------|------------|
+| Owner        | Test       |
+| Status       | draft      |
+| Last updated | 2026-02-03 |
+
+---
+
+#### Step 0: Setup {#step-0}
+
+**Commit:** `feat: setup`
+
+**Tasks:**
+- [x] Task one
+- [ ] Task two
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0, #step-2
+
+**Beads:** type=task, priority=1, labels=backend,api, estimate_minutes=30
+
+```text
+-  [X]   left alone
+
+
+```
+
+#### Step 2: Ship {#step-2}
+
+**Depends on:** #step-0
+"#;
+
+    #[test]
+    fn test_format_produces_canonical_form() {
+        assert_eq!(format_speck(MESSY).unwrap(), CANONICAL);
+    }
+
+    #[test]
+    fn test_format_is_idempotent() {
+        assert_eq!(format_speck(CANONICAL).unwrap(), CANONICAL);
+        assert!(is_formatted(CANONICAL).unwrap());
+        assert!(!is_formatted(MESSY).unwrap());
+    }
+
+    #[test]
+    fn test_format_preserves_speck() {
+        let before = parse_speck(MESSY).unwrap();
+        let after = parse_speck(&format_speck(MESSY).unwrap()).unwrap();
+        assert_eq!(
+            semantic_view(&before).unwrap(),
+            semantic_view(&after).unwrap()
+        );
+        assert_eq!(after.metadata.owner, Some("Test".to_string()));
+        assert_eq!(after.steps[1].depends_on, vec!["step-0", "step-2"]);
+        assert!(after.steps[0].tasks[0].checked);
+    }
+
+    #[test]
+    fn test_format_preserves_crlf() {
+        let content = "# Title\r\ntext\r\n\r\n\r\nmore\r\n";
+        assert_eq!(
+            format_speck(content).unwrap(),
+            "# Title\r\n\r\ntext\r\n\r\nmore\r\n"
+        );
+    }
+
+    #[test]
+    fn test_format_leaves_annotated_depends_on() {
+        let line = "**Depends on:** #step-1 (schema), #step-0";
+        let tree = SpeckTree::parse(line);
+        let refs = match &tree.lines()[0].node {
+            CstNode::DependsOn { refs } => refs.clone(),
+            other => panic!("expected depends on, got {:?}", other),
+        };
+        assert_eq!(format_depends_on(&tree, line, &refs, &HashMap::new()), line);
+    }
+
+    #[test]
+    fn test_format_table_leaves_irregular_tables() {
+        let rows = ["| Field | Value |", "|---|---|", "| Owner | a | extra |"];
+        assert_eq!(format_table(&rows), rows.to_vec());
+    }
+
+    #[test]
+    fn test_format_beads_hints_keeps_unknown_keys_last() {
+        assert_eq!(
+            format_beads_hints("**Beads:** custom=1, priority=2"),
+            "**Beads:** priority=2, custom=1"
+        );
+    }
+
+    #[test]
+    fn test_format_empty() {
+        assert_eq!(format_speck("").unwrap(), "");
+        assert_eq!(format_speck("\n\n").unwrap(), "");
+    }
+}
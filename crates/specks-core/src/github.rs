@@ -0,0 +1,1126 @@
+//! GitHub Issues tracker backend
+//!
+//! [`GitHubTracker`] maps beads onto GitHub Issues through the REST API: the
+//! root bead becomes a tracking issue (or a milestone), steps become
+//! sub-issues listed as a task list on their parent, `**Depends on:**` edges
+//! become "blocked by" links and close reasons become closing comments.
+//! Priorities are stored as `priority:N` labels; an issue without one has
+//! the tracker default, [`DEFAULT_PRIORITY`]. Milestones have no labels, so
+//! a milestone root always has the default priority.
+//!
+//! Requests go through `curl` against a configurable API base URL, so the
+//! tracker works with GitHub Enterprise and with a local stand-in server.
+//! Bead IDs are `gh-<number>` for issues and `gh-m<number>` for milestones,
+//! both accepted by [`crate::is_valid_bead_id`].
+
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_json::{Value, json};
+
+use crate::beads::{DepResult, DependencyRef, Issue, IssueDetails, IssueWithDependencyMetadata};
+use crate::error::SpecksError;
+use crate::snapshot::DEFAULT_PRIORITY;
+use crate::tracker::IssueTracker;
+
+/// Marker that starts a specks-managed section of an issue body
+static SECTION_MARKER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\n*<!-- specks:([a-z-]+) -->\n").unwrap());
+
+/// Body sections after the description, in the order they are written
+const SECTIONS: [&str; 5] = ["design", "acceptance", "notes", "sub-issues", "close"];
+
+/// Marker on comments that hold a close reason
+const CLOSE_MARKER: &str = "<!-- specks:close -->";
+
+/// Prefix of the label that holds an issue's priority (`priority:1`)
+const PRIORITY_LABEL: &str = "priority:";
+
+/// What a GitHub issue reference points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GitHubRef {
+    Issue(u64),
+    Milestone(u64),
+}
+
+impl GitHubRef {
+    fn parse(id: &str) -> Result<Self, SpecksError> {
+        let rest = id.strip_prefix("gh-");
+        let parsed = match rest.and_then(|r| r.strip_prefix('m')) {
+            Some(number) => number.parse().ok().map(GitHubRef::Milestone),
+            None => rest.and_then(|r| r.parse().ok()).map(GitHubRef::Issue),
+        };
+        parsed.ok_or_else(|| {
+            SpecksError::BeadsCommand(format!("not a GitHub issue reference: {}", id))
+        })
+    }
+
+    fn issue(id: &str) -> Result<u64, SpecksError> {
+        match Self::parse(id)? {
+            GitHubRef::Issue(number) => Ok(number),
+            GitHubRef::Milestone(_) => Err(SpecksError::BeadsCommand(format!(
+                "{} is a milestone; only issues support this operation",
+                id
+            ))),
+        }
+    }
+}
+
+/// Bead ID for a GitHub issue number
+fn issue_id(number: u64) -> String {
+    format!("gh-{}", number)
+}
+
+/// Issue body split into its description and specks-managed sections
+#[derive(Debug, Default, Clone, PartialEq)]
+struct IssueBody {
+    description: String,
+    sections: Vec<(String, String)>,
+}
+
+impl IssueBody {
+    fn parse(body: &str) -> Self {
+        let mut parsed = IssueBody::default();
+        let mut last: Option<(String, usize)> = None;
+        for caps in SECTION_MARKER.captures_iter(body) {
+            let whole = caps.get(0).unwrap();
+            let text = &body[last.as_ref().map_or(0, |(_, end)| *end)..whole.start()];
+            match last.take() {
+                Some((name, _)) => parsed.set(&name, text),
+                None => parsed.description = text.trim_end().to_string(),
+            }
+            last = Some((caps[1].to_string(), whole.end()));
+        }
+        match last {
+            Some((name, end)) => parsed.set(&name, &body[end..]),
+            None => parsed.description = body.trim_end().to_string(),
+        }
+        parsed
+    }
+
+    fn get(&self, name: &str) -> Option<String> {
+        self.sections
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, content)| content.clone())
+    }
+
+    fn set(&mut self, name: &str, content: &str) {
+        let content = content.trim_end().to_string();
+        self.sections.retain(|(n, _)| n != name);
+        if !content.is_empty() {
+            self.sections.push((name.to_string(), content));
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = self.description.clone();
+        for name in SECTIONS {
+            if let Some(content) = self.get(name) {
+                out.push_str(&format!("\n\n<!-- specks:{} -->\n{}", name, content));
+            }
+        }
+        out
+    }
+}
+
+/// GitHub Issues tracker
+#[derive(Debug, Clone)]
+pub struct GitHubTracker {
+    /// REST API base URL, e.g. `https://api.github.com`
+    api_url: String,
+    /// Repository as `owner/name`
+    repo: String,
+    /// API token sent as a bearer token
+    token: Option<String>,
+    /// Create root beads as milestones instead of tracking issues
+    milestone_root: bool,
+    /// Path to the curl binary
+    curl_path: String,
+}
+
+impl GitHubTracker {
+    /// Create a tracker for `repo` (`owner/name`) served at `api_url`
+    pub fn new(api_url: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self {
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            repo: repo.into(),
+            token: None,
+            milestone_root: false,
+            curl_path: "curl".to_string(),
+        }
+    }
+
+    /// Authenticate requests with `token`
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token.filter(|t| !t.is_empty());
+        self
+    }
+
+    /// Create root beads as milestones rather than tracking issues
+    pub fn with_milestone_root(mut self, milestone_root: bool) -> Self {
+        self.milestone_root = milestone_root;
+        self
+    }
+
+    /// Send a request and return the decoded JSON response (`Null` when empty)
+    fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, SpecksError> {
+        let url = format!("{}/repos/{}{}", self.api_url, self.repo, path);
+        let mut cmd = Command::new(&self.curl_path);
+        cmd.args(["-sS", "-X", method])
+            .args(["-H", "Accept: application/vnd.github+json"])
+            .args(["-H", "X-GitHub-Api-Version: 2022-11-28"])
+            .args(["-H", "Expect:"])
+            .args(["-w", "\n%{http_code}"])
+            // Headers with secrets are read from stdin so they stay out of `ps`
+            .args(["-K", "-"]);
+        if let Some(body) = body {
+            cmd.args(["-H", "Content-Type: application/json"])
+                .arg("--data-binary")
+                .arg(body.to_string());
+        }
+        cmd.arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| SpecksError::BeadsCommand(format!("failed to run curl: {}", e)))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            let config = match &self.token {
+                Some(token) => format!("header = \"Authorization: Bearer {}\"\n", token),
+                None => String::new(),
+            };
+            stdin.write_all(config.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(SpecksError::BeadsCommand(format!(
+                "{} {} failed: {}",
+                method,
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (text, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+        let status: u16 = status.trim().parse().unwrap_or(0);
+        let value = if text.trim().is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_str(text).map_err(|e| {
+                SpecksError::BeadsCommand(format!("invalid response from {}: {}", url, e))
+            })?
+        };
+        if !(200..300).contains(&status) {
+            let message = value["message"].as_str().unwrap_or(text.trim());
+            return Err(SpecksError::BeadsCommand(format!(
+                "{} {} returned {}: {}",
+                method, url, status, message
+            )));
+        }
+        Ok(value)
+    }
+
+    /// GET every page of a list endpoint
+    fn get_all(&self, path: &str) -> Result<Vec<Value>, SpecksError> {
+        const PER_PAGE: usize = 100;
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let mut items = Vec::new();
+        for page in 1.. {
+            let batch = self.request(
+                "GET",
+                &format!("{}{}per_page={}&page={}", path, separator, PER_PAGE, page),
+                None,
+            )?;
+            let batch = batch.as_array().cloned().unwrap_or_default();
+            let done = batch.len() < PER_PAGE;
+            items.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    fn get_issue(&self, number: u64) -> Result<Value, SpecksError> {
+        self.request("GET", &format!("/issues/{}", number), None)
+    }
+
+    fn get_milestone(&self, number: u64) -> Result<Value, SpecksError> {
+        self.request("GET", &format!("/milestones/{}", number), None)
+    }
+
+    /// Issues that block `number`
+    fn blocked_by(&self, number: u64) -> Result<Vec<Value>, SpecksError> {
+        self.get_all(&format!("/issues/{}/dependencies/blocked_by", number))
+    }
+
+    /// Rewrite the specks-managed body of an issue or milestone
+    fn edit_body(&self, id: &str, f: impl FnOnce(&mut IssueBody)) -> Result<(), SpecksError> {
+        let (path, field) = match GitHubRef::parse(id)? {
+            GitHubRef::Issue(n) => (format!("/issues/{}", n), "body"),
+            GitHubRef::Milestone(n) => (format!("/milestones/{}", n), "description"),
+        };
+        let current = self.request("GET", &path, None)?;
+        let mut body = IssueBody::parse(current[field].as_str().unwrap_or_default());
+        let before = body.clone();
+        f(&mut body);
+        if body != before {
+            let mut patch = json!({});
+            patch[field] = json!(body.render());
+            self.request("PATCH", &path, Some(&patch))?;
+        }
+        Ok(())
+    }
+
+    /// Mark `number` done in its parent's sub-issue task list
+    fn check_off_in_parent(&self, number: u64) -> Result<(), SpecksError> {
+        let Ok(parent) = self.request("GET", &format!("/issues/{}/parent", number), None) else {
+            return Ok(());
+        };
+        let Some(parent_number) = parent["number"].as_u64() else {
+            return Ok(());
+        };
+        let item = format!("- [ ] #{}", number);
+        self.edit_body(&issue_id(parent_number), |body| {
+            if let Some(list) = body.get("sub-issues") {
+                let list = list
+                    .lines()
+                    .map(|line| match line.strip_prefix(&item) {
+                        // `#1` must not tick `#12`
+                        Some(rest) if !rest.starts_with(|c: char| c.is_ascii_digit()) => {
+                            format!("- [x] #{}{}", number, rest)
+                        }
+                        _ => line.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                body.set("sub-issues", &list);
+            }
+        })
+    }
+}
+
+/// `open`/`closed` from a GitHub `state`
+fn status(value: &Value) -> String {
+    value["state"].as_str().unwrap_or("open").to_string()
+}
+
+/// Label names of a GitHub issue
+fn label_names(value: &Value) -> impl Iterator<Item = &str> {
+    value["labels"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|label| label["name"].as_str())
+}
+
+/// Priority from a `priority:N` label, or the default without one
+fn priority(value: &Value) -> i32 {
+    label_names(value)
+        .find_map(|name| name.strip_prefix(PRIORITY_LABEL)?.trim().parse().ok())
+        .unwrap_or(DEFAULT_PRIORITY)
+}
+
+/// Summary of a GitHub issue
+fn issue_summary(value: &Value) -> Issue {
+    Issue {
+        id: issue_id(value["number"].as_u64().unwrap_or_default()),
+        title: value["title"].as_str().unwrap_or_default().to_string(),
+        description: IssueBody::parse(value["body"].as_str().unwrap_or_default()).description,
+        status: status(value),
+        priority: priority(value),
+        issue_type: "task".to_string(),
+    }
+}
+
+/// Whether a list response item is a pull request rather than an issue
+fn is_pull_request(value: &Value) -> bool {
+    value.get("pull_request").is_some_and(|pr| !pr.is_null())
+}
+
+fn blocks(value: &Value) -> DependencyRef {
+    DependencyRef {
+        id: issue_id(value["number"].as_u64().unwrap_or_default()),
+        dependency_type: "blocks".to_string(),
+    }
+}
+
+impl IssueTracker for GitHubTracker {
+    fn is_installed(&self, _working_dir: Option<&Path>) -> bool {
+        Command::new(&self.curl_path)
+            .arg("--version")
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+
+    fn is_initialized(&self, _project_root: &Path) -> bool {
+        self.repo.split_once('/').is_some_and(|(owner, name)| {
+            !owner.is_empty() && !name.is_empty() && !name.contains('/')
+        })
+    }
+
+    fn create(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        parent: Option<&str>,
+        _issue_type: Option<&str>,
+        priority: Option<i32>,
+        design: Option<&str>,
+        acceptance: Option<&str>,
+        notes: Option<&str>,
+        _working_dir: Option<&Path>,
+    ) -> Result<Issue, SpecksError> {
+        let mut body = IssueBody {
+            description: description.unwrap_or_default().to_string(),
+            sections: Vec::new(),
+        };
+        body.set("design", design.unwrap_or_default());
+        body.set("acceptance", acceptance.unwrap_or_default());
+        body.set("notes", notes.unwrap_or_default());
+
+        let parent = parent.map(GitHubRef::parse).transpose()?;
+        if parent.is_none() && self.milestone_root {
+            let milestone = self.request(
+                "POST",
+                "/milestones",
+                Some(&json!({ "title": title, "description": body.render() })),
+            )?;
+            return Ok(Issue {
+                id: format!("gh-m{}", milestone["number"].as_u64().unwrap_or_default()),
+                title: title.to_string(),
+                description: body.description,
+                status: "open".to_string(),
+                priority: DEFAULT_PRIORITY,
+                issue_type: "milestone".to_string(),
+            });
+        }
+
+        let mut request = json!({ "title": title, "body": body.render() });
+        if let Some(GitHubRef::Milestone(number)) = parent {
+            request["milestone"] = json!(number);
+        }
+        if let Some(priority) = priority {
+            request["labels"] = json!([format!("{}{}", PRIORITY_LABEL, priority)]);
+        }
+        let created = self.request("POST", "/issues", Some(&request))?;
+        let number = created["number"].as_u64().unwrap_or_default();
+
+        if let Some(GitHubRef::Issue(parent_number)) = parent {
+            self.request(
+                "POST",
+                &format!("/issues/{}/sub_issues", parent_number),
+                Some(&json!({ "sub_issue_id": created["id"] })),
+            )?;
+            self.edit_body(&issue_id(parent_number), |body| {
+                let mut list = body.get("sub-issues").unwrap_or_default();
+                if !list.is_empty() {
+                    list.push('\n');
+                }
+                list.push_str(&format!("- [ ] #{}", number));
+                body.set("sub-issues", &list);
+            })?;
+        }
+
+        Ok(issue_summary(&created))
+    }
+
+    fn show(&self, id: &str, _working_dir: Option<&Path>) -> Result<IssueDetails, SpecksError> {
+        let (value, body_field, issue_type) = match GitHubRef::parse(id)? {
+            GitHubRef::Issue(n) => (self.get_issue(n)?, "body", "task"),
+            GitHubRef::Milestone(n) => (self.get_milestone(n)?, "description", "milestone"),
+        };
+        let body = IssueBody::parse(value[body_field].as_str().unwrap_or_default());
+        let mut details = IssueDetails {
+            id: id.to_string(),
+            title: value["title"].as_str().unwrap_or_default().to_string(),
+            description: body.description.clone(),
+            status: status(&value),
+            priority: priority(&value),
+            issue_type: issue_type.to_string(),
+            dependencies: vec![],
+            dependents: vec![],
+            design: body.get("design"),
+            acceptance_criteria: body.get("acceptance"),
+            notes: body.get("notes"),
+            close_reason: body.get("close"),
+            closed_at: value["closed_at"].as_str().map(str::to_string),
+            labels: label_names(&value)
+                .filter(|name| !name.starts_with(PRIORITY_LABEL))
+                .map(str::to_string)
+                .collect(),
+            metadata: None,
+        };
+
+        if let GitHubRef::Issue(n) = GitHubRef::parse(id)? {
+            details.dependencies = self.blocked_by(n)?.iter().map(blocks).collect();
+            details.dependents = self
+                .get_all(&format!("/issues/{}/dependencies/blocking", n))?
+                .iter()
+                .map(blocks)
+                .collect();
+            if details.status == "closed" {
+                details.close_reason = self
+                    .get_all(&format!("/issues/{}/comments", n))?
+                    .iter()
+                    .rev()
+                    .filter_map(|c| c["body"].as_str()?.strip_prefix(CLOSE_MARKER))
+                    .map(|reason| reason.trim().to_string())
+                    .next();
+            }
+        }
+        Ok(details)
+    }
+
+    fn bead_exists(&self, id: &str, _working_dir: Option<&Path>) -> bool {
+        match GitHubRef::parse(id) {
+            Ok(GitHubRef::Issue(n)) => self.get_issue(n).is_ok_and(|v| !is_pull_request(&v)),
+            Ok(GitHubRef::Milestone(n)) => self.get_milestone(n).is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    fn list_by_ids(
+        &self,
+        ids: &[String],
+        working_dir: Option<&Path>,
+    ) -> Result<HashSet<String>, SpecksError> {
+        Ok(ids
+            .iter()
+            .filter(|id| self.bead_exists(id, working_dir))
+            .cloned()
+            .collect())
+    }
+
+    fn update_description(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.edit_body(id, |body| body.description = content.trim_end().to_string())
+    }
+
+    fn update_design(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.edit_body(id, |body| body.set("design", content))
+    }
+
+    fn update_acceptance(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.edit_body(id, |body| body.set("acceptance", content))
+    }
+
+    fn update_notes(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.edit_body(id, |body| body.set("notes", content))
+    }
+
+    fn close(
+        &self,
+        id: &str,
+        reason: Option<&str>,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        let reason = reason.filter(|r| !r.is_empty());
+        match GitHubRef::parse(id)? {
+            GitHubRef::Issue(n) => {
+                if let Some(reason) = reason {
+                    self.request(
+                        "POST",
+                        &format!("/issues/{}/comments", n),
+                        Some(&json!({ "body": format!("{}\n{}", CLOSE_MARKER, reason) })),
+                    )?;
+                }
+                self.request(
+                    "PATCH",
+                    &format!("/issues/{}", n),
+                    Some(&json!({ "state": "closed", "state_reason": "completed" })),
+                )?;
+                self.check_off_in_parent(n)
+            }
+            GitHubRef::Milestone(n) => {
+                // Milestones have no comments; keep the reason in the description
+                if let Some(reason) = reason {
+                    self.edit_body(id, |body| body.set("close", reason))?;
+                }
+                self.request(
+                    "PATCH",
+                    &format!("/milestones/{}", n),
+                    Some(&json!({ "state": "closed" })),
+                )?;
+                Ok(())
+            }
+        }
+    }
+
+    fn dep_add(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        let from = GitHubRef::issue(from_id)?;
+        let to = self.get_issue(GitHubRef::issue(to_id)?)?;
+        let already = self.blocked_by(from)?.iter().any(|b| b["id"] == to["id"]);
+        if !already {
+            self.request(
+                "POST",
+                &format!("/issues/{}/dependencies/blocked_by", from),
+                Some(&json!({ "issue_id": to["id"] })),
+            )?;
+        }
+        Ok(DepResult {
+            status: "added".to_string(),
+            issue_id: from_id.to_string(),
+            depends_on_id: to_id.to_string(),
+            dep_type: "blocks".to_string(),
+        })
+    }
+
+    fn dep_remove(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        let from = GitHubRef::issue(from_id)?;
+        let to = self.get_issue(GitHubRef::issue(to_id)?)?;
+        self.request(
+            "DELETE",
+            &format!(
+                "/issues/{}/dependencies/blocked_by/{}",
+                from,
+                to["id"].as_u64().unwrap_or_default()
+            ),
+            None,
+        )?;
+        Ok(DepResult {
+            status: "removed".to_string(),
+            issue_id: from_id.to_string(),
+            depends_on_id: to_id.to_string(),
+            dep_type: String::new(),
+        })
+    }
+
+    fn dep_list(
+        &self,
+        id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueWithDependencyMetadata>, SpecksError> {
+        let GitHubRef::Issue(n) = GitHubRef::parse(id)? else {
+            return Ok(vec![]);
+        };
+        Ok(self
+            .blocked_by(n)?
+            .iter()
+            .map(|blocker| IssueWithDependencyMetadata {
+                id: issue_id(blocker["number"].as_u64().unwrap_or_default()),
+                dependency_type: "blocks".to_string(),
+                title: blocker["title"].as_str().unwrap_or_default().to_string(),
+                status: status(blocker),
+                priority: priority(blocker),
+                issue_type: "task".to_string(),
+            })
+            .collect())
+    }
+
+    fn children(
+        &self,
+        parent_id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        let children = match GitHubRef::parse(parent_id)? {
+            GitHubRef::Issue(n) => self.get_all(&format!("/issues/{}/sub_issues", n))?,
+            GitHubRef::Milestone(n) => {
+                self.get_all(&format!("/issues?milestone={}&state=all", n))?
+            }
+        };
+        Ok(children
+            .iter()
+            .filter(|c| !is_pull_request(c))
+            .map(issue_summary)
+            .collect())
+    }
+
+    fn ready(
+        &self,
+        parent_id: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        let candidates = match parent_id {
+            Some(parent_id) => self.children(parent_id, working_dir)?,
+            None => self
+                .get_all("/issues?state=open")?
+                .iter()
+                .filter(|i| !is_pull_request(i))
+                .map(issue_summary)
+                .collect(),
+        };
+        let mut ready = Vec::new();
+        for issue in candidates.into_iter().filter(|i| i.status == "open") {
+            let number = GitHubRef::issue(&issue.id)?;
+            if self
+                .blocked_by(number)?
+                .iter()
+                .all(|b| status(b) == "closed")
+            {
+                ready.push(issue);
+            }
+        }
+        Ok(ready)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::is_valid_bead_id;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    /// Minimal stand-in for the parts of the GitHub REST API the tracker uses
+    #[derive(Default)]
+    struct MockGitHub {
+        issues: Vec<Value>,
+        milestones: Vec<Value>,
+        /// (parent, child) issue numbers
+        sub_issues: Vec<(u64, u64)>,
+        /// (issue, blocker) issue numbers
+        blocked_by: Vec<(u64, u64)>,
+        /// (issue number, comment body)
+        comments: Vec<(u64, String)>,
+        /// Authorization header of the last request
+        auth: Option<String>,
+    }
+
+    impl MockGitHub {
+        fn issue(&self, number: u64) -> Option<&Value> {
+            self.issues.get((number as usize).checked_sub(1)?)
+        }
+
+        fn issues(&self, numbers: impl Iterator<Item = u64>) -> Value {
+            Value::Array(numbers.filter_map(|n| self.issue(n).cloned()).collect())
+        }
+
+        fn handle(&mut self, method: &str, target: &str, body: &Value) -> (u16, Value) {
+            let not_found = (404, json!({ "message": "Not Found" }));
+            let Some(target) = target.strip_prefix("/repos/acme/widgets/") else {
+                return not_found;
+            };
+            let (path, query) = target.split_once('?').unwrap_or((target, ""));
+            let param = |key: &str| {
+                query
+                    .split('&')
+                    .find_map(|kv| kv.strip_prefix(key)?.strip_prefix('='))
+                    .map(str::to_string)
+            };
+            if param("page").is_some_and(|p| p != "1") {
+                return (200, json!([]));
+            }
+            let segments: Vec<&str> = path.split('/').collect();
+            let number = |i: usize| segments.get(i).and_then(|s| s.parse::<u64>().ok());
+            let closed_at = json!("2026-03-01T12:00:00Z");
+
+            match (method, segments.as_slice()) {
+                ("POST", ["issues"]) => {
+                    let number = self.issues.len() as u64 + 1;
+                    let issue = json!({
+                        "id": 1000 + number,
+                        "number": number,
+                        "title": body["title"],
+                        "body": body["body"],
+                        "state": "open",
+                        "closed_at": null,
+                        "milestone": body.get("milestone").cloned().unwrap_or(Value::Null),
+                        "labels": body["labels"]
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .map(|name| json!({ "name": name }))
+                            .collect::<Vec<_>>(),
+                    });
+                    self.issues.push(issue.clone());
+                    (201, issue)
+                }
+                ("GET", ["issues"]) => {
+                    let milestone = param("milestone").and_then(|m| m.parse::<u64>().ok());
+                    let all = param("state").as_deref() == Some("all");
+                    let issues = self
+                        .issues
+                        .iter()
+                        .filter(|i| milestone.is_none_or(|m| i["milestone"] == json!(m)))
+                        .filter(|i| all || i["state"] == "open")
+                        .cloned()
+                        .collect();
+                    (200, Value::Array(issues))
+                }
+                ("GET", ["issues", _]) => match number(1).and_then(|n| self.issue(n)) {
+                    Some(issue) => (200, issue.clone()),
+                    None => not_found,
+                },
+                ("PATCH", ["issues", _]) => {
+                    let Some(n) = number(1).filter(|n| self.issue(*n).is_some()) else {
+                        return not_found;
+                    };
+                    let issue = &mut self.issues[n as usize - 1];
+                    for field in ["title", "body", "state"] {
+                        if let Some(value) = body.get(field) {
+                            issue[field] = value.clone();
+                        }
+                    }
+                    if issue["state"] == "closed" {
+                        issue["closed_at"] = closed_at;
+                    }
+                    (200, issue.clone())
+                }
+                ("POST", ["issues", _, "sub_issues"]) => {
+                    let child = body["sub_issue_id"].as_u64().unwrap() - 1000;
+                    self.sub_issues.push((number(1).unwrap(), child));
+                    (201, self.issue(number(1).unwrap()).cloned().unwrap())
+                }
+                ("GET", ["issues", _, "sub_issues"]) => {
+                    let parent = number(1).unwrap();
+                    let children = self.sub_issues.iter().filter(|(p, _)| *p == parent);
+                    (200, self.issues(children.map(|(_, c)| *c)))
+                }
+                ("GET", ["issues", _, "parent"]) => {
+                    let child = number(1).unwrap();
+                    match self.sub_issues.iter().find(|(_, c)| *c == child) {
+                        Some((parent, _)) => (200, self.issue(*parent).cloned().unwrap()),
+                        None => not_found,
+                    }
+                }
+                ("POST", ["issues", _, "comments"]) => {
+                    let text = body["body"].as_str().unwrap().to_string();
+                    self.comments.push((number(1).unwrap(), text.clone()));
+                    (201, json!({ "body": text }))
+                }
+                ("GET", ["issues", _, "comments"]) => {
+                    let n = number(1).unwrap();
+                    let comments = self.comments.iter().filter(|(i, _)| *i == n);
+                    (200, comments.map(|(_, b)| json!({ "body": b })).collect())
+                }
+                ("POST", ["issues", _, "dependencies", "blocked_by"]) => {
+                    let blocker = body["issue_id"].as_u64().unwrap() - 1000;
+                    self.blocked_by.push((number(1).unwrap(), blocker));
+                    (201, self.issue(number(1).unwrap()).cloned().unwrap())
+                }
+                ("GET", ["issues", _, "dependencies", "blocked_by"]) => {
+                    let n = number(1).unwrap();
+                    let blockers = self.blocked_by.iter().filter(|(i, _)| *i == n);
+                    (200, self.issues(blockers.map(|(_, b)| *b)))
+                }
+                ("GET", ["issues", _, "dependencies", "blocking"]) => {
+                    let n = number(1).unwrap();
+                    let blocked = self.blocked_by.iter().filter(|(_, b)| *b == n);
+                    (200, self.issues(blocked.map(|(i, _)| *i)))
+                }
+                ("DELETE", ["issues", _, "dependencies", "blocked_by", _]) => {
+                    let (n, blocker) = (number(1).unwrap(), number(4).unwrap() - 1000);
+                    self.blocked_by.retain(|edge| *edge != (n, blocker));
+                    (200, self.issue(n).cloned().unwrap())
+                }
+                ("POST", ["milestones"]) => {
+                    let number = self.milestones.len() as u64 + 1;
+                    let milestone = json!({
+                        "number": number,
+                        "title": body["title"],
+                        "description": body["description"],
+                        "state": "open",
+                        "closed_at": null,
+                    });
+                    self.milestones.push(milestone.clone());
+                    (201, milestone)
+                }
+                ("GET" | "PATCH", ["milestones", _]) => {
+                    let Some(milestone) = number(1)
+                        .and_then(|n| self.milestones.get_mut((n as usize).checked_sub(1)?))
+                    else {
+                        return not_found;
+                    };
+                    if method == "PATCH" {
+                        for field in ["title", "description", "state"] {
+                            if let Some(value) = body.get(field) {
+                                milestone[field] = value.clone();
+                            }
+                        }
+                        if milestone["state"] == "closed" {
+                            milestone["closed_at"] = closed_at;
+                        }
+                    }
+                    (200, milestone.clone())
+                }
+                _ => not_found,
+            }
+        }
+    }
+
+    /// Serve a [`MockGitHub`] on a local port, returning its base URL
+    fn serve() -> (String, Arc<Mutex<MockGitHub>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(MockGitHub::default()));
+        let shared = Arc::clone(&state);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let target = parts.next().unwrap_or_default().to_string();
+
+                let mut length = 0;
+                let mut auth = None;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        match name.to_ascii_lowercase().as_str() {
+                            "content-length" => length = value.trim().parse().unwrap(),
+                            "authorization" => auth = Some(value.trim().to_string()),
+                            _ => {}
+                        }
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+                let (status, response) = {
+                    let mut mock = shared.lock().unwrap();
+                    mock.auth = auth;
+                    mock.handle(&method, &target, &body)
+                };
+                let text = response.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    text.len(),
+                    text
+                )
+                .unwrap();
+            }
+        });
+        (url, state)
+    }
+
+    fn create(tracker: &GitHubTracker, title: &str, parent: Option<&str>) -> Issue {
+        tracker
+            .create(
+                title,
+                Some("Body"),
+                parent,
+                None,
+                None,
+                Some("Design"),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+    }
+
+    fn ids(issues: Vec<Issue>) -> Vec<String> {
+        issues.into_iter().map(|i| i.id).collect()
+    }
+
+    #[test]
+    fn test_issue_body_round_trip() {
+        let mut body = IssueBody::parse("Intro\n\n## Tasks\n- [ ] one");
+        assert_eq!(body.description, "Intro\n\n## Tasks\n- [ ] one");
+        body.set("notes", "note");
+        body.set("design", "design");
+        let rendered = body.render();
+        assert!(rendered.find("specks:design").unwrap() < rendered.find("specks:notes").unwrap());
+
+        let parsed = IssueBody::parse(&rendered);
+        assert_eq!(parsed.description, body.description);
+        assert_eq!(parsed.get("design").as_deref(), Some("design"));
+        assert_eq!(parsed.get("notes").as_deref(), Some("note"));
+        assert_eq!(parsed.render(), rendered);
+    }
+
+    #[test]
+    fn test_github_ref_parse() {
+        assert_eq!(GitHubRef::parse("gh-12").unwrap(), GitHubRef::Issue(12));
+        assert_eq!(GitHubRef::parse("gh-m3").unwrap(), GitHubRef::Milestone(3));
+        assert!(GitHubRef::parse("bd-12").is_err());
+        assert!(GitHubRef::parse("gh-x").is_err());
+        assert!(GitHubRef::issue("gh-m3").is_err());
+        assert!(is_valid_bead_id("gh-12") && is_valid_bead_id("gh-m3"));
+    }
+
+    #[test]
+    fn test_github_tracker_sub_issues_deps_and_close() {
+        let (url, mock) = serve();
+        let tracker =
+            GitHubTracker::new(url, "acme/widgets").with_token(Some("secret".to_string()));
+        assert!(tracker.is_installed(None));
+        assert!(tracker.is_initialized(Path::new(".")));
+        assert!(!GitHubTracker::new("http://x", "widgets").is_initialized(Path::new(".")));
+
+        let root = create(&tracker, "Phase", None);
+        let a = create(&tracker, "Step 0", Some(&root.id));
+        let b = create(&tracker, "Step 1", Some(&root.id));
+        assert_eq!((root.id.as_str(), a.id.as_str()), ("gh-1", "gh-2"));
+        assert_eq!(mock.lock().unwrap().auth.as_deref(), Some("Bearer secret"));
+
+        let details = tracker.show(&root.id, None).unwrap();
+        assert_eq!(details.description, "Body");
+        assert_eq!(details.design.as_deref(), Some("Design"));
+        let root_body = mock.lock().unwrap().issues[0]["body"].clone();
+        assert!(root_body.as_str().unwrap().ends_with("- [ ] #2\n- [ ] #3"));
+
+        tracker.dep_add(&b.id, &a.id, None).unwrap();
+        tracker.dep_add(&b.id, &a.id, None).unwrap();
+        assert_eq!(mock.lock().unwrap().blocked_by, vec![(3, 2)]);
+        assert_eq!(
+            ids(tracker.children(&root.id, None).unwrap()),
+            ["gh-2", "gh-3"]
+        );
+        assert_eq!(ids(tracker.ready(Some(&root.id), None).unwrap()), ["gh-2"]);
+        let deps = tracker.dep_list(&b.id, None).unwrap();
+        assert_eq!(
+            (deps[0].id.as_str(), deps[0].status.as_str()),
+            ("gh-2", "open")
+        );
+        assert_eq!(tracker.show(&a.id, None).unwrap().dependents[0].id, "gh-3");
+
+        tracker.update_notes(&a.id, "Coder notes", None).unwrap();
+        tracker.append_notes(&a.id, "Reviewer notes", None).unwrap();
+        tracker
+            .close(&a.id, Some("Committed: abc123 -- step 0"), None)
+            .unwrap();
+        let closed = tracker.show(&a.id, None).unwrap();
+        assert_eq!(closed.status, "closed");
+        assert_eq!(
+            closed.close_reason.as_deref(),
+            Some("Committed: abc123 -- step 0")
+        );
+        assert_eq!(closed.closed_at.as_deref(), Some("2026-03-01T12:00:00Z"));
+        assert_eq!(
+            closed.notes.as_deref(),
+            Some("Coder notes\n\n---\n\nReviewer notes")
+        );
+        let root_body = mock.lock().unwrap().issues[0]["body"].clone();
+        assert!(root_body.as_str().unwrap().ends_with("- [x] #2\n- [ ] #3"));
+        assert_eq!(ids(tracker.ready(Some(&root.id), None).unwrap()), ["gh-3"]);
+
+        tracker.dep_remove(&b.id, &a.id, None).unwrap();
+        assert!(tracker.dep_list(&b.id, None).unwrap().is_empty());
+
+        let known = ["gh-2".to_string(), "gh-9".to_string()];
+        let existing = tracker.list_by_ids(&known, None).unwrap();
+        assert!(existing.contains("gh-2") && !existing.contains("gh-9"));
+        assert!(tracker.show("gh-9", None).is_err());
+    }
+
+    #[test]
+    fn test_github_tracker_close_ticks_only_its_own_item() {
+        let (url, mock) = serve();
+        let tracker = GitHubTracker::new(url, "acme/widgets");
+
+        let step = create(&tracker, "Step 0", None);
+        let root = create(&tracker, "Phase", None);
+        assert_eq!(root.id, "gh-2");
+        let mut body = IssueBody::parse("Body");
+        body.set("sub-issues", "- [ ] #1\n- [ ] #12\n- [ ] #1: again");
+        {
+            let mut mock = mock.lock().unwrap();
+            mock.sub_issues.push((2, 1));
+            mock.issues[1]["body"] = json!(body.render());
+        }
+
+        tracker.close(&step.id, None, None).unwrap();
+        let root_body = mock.lock().unwrap().issues[1]["body"].clone();
+        assert!(
+            root_body
+                .as_str()
+                .unwrap()
+                .ends_with("- [x] #1\n- [ ] #12\n- [x] #1: again")
+        );
+    }
+
+    #[test]
+    fn test_github_tracker_priority_labels() {
+        let (url, mock) = serve();
+        let tracker = GitHubTracker::new(url, "acme/widgets");
+
+        let urgent = tracker
+            .create("Urgent", None, None, None, Some(1), None, None, None, None)
+            .unwrap();
+        assert_eq!(urgent.priority, 1);
+        let details = tracker.show(&urgent.id, None).unwrap();
+        assert_eq!(details.priority, 1);
+        assert!(details.labels.is_empty());
+
+        // No priority label: the default, with other labels kept
+        let plain = create(&tracker, "Plain", None);
+        assert_eq!(plain.priority, DEFAULT_PRIORITY);
+        mock.lock().unwrap().issues[1]["labels"] =
+            json!([{ "name": "api" }, { "name": "priority:3" }]);
+        let details = tracker.show(&plain.id, None).unwrap();
+        assert_eq!(details.priority, 3);
+        assert_eq!(details.labels, vec!["api"]);
+    }
+
+    #[test]
+    fn test_github_tracker_milestone_root() {
+        let (url, mock) = serve();
+        let tracker = GitHubTracker::new(url, "acme/widgets").with_milestone_root(true);
+
+        let root = create(&tracker, "Phase", None);
+        assert_eq!(root.id, "gh-m1");
+        let step = create(&tracker, "Step 0", Some(&root.id));
+        assert_eq!(step.id, "gh-1");
+        assert_eq!(mock.lock().unwrap().issues[0]["milestone"], json!(1));
+        assert!(mock.lock().unwrap().auth.is_none());
+
+        assert_eq!(ids(tracker.children(&root.id, None).unwrap()), ["gh-1"]);
+        assert_eq!(ids(tracker.ready(Some(&root.id), None).unwrap()), ["gh-1"]);
+        assert!(tracker.dep_add(&step.id, &root.id, None).is_err());
+
+        tracker.update_design(&root.id, "New design", None).unwrap();
+        tracker
+            .close(&root.id, Some("All steps done"), None)
+            .unwrap();
+        let details = tracker.show(&root.id, None).unwrap();
+        assert_eq!(details.issue_type, "milestone");
+        assert_eq!(details.status, "closed");
+        assert_eq!(details.design.as_deref(), Some("New design"));
+        assert_eq!(details.close_reason.as_deref(), Some("All steps done"));
+    }
+}
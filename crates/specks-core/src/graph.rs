@@ -0,0 +1,403 @@
+//! Step dependency graph
+//!
+//! One node per top-level step, in document order. A substep's dependencies
+//! count as dependencies of its step, and a dependency on another step's
+//! substep counts as one on that step. Dependencies on other specks are left
+//! out: they constrain when a speck can start, not the shape of its plan.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::companion::external_dependency;
+use crate::types::{BeadsHints, Speck, Step};
+
+/// A step and the steps it depends on
+#[derive(Debug, Clone)]
+pub struct StepNode<'a> {
+    pub step: &'a Step,
+    /// Indices of the steps this one depends on, in first-seen order
+    pub deps: Vec<usize>,
+    /// The step's estimate, or the sum of its substeps' estimates
+    pub estimate_minutes: Option<u32>,
+}
+
+/// A dependency already implied by another one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedundantEdge {
+    pub step: usize,
+    pub dep: usize,
+    /// Direct dependency of `step` through which `dep` is reached
+    pub via: usize,
+}
+
+/// The longest chain of dependent steps, weighted by estimates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CriticalPath {
+    /// Step indices, first step first
+    pub steps: Vec<usize>,
+    /// Sum of the estimates along the path
+    pub minutes: u32,
+    /// Steps on the path without an estimate
+    pub unestimated: usize,
+}
+
+/// Dependency graph of a speck's steps
+#[derive(Debug, Clone)]
+pub struct StepGraph<'a> {
+    pub nodes: Vec<StepNode<'a>>,
+}
+
+impl<'a> StepGraph<'a> {
+    /// Build the graph; unknown anchors are ignored (E010 reports them)
+    pub fn from_speck(speck: &'a Speck) -> Self {
+        let mut owner: HashMap<&str, usize> = HashMap::new();
+        for (index, step) in speck.steps.iter().enumerate() {
+            owner.insert(&step.anchor, index);
+            for substep in step.all_substeps() {
+                owner.insert(&substep.anchor, index);
+            }
+        }
+
+        let nodes = speck
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let substeps = step.all_substeps();
+                let written = step
+                    .depends_on
+                    .iter()
+                    .chain(substeps.iter().flat_map(|s| &s.depends_on));
+                let mut deps: Vec<usize> = Vec::new();
+                for dep in written.filter(|d| external_dependency(d).is_none()) {
+                    if let Some(&target) = owner.get(dep.as_str()) {
+                        if target != index && !deps.contains(&target) {
+                            deps.push(target);
+                        }
+                    }
+                }
+
+                let estimate =
+                    |hints: &Option<BeadsHints>| hints.as_ref().and_then(|h| h.estimate_minutes);
+                let estimate_minutes = estimate(&step.beads_hints).or_else(|| {
+                    substeps
+                        .iter()
+                        .filter_map(|s| estimate(&s.beads_hints))
+                        .reduce(|a, b| a + b)
+                });
+
+                StepNode {
+                    step,
+                    deps,
+                    estimate_minutes,
+                }
+            })
+            .collect();
+
+        Self { nodes }
+    }
+
+    /// Whether `to` is reachable from `from` by following dependencies
+    pub fn depends_transitively(&self, from: usize, to: usize) -> bool {
+        let mut seen: HashSet<usize> = HashSet::new();
+        let mut queue: VecDeque<usize> = self.nodes[from].deps.iter().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                return true;
+            }
+            if seen.insert(node) {
+                queue.extend(&self.nodes[node].deps);
+            }
+        }
+        false
+    }
+
+    /// Step indices with every step after the steps it depends on, ties in
+    /// document order; `None` if the dependencies contain a cycle
+    pub fn topological_order(&self) -> Option<Vec<usize>> {
+        let mut remaining: Vec<usize> = self.nodes.iter().map(|n| n.deps.len()).collect();
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate() {
+            for &dep in &node.deps {
+                dependents[dep].push(index);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut ready: Vec<usize> = (0..self.nodes.len())
+            .filter(|&i| remaining[i] == 0)
+            .collect();
+        while let Some(&next) = ready.iter().min() {
+            ready.retain(|&i| i != next);
+            order.push(next);
+            for &dependent in &dependents[next] {
+                remaining[dependent] -= 1;
+                if remaining[dependent] == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+
+        (order.len() == self.nodes.len()).then_some(order)
+    }
+
+    /// Dependencies implied by another dependency of the same step
+    ///
+    /// Only meaningful for an acyclic graph; empty if there is a cycle.
+    pub fn redundant_edges(&self) -> Vec<RedundantEdge> {
+        if self.topological_order().is_none() {
+            return Vec::new();
+        }
+        let mut edges = Vec::new();
+        for (step, node) in self.nodes.iter().enumerate() {
+            for &dep in &node.deps {
+                let via = node
+                    .deps
+                    .iter()
+                    .copied()
+                    .find(|&other| other != dep && self.depends_transitively(other, dep));
+                if let Some(via) = via {
+                    edges.push(RedundantEdge { step, dep, via });
+                }
+            }
+        }
+        edges
+    }
+
+    /// `(step, dep)` pairs where a step depends on a step after it
+    pub fn later_dependencies(&self) -> Vec<(usize, usize)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(step, node)| {
+                node.deps
+                    .iter()
+                    .filter(move |&&dep| dep > step)
+                    .map(move |&dep| (step, dep))
+            })
+            .collect()
+    }
+
+    /// Steps with dependencies that never lead back to the first step
+    ///
+    /// Steps without any dependencies are left out (W007 covers them).
+    pub fn unreachable_from_first(&self) -> Vec<usize> {
+        (1..self.nodes.len())
+            .filter(|&i| !self.nodes[i].deps.is_empty() && !self.depends_transitively(i, 0))
+            .collect()
+    }
+
+    /// Unfinished steps grouped into waves of steps that can run in parallel
+    ///
+    /// The first wave holds the steps whose dependencies are all `complete`;
+    /// each later wave holds the steps whose dependencies are complete or in
+    /// an earlier wave. `waiting` steps also wait on work outside the graph
+    /// (another speck's steps), so they start in the second wave at the
+    /// earliest; the first wave is empty if every startable step is waiting.
+    /// Steps on a dependency cycle are never reached.
+    pub fn waves(&self, complete: &[bool], waiting: &[bool]) -> Vec<Vec<usize>> {
+        let mut done = complete.to_vec();
+        let mut waves: Vec<Vec<usize>> = Vec::new();
+        loop {
+            let startable: Vec<usize> = (0..self.nodes.len())
+                .filter(|&i| !done[i] && self.nodes[i].deps.iter().all(|&dep| done[dep]))
+                .collect();
+            if startable.is_empty() {
+                return waves;
+            }
+            let wave: Vec<usize> = startable
+                .into_iter()
+                .filter(|&i| !(waves.is_empty() && waiting[i]))
+                .collect();
+            for &i in &wave {
+                done[i] = true;
+            }
+            waves.push(wave);
+        }
+    }
+
+    /// The chain with the largest total estimate (then the most steps);
+    /// `None` if there are no steps or the dependencies contain a cycle
+    pub fn critical_path(&self) -> Option<CriticalPath> {
+        self.heaviest_path(&vec![false; self.nodes.len()])
+    }
+
+    /// The critical path through the unfinished steps: those not `complete`
+    ///
+    /// `None` if every step is complete or the dependencies contain a cycle.
+    pub fn remaining_critical_path(&self, complete: &[bool]) -> Option<CriticalPath> {
+        self.heaviest_path(complete)
+    }
+
+    /// Heaviest chain of dependent steps, leaving out the `skip`ped ones
+    fn heaviest_path(&self, skip: &[bool]) -> Option<CriticalPath> {
+        let order = self.topological_order()?;
+        // Per step: (minutes, steps) of the heaviest chain ending there, and
+        // the dependency that chain comes through
+        let mut best: Vec<((u32, usize), Option<usize>)> = vec![((0, 0), None); self.nodes.len()];
+        for &index in order.iter().filter(|&&i| !skip[i]) {
+            let node = &self.nodes[index];
+            let (weight, prev) = node
+                .deps
+                .iter()
+                .filter(|&&dep| !skip[dep])
+                .map(|&dep| (best[dep].0, Some(dep)))
+                .max_by_key(|(weight, _)| *weight)
+                .unwrap_or(((0, 0), None));
+            let minutes = weight.0 + node.estimate_minutes.unwrap_or(0);
+            best[index] = ((minutes, weight.1 + 1), prev);
+        }
+
+        let end = (0..self.nodes.len())
+            .rev()
+            .filter(|&i| !skip[i])
+            .max_by_key(|&i| best[i].0)?;
+        let mut steps = vec![end];
+        while let Some(prev) = best[*steps.last().unwrap()].1 {
+            steps.push(prev);
+        }
+        steps.reverse();
+
+        Some(CriticalPath {
+            minutes: best[end].0.0,
+            unestimated: steps
+                .iter()
+                .filter(|&&i| self.nodes[i].estimate_minutes.is_none())
+                .count(),
+            steps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_speck;
+
+    const SPECK: &str = r#"## Phase 1.0: Graph {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Beads:** estimate_minutes=30
+
+#### Step 1: Model {#step-1}
+
+**Depends on:** #step-0
+
+**Beads:** estimate_minutes=60
+
+#### Step 2: Api {#step-2}
+
+**Depends on:** #step-0, #step-1, #step-3
+
+##### Step 2.1: Routes {#step-2-1}
+
+**Depends on:** #step-1
+
+**Beads:** estimate_minutes=20
+
+##### Step 2.2: Handlers {#step-2-2}
+
+**Beads:** estimate_minutes=25
+
+#### Step 3: Docs {#step-3}
+
+**Depends on:** #step-4
+
+#### Step 4: Notes {#step-4}
+"#;
+
+    fn numbers(graph: &StepGraph, indices: &[usize]) -> Vec<String> {
+        indices
+            .iter()
+            .map(|&i| graph.nodes[i].step.number.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_graph_folds_substeps_into_steps() {
+        let speck = parse_speck(SPECK).unwrap();
+        let graph = StepGraph::from_speck(&speck);
+        assert_eq!(graph.nodes.len(), 5);
+        assert_eq!(graph.nodes[2].deps, vec![0, 1, 3]);
+        assert_eq!(graph.nodes[2].estimate_minutes, Some(45));
+        assert_eq!(graph.nodes[3].estimate_minutes, None);
+    }
+
+    #[test]
+    fn test_graph_structure_checks() {
+        let speck = parse_speck(SPECK).unwrap();
+        let graph = StepGraph::from_speck(&speck);
+
+        assert_eq!(
+            graph.redundant_edges(),
+            vec![RedundantEdge {
+                step: 2,
+                dep: 0,
+                via: 1
+            }]
+        );
+        assert_eq!(graph.later_dependencies(), vec![(2, 3), (3, 4)]);
+        assert_eq!(numbers(&graph, &graph.unreachable_from_first()), vec!["3"]);
+        assert_eq!(graph.topological_order(), Some(vec![0, 1, 4, 3, 2]));
+    }
+
+    #[test]
+    fn test_critical_path() {
+        let speck = parse_speck(SPECK).unwrap();
+        let graph = StepGraph::from_speck(&speck);
+        let path = graph.critical_path().unwrap();
+        assert_eq!(numbers(&graph, &path.steps), vec!["0", "1", "2"]);
+        assert_eq!(path.minutes, 135);
+        assert_eq!(path.unestimated, 0);
+    }
+
+    #[test]
+    fn test_remaining_critical_path() {
+        let speck = parse_speck(SPECK).unwrap();
+        let graph = StepGraph::from_speck(&speck);
+        let path = graph
+            .remaining_critical_path(&[true, false, false, false, false])
+            .unwrap();
+        assert_eq!(numbers(&graph, &path.steps), vec!["1", "2"]);
+        assert_eq!(path.minutes, 105);
+        assert!(graph.remaining_critical_path(&[true; 5]).is_none());
+    }
+
+    #[test]
+    fn test_waves() {
+        let speck = parse_speck(SPECK).unwrap();
+        let graph = StepGraph::from_speck(&speck);
+        assert_eq!(
+            graph.waves(&[false; 5], &[false; 5]),
+            vec![vec![0, 4], vec![1, 3], vec![2]]
+        );
+        assert_eq!(
+            graph.waves(&[true, false, false, false, true], &[false; 5]),
+            vec![vec![1, 3], vec![2]]
+        );
+        // A step waiting on another speck moves out of the first wave, and
+        // its dependents with it
+        assert_eq!(
+            graph.waves(&[false; 5], &[true, false, false, false, false]),
+            vec![vec![4], vec![0, 3], vec![1], vec![2]]
+        );
+        assert_eq!(
+            graph.waves(
+                &[true, true, false, true, true],
+                &[false, false, true, false, false]
+            ),
+            vec![vec![], vec![2]]
+        );
+    }
+
+    #[test]
+    fn test_cycle_has_no_order_or_critical_path() {
+        let speck =
+            parse_speck(&SPECK.replace("**Depends on:** #step-4", "**Depends on:** #step-2"))
+                .unwrap();
+        let graph = StepGraph::from_speck(&speck);
+        assert!(graph.topological_order().is_none());
+        assert!(graph.critical_path().is_none());
+        assert!(graph.redundant_edges().is_empty());
+    }
+}
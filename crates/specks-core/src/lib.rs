@@ -14,12 +14,48 @@ pub mod types;
 /// Speck file parsing
 pub mod parser;
 
+/// Multi-file specks: companion files and cross-file references
+pub mod companion;
+
+/// Lossless speck syntax tree with span-based editing
+pub mod cst;
+
+/// Canonical speck formatting
+pub mod formatter;
+
+/// Autofix for mechanically fixable diagnostics
+pub mod fixer;
+
+/// Step dependency graph analysis
+pub mod graph;
+
+/// Estimate rollups and actual time from logs and beads
+pub mod estimate;
+
 /// Validation logic and rules
 pub mod validator;
 
+/// Validation baselines for specks with known issues
+pub mod baseline;
+
+/// Semantic regressions between two versions of a speck
+pub mod regression;
+
+/// Speck templates and variable substitution
+pub mod template;
+
 /// Beads integration utilities
 pub mod beads;
 
+/// Issue tracker backends
+pub mod tracker;
+
+/// GitHub Issues tracker backend
+pub mod github;
+
+/// Last-synced bead fields for three-way pull
+pub mod snapshot;
+
 /// Interaction adapter for mode-agnostic user interaction
 pub mod interaction;
 
@@ -30,25 +66,48 @@ pub mod session;
 pub mod worktree;
 
 // Re-exports for convenience
+pub use baseline::{BASELINE_FILE, Baseline, BaselineEntry, BaselineMatch};
 pub use beads::{
     BeadStatus, BeadsCli, CloseReasonParsed, Issue, IssueDetails, is_valid_bead_id,
     parse_close_reason,
 };
+pub use companion::{
+    ExternalStep, cross_file_refs, external_dependency, included_files, is_companion_file_name,
+    load_companions, referenced_files, resolve_external_step,
+};
 pub use config::{
-    BeadsConfig, Config, NamingConfig, RESERVED_FILES, SpecksConfig, find_project_root,
-    find_project_root_from, find_specks, is_reserved_file, speck_name_from_path,
+    BeadsConfig, Config, CustomRuleConfig, GitHubConfig, NamingConfig, RESERVED_FILES,
+    SpecksConfig, ValidationRulesConfig, find_project_root, find_project_root_from, find_specks,
+    is_reserved_file, speck_name_from_path,
 };
+pub use cst::{CstLine, CstNode, Span, SpeckTree, StepBlock};
 pub use error::SpecksError;
+pub use estimate::{
+    EstimateRollup, LogEntry, StepTime, bead_completions, format_duration, log_completions,
+    parse_log_entries, rollup_estimates,
+};
+pub use fixer::{Fix, FixResult, fix_speck, unified_diff};
+pub use formatter::{format_speck, is_formatted};
+pub use github::GitHubTracker;
+pub use graph::{CriticalPath, RedundantEdge, StepGraph, StepNode};
 pub use interaction::{InteractionAdapter, InteractionError, InteractionResult, ProgressHandle};
 pub use parser::parse_speck;
-pub use session::now_iso8601;
+pub use regression::check_regressions;
+pub use session::{format_iso8601, now_iso8601, now_unix, parse_iso8601};
+pub use snapshot::{
+    BeadFields, BeadSnapshot, FieldChange, FieldsMerge, SNAPSHOT_FILE, SnapshotEntry,
+};
+pub use template::{render_template, template_variables, title_from_name};
+pub use tracker::{FileTracker, IssueTracker, MemoryTracker};
 pub use types::{
-    Anchor, BeadsHints, Checkpoint, CheckpointKind, Decision, ParseDiagnostic, Question, Speck,
-    SpeckMetadata, SpeckStatus, Step, Substep,
+    Anchor, BeadsHints, CheckMark, Checkpoint, CheckpointKind, Companion, Decision, Label,
+    LabelKind, ParseDiagnostic, Question, Speck, SpeckMetadata, SpeckStatus, Step, Substep,
+    Suppression,
 };
 pub use validator::{
-    Severity, ValidationConfig, ValidationIssue, ValidationLevel, ValidationResult, validate_speck,
-    validate_speck_with_config,
+    CustomCheck, CustomRule, RULES, RuleSetting, Severity, StepField, ValidationConfig,
+    ValidationIssue, ValidationLevel, ValidationResult, cited_labels, rule_description,
+    validate_speck, validate_speck_with_config,
 };
 pub use worktree::{
     CleanupMode, CleanupResult, DiscoveredWorktree, WorktreeConfig, WorktreeDiscovery,
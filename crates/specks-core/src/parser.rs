@@ -9,12 +9,13 @@
 
 use crate::error::SpecksError;
 use crate::types::{
-    Anchor, BeadsHints, Checkpoint, CheckpointKind, Decision, Question, Speck, Step, Substep,
+    Anchor, BeadsHints, CheckMark, Checkpoint, CheckpointKind, Decision, Label, LabelKind,
+    Question, Speck, Step, Substep, Suppression,
 };
 use std::collections::HashMap;
 
 /// Regex patterns for parsing (compiled once)
-mod patterns {
+pub(crate) mod patterns {
     use std::sync::LazyLock;
 
     pub static ANCHOR: LazyLock<regex::Regex> =
@@ -26,7 +27,7 @@ mod patterns {
 
     pub static STEP_HEADER: LazyLock<regex::Regex> = LazyLock::new(|| {
         regex::Regex::new(
-            r"^#{3,5}\s+Step\s+(\d+(?:\.\d+)?):?\s*(.+?)\s*(?:\{#([a-z0-9-]+)\})?\s*$",
+            r"^#{3,6}\s+Step\s+(\d+(?:\.\d+)*):?\s*(.+?)\s*(?:\{#([a-z0-9-]+)\})?\s*$",
         )
         .unwrap()
     });
@@ -38,8 +39,29 @@ mod patterns {
         .unwrap()
     });
 
+    /// Labeled artifact, as a bold line or a heading:
+    /// `**Spec S01: Title** {#s01-slug}` or `#### Table T01: Title {#t01-slug}`
+    pub static LABEL: LazyLock<regex::Regex> = LazyLock::new(|| {
+        regex::Regex::new(
+            r"^(?:#{2,6}\s+|\*\*)(Spec|Table|List|Risk|Milestone|Concept|Diagram)\s+((?:Diag|[STLRMC])\d+):\s*(.+?)\s*(?:\*\*)?\s*(?:\{#([a-z0-9-]+)\})?\s*$",
+        )
+        .unwrap()
+    });
+
+    /// Bold field label inside a decision or question body
+    pub static BODY_FIELD: LazyLock<regex::Regex> = LazyLock::new(|| {
+        regex::Regex::new(
+            r"^\*\*(Decision|Rationale|Implications|Question|Why it matters|Options|Plan to resolve|Resolution)(?:\s*\([^)]*\))?:\*\*\s*(.*)$",
+        )
+        .unwrap()
+    });
+
+    /// Checkbox item: `[ ]`, `[x]`, or skipped/blocked/failed `[-]`, `[~]`, `[!]`
     pub static CHECKBOX: LazyLock<regex::Regex> =
-        LazyLock::new(|| regex::Regex::new(r"^-\s+\[([ xX])\]\s*(.+)$").unwrap());
+        LazyLock::new(|| regex::Regex::new(r"^-\s+\[([ xX~!-])\]\s*(.+)$").unwrap());
+
+    pub static METADATA_HEADER: LazyLock<regex::Regex> =
+        LazyLock::new(|| regex::Regex::new(r"^\|\s*Field\s*\|\s*Value\s*\|").unwrap());
 
     pub static METADATA_ROW: LazyLock<regex::Regex> =
         LazyLock::new(|| regex::Regex::new(r"^\|\s*([^|]+?)\s*\|\s*([^|]*?)\s*\|").unwrap());
@@ -72,6 +94,14 @@ mod patterns {
         regex::Regex::new(r"^(#{1,6})\s+(.+?)\s*(?:\{#([a-z0-9-]+)\})?\s*$").unwrap()
     });
 
+    /// Inline suppression: `<!-- specks-ignore W003, W004: reason -->`
+    pub static SPECKS_IGNORE: LazyLock<regex::Regex> = LazyLock::new(|| {
+        regex::Regex::new(
+            r"^\s*<!--\s*specks-ignore\s+([A-Z]\d{3}(?:\s*,\s*[A-Z]\d{3})*)\s*(?::\s*(.*?))?\s*-->\s*$",
+        )
+        .unwrap()
+    });
+
     // Near-miss patterns for diagnostics (relaxed versions of strict patterns)
     pub static NEAR_MISS_STEP: LazyLock<regex::Regex> =
         LazyLock::new(|| regex::Regex::new(r"(?i)^#{1,6}\s+step\s+\d+").unwrap());
@@ -104,6 +134,8 @@ const KNOWN_METADATA_FIELDS: &[&str] = &[
     "tracking",
     "last updated",
     "beads root",
+    "includes",
+    "depends on",
 ];
 
 /// Parse a speck file from its contents
@@ -119,10 +151,14 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
     // Track current parsing context
     let mut in_metadata_table = false;
     let mut in_step: Option<usize> = None; // Index into speck.steps
-    let mut in_substep: Option<usize> = None; // Index into current step's substeps
+    let mut substep_path: Vec<usize> = Vec::new(); // Indices from the current step down to the open substep
     let mut current_section = CurrentSection::None;
     let mut anchor_locations: HashMap<String, usize> = HashMap::new();
     let mut in_code_block = false;
+    let mut in_body: Option<BodyOwner> = None;
+    let mut body_field: Option<BodyField> = None;
+    let mut pending_suppressions: Vec<usize> = Vec::new(); // Waiting for the next heading
+    let mut open_suppressions: Vec<(usize, usize)> = Vec::new(); // (index, heading level)
 
     for (line_num, line) in lines.iter().enumerate() {
         let line_number = line_num + 1; // 1-indexed
@@ -229,6 +265,53 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
             }
         }
 
+        // Inline suppressions cover the next heading's section
+        if let Some(caps) = patterns::SPECKS_IGNORE.captures(line) {
+            speck.suppressions.push(Suppression {
+                codes: caps
+                    .get(1)
+                    .unwrap()
+                    .as_str()
+                    .split(',')
+                    .map(|c| c.trim().to_string())
+                    .collect(),
+                reason: caps
+                    .get(2)
+                    .map(|m| m.as_str().to_string())
+                    .filter(|r| !r.is_empty()),
+                line: line_number,
+                start_line: 0,
+                end_line: 0,
+            });
+            pending_suppressions.push(speck.suppressions.len() - 1);
+            continue;
+        }
+        if line.trim_start().starts_with("<!--") && line.contains("specks-ignore") {
+            speck.diagnostics.push(crate::types::ParseDiagnostic {
+                code: "P009".to_string(),
+                message: "Malformed specks-ignore comment".to_string(),
+                line: line_number,
+                suggestion: Some(
+                    "Use <!-- specks-ignore W003: reason --> with one or more rule codes"
+                        .to_string(),
+                ),
+            });
+        }
+        if let Some(caps) = patterns::SECTION_HEADER.captures(line) {
+            let level = caps.get(1).unwrap().as_str().len();
+            open_suppressions.retain(|&(idx, open_level)| {
+                let closes = level <= open_level;
+                if closes {
+                    speck.suppressions[idx].end_line = line_number - 1;
+                }
+                !closes
+            });
+            for idx in pending_suppressions.drain(..) {
+                speck.suppressions[idx].start_line = line_number;
+                open_suppressions.push((idx, level));
+            }
+        }
+
         // Parse phase header
         if let Some(caps) = patterns::PHASE_HEADER.captures(line) {
             matched = true;
@@ -247,7 +330,7 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
         }
 
         // Detect metadata table start
-        if line.contains("| Field | Value |") || line.contains("|------|-------|") {
+        if patterns::METADATA_HEADER.is_match(line) || line.contains("|------|-------|") {
             in_metadata_table = true;
             continue;
         }
@@ -275,7 +358,7 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                         message: format!("Unrecognized metadata field: {}", field),
                         line: line_number,
                         suggestion: Some(
-                            "Known fields: Owner, Status, Target branch, Tracking issue/PR, Last updated, Beads Root".to_string()
+                            "Known fields: Owner, Status, Target branch, Tracking issue/PR, Last updated, Beads Root, Includes, Depends on".to_string()
                         ),
                     });
                 }
@@ -288,7 +371,22 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                         speck.metadata.tracking = non_empty_value(value)
                     }
                     "last updated" => speck.metadata.last_updated = non_empty_value(value),
-                    _ => {}
+                    "includes" => {
+                        speck.metadata.includes = value
+                            .split(',')
+                            .map(|f| f.trim().trim_matches('`').trim_start_matches("./"))
+                            .filter(|f| !f.is_empty())
+                            .map(|f| f.to_string())
+                            .collect()
+                    }
+                    "depends on" => speck.metadata.depends_on = dependency_refs(value),
+                    "beads root" => {}
+                    _ => {
+                        speck
+                            .metadata
+                            .extra
+                            .insert(field.to_string(), value.to_string());
+                    }
                 }
 
                 // Check for Beads Root in metadata
@@ -320,6 +418,26 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
             continue;
         }
 
+        // Parse decision/question body fields; the body ends at the next heading or rule
+        if let Some(owner) = in_body {
+            if line.starts_with('#') || line.trim() == "---" {
+                in_body = None;
+                body_field = None;
+            } else if let Some((field, inline)) =
+                patterns::BODY_FIELD.captures(line).and_then(|caps| {
+                    let field = BodyField::from_label(owner, caps.get(1).unwrap().as_str())?;
+                    Some((field, caps.get(2).map_or("", |m| m.as_str())))
+                })
+            {
+                matched = true;
+                body_field = Some(field);
+                append_body_text(&mut speck, owner, field, inline);
+                continue;
+            } else if let Some(field) = body_field {
+                append_body_text(&mut speck, owner, field, line);
+            }
+        }
+
         // Parse decision/question headers
         if let Some(caps) = patterns::DECISION_HEADER.captures(line) {
             matched = true;
@@ -335,7 +453,9 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                     status,
                     anchor,
                     line: line_number,
+                    ..Default::default()
                 });
+                in_body = Some(BodyOwner::Decision(speck.decisions.len() - 1));
             } else if id.starts_with('Q') {
                 speck.questions.push(Question {
                     id: id.to_string(),
@@ -343,11 +463,29 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                     resolution: status,
                     anchor,
                     line: line_number,
+                    ..Default::default()
                 });
+                in_body = Some(BodyOwner::Question(speck.questions.len() - 1));
             }
+            body_field = None;
             continue;
         }
 
+        // Parse labeled artifacts (Spec S01, Table T01, Risk R01, ...)
+        if let Some(caps) = patterns::LABEL.captures(line) {
+            if let Some(kind) = LabelKind::from_word(caps.get(1).unwrap().as_str()) {
+                matched = true;
+                speck.labels.push(Label {
+                    id: caps.get(2).unwrap().as_str().to_string(),
+                    kind,
+                    title: caps.get(3).unwrap().as_str().to_string(),
+                    anchor: caps.get(4).map(|m| m.as_str().to_string()),
+                    line: line_number,
+                });
+                continue;
+            }
+        }
+
         // Parse step headers
         if let Some(caps) = patterns::STEP_HEADER.captures(line) {
             matched = true;
@@ -370,9 +508,36 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                 };
 
                 if let Some(step_idx) = in_step {
-                    let substep_idx = speck.steps[step_idx].substeps.len();
-                    speck.steps[step_idx].substeps.push(substep);
-                    in_substep = Some(substep_idx);
+                    // Nest under the open substep one level up, or as deep as the
+                    // tree goes when intermediate levels are missing
+                    substep_path.truncate(substep.depth() - 1);
+                    let step = &mut speck.steps[step_idx];
+                    let (parent_number, siblings) = if substep_path.is_empty() {
+                        (step.number.clone(), &mut step.substeps)
+                    } else {
+                        let parent = substep_at(step, &substep_path);
+                        (parent.number.clone(), &mut parent.substeps)
+                    };
+
+                    if parent_number != substep.parent_number() {
+                        speck.diagnostics.push(crate::types::ParseDiagnostic {
+                            code: "P008".to_string(),
+                            message: format!(
+                                "Step {} has no enclosing Step {}; nested under Step {}",
+                                number,
+                                substep.parent_number(),
+                                parent_number
+                            ),
+                            line: line_number,
+                            suggestion: Some(format!(
+                                "Add a Step {} header above it or renumber it",
+                                substep.parent_number()
+                            )),
+                        });
+                    }
+
+                    siblings.push(substep);
+                    substep_path.push(siblings.len() - 1);
                 }
             } else {
                 // This is a main step
@@ -385,7 +550,7 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                 };
                 speck.steps.push(step);
                 in_step = Some(speck.steps.len() - 1);
-                in_substep = None;
+                substep_path.clear();
             }
 
             current_section = CurrentSection::None;
@@ -397,16 +562,10 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
             // Parse **Depends on:** line
             if let Some(caps) = patterns::DEPENDS_ON.captures(line) {
                 matched = true;
-                let deps_str = caps.get(1).unwrap().as_str();
-                let deps: Vec<String> = patterns::ANCHOR_REF
-                    .captures_iter(deps_str)
-                    .map(|c| c.get(1).unwrap().as_str().to_string())
-                    .collect();
-
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        speck.steps[step_idx].substeps[substep_idx].depends_on = deps;
-                    }
+                let deps = dependency_refs(caps.get(1).unwrap().as_str());
+
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    substep_at(&mut speck.steps[step_idx], &substep_path).depends_on = deps;
                 } else if let Some(step_idx) = in_step {
                     speck.steps[step_idx].depends_on = deps;
                 }
@@ -418,10 +577,8 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                 matched = true;
                 let bead_id = caps.get(1).unwrap().as_str().to_string();
 
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        speck.steps[step_idx].substeps[substep_idx].bead_id = Some(bead_id);
-                    }
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    substep_at(&mut speck.steps[step_idx], &substep_path).bead_id = Some(bead_id);
                 } else if let Some(step_idx) = in_step {
                     speck.steps[step_idx].bead_id = Some(bead_id);
                 }
@@ -434,10 +591,8 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                 let hints_str = caps.get(1).unwrap().as_str();
                 let hints = parse_beads_hints(hints_str);
 
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        speck.steps[step_idx].substeps[substep_idx].beads_hints = Some(hints);
-                    }
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    substep_at(&mut speck.steps[step_idx], &substep_path).beads_hints = Some(hints);
                 } else if let Some(step_idx) = in_step {
                     speck.steps[step_idx].beads_hints = Some(hints);
                 }
@@ -449,11 +604,9 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                 matched = true;
                 let commit_msg = caps.get(1).unwrap().as_str().to_string();
 
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        speck.steps[step_idx].substeps[substep_idx].commit_message =
-                            Some(commit_msg);
-                    }
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    substep_at(&mut speck.steps[step_idx], &substep_path).commit_message =
+                        Some(commit_msg);
                 } else if let Some(step_idx) = in_step {
                     speck.steps[step_idx].commit_message = Some(commit_msg);
                 }
@@ -465,10 +618,8 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                 matched = true;
                 let refs = caps.get(1).unwrap().as_str().to_string();
 
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        speck.steps[step_idx].substeps[substep_idx].references = Some(refs);
-                    }
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    substep_at(&mut speck.steps[step_idx], &substep_path).references = Some(refs);
                 } else if let Some(step_idx) = in_step {
                     speck.steps[step_idx].references = Some(refs);
                 }
@@ -478,17 +629,17 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
             // Parse checkbox items
             if let Some(caps) = patterns::CHECKBOX.captures(line) {
                 matched = true;
-                let checked = caps.get(1).unwrap().as_str() != " ";
-                let text = caps.get(2).unwrap().as_str().to_string();
+                let state = caps.get(1).unwrap().as_str();
+                let checked = state.eq_ignore_ascii_case("x");
+                let mark = CheckMark::from_marker(state);
+                let mut text = caps.get(2).unwrap().as_str().to_string();
 
                 // Special handling for Artifacts section: capture text as plain artifact item
                 if current_section == CurrentSection::Artifacts {
-                    if let Some(substep_idx) = in_substep {
-                        if let Some(step_idx) = in_step {
-                            speck.steps[step_idx].substeps[substep_idx]
-                                .artifacts
-                                .push(text);
-                        }
+                    if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                        substep_at(&mut speck.steps[step_idx], &substep_path)
+                            .artifacts
+                            .push(text);
                     } else if let Some(step_idx) = in_step {
                         speck.steps[step_idx].artifacts.push(text);
                     }
@@ -502,31 +653,38 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
                     _ => CheckpointKind::Task, // Default to task
                 };
 
+                // A marked item may give its reason after ` -- ` (or an em dash)
+                let reason = mark.and_then(|_| {
+                    let (item, reason) = text
+                        .split_once(" -- ")
+                        .or_else(|| text.split_once(" \u{2014} "))?;
+                    let reason = reason.trim().to_string();
+                    text = item.trim_end().to_string();
+                    Some(reason)
+                });
+
                 let checkpoint = Checkpoint {
-                    checked,
-                    text,
-                    kind,
-                    line: line_number,
+                    mark,
+                    reason,
+                    ..Checkpoint::new(kind, text, checked, line_number)
                 };
 
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        match kind {
-                            CheckpointKind::Task => {
-                                speck.steps[step_idx].substeps[substep_idx]
-                                    .tasks
-                                    .push(checkpoint);
-                            }
-                            CheckpointKind::Test => {
-                                speck.steps[step_idx].substeps[substep_idx]
-                                    .tests
-                                    .push(checkpoint);
-                            }
-                            CheckpointKind::Checkpoint => {
-                                speck.steps[step_idx].substeps[substep_idx]
-                                    .checkpoints
-                                    .push(checkpoint);
-                            }
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    match kind {
+                        CheckpointKind::Task => {
+                            substep_at(&mut speck.steps[step_idx], &substep_path)
+                                .tasks
+                                .push(checkpoint);
+                        }
+                        CheckpointKind::Test => {
+                            substep_at(&mut speck.steps[step_idx], &substep_path)
+                                .tests
+                                .push(checkpoint);
+                        }
+                        CheckpointKind::Checkpoint => {
+                            substep_at(&mut speck.steps[step_idx], &substep_path)
+                                .checkpoints
+                                .push(checkpoint);
                         }
                     }
                 } else if let Some(step_idx) = in_step {
@@ -549,12 +707,10 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
             if current_section == CurrentSection::Artifacts && line.trim_start().starts_with("- ") {
                 matched = true;
                 let text = line.trim_start().strip_prefix("- ").unwrap().to_string();
-                if let Some(substep_idx) = in_substep {
-                    if let Some(step_idx) = in_step {
-                        speck.steps[step_idx].substeps[substep_idx]
-                            .artifacts
-                            .push(text);
-                    }
+                if let Some(step_idx) = in_step.filter(|_| !substep_path.is_empty()) {
+                    substep_at(&mut speck.steps[step_idx], &substep_path)
+                        .artifacts
+                        .push(text);
                 } else if let Some(step_idx) = in_step {
                     speck.steps[step_idx].artifacts.push(text);
                 }
@@ -630,6 +786,20 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
         }
     }
 
+    for (idx, _) in open_suppressions {
+        speck.suppressions[idx].end_line = lines.len();
+    }
+    for idx in pending_suppressions {
+        speck.diagnostics.push(crate::types::ParseDiagnostic {
+            code: "P009".to_string(),
+            message: "specks-ignore comment is not followed by a heading".to_string(),
+            line: speck.suppressions[idx].line,
+            suggestion: Some(
+                "Place the comment directly above the heading or step it applies to".to_string(),
+            ),
+        });
+    }
+
     Ok(speck)
 }
 
@@ -637,50 +807,55 @@ pub fn parse_speck(content: &str) -> Result<Speck, SpecksError> {
 fn parse_beads_hints(hints_str: &str) -> BeadsHints {
     let mut hints = BeadsHints::default();
 
-    // Split by comma followed by space and key= pattern to handle labels with commas
-    // We need to find key=value pairs more carefully
-    let mut remaining = hints_str.trim();
-
-    while !remaining.is_empty() {
-        // Find the next key=value pair
-        if let Some(eq_pos) = remaining.find('=') {
-            let key = remaining[..eq_pos].trim().to_lowercase();
-
-            // Find the end of this value (next ", key=" pattern or end of string)
-            let value_start = eq_pos + 1;
-            let rest = &remaining[value_start..];
-
-            // Look for next key= pattern (space + word + =)
-            let value_end = find_next_key_start(rest);
-            let value = rest[..value_end].trim().trim_end_matches(',').trim();
-
-            match key.as_str() {
-                "type" => hints.issue_type = Some(value.to_string()),
-                "priority" => {
-                    if let Ok(p) = value.parse::<u8>() {
-                        hints.priority = Some(p);
-                    }
+    for (key, value) in split_beads_hints(hints_str) {
+        match key.to_lowercase().as_str() {
+            "type" => hints.issue_type = Some(value.to_string()),
+            "priority" => {
+                if let Ok(p) = value.parse::<u8>() {
+                    hints.priority = Some(p);
                 }
-                "labels" => {
-                    hints.labels = value.split(',').map(|s| s.trim().to_string()).collect();
-                }
-                "estimate_minutes" | "estimate" => {
-                    if let Ok(e) = value.parse::<u32>() {
-                        hints.estimate_minutes = Some(e);
-                    }
+            }
+            "labels" => {
+                hints.labels = value.split(',').map(|s| s.trim().to_string()).collect();
+            }
+            "estimate_minutes" | "estimate" => {
+                if let Ok(e) = value.parse::<u32>() {
+                    hints.estimate_minutes = Some(e);
                 }
-                _ => {}
             }
-
-            remaining = rest[value_end..].trim().trim_start_matches(',').trim();
-        } else {
-            break;
+            _ => {}
         }
     }
 
     hints
 }
 
+/// Split a hints string into raw `(key, value)` pairs, in source order
+///
+/// Values may contain commas (e.g. `labels=backend,api`); a new pair starts
+/// only at a comma followed by `key=`.
+pub(crate) fn split_beads_hints(hints_str: &str) -> Vec<(&str, &str)> {
+    let mut pairs = Vec::new();
+    let mut remaining = hints_str.trim();
+
+    while !remaining.is_empty() {
+        let Some(eq_pos) = remaining.find('=') else {
+            break;
+        };
+        let key = remaining[..eq_pos].trim();
+
+        // Find the end of this value (next ", key=" pattern or end of string)
+        let rest = &remaining[eq_pos + 1..];
+        let value_end = find_next_key_start(rest);
+        let value = rest[..value_end].trim().trim_end_matches(',').trim();
+        pairs.push((key, value));
+
+        remaining = rest[value_end..].trim().trim_start_matches(',').trim();
+    }
+
+    pairs
+}
+
 /// Find the start of the next key=value pair in a hints string
 fn find_next_key_start(s: &str) -> usize {
     // Look for patterns like ", key=" where key is a word
@@ -711,6 +886,15 @@ fn find_next_key_start(s: &str) -> usize {
     s.len()
 }
 
+/// Follow a path of substep indices down from a step
+fn substep_at<'a>(step: &'a mut Step, path: &[usize]) -> &'a mut Substep {
+    let (first, rest) = path.split_first().expect("substep path is not empty");
+    rest.iter()
+        .fold(&mut step.substeps[*first], |substep, &idx| {
+            &mut substep.substeps[idx]
+        })
+}
+
 /// Track which section we're currently parsing within a step
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CurrentSection {
@@ -722,6 +906,109 @@ enum CurrentSection {
     Other,
 }
 
+/// Decision or question whose body is being parsed (index into the speck's list)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyOwner {
+    Decision(usize),
+    Question(usize),
+}
+
+/// Bold field within a decision or question body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyField {
+    Statement,
+    Rationale,
+    Implications,
+    Question,
+    WhyItMatters,
+    Options,
+    PlanToResolve,
+    Resolution,
+}
+
+impl BodyField {
+    /// Map a field label to a field, if it belongs to this kind of body
+    fn from_label(owner: BodyOwner, label: &str) -> Option<Self> {
+        match (owner, label) {
+            (BodyOwner::Decision(_), "Decision") => Some(Self::Statement),
+            (BodyOwner::Decision(_), "Rationale") => Some(Self::Rationale),
+            (BodyOwner::Decision(_), "Implications") => Some(Self::Implications),
+            (BodyOwner::Question(_), "Question") => Some(Self::Question),
+            (BodyOwner::Question(_), "Why it matters") => Some(Self::WhyItMatters),
+            (BodyOwner::Question(_), "Options") => Some(Self::Options),
+            (BodyOwner::Question(_), "Plan to resolve") => Some(Self::PlanToResolve),
+            (BodyOwner::Question(_), "Resolution") => Some(Self::Resolution),
+            _ => None,
+        }
+    }
+}
+
+/// Append a line of body text to a decision or question field
+///
+/// List fields take one item per bullet; other lines continue the last item.
+/// Text fields join their lines with spaces. Blank lines are ignored.
+fn append_body_text(speck: &mut Speck, owner: BodyOwner, field: BodyField, line: &str) {
+    let text = line.trim();
+    if text.is_empty() {
+        return;
+    }
+
+    let (list, scalar) = match owner {
+        BodyOwner::Decision(idx) => {
+            let decision = &mut speck.decisions[idx];
+            match field {
+                BodyField::Statement => (None, Some(&mut decision.statement)),
+                BodyField::Rationale => (Some(&mut decision.rationale), None),
+                BodyField::Implications => (Some(&mut decision.implications), None),
+                _ => return,
+            }
+        }
+        BodyOwner::Question(idx) => {
+            let question = &mut speck.questions[idx];
+            match field {
+                BodyField::Question => (None, Some(&mut question.question)),
+                BodyField::WhyItMatters => (None, Some(&mut question.why_it_matters)),
+                BodyField::Options => (Some(&mut question.options), None),
+                BodyField::PlanToResolve => (None, Some(&mut question.plan_to_resolve)),
+                BodyField::Resolution => (None, Some(&mut question.resolution_note)),
+                _ => return,
+            }
+        }
+    };
+
+    if let Some(items) = list {
+        let bullet = text.strip_prefix("- ").or_else(|| text.strip_prefix("* "));
+        match (bullet, items.last_mut()) {
+            (Some(item), _) => items.push(item.trim().to_string()),
+            (None, Some(last)) => {
+                last.push(' ');
+                last.push_str(text);
+            }
+            (None, None) => items.push(text.to_string()),
+        }
+    } else if let Some(value) = scalar {
+        match value {
+            Some(existing) => {
+                existing.push(' ');
+                existing.push_str(text);
+            }
+            None => *value = Some(text.to_string()),
+        }
+    }
+}
+
+/// Dependency refs in a `Depends on` value: `#step-0` becomes `step-0`,
+/// `specks-auth.md#step-3` stays qualified by its file
+fn dependency_refs(value: &str) -> Vec<String> {
+    crate::companion::FILE_ANCHOR_REF
+        .captures_iter(value)
+        .map(|c| match c.get(1) {
+            Some(file) => format!("{}#{}", file.as_str(), &c[2]),
+            None => c[2].to_string(),
+        })
+        .collect()
+}
+
 /// Convert a value to Option, returning None only if empty
 /// Per spec: TBD is considered "present" for Owner and Tracking fields
 /// Per spec: <...> placeholders are stored but generate a warning
@@ -852,6 +1139,33 @@ mod tests {
         assert_eq!(speck.steps[2].depends_on, vec!["step-0", "step-1"]);
     }
 
+    #[test]
+    fn test_parse_cross_speck_depends_on() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Depends on | specks-auth.md#step-3, ./specks-db.md#step-0 |
+
+#### Step 0: First {#step-0}
+
+**Depends on:** specks-auth.md#step-1, #step-2
+"#;
+        let speck = parse_speck(content).unwrap();
+        assert_eq!(
+            speck.metadata.depends_on,
+            vec!["specks-auth.md#step-3", "specks-db.md#step-0"]
+        );
+        assert_eq!(
+            speck.steps[0].depends_on,
+            vec!["specks-auth.md#step-1", "step-2"]
+        );
+        assert!(speck.diagnostics.iter().all(|d| d.code != "P004"));
+    }
+
     #[test]
     fn test_parse_bead_line() {
         let content = r#"## Phase 1.0: Test {#phase-1}
@@ -943,6 +1257,51 @@ mod tests {
         assert!(step.substeps[1].tasks[0].checked);
     }
 
+    #[test]
+    fn test_parse_nested_substeps() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+#### Step 2: Big Step {#step-2}
+
+##### Step 2.1: First {#step-2-1}
+
+###### Step 2.1.1: Deeper
+
+**Depends on:** #step-2-1
+
+**Tasks:**
+- [ ] Deep task
+
+###### Step 2.1.2: Deeper still {#step-2-1-2}
+
+##### Step 2.2: Second {#step-2-2}
+
+**Tasks:**
+- [x] Back at level one
+
+###### Step 2.3.1: Orphan {#step-2-3-1}
+"#;
+
+        let speck = parse_speck(content).unwrap();
+
+        let step = &speck.steps[0];
+        assert_eq!(step.substeps.len(), 2);
+        let first = &step.substeps[0];
+        assert_eq!(first.substeps.len(), 2);
+        assert_eq!(first.substeps[0].anchor, "step-2-1-1");
+        assert_eq!(first.substeps[0].depends_on, vec!["step-2-1"]);
+        assert_eq!(first.substeps[0].tasks.len(), 1);
+        assert_eq!(first.substeps[1].depth(), 2);
+        assert_eq!(first.substeps[1].parent_number(), "2.1");
+        assert_eq!(step.substeps[1].tasks.len(), 1);
+
+        // 2.3.1 has no Step 2.3, so it lands under the open 2.2 with a diagnostic
+        assert_eq!(step.substeps[1].substeps[0].number, "2.3.1");
+        assert_eq!(speck.diagnostics.len(), 1);
+        assert_eq!(speck.diagnostics[0].code, "P008");
+        assert_eq!(speck.diagnostics[0].line, 21);
+    }
+
     #[test]
     fn test_parse_decisions() {
         let content = r#"## Phase 1.0: Test {#phase-1}
@@ -978,6 +1337,45 @@ mod tests {
         assert_eq!(speck.decisions[1].status, Some("OPEN".to_string()));
     }
 
+    #[test]
+    fn test_parse_labels() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Specification {#specification}
+
+**Spec S01: Response Schema** {#s01-response}
+
+Schema text.
+
+**Table T01: Error Codes** {#t01-error-codes}
+
+#### Risk R01: Slow parser {#r01-slow-parser}
+
+**Diagram Diag01: Flow**
+
+- **Specs**: `**Spec S09: <Title>** {#s09-slug}` (inline example, not a label)
+"#;
+
+        let speck = parse_speck(content).unwrap();
+
+        let ids: Vec<_> = speck.labels.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["S01", "T01", "R01", "Diag01"]);
+
+        let spec = speck.label("S01").unwrap();
+        assert_eq!(spec.kind, LabelKind::Spec);
+        assert_eq!(spec.title, "Response Schema");
+        assert_eq!(spec.anchor, Some("s01-response".to_string()));
+        assert_eq!(spec.line, 5);
+
+        let risk = speck.label("R01").unwrap();
+        assert_eq!(risk.kind, LabelKind::Risk);
+        assert_eq!(risk.title, "Slow parser");
+
+        let diagram = speck.label("Diag01").unwrap();
+        assert_eq!(diagram.kind, LabelKind::Diagram);
+        assert_eq!(diagram.anchor, None);
+    }
+
     #[test]
     fn test_parse_questions() {
         let content = r#"## Phase 1.0: Test {#phase-1}
@@ -1003,6 +1401,85 @@ mod tests {
         assert_eq!(speck.questions[0].id, "Q01");
         assert_eq!(speck.questions[0].title, "Distribution strategy");
         assert_eq!(speck.questions[0].resolution, Some("DEFERRED".to_string()));
+        assert_eq!(
+            speck.questions[0].question,
+            Some("How to distribute?".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_decision_and_question_bodies() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Open Questions {#open-questions}
+
+#### [Q01] Cache location (OPEN) {#q01-cache}
+
+**Question:** Where should the cache live?
+
+**Why it matters:** Wrong choice breaks
+sandboxed installs.
+
+**Options (if known):**
+- XDG cache dir
+- Project-local `.specks/cache`
+
+**Plan to resolve:** Spike both.
+
+**Resolution:** OPEN
+
+---
+
+### Design Decisions {#design-decisions}
+
+#### [D01] Use Rust (DECIDED) {#d01-use-rust}
+
+**Decision:** Build the CLI in Rust.
+
+**Rationale:**
+- Single static binary
+- Strong typing for the parser
+
+**Implications:**
+- Contributors need a Rust toolchain
+
+#### [D02] Use clap (DECIDED) {#d02-use-clap}
+
+**Decision:** Parse arguments with clap.
+
+**Rationale:** Derive API keeps the CLI declarative.
+
+### Execution Steps {#execution-steps}
+
+**Rationale:** Not part of any decision.
+"#;
+
+        let speck = parse_speck(content).unwrap();
+
+        let q = &speck.questions[0];
+        assert_eq!(q.question, Some("Where should the cache live?".to_string()));
+        assert_eq!(
+            q.why_it_matters,
+            Some("Wrong choice breaks sandboxed installs.".to_string())
+        );
+        assert_eq!(
+            q.options,
+            vec!["XDG cache dir", "Project-local `.specks/cache`"]
+        );
+        assert_eq!(q.plan_to_resolve, Some("Spike both.".to_string()));
+        assert_eq!(q.resolution_note, Some("OPEN".to_string()));
+
+        let d1 = &speck.decisions[0];
+        assert_eq!(d1.statement, Some("Build the CLI in Rust.".to_string()));
+        assert_eq!(
+            d1.rationale,
+            vec!["Single static binary", "Strong typing for the parser"]
+        );
+        assert_eq!(d1.implications, vec!["Contributors need a Rust toolchain"]);
+
+        let d2 = &speck.decisions[1];
+        assert_eq!(d2.rationale, vec!["Derive API keeps the CLI declarative."]);
+        assert!(d2.implications.is_empty());
     }
 
     #[test]
@@ -1056,6 +1533,38 @@ mod tests {
         assert!(speck.steps[0].tasks[2].checked);
     }
 
+    #[test]
+    fn test_checkbox_marks_with_reasons() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+#### Step 0: Test {#step-0}
+
+**Tests:**
+- [-] Load test -- no staging cluster yet
+- [~] Integration test
+- [!] Fuzz test — crashes on empty input
+- [ ] Unit test -- not a reason
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let tests = &speck.steps[0].tests;
+
+        assert_eq!(tests.len(), 4);
+        assert_eq!(tests[0].mark, Some(CheckMark::Skipped));
+        assert_eq!(tests[0].text, "Load test");
+        assert_eq!(tests[0].reason.as_deref(), Some("no staging cluster yet"));
+        assert_eq!(tests[1].mark, Some(CheckMark::Blocked));
+        assert_eq!(tests[1].reason, None);
+        assert_eq!(tests[2].mark, Some(CheckMark::Failed));
+        assert_eq!(tests[2].text, "Fuzz test");
+        assert_eq!(tests[2].reason.as_deref(), Some("crashes on empty input"));
+        assert!(tests.iter().all(|t| !t.checked));
+
+        // Only marked items carry a reason
+        assert_eq!(tests[3].mark, None);
+        assert_eq!(tests[3].text, "Unit test -- not a reason");
+    }
+
     #[test]
     fn test_malformed_markdown_graceful() {
         // Parser should not panic on malformed content
@@ -1505,6 +2014,8 @@ This is content.
                 .unwrap()
                 .contains("Owner")
         );
+        assert_eq!(speck.metadata.field("author"), Some("Test User"));
+        assert_eq!(speck.metadata.field("Status"), Some("active"));
     }
 
     #[test]
@@ -1841,4 +2352,54 @@ Example:
             .count();
         assert_eq!(p005_count, 0, "P005 should not fire for valid anchors");
     }
+
+    #[test]
+    fn test_specks_ignore_scopes_to_next_heading() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+<!-- specks-ignore W003, W004: integration-only step -->
+#### Step 0: Setup {#step-0}
+
+##### Step 0.1: Sub {#step-0-1}
+
+#### Step 1: Build {#step-1}
+
+<!-- specks-ignore W003 -->
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        assert_eq!(speck.suppressions.len(), 2);
+
+        let first = &speck.suppressions[0];
+        assert_eq!(first.codes, vec!["W003", "W004"]);
+        assert_eq!(first.reason.as_deref(), Some("integration-only step"));
+        assert_eq!(first.line, 3);
+        assert_eq!((first.start_line, first.end_line), (4, 7));
+        assert!(first.covers("W003", 6)); // substep is inside the step
+        assert!(!first.covers("W003", 8));
+        assert!(!first.covers("W009", 4));
+
+        // Trailing comment with no heading after it
+        assert_eq!(speck.suppressions[1].start_line, 0);
+        let p009: Vec<_> = speck
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == "P009")
+            .collect();
+        assert_eq!(p009.len(), 1);
+        assert_eq!(p009[0].line, 10);
+    }
+
+    #[test]
+    fn test_specks_ignore_malformed_p009() {
+        let content = "## Phase 1.0: Test {#phase-1}\n\n<!-- specks-ignore everything -->\n#### Step 0: Setup {#step-0}\n";
+        let speck = parse_speck(content).unwrap();
+        assert!(speck.suppressions.is_empty());
+        assert!(
+            speck
+                .diagnostics
+                .iter()
+                .any(|d| d.code == "P009" && d.line == 3)
+        );
+    }
 }
@@ -0,0 +1,349 @@
+//! Semantic regressions between two versions of a speck
+//!
+//! Validation looks at one version of a speck. These checks compare it with
+//! an earlier version (typically a git revision) and flag edits that undo
+//! recorded progress or break other specks:
+//!
+//! - R001: a checked item was unchecked
+//! - R002: a step or substep that had a bead was removed
+//! - R003: an anchor another speck references was removed
+//! - R004: the Status moved backwards (e.g., done to active)
+
+use std::collections::{HashMap, HashSet};
+
+use crate::types::{Checkpoint, CheckpointKind, Speck};
+use crate::validator::{
+    Severity, ValidationConfig, ValidationIssue, ValidationResult, apply_rule_settings,
+};
+
+/// The fields regression checks compare, shared by steps and substeps
+struct StepItems<'a> {
+    number: &'a str,
+    anchor: &'a str,
+    bead_id: Option<&'a str>,
+    items: Vec<&'a Checkpoint>,
+}
+
+/// Every step and substep of a speck, in document order
+fn step_items(speck: &Speck) -> Vec<StepItems<'_>> {
+    let mut steps = Vec::new();
+    for step in &speck.steps {
+        steps.push(StepItems {
+            number: &step.number,
+            anchor: &step.anchor,
+            bead_id: step.bead_id.as_deref(),
+            items: step.items().collect(),
+        });
+        for substep in step.all_substeps() {
+            steps.push(StepItems {
+                number: &substep.number,
+                anchor: &substep.anchor,
+                bead_id: substep.bead_id.as_deref(),
+                items: substep.items().collect(),
+            });
+        }
+    }
+    steps
+}
+
+/// Position of a Status value in the draft -> active -> done lifecycle
+fn status_rank(status: Option<&str>) -> Option<u8> {
+    match status?.trim().to_lowercase().as_str() {
+        "draft" => Some(0),
+        "active" => Some(1),
+        "done" => Some(2),
+        _ => None,
+    }
+}
+
+fn kind_name(kind: CheckpointKind) -> &'static str {
+    match kind {
+        CheckpointKind::Task => "Task",
+        CheckpointKind::Test => "Test",
+        CheckpointKind::Checkpoint => "Checkpoint",
+    }
+}
+
+/// Add regression issues for `new` compared with `old` to `result`
+///
+/// `referenced_by` lists `(anchor, speck file)` pairs: anchors of this speck
+/// that other specks reference. Rule overrides, suppressions and the level
+/// cutoff from `config` apply to the new issues as they do to validation.
+pub fn check_regressions(
+    old: &Speck,
+    new: &Speck,
+    referenced_by: &[(String, String)],
+    config: &ValidationConfig,
+    result: &mut ValidationResult,
+) {
+    let mut regressions = ValidationResult::new();
+    let old_steps = step_items(old);
+    let new_steps = step_items(new);
+    let new_by_anchor: HashMap<&str, &StepItems> =
+        new_steps.iter().map(|s| (s.anchor, s)).collect();
+
+    // R001: checked items that are unchecked now, matched by step, kind and text
+    for old_step in &old_steps {
+        let Some(new_step) = new_by_anchor.get(old_step.anchor) else {
+            continue;
+        };
+        let mut lost: HashMap<(CheckpointKind, &str), isize> = HashMap::new();
+        for item in old_step.items.iter().filter(|i| i.checked) {
+            *lost.entry((item.kind, item.text.as_str())).or_default() += 1;
+        }
+        for item in new_step.items.iter().filter(|i| i.checked) {
+            *lost.entry((item.kind, item.text.as_str())).or_default() -= 1;
+        }
+        for item in new_step.items.iter().filter(|i| !i.checked) {
+            let Some(count) = lost.get_mut(&(item.kind, item.text.as_str())) else {
+                continue;
+            };
+            if *count > 0 {
+                *count -= 1;
+                regressions.add_issue(
+                    ValidationIssue::new(
+                        "R001",
+                        Severity::Warning,
+                        format!(
+                            "Step {}: {} '{}' was checked and is now unchecked",
+                            new_step.number,
+                            kind_name(item.kind),
+                            item.text
+                        ),
+                    )
+                    .at_line(item.line)
+                    .with_anchor(new_step.anchor),
+                );
+            }
+        }
+    }
+
+    // R002: removed steps that had a bead
+    for old_step in &old_steps {
+        if let Some(bead_id) = old_step.bead_id {
+            if !new_by_anchor.contains_key(old_step.anchor) {
+                regressions.add_issue(
+                    ValidationIssue::new(
+                        "R002",
+                        Severity::Error,
+                        format!(
+                            "Step {} (#{}) was removed but had bead {}",
+                            old_step.number, old_step.anchor, bead_id
+                        ),
+                    )
+                    .with_anchor(old_step.anchor),
+                );
+            }
+        }
+    }
+
+    // R003: removed anchors that other specks reference
+    let new_anchors: HashSet<&str> = new
+        .anchors
+        .iter()
+        .chain(new.companions.iter().flat_map(|c| &c.anchors))
+        .map(|a| a.name.as_str())
+        .collect();
+    let mut reported = HashSet::new();
+    for anchor in &old.anchors {
+        let name = anchor.name.as_str();
+        if new_anchors.contains(name) || !reported.insert(name) {
+            continue;
+        }
+        let files: Vec<&str> = referenced_by
+            .iter()
+            .filter(|(a, _)| a == name)
+            .map(|(_, file)| file.as_str())
+            .collect();
+        if !files.is_empty() {
+            regressions.add_issue(
+                ValidationIssue::new(
+                    "R003",
+                    Severity::Error,
+                    format!(
+                        "Anchor #{} was removed but is referenced by {}",
+                        name,
+                        files.join(", ")
+                    ),
+                )
+                .with_anchor(name),
+            );
+        }
+    }
+
+    // R004: Status moved backwards
+    let old_status = old.metadata.status.as_deref();
+    let new_status = new.metadata.status.as_deref();
+    if let (Some(before), Some(after)) = (status_rank(old_status), status_rank(new_status)) {
+        if after < before {
+            regressions.add_issue(ValidationIssue::new(
+                "R004",
+                Severity::Warning,
+                format!(
+                    "Status moved backwards: {} -> {}",
+                    old_status.unwrap_or_default().trim(),
+                    new_status.unwrap_or_default().trim()
+                ),
+            ));
+        }
+    }
+
+    apply_rule_settings(new, config, &mut regressions);
+    result.issues.extend(regressions.issues);
+    result.valid = result.error_count() == 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_speck;
+    use crate::validator::RuleSetting;
+
+    const OLD: &str = r#"## Phase 1.0: Auth {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | done |
+| Last updated | 2026-02-04 |
+
+#### Step 0: Setup {#step-0}
+
+**Bead:** `bd-abc.1`
+
+**Tasks:**
+- [x] Create module
+- [x] Add config
+
+**Tests:**
+- [x] Unit test
+
+#### Step 1: Tokens {#step-1}
+
+**Bead:** `bd-abc.2`
+
+**Tasks:**
+- [x] Issue tokens
+
+#### Step 2: Docs {#step-2}
+
+**Tasks:**
+- [ ] Write docs
+"#;
+
+    fn check(
+        old: &str,
+        new: &str,
+        refs: &[(&str, &str)],
+        config: &ValidationConfig,
+    ) -> Vec<ValidationIssue> {
+        let refs: Vec<(String, String)> = refs
+            .iter()
+            .map(|(a, f)| (a.to_string(), f.to_string()))
+            .collect();
+        let mut result = ValidationResult::new();
+        check_regressions(
+            &parse_speck(old).unwrap(),
+            &parse_speck(new).unwrap(),
+            &refs,
+            config,
+            &mut result,
+        );
+        result.issues
+    }
+
+    fn codes(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues.iter().map(|i| i.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_unchanged_speck_has_no_regressions() {
+        let issues = check(OLD, OLD, &[("step-2", "specks-b.md")], &Default::default());
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn test_r001_unchecked_items() {
+        let new = OLD
+            .replace("- [x] Add config", "- [ ] Add config")
+            .replace("- [x] Unit test", "- [ ] Unit test")
+            // Reworded items are not regressions
+            .replace("- [x] Create module", "- [ ] Create the module");
+        let issues = check(OLD, &new, &[], &Default::default());
+        assert_eq!(codes(&issues), vec!["R001", "R001"]);
+        assert_eq!(
+            issues[0].message,
+            "Step 0: Task 'Add config' was checked and is now unchecked"
+        );
+        assert_eq!(issues[0].anchor.as_deref(), Some("#step-0"));
+        assert_eq!(issues[0].line, Some(17));
+        assert!(issues[1].message.starts_with("Step 0: Test 'Unit test'"));
+    }
+
+    #[test]
+    fn test_r002_removed_step_with_bead() {
+        let start = OLD.find("#### Step 1").unwrap();
+        let end = OLD.find("#### Step 2").unwrap();
+        let new = format!("{}{}", &OLD[..start], &OLD[end..]);
+        let issues = check(OLD, &new, &[], &Default::default());
+        assert_eq!(codes(&issues), vec!["R002"]);
+        assert_eq!(
+            issues[0].message,
+            "Step 1 (#step-1) was removed but had bead bd-abc.2"
+        );
+
+        // Removing a step without a bead is fine
+        let new = &OLD[..OLD.find("#### Step 2").unwrap()];
+        assert!(check(OLD, new, &[], &Default::default()).is_empty());
+    }
+
+    #[test]
+    fn test_r003_removed_anchor_referenced_elsewhere() {
+        let new = &OLD[..OLD.find("#### Step 2").unwrap()];
+        let refs = [("step-2", "specks-b.md"), ("step-2", "specks-c.md")];
+        let issues = check(OLD, new, &refs, &Default::default());
+        assert_eq!(codes(&issues), vec!["R003"]);
+        assert_eq!(
+            issues[0].message,
+            "Anchor #step-2 was removed but is referenced by specks-b.md, specks-c.md"
+        );
+    }
+
+    #[test]
+    fn test_r004_status_moved_backwards() {
+        let new = OLD.replace("| Status | done |", "| Status | active |");
+        let issues = check(OLD, &new, &[], &Default::default());
+        assert_eq!(codes(&issues), vec!["R004"]);
+        assert_eq!(issues[0].message, "Status moved backwards: done -> active");
+
+        // Forwards is progress
+        assert!(check(&new, OLD, &[], &Default::default()).is_empty());
+    }
+
+    #[test]
+    fn test_regressions_respect_rule_settings() {
+        let new = OLD
+            .replace("| Status | done |", "| Status | draft |")
+            .replace("- [x] Add config", "- [ ] Add config");
+        let config = ValidationConfig {
+            rules: HashMap::from([
+                ("R004".to_string(), RuleSetting::Off),
+                ("R001".to_string(), RuleSetting::Severity(Severity::Error)),
+            ]),
+            ..Default::default()
+        };
+        let mut result = ValidationResult::new();
+        check_regressions(
+            &parse_speck(OLD).unwrap(),
+            &parse_speck(&new).unwrap(),
+            &[],
+            &config,
+            &mut result,
+        );
+        assert_eq!(codes(&result.issues), vec!["R001"]);
+        assert_eq!(result.issues[0].severity, Severity::Error);
+        assert!(!result.valid);
+    }
+}
@@ -10,73 +10,138 @@ pub fn now_iso8601() -> String {
     let duration = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("SystemTime before UNIX_EPOCH");
+    let millis = duration.subsec_millis();
 
-    let secs = duration.as_secs();
-    let nanos = duration.subsec_nanos();
-
-    // Convert to date/time components
-    const SECONDS_PER_DAY: u64 = 86400;
-    const DAYS_TO_EPOCH: i64 = 719162; // Days from 0000-01-01 to 1970-01-01
-
-    let days_since_epoch = (secs / SECONDS_PER_DAY) as i64;
-    let seconds_today = secs % SECONDS_PER_DAY;
+    // "YYYY-MM-DDTHH:MM:SS" plus milliseconds
+    let seconds = format_iso8601(duration.as_secs() as i64);
+    format!("{}.{:03}Z", &seconds[..19], millis)
+}
 
-    let hours = seconds_today / 3600;
-    let minutes = (seconds_today % 3600) / 60;
-    let seconds = seconds_today % 60;
-    let millis = nanos / 1_000_000;
+/// Current time in seconds since the Unix epoch
+pub fn now_unix() -> i64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
 
-    // Calculate year, month, day (simplified algorithm)
-    let total_days = DAYS_TO_EPOCH + days_since_epoch;
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("SystemTime before UNIX_EPOCH")
+        .as_secs() as i64
+}
 
-    // Approximate year (will refine)
-    let mut year = (total_days / 365) as i32;
-    let mut remaining_days = total_days - year_to_days(year);
+/// Parse an ISO 8601 timestamp into seconds since the Unix epoch
+///
+/// Accepts a plain date ("2026-02-13") or a date and time with optional
+/// fractional seconds and a `Z` or `+HH:MM` offset
+/// ("2026-02-13T02:24:00.123-08:00"). Returns `None` for anything else.
+pub fn parse_iso8601(timestamp: &str) -> Option<i64> {
+    let timestamp = timestamp.trim();
+    let (date, time) = match timestamp.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (timestamp, None),
+    };
 
-    // Adjust for leap years
-    while remaining_days < 0 {
-        year -= 1;
-        remaining_days = total_days - year_to_days(year);
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
     }
-    while remaining_days >= days_in_year(year) {
-        remaining_days -= days_in_year(year);
-        year += 1;
+    let mut secs = days_from_civil(year, month, day) * 86400;
+
+    if let Some(time) = time {
+        let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+            (clock, 0)
+        } else if let Some(pos) = time.rfind(['+', '-']) {
+            let (clock, offset) = time.split_at(pos);
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+            if digits.len() != 4 {
+                return None;
+            }
+            let hours: i64 = digits[..2].parse().ok()?;
+            let minutes: i64 = digits[2..].parse().ok()?;
+            (clock, sign * (hours * 3600 + minutes * 60))
+        } else {
+            (time, 0)
+        };
+
+        let clock = clock.split('.').next()?;
+        let mut fields = clock.split(':');
+        let hours: i64 = fields.next()?.parse().ok()?;
+        let minutes: i64 = fields.next()?.parse().ok()?;
+        let seconds: i64 = fields.next().unwrap_or("0").parse().ok()?;
+        secs += hours * 3600 + minutes * 60 + seconds - offset;
     }
 
-    // Calculate month and day
-    let is_leap = is_leap_year(year);
-    let mut month = 1;
-    let mut day = remaining_days + 1;
-
-    let days_in_months = if is_leap {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
-
-    for (m, &days) in days_in_months.iter().enumerate() {
-        if day <= days as i64 {
-            month = m + 1;
-            break;
-        }
-        day -= days as i64;
-    }
+    Some(secs)
+}
 
+/// Format seconds since the Unix epoch as "YYYY-MM-DDTHH:MM:SSZ"
+pub fn format_iso8601(secs: i64) -> String {
+    let (year, month, day) = civil_from_days(secs.div_euclid(86400));
+    let seconds_today = secs.rem_euclid(86400);
     format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
-        year, month, day, hours, minutes, seconds, millis
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_today / 3600,
+        seconds_today % 3600 / 60,
+        seconds_today % 60
     )
 }
 
-fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+/// Days from 1970-01-01 to a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
 }
 
-fn days_in_year(year: i32) -> i64 {
-    if is_leap_year(year) { 366 } else { 365 }
+/// Date of a day count from 1970-01-01, as (year, month, day)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
 }
 
-fn year_to_days(year: i32) -> i64 {
-    let y = year as i64;
-    y * 365 + y / 4 - y / 100 + y / 400
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso8601() {
+        assert_eq!(parse_iso8601("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_iso8601("2025-02-13T02:24:00Z"), Some(1739413440));
+        assert_eq!(parse_iso8601("2025-02-13T02:24:00.250Z"), Some(1739413440));
+        assert_eq!(parse_iso8601("2025-02-12T18:24:00-08:00"), Some(1739413440));
+        assert_eq!(parse_iso8601("2025-02-13"), Some(1739404800));
+        assert_eq!(parse_iso8601("2024-02-29T12:00:00+0000"), Some(1709208000));
+        assert_eq!(parse_iso8601("soon"), None);
+        assert_eq!(parse_iso8601("2025-13-01"), None);
+    }
+
+    #[test]
+    fn test_format_iso8601_round_trip() {
+        assert_eq!(format_iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_iso8601(1739413440), "2025-02-13T02:24:00Z");
+        assert_eq!(format_iso8601(1709208000), "2024-02-29T12:00:00Z");
+        let now = now_unix();
+        assert_eq!(parse_iso8601(&format_iso8601(now)), Some(now));
+        assert_eq!(
+            parse_iso8601(&now_iso8601()).map(|t| (t - now).abs() < 5),
+            Some(true)
+        );
+    }
 }
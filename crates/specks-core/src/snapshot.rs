@@ -0,0 +1,294 @@
+//! Last-synced bead fields for three-way `beads pull --fields`
+//!
+//! For every step bead it creates, `beads sync` records the step's title,
+//! priority and labels twice: as written in the speck and as set on the bead.
+//! `beads pull --fields` compares both sides against that record to tell
+//! which one changed since, so edits made in the tracker can be pulled into
+//! the speck without overwriting edits made to the speck.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::beads::IssueDetails;
+use crate::error::SpecksError;
+use crate::types::BeadsHints;
+
+/// Snapshot file name inside `.specks/`
+pub const SNAPSHOT_FILE: &str = "beads-snapshot.json";
+
+/// Priority trackers give new issues when none is set
+pub const DEFAULT_PRIORITY: i32 = 2;
+
+/// Step title from a bead title, without the `Step N: ` prefix sync adds
+pub fn step_title_from_bead(title: &str) -> &str {
+    let title = title.trim();
+    title
+        .strip_prefix("Step ")
+        .and_then(|rest| rest.split_once(": "))
+        .filter(|(number, _)| number.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        .map_or(title, |(_, rest)| rest)
+}
+
+/// The fields `pull --fields` keeps in step with the tracker
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeadFields {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    /// Sorted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+}
+
+impl BeadFields {
+    /// Fields of a step as written in the speck
+    pub fn from_step(title: &str, hints: Option<&BeadsHints>) -> Self {
+        let mut labels: Vec<String> = hints
+            .map(|h| h.labels.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|l| !l.is_empty())
+            .collect();
+        labels.sort();
+        Self {
+            title: title.to_string(),
+            priority: hints.and_then(|h| h.priority).map(i32::from),
+            labels,
+        }
+    }
+
+    /// Fields of a bead
+    pub fn from_issue(details: &IssueDetails) -> Self {
+        let mut labels = details.labels.clone();
+        labels.sort();
+        Self {
+            title: step_title_from_bead(&details.title).to_string(),
+            priority: Some(details.priority),
+            labels,
+        }
+    }
+
+    /// Fields of a bead that sync just created for a step titled `title`
+    pub fn created(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            priority: Some(DEFAULT_PRIORITY),
+            labels: Vec::new(),
+        }
+    }
+}
+
+/// One bead's fields on both sides at the last sync or pull
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    pub speck: BeadFields,
+    pub bead: BeadFields,
+}
+
+/// What to do with one field after a three-way comparison
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange<T> {
+    /// The bead did not change (the speck may have)
+    Keep,
+    /// Only the bead changed: write its value into the speck
+    Pull(T),
+    /// Both sides changed to the same value
+    Converged,
+    /// Both sides changed to different values
+    Conflict { speck: T, bead: T },
+}
+
+/// Outcome of comparing a step and its bead against their snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldsMerge {
+    pub title: FieldChange<String>,
+    pub priority: FieldChange<Option<i32>>,
+    pub labels: FieldChange<Vec<String>>,
+}
+
+impl SnapshotEntry {
+    /// Record both sides as they are now
+    pub fn new(speck: BeadFields, bead: BeadFields) -> Self {
+        Self { speck, bead }
+    }
+
+    /// Compare current fields against this entry.
+    ///
+    /// The entry is left as it is; once a pulled value has been written to
+    /// the speck, or both sides have converged, record it with the matching
+    /// `settle_*` method. Kept and conflicting fields stay as recorded, so a
+    /// conflict is reported again until one side is changed to match the other.
+    pub fn merge(&self, speck: &BeadFields, bead: &BeadFields) -> FieldsMerge {
+        FieldsMerge {
+            title: merge_field(
+                &self.speck.title,
+                &self.bead.title,
+                &speck.title,
+                &bead.title,
+            ),
+            priority: merge_field(
+                &self.speck.priority,
+                &self.bead.priority,
+                &speck.priority,
+                &bead.priority,
+            ),
+            labels: merge_field(
+                &self.speck.labels,
+                &self.bead.labels,
+                &speck.labels,
+                &bead.labels,
+            ),
+        }
+    }
+
+    /// Record a title both sides now share
+    pub fn settle_title(&mut self, title: &str) {
+        self.speck.title = title.to_string();
+        self.bead.title = title.to_string();
+    }
+
+    /// Record a priority both sides now share
+    pub fn settle_priority(&mut self, priority: Option<i32>) {
+        self.speck.priority = priority;
+        self.bead.priority = priority;
+    }
+
+    /// Record labels both sides now share
+    pub fn settle_labels(&mut self, labels: &[String]) {
+        self.speck.labels = labels.to_vec();
+        self.bead.labels = labels.to_vec();
+    }
+}
+
+/// Three-way comparison of one field against its recorded values
+fn merge_field<T: PartialEq + Clone>(
+    base_speck: &T,
+    base_bead: &T,
+    speck: &T,
+    bead: &T,
+) -> FieldChange<T> {
+    if bead == base_bead {
+        FieldChange::Keep
+    } else if speck == bead {
+        FieldChange::Converged
+    } else if speck == base_speck {
+        FieldChange::Pull(bead.clone())
+    } else {
+        FieldChange::Conflict {
+            speck: speck.clone(),
+            bead: bead.clone(),
+        }
+    }
+}
+
+/// Snapshot entries by bead ID, stored as `.specks/beads-snapshot.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeadSnapshot {
+    pub beads: BTreeMap<String, SnapshotEntry>,
+}
+
+impl BeadSnapshot {
+    /// Load a snapshot; empty if the file does not exist
+    pub fn load(path: &Path) -> Result<Self, SpecksError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(SpecksError::Io(e)),
+        };
+        serde_json::from_str(&content).map_err(|e| SpecksError::Parse {
+            message: format!("invalid bead snapshot {}: {}", path.display(), e),
+            line: None,
+        })
+    }
+
+    /// Write the snapshot as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<(), SpecksError> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| SpecksError::Config(e.to_string()))?;
+        fs::write(path, json + "\n")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(title: &str, priority: i32, labels: &[&str]) -> BeadFields {
+        BeadFields {
+            title: title.to_string(),
+            priority: Some(priority),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_step_title_from_bead() {
+        assert_eq!(step_title_from_bead("Step 2.1: Wire it up"), "Wire it up");
+        assert_eq!(step_title_from_bead("Wire it up"), "Wire it up");
+        assert_eq!(step_title_from_bead("Step up: now"), "Step up: now");
+    }
+
+    #[test]
+    fn test_merge_tells_which_side_changed() {
+        let base = fields("Build", 2, &[]);
+        let mut entry = SnapshotEntry::new(base.clone(), base.clone());
+
+        // Only the bead changed: pull it, leaving the entry as recorded
+        let bead = fields("Build the thing", 1, &["api"]);
+        let merge = entry.merge(&base, &bead);
+        assert_eq!(
+            merge.title,
+            FieldChange::Pull("Build the thing".to_string())
+        );
+        assert_eq!(merge.priority, FieldChange::Pull(Some(1)));
+        assert_eq!(merge.labels, FieldChange::Pull(vec!["api".to_string()]));
+        assert_eq!(entry, SnapshotEntry::new(base.clone(), base.clone()));
+
+        // Settling every field moves the entry forward
+        entry.settle_title(&bead.title);
+        entry.settle_priority(bead.priority);
+        entry.settle_labels(&bead.labels);
+        assert_eq!(entry, SnapshotEntry::new(bead.clone(), bead.clone()));
+
+        // Only the speck changed: keep it
+        let speck = fields("Build it", 1, &["api"]);
+        assert_eq!(entry.merge(&speck, &bead).title, FieldChange::Keep);
+
+        // Both changed: conflict unless they agree
+        let bead = fields("Build everything", 3, &["api"]);
+        let speck = fields("Build it", 3, &["api"]);
+        let merge = entry.merge(&speck, &bead);
+        assert_eq!(
+            merge.title,
+            FieldChange::Conflict {
+                speck: "Build it".to_string(),
+                bead: "Build everything".to_string()
+            }
+        );
+        assert_eq!(merge.priority, FieldChange::Converged);
+        assert_eq!(entry.speck.title, "Build the thing");
+        assert_eq!(entry.bead.priority, Some(1));
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join(SNAPSHOT_FILE);
+        assert_eq!(BeadSnapshot::load(&path).unwrap(), BeadSnapshot::default());
+
+        let mut snapshot = BeadSnapshot::default();
+        snapshot.beads.insert(
+            "bd-1.1".to_string(),
+            SnapshotEntry::new(
+                BeadFields::from_step("Build", None),
+                BeadFields::created("Build"),
+            ),
+        );
+        snapshot.save(&path).unwrap();
+        assert_eq!(BeadSnapshot::load(&path).unwrap(), snapshot);
+    }
+}
@@ -0,0 +1,109 @@
+//! Speck templates and variable substitution
+//!
+//! Templates are ordinary speck files with `{{variable}}` placeholders.
+//! `specks new` fills in `name`, `title`, `owner`, `date` and `status`, plus
+//! any variables passed on the command line.
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::SpecksError;
+
+/// Template placeholder: `{{name}}`, optionally padded with spaces
+static TEMPLATE_VAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}").unwrap());
+
+/// Variables referenced by a template, in first-seen order
+pub fn template_variables(template: &str) -> Vec<String> {
+    let mut vars: Vec<String> = Vec::new();
+    for cap in TEMPLATE_VAR.captures_iter(template) {
+        let var = cap.get(1).unwrap().as_str();
+        if !vars.iter().any(|v| v == var) {
+            vars.push(var.to_string());
+        }
+    }
+    vars
+}
+
+/// Substitute `{{variable}}` placeholders
+///
+/// Every placeholder must have a value; the error lists the ones that don't.
+pub fn render_template(
+    template: &str,
+    vars: &BTreeMap<String, String>,
+) -> Result<String, SpecksError> {
+    let missing: Vec<String> = template_variables(template)
+        .into_iter()
+        .filter(|var| !vars.contains_key(var))
+        .collect();
+    if !missing.is_empty() {
+        return Err(SpecksError::TemplateVariableMissing {
+            names: missing.join(", "),
+        });
+    }
+
+    Ok(TEMPLATE_VAR
+        .replace_all(template, |caps: &regex::Captures| {
+            vars[caps.get(1).unwrap().as_str()].clone()
+        })
+        .into_owned())
+}
+
+/// Human-readable title from a speck name ("user-auth" -> "User Auth")
+pub fn title_from_name(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_template_substitutes_variables() {
+        let template = "## Phase 1.0: {{title}} {#phase-1}\n| Owner | {{ owner }} |\n{{title}}";
+        let out = render_template(template, &vars(&[("title", "Auth"), ("owner", "kim")])).unwrap();
+        assert_eq!(out, "## Phase 1.0: Auth {#phase-1}\n| Owner | kim |\nAuth");
+    }
+
+    #[test]
+    fn test_render_template_reports_missing_variables() {
+        let err =
+            render_template("{{title}} {{team}} {{ticket}}", &vars(&[("title", "X")])).unwrap_err();
+        assert_eq!(err.code(), "E041");
+        assert!(err.to_string().contains("team, ticket"));
+    }
+
+    #[test]
+    fn test_template_variables_ignores_anchors() {
+        assert_eq!(
+            template_variables("{#step-0} {{name}} {{date}} {{name}}"),
+            vec!["name", "date"]
+        );
+    }
+
+    #[test]
+    fn test_title_from_name() {
+        assert_eq!(title_from_name("user-auth"), "User Auth");
+        assert_eq!(title_from_name("fix_login-bug"), "Fix Login Bug");
+        assert_eq!(title_from_name("x"), "X");
+    }
+}
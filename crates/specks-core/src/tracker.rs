@@ -0,0 +1,1148 @@
+//! Issue tracker backends
+//!
+//! Beads commands, `specks status` and the merge and step-commit bead checks
+//! talk to an [`IssueTracker`] rather than to `bd` directly. [`BeadsCli`] is
+//! the default implementation; [`FileTracker`] stores issues as JSON under
+//! `.specks/tracker/` for projects without `bd`;
+//! [`GitHubTracker`](crate::github::GitHubTracker) uses GitHub Issues;
+//! [`MemoryTracker`] keeps issues in memory for tests.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::beads::{
+    BeadsCli, DepResult, DependencyRef, Issue, IssueDetails, IssueWithDependencyMetadata,
+};
+use crate::error::SpecksError;
+use crate::session::now_iso8601;
+
+/// Operations specks needs from an issue tracker
+///
+/// `working_dir` is where the tracker should look for its state (for `bd`,
+/// the directory holding `.beads/`); backends that do not need it ignore it.
+pub trait IssueTracker {
+    /// Check if the tracker can be reached
+    fn is_installed(&self, working_dir: Option<&Path>) -> bool;
+
+    /// Check if the tracker is set up for the project
+    fn is_initialized(&self, project_root: &Path) -> bool;
+
+    /// Create an issue, optionally as a child of `parent`
+    #[allow(clippy::too_many_arguments)] // Mirrors `bd create` flags
+    fn create(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        parent: Option<&str>,
+        issue_type: Option<&str>,
+        priority: Option<i32>,
+        design: Option<&str>,
+        acceptance: Option<&str>,
+        notes: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Issue, SpecksError>;
+
+    /// Show an issue by ID
+    fn show(&self, id: &str, working_dir: Option<&Path>) -> Result<IssueDetails, SpecksError>;
+
+    /// Replace the description of an issue
+    fn update_description(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError>;
+
+    /// Replace the design of an issue
+    fn update_design(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError>;
+
+    /// Replace the acceptance criteria of an issue
+    fn update_acceptance(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError>;
+
+    /// Replace the notes of an issue
+    fn update_notes(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError>;
+
+    /// Close an issue
+    fn close(
+        &self,
+        id: &str,
+        reason: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError>;
+
+    /// Make `from_id` depend on `to_id`
+    fn dep_add(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError>;
+
+    /// Remove the dependency of `from_id` on `to_id`
+    fn dep_remove(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError>;
+
+    /// List the issues `id` depends on
+    fn dep_list(
+        &self,
+        id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueWithDependencyMetadata>, SpecksError>;
+
+    /// List the direct children of an issue
+    fn children(
+        &self,
+        parent_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError>;
+
+    /// List open issues whose dependencies are all closed, optionally only
+    /// children of `parent_id`
+    fn ready(
+        &self,
+        parent_id: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError>;
+
+    /// Check if an issue exists
+    fn bead_exists(&self, id: &str, working_dir: Option<&Path>) -> bool {
+        self.show(id, working_dir).is_ok()
+    }
+
+    /// Append to the notes of an issue, separated by `---` (per D03)
+    fn append_notes(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        let current = self.show(id, working_dir)?.notes.unwrap_or_default();
+        self.update_notes(id, &append_section(&current, content), working_dir)
+    }
+
+    /// Append to the design of an issue, separated by `---` (per D03)
+    fn append_design(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        let current = self.show(id, working_dir)?.design.unwrap_or_default();
+        self.update_design(id, &append_section(&current, content), working_dir)
+    }
+
+    /// The subset of `ids` that exist
+    fn list_by_ids(
+        &self,
+        ids: &[String],
+        working_dir: Option<&Path>,
+    ) -> Result<HashSet<String>, SpecksError> {
+        Ok(ids
+            .iter()
+            .filter(|id| self.bead_exists(id, working_dir))
+            .cloned()
+            .collect())
+    }
+
+    /// Full details of the direct children of an issue
+    fn list_children_detailed(
+        &self,
+        parent_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueDetails>, SpecksError> {
+        self.children(parent_id, working_dir)?
+            .iter()
+            .map(|child| self.show(&child.id, working_dir))
+            .collect()
+    }
+}
+
+/// Append `content` to a field, with a `---` separator if it is not empty
+fn append_section(current: &str, content: &str) -> String {
+    if current.is_empty() {
+        content.to_string()
+    } else {
+        format!("{}\n\n---\n\n{}", current, content)
+    }
+}
+
+impl IssueTracker for BeadsCli {
+    fn is_installed(&self, working_dir: Option<&Path>) -> bool {
+        BeadsCli::is_installed(self, working_dir)
+    }
+
+    fn is_initialized(&self, project_root: &Path) -> bool {
+        BeadsCli::is_initialized(self, project_root)
+    }
+
+    fn create(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        parent: Option<&str>,
+        issue_type: Option<&str>,
+        priority: Option<i32>,
+        design: Option<&str>,
+        acceptance: Option<&str>,
+        notes: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Issue, SpecksError> {
+        BeadsCli::create(
+            self,
+            title,
+            description,
+            parent,
+            issue_type,
+            priority,
+            design,
+            acceptance,
+            notes,
+            working_dir,
+        )
+    }
+
+    fn show(&self, id: &str, working_dir: Option<&Path>) -> Result<IssueDetails, SpecksError> {
+        BeadsCli::show(self, id, working_dir)
+    }
+
+    fn update_description(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        BeadsCli::update_description(self, id, content, working_dir)
+    }
+
+    fn update_design(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        BeadsCli::update_design(self, id, content, working_dir)
+    }
+
+    fn update_acceptance(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        BeadsCli::update_acceptance(self, id, content, working_dir)
+    }
+
+    fn update_notes(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        BeadsCli::update_notes(self, id, content, working_dir)
+    }
+
+    fn close(
+        &self,
+        id: &str,
+        reason: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        BeadsCli::close(self, id, reason, working_dir)
+    }
+
+    fn dep_add(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        BeadsCli::dep_add(self, from_id, to_id, working_dir)
+    }
+
+    fn dep_remove(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        BeadsCli::dep_remove(self, from_id, to_id, working_dir)
+    }
+
+    fn dep_list(
+        &self,
+        id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueWithDependencyMetadata>, SpecksError> {
+        BeadsCli::dep_list(self, id, working_dir)
+    }
+
+    fn children(
+        &self,
+        parent_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        BeadsCli::children(self, parent_id, working_dir)
+    }
+
+    fn ready(
+        &self,
+        parent_id: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        BeadsCli::ready(self, parent_id, working_dir)
+    }
+
+    fn list_by_ids(
+        &self,
+        ids: &[String],
+        working_dir: Option<&Path>,
+    ) -> Result<HashSet<String>, SpecksError> {
+        BeadsCli::list_by_ids(self, ids, working_dir)
+    }
+
+    fn list_children_detailed(
+        &self,
+        parent_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueDetails>, SpecksError> {
+        BeadsCli::list_children_detailed(self, parent_id, working_dir)
+    }
+}
+
+/// Issues and dependencies held by a [`MemoryTracker`]
+#[derive(Debug, Default)]
+struct MemoryState {
+    /// Issues by ID
+    issues: BTreeMap<String, IssueDetails>,
+    /// Parent of each child issue
+    parents: HashMap<String, String>,
+}
+
+impl MemoryState {
+    /// Next free ID under `parent`, or at the top level with `prefix`
+    fn next_id(&self, prefix: &str, parent: Option<&str>) -> String {
+        let base = match parent {
+            Some(parent) => format!("{}.", parent),
+            None => format!("{}-", prefix),
+        };
+        let last = self
+            .issues
+            .keys()
+            .filter(|id| self.parents.get(*id).map(String::as_str) == parent)
+            .filter_map(|id| id.strip_prefix(&base)?.parse::<usize>().ok())
+            .max()
+            .unwrap_or(0);
+        format!("{}{}", base, last + 1)
+    }
+}
+
+/// In-memory issue tracker for tests
+///
+/// Top-level issues get IDs `<prefix>-1`, `<prefix>-2`, ...; children append
+/// `.1`, `.2`, ... to their parent's ID, the way `bd` numbers them.
+#[derive(Debug)]
+pub struct MemoryTracker {
+    prefix: String,
+    state: Mutex<MemoryState>,
+}
+
+impl Default for MemoryTracker {
+    fn default() -> Self {
+        Self::new("mem")
+    }
+}
+
+impl MemoryTracker {
+    /// Create an empty tracker whose IDs start with `prefix`
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            state: Mutex::new(MemoryState::default()),
+        }
+    }
+
+    /// All issues, ordered by ID
+    pub fn issues(&self) -> Vec<IssueDetails> {
+        self.state().issues.values().cloned().collect()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Run `f` on an existing issue
+    fn with_issue<T>(
+        &self,
+        id: &str,
+        f: impl FnOnce(&mut IssueDetails) -> T,
+    ) -> Result<T, SpecksError> {
+        let mut state = self.state();
+        let issue = state
+            .issues
+            .get_mut(id)
+            .ok_or_else(|| SpecksError::BeadsCommand(format!("issue {} not found", id)))?;
+        Ok(f(issue))
+    }
+}
+
+/// Summary of an issue as returned by create, children and ready
+fn summary(details: &IssueDetails) -> Issue {
+    Issue {
+        id: details.id.clone(),
+        title: details.title.clone(),
+        description: details.description.clone(),
+        status: details.status.clone(),
+        priority: details.priority,
+        issue_type: details.issue_type.clone(),
+    }
+}
+
+/// `Some(content)`, or `None` for empty content, as `bd show` reports fields
+fn non_empty(content: Option<&str>) -> Option<String> {
+    content.filter(|c| !c.is_empty()).map(str::to_string)
+}
+
+impl IssueTracker for MemoryTracker {
+    fn is_installed(&self, _working_dir: Option<&Path>) -> bool {
+        true
+    }
+
+    fn is_initialized(&self, _project_root: &Path) -> bool {
+        true
+    }
+
+    fn create(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        parent: Option<&str>,
+        issue_type: Option<&str>,
+        priority: Option<i32>,
+        design: Option<&str>,
+        acceptance: Option<&str>,
+        notes: Option<&str>,
+        _working_dir: Option<&Path>,
+    ) -> Result<Issue, SpecksError> {
+        let mut state = self.state();
+        if let Some(parent) = parent {
+            if !state.issues.contains_key(parent) {
+                return Err(SpecksError::BeadsCommand(format!(
+                    "parent issue {} not found",
+                    parent
+                )));
+            }
+        }
+
+        let id = state.next_id(&self.prefix, parent);
+
+        let details = IssueDetails {
+            id: id.clone(),
+            title: title.to_string(),
+            description: description.unwrap_or_default().to_string(),
+            status: "open".to_string(),
+            priority: priority.unwrap_or(2),
+            issue_type: issue_type.unwrap_or("task").to_string(),
+            dependencies: vec![],
+            dependents: vec![],
+            design: non_empty(design),
+            acceptance_criteria: non_empty(acceptance),
+            notes: non_empty(notes),
+            close_reason: None,
+            closed_at: None,
+            labels: vec![],
+            metadata: None,
+        };
+        let issue = summary(&details);
+        state.issues.insert(id.clone(), details);
+        if let Some(parent) = parent {
+            state.parents.insert(id, parent.to_string());
+        }
+        Ok(issue)
+    }
+
+    fn show(&self, id: &str, _working_dir: Option<&Path>) -> Result<IssueDetails, SpecksError> {
+        self.with_issue(id, |issue| issue.clone())
+    }
+
+    fn update_description(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_issue(id, |issue| issue.description = content.to_string())
+    }
+
+    fn update_design(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_issue(id, |issue| issue.design = non_empty(Some(content)))
+    }
+
+    fn update_acceptance(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_issue(id, |issue| {
+            issue.acceptance_criteria = non_empty(Some(content))
+        })
+    }
+
+    fn update_notes(
+        &self,
+        id: &str,
+        content: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_issue(id, |issue| issue.notes = non_empty(Some(content)))
+    }
+
+    fn close(
+        &self,
+        id: &str,
+        reason: Option<&str>,
+        _working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_issue(id, |issue| {
+            issue.status = "closed".to_string();
+            issue.close_reason = non_empty(reason);
+            issue.closed_at = Some(now_iso8601());
+        })
+    }
+
+    fn dep_add(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        let mut state = self.state();
+        for id in [from_id, to_id] {
+            if !state.issues.contains_key(id) {
+                return Err(SpecksError::BeadsCommand(format!("issue {} not found", id)));
+            }
+        }
+        let link = |id: &str| DependencyRef {
+            id: id.to_string(),
+            dependency_type: "blocks".to_string(),
+        };
+        if let Some(from) = state.issues.get_mut(from_id) {
+            if !from.dependencies.iter().any(|d| d.id == to_id) {
+                from.dependencies.push(link(to_id));
+            }
+        }
+        if let Some(to) = state.issues.get_mut(to_id) {
+            if !to.dependents.iter().any(|d| d.id == from_id) {
+                to.dependents.push(link(from_id));
+            }
+        }
+        Ok(DepResult {
+            status: "added".to_string(),
+            issue_id: from_id.to_string(),
+            depends_on_id: to_id.to_string(),
+            dep_type: "blocks".to_string(),
+        })
+    }
+
+    fn dep_remove(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        let mut state = self.state();
+        if let Some(from) = state.issues.get_mut(from_id) {
+            from.dependencies.retain(|d| d.id != to_id);
+        }
+        if let Some(to) = state.issues.get_mut(to_id) {
+            to.dependents.retain(|d| d.id != from_id);
+        }
+        Ok(DepResult {
+            status: "removed".to_string(),
+            issue_id: from_id.to_string(),
+            depends_on_id: to_id.to_string(),
+            dep_type: String::new(),
+        })
+    }
+
+    fn dep_list(
+        &self,
+        id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueWithDependencyMetadata>, SpecksError> {
+        let state = self.state();
+        let issue = state
+            .issues
+            .get(id)
+            .ok_or_else(|| SpecksError::BeadsCommand(format!("issue {} not found", id)))?;
+        Ok(issue
+            .dependencies
+            .iter()
+            .filter_map(|dep| {
+                let target = state.issues.get(&dep.id)?;
+                Some(IssueWithDependencyMetadata {
+                    id: target.id.clone(),
+                    dependency_type: dep.dependency_type.clone(),
+                    title: target.title.clone(),
+                    status: target.status.clone(),
+                    priority: target.priority,
+                    issue_type: target.issue_type.clone(),
+                })
+            })
+            .collect())
+    }
+
+    fn children(
+        &self,
+        parent_id: &str,
+        _working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        let state = self.state();
+        Ok(state
+            .issues
+            .values()
+            .filter(|issue| state.parents.get(&issue.id).map(String::as_str) == Some(parent_id))
+            .map(summary)
+            .collect())
+    }
+
+    fn ready(
+        &self,
+        parent_id: Option<&str>,
+        _working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        let state = self.state();
+        let closed = |id: &str| state.issues.get(id).is_some_and(|i| i.status == "closed");
+        Ok(state
+            .issues
+            .values()
+            .filter(|issue| {
+                parent_id
+                    .is_none_or(|p| state.parents.get(&issue.id).map(String::as_str) == Some(p))
+            })
+            .filter(|issue| issue.status == "open")
+            .filter(|issue| issue.dependencies.iter().all(|d| closed(&d.id)))
+            .map(summary)
+            .collect())
+    }
+}
+
+/// Issue as stored in a [`FileTracker`] file
+#[derive(Serialize, Deserialize)]
+struct StoredIssue {
+    /// Parent issue ID, for child issues
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+    #[serde(flatten)]
+    issue: IssueDetails,
+}
+
+/// File-backed issue tracker for projects without `bd`
+///
+/// Each issue is stored as `.specks/tracker/<id>.json`, including its
+/// dependency edges, parent and close reason. IDs follow the `bd` scheme
+/// (`<prefix>-N`, children `<parent>.N`). State always lives under the
+/// project root the tracker was opened with; `working_dir` is ignored.
+#[derive(Debug, Clone)]
+pub struct FileTracker {
+    project_root: PathBuf,
+    prefix: String,
+}
+
+impl FileTracker {
+    /// Open the tracker for `project_root`, creating IDs with `prefix`
+    pub fn new(project_root: impl Into<PathBuf>, prefix: impl Into<String>) -> Self {
+        Self {
+            project_root: project_root.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Directory holding the issue files
+    pub fn dir(&self) -> PathBuf {
+        self.project_root.join(".specks").join("tracker")
+    }
+
+    /// Load all issues, along with the file contents they were read from
+    fn load(&self) -> Result<(MemoryState, HashMap<String, String>), SpecksError> {
+        let mut state = MemoryState::default();
+        let mut contents = HashMap::new();
+        let dir = self.dir();
+        if !dir.is_dir() {
+            return Ok((state, contents));
+        }
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+            let text = fs::read_to_string(&path)?;
+            let stored: StoredIssue = serde_json::from_str(&text).map_err(|e| {
+                SpecksError::BeadsCommand(format!("invalid tracker file {}: {}", path.display(), e))
+            })?;
+            let id = stored.issue.id.clone();
+            if let Some(parent) = stored.parent {
+                state.parents.insert(id.clone(), parent);
+            }
+            state.issues.insert(id.clone(), stored.issue);
+            contents.insert(id, text);
+        }
+        Ok((state, contents))
+    }
+
+    /// Write issues whose contents differ from what was loaded
+    fn save(
+        &self,
+        state: &MemoryState,
+        loaded: &HashMap<String, String>,
+    ) -> Result<(), SpecksError> {
+        let dir = self.dir();
+        for (id, issue) in &state.issues {
+            let stored = StoredIssue {
+                parent: state.parents.get(id).cloned(),
+                issue: issue.clone(),
+            };
+            let text = serde_json::to_string_pretty(&stored)
+                .map_err(|e| SpecksError::BeadsCommand(e.to_string()))?
+                + "\n";
+            if loaded.get(id) != Some(&text) {
+                fs::create_dir_all(&dir)?;
+                fs::write(dir.join(format!("{}.json", id)), text)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `f` against the stored issues and persist any changes
+    fn with_tracker<T>(
+        &self,
+        f: impl FnOnce(&MemoryTracker) -> Result<T, SpecksError>,
+    ) -> Result<T, SpecksError> {
+        let (state, loaded) = self.load()?;
+        let tracker = MemoryTracker {
+            prefix: self.prefix.clone(),
+            state: Mutex::new(state),
+        };
+        let result = f(&tracker)?;
+        self.save(&tracker.state(), &loaded)?;
+        Ok(result)
+    }
+}
+
+impl IssueTracker for FileTracker {
+    fn is_installed(&self, _working_dir: Option<&Path>) -> bool {
+        true
+    }
+
+    fn is_initialized(&self, _project_root: &Path) -> bool {
+        self.project_root.join(".specks").is_dir()
+    }
+
+    fn create(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        parent: Option<&str>,
+        issue_type: Option<&str>,
+        priority: Option<i32>,
+        design: Option<&str>,
+        acceptance: Option<&str>,
+        notes: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Issue, SpecksError> {
+        self.with_tracker(|t| {
+            t.create(
+                title,
+                description,
+                parent,
+                issue_type,
+                priority,
+                design,
+                acceptance,
+                notes,
+                working_dir,
+            )
+        })
+    }
+
+    fn show(&self, id: &str, working_dir: Option<&Path>) -> Result<IssueDetails, SpecksError> {
+        self.with_tracker(|t| t.show(id, working_dir))
+    }
+
+    fn update_description(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_tracker(|t| t.update_description(id, content, working_dir))
+    }
+
+    fn update_design(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_tracker(|t| t.update_design(id, content, working_dir))
+    }
+
+    fn update_acceptance(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_tracker(|t| t.update_acceptance(id, content, working_dir))
+    }
+
+    fn update_notes(
+        &self,
+        id: &str,
+        content: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_tracker(|t| t.update_notes(id, content, working_dir))
+    }
+
+    fn close(
+        &self,
+        id: &str,
+        reason: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<(), SpecksError> {
+        self.with_tracker(|t| t.close(id, reason, working_dir))
+    }
+
+    fn dep_add(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        self.with_tracker(|t| t.dep_add(from_id, to_id, working_dir))
+    }
+
+    fn dep_remove(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<DepResult, SpecksError> {
+        self.with_tracker(|t| t.dep_remove(from_id, to_id, working_dir))
+    }
+
+    fn dep_list(
+        &self,
+        id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<IssueWithDependencyMetadata>, SpecksError> {
+        self.with_tracker(|t| t.dep_list(id, working_dir))
+    }
+
+    fn children(
+        &self,
+        parent_id: &str,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        self.with_tracker(|t| t.children(parent_id, working_dir))
+    }
+
+    fn ready(
+        &self,
+        parent_id: Option<&str>,
+        working_dir: Option<&Path>,
+    ) -> Result<Vec<Issue>, SpecksError> {
+        self.with_tracker(|t| t.ready(parent_id, working_dir))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beads::is_valid_bead_id;
+
+    #[test]
+    fn test_memory_tracker_create_and_children() {
+        let tracker = MemoryTracker::default();
+        let root = tracker
+            .create(
+                "Root",
+                None,
+                None,
+                Some("epic"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let first = tracker
+            .create(
+                "First",
+                Some("desc"),
+                Some(&root.id),
+                None,
+                Some(1),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let second = tracker
+            .create(
+                "Second",
+                None,
+                Some(&root.id),
+                None,
+                None,
+                Some(""),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(root.id, "mem-1");
+        assert_eq!(first.id, "mem-1.1");
+        assert_eq!(second.id, "mem-1.2");
+        assert!(is_valid_bead_id(&first.id));
+        assert_eq!(first.priority, 1);
+        assert_eq!(tracker.show(&second.id, None).unwrap().design, None);
+
+        let children: Vec<String> = tracker
+            .children(&root.id, None)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(children, vec!["mem-1.1", "mem-1.2"]);
+        assert!(tracker.children(&first.id, None).unwrap().is_empty());
+        assert!(
+            tracker
+                .create(
+                    "Orphan",
+                    None,
+                    Some("mem-9"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                )
+                .is_err()
+        );
+
+        let details = tracker.list_children_detailed(&root.id, None).unwrap();
+        assert_eq!(details[0].description, "desc");
+        assert_eq!(
+            tracker
+                .list_by_ids(&["mem-1.1".to_string(), "mem-7".to_string()], None)
+                .unwrap(),
+            HashSet::from(["mem-1.1".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_memory_tracker_deps_ready_and_close() {
+        let tracker = MemoryTracker::new("bd-test");
+        let root = tracker
+            .create("Root", None, None, None, None, None, None, None, None)
+            .unwrap();
+        let a = tracker
+            .create(
+                "A",
+                None,
+                Some(&root.id),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let b = tracker
+            .create(
+                "B",
+                None,
+                Some(&root.id),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        tracker.dep_add(&b.id, &a.id, None).unwrap();
+        tracker.dep_add(&b.id, &a.id, None).unwrap();
+        assert!(tracker.dep_add(&b.id, "bd-test-9", None).is_err());
+        let deps = tracker.dep_list(&b.id, None).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].id, a.id);
+        assert_eq!(tracker.show(&a.id, None).unwrap().dependents[0].id, b.id);
+
+        let ready = |tracker: &MemoryTracker| -> Vec<String> {
+            tracker
+                .ready(Some(&root.id), None)
+                .unwrap()
+                .into_iter()
+                .map(|i| i.id)
+                .collect()
+        };
+        assert_eq!(ready(&tracker), vec![a.id.clone()]);
+
+        tracker
+            .close(&a.id, Some("Committed: abc -- done"), None)
+            .unwrap();
+        let closed = tracker.show(&a.id, None).unwrap();
+        assert_eq!(closed.status, "closed");
+        assert!(closed.closed_at.is_some());
+        assert_eq!(ready(&tracker), vec![b.id.clone()]);
+
+        tracker.dep_remove(&b.id, &a.id, None).unwrap();
+        assert!(tracker.dep_list(&b.id, None).unwrap().is_empty());
+        assert!(tracker.show(&a.id, None).unwrap().dependents.is_empty());
+    }
+
+    #[test]
+    fn test_memory_tracker_append_fields() {
+        let tracker = MemoryTracker::default();
+        let issue = tracker
+            .create("Issue", None, None, None, None, None, None, None, None)
+            .unwrap();
+        let tracker: &dyn IssueTracker = &tracker;
+
+        tracker.append_notes(&issue.id, "first", None).unwrap();
+        tracker.append_notes(&issue.id, "second", None).unwrap();
+        tracker.update_design(&issue.id, "design", None).unwrap();
+        tracker.append_design(&issue.id, "more", None).unwrap();
+
+        let details = tracker.show(&issue.id, None).unwrap();
+        assert_eq!(details.notes.as_deref(), Some("first\n\n---\n\nsecond"));
+        assert_eq!(details.design.as_deref(), Some("design\n\n---\n\nmore"));
+        assert!(tracker.update_notes("mem-9", "x", None).is_err());
+        assert!(!tracker.bead_exists("mem-9", None));
+    }
+
+    #[test]
+    fn test_file_tracker_persists_issues() {
+        let temp = tempfile::tempdir().unwrap();
+        let tracker = FileTracker::new(temp.path(), "sp");
+        assert!(!tracker.is_initialized(temp.path()));
+        std::fs::create_dir(temp.path().join(".specks")).unwrap();
+        assert!(tracker.is_initialized(temp.path()));
+
+        let root = tracker
+            .create(
+                "Root",
+                None,
+                None,
+                Some("epic"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let a = tracker
+            .create(
+                "A",
+                None,
+                Some(&root.id),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let b = tracker
+            .create(
+                "B",
+                None,
+                Some(&root.id),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(root.id, "sp-1");
+        assert_eq!(b.id, "sp-1.2");
+        assert!(is_valid_bead_id(&b.id));
+        tracker.dep_add(&b.id, &a.id, None).unwrap();
+        tracker
+            .close(&a.id, Some("Committed: abc -- A"), None)
+            .unwrap();
+        assert!(tracker.dir().join("sp-1.2.json").is_file());
+
+        // A fresh handle sees the same state
+        let reopened = FileTracker::new(temp.path(), "sp");
+        let a = reopened.show(&a.id, None).unwrap();
+        assert_eq!(a.status, "closed");
+        assert_eq!(a.close_reason.as_deref(), Some("Committed: abc -- A"));
+        assert_eq!(reopened.show(&b.id, None).unwrap().dependencies[0].id, a.id);
+        assert_eq!(reopened.children(&root.id, None).unwrap().len(), 2);
+        let ready: Vec<String> = reopened
+            .ready(Some(&root.id), None)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(ready, vec![b.id.clone()]);
+
+        let c = reopened
+            .create(
+                "C",
+                None,
+                Some(&root.id),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(c.id, "sp-1.3");
+        assert!(reopened.show("sp-9", None).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 //! Core data types for specks
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// A diagnostic emitted during parsing (near-miss, code block content, etc.)
@@ -15,6 +17,33 @@ pub struct ParseDiagnostic {
     pub suggestion: Option<String>,
 }
 
+/// An inline `<!-- specks-ignore W003: reason -->` comment
+///
+/// Covers the next heading and everything under it, up to the next heading
+/// of the same or a higher level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// Rule codes to ignore (e.g., "W003")
+    pub codes: Vec<String>,
+    /// Why the rules do not apply here
+    pub reason: Option<String>,
+    /// Line of the comment
+    pub line: usize,
+    /// First line covered (the heading); 0 if no heading follows
+    pub start_line: usize,
+    /// Last line covered
+    pub end_line: usize,
+}
+
+impl Suppression {
+    /// Whether this suppression silences `code` on `line`
+    pub fn covers(&self, code: &str, line: usize) -> bool {
+        self.start_line > 0
+            && (self.start_line..=self.end_line).contains(&line)
+            && self.codes.iter().any(|c| c == code)
+    }
+}
+
 /// A parsed speck document
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Speck {
@@ -34,6 +63,9 @@ pub struct Speck {
     pub decisions: Vec<Decision>,
     /// Open questions
     pub questions: Vec<Question>,
+    /// Labeled artifacts (Spec S01, Table T01, Risk R01, ...)
+    #[serde(default)]
+    pub labels: Vec<Label>,
     /// Execution steps
     pub steps: Vec<Step>,
     /// Raw content (for line number lookups)
@@ -41,6 +73,38 @@ pub struct Speck {
     /// Parse diagnostics (near-miss patterns, code block issues)
     #[serde(default)]
     pub diagnostics: Vec<ParseDiagnostic>,
+    /// Companion files loaded alongside this speck (see `companion::load_companions`)
+    #[serde(default)]
+    pub companions: Vec<Companion>,
+    /// Inline `specks-ignore` comments
+    #[serde(default)]
+    pub suppressions: Vec<Suppression>,
+}
+
+/// A file loaded alongside a speck to resolve cross-file references
+///
+/// Files listed in the `Includes` metadata row are part of the speck itself;
+/// other files are loaded only because a reference like
+/// `specks-auth-reference.md#t03-error-codes` points into them.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Companion {
+    /// File name as written in the speck (relative to the speck's directory)
+    pub file: String,
+    /// Listed in the speck's `Includes` metadata
+    pub included: bool,
+    /// Whether the file could be read
+    pub found: bool,
+    /// Anchors defined in the file
+    pub anchors: Vec<Anchor>,
+    /// Labeled artifacts defined in the file
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    /// Step and substep anchors of the file with their dependencies
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<(String, Vec<String>)>,
+    /// Raw content
+    #[serde(skip)]
+    pub raw_content: String,
 }
 
 /// Plan metadata section from a speck
@@ -58,9 +122,39 @@ pub struct SpeckMetadata {
     pub last_updated: Option<String>,
     /// Beads root ID (optional, set after beads sync)
     pub beads_root_id: Option<String>,
+    /// Companion files that are part of this speck (from the `Includes` row)
+    #[serde(default)]
+    pub includes: Vec<String>,
+    /// Steps of other specks this whole speck waits on (from the `Depends on`
+    /// row), as `specks-auth.md#step-3`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Rows with unrecognized field names, keyed by the name as written
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, String>,
 }
 
 impl SpeckMetadata {
+    /// Non-empty value of a metadata field by name, ignoring case
+    ///
+    /// Covers both the built-in fields and unrecognized rows.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        let value = match name.to_lowercase().as_str() {
+            "owner" => self.owner.as_deref(),
+            "status" => self.status.as_deref(),
+            "target branch" => self.target_branch.as_deref(),
+            "tracking issue/pr" | "tracking issue" | "tracking" => self.tracking.as_deref(),
+            "last updated" => self.last_updated.as_deref(),
+            "beads root" => self.beads_root_id.as_deref(),
+            lower => self
+                .extra
+                .iter()
+                .find(|(field, _)| field.to_lowercase() == lower)
+                .map(|(_, value)| value.as_str()),
+        };
+        value.filter(|v| !v.trim().is_empty())
+    }
+
     /// Check if the status value is valid (draft, active, done)
     pub fn is_valid_status(&self) -> bool {
         match &self.status {
@@ -83,7 +177,7 @@ pub struct Anchor {
 }
 
 /// A design decision
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Decision {
     /// Decision ID (e.g., "D01")
     pub id: String,
@@ -95,10 +189,29 @@ pub struct Decision {
     pub anchor: Option<String>,
     /// Line number
     pub line: usize,
+    /// One-sentence statement from the `**Decision:**` field
+    #[serde(default)]
+    pub statement: Option<String>,
+    /// Items from the `**Rationale:**` field
+    #[serde(default)]
+    pub rationale: Vec<String>,
+    /// Items from the `**Implications:**` field
+    #[serde(default)]
+    pub implications: Vec<String>,
+}
+
+impl Decision {
+    /// Title followed by the decision statement, when one is recorded
+    pub fn summary(&self) -> String {
+        match &self.statement {
+            Some(statement) => format!("{}: {}", self.title, statement),
+            None => self.title.clone(),
+        }
+    }
 }
 
 /// An open question
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Question {
     /// Question ID (e.g., "Q01")
     pub id: String,
@@ -110,6 +223,100 @@ pub struct Question {
     pub anchor: Option<String>,
     /// Line number
     pub line: usize,
+    /// Text of the `**Question:**` field
+    #[serde(default)]
+    pub question: Option<String>,
+    /// Text of the `**Why it matters:**` field
+    #[serde(default)]
+    pub why_it_matters: Option<String>,
+    /// Items from the `**Options:**` field
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Text of the `**Plan to resolve:**` field
+    #[serde(default)]
+    pub plan_to_resolve: Option<String>,
+    /// Text of the `**Resolution:**` field (the header status is `resolution`)
+    #[serde(default)]
+    pub resolution_note: Option<String>,
+}
+
+/// Kind of a labeled plan artifact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelKind {
+    /// `Spec S01`
+    Spec,
+    /// `Table T01`
+    Table,
+    /// `List L01`
+    List,
+    /// `Risk R01`
+    Risk,
+    /// `Milestone M01`
+    Milestone,
+    /// `Concept C01`
+    Concept,
+    /// `Diagram Diag01`
+    Diagram,
+}
+
+impl LabelKind {
+    /// Parse the label word ("Spec", "Tables", ...), accepting the plural form
+    pub fn from_word(word: &str) -> Option<Self> {
+        match word.strip_suffix('s').unwrap_or(word) {
+            "Spec" => Some(LabelKind::Spec),
+            "Table" => Some(LabelKind::Table),
+            "List" => Some(LabelKind::List),
+            "Risk" => Some(LabelKind::Risk),
+            "Milestone" => Some(LabelKind::Milestone),
+            "Concept" => Some(LabelKind::Concept),
+            "Diagram" => Some(LabelKind::Diagram),
+            _ => None,
+        }
+    }
+
+    /// ID prefix for this kind (e.g., "S" for specs, "Diag" for diagrams)
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            LabelKind::Spec => "S",
+            LabelKind::Table => "T",
+            LabelKind::List => "L",
+            LabelKind::Risk => "R",
+            LabelKind::Milestone => "M",
+            LabelKind::Concept => "C",
+            LabelKind::Diagram => "Diag",
+        }
+    }
+}
+
+impl std::fmt::Display for LabelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let word = match self {
+            LabelKind::Spec => "Spec",
+            LabelKind::Table => "Table",
+            LabelKind::List => "List",
+            LabelKind::Risk => "Risk",
+            LabelKind::Milestone => "Milestone",
+            LabelKind::Concept => "Concept",
+            LabelKind::Diagram => "Diagram",
+        };
+        write!(f, "{}", word)
+    }
+}
+
+/// A labeled plan artifact, e.g. `**Spec S01: Title** {#s01-slug}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Label {
+    /// Label ID (e.g., "S01", "Diag02")
+    pub id: String,
+    /// Kind of artifact
+    pub kind: LabelKind,
+    /// Label title
+    pub title: String,
+    /// Anchor name
+    pub anchor: Option<String>,
+    /// Line number
+    pub line: usize,
 }
 
 /// An execution step within a speck
@@ -142,21 +349,55 @@ pub struct Step {
     /// Artifact items (deliverables from this step)
     #[serde(default)]
     pub artifacts: Vec<String>,
-    /// Substeps (for nested steps like 2.1, 2.2)
+    /// Substeps (for nested steps like 2.1, 2.2); each may nest further
     pub substeps: Vec<Substep>,
 }
 
 impl Step {
     /// Count total checkbox items (tasks + tests + checkpoints)
+    ///
+    /// Skipped items (`- [-]`) are not counted: they never hold up completion.
     pub fn total_items(&self) -> usize {
-        self.tasks.len() + self.tests.len() + self.checkpoints.len()
+        self.items().filter(|c| !c.is_skipped()).count()
     }
 
     /// Count completed checkbox items
     pub fn completed_items(&self) -> usize {
-        self.tasks.iter().filter(|c| c.checked).count()
-            + self.tests.iter().filter(|c| c.checked).count()
-            + self.checkpoints.iter().filter(|c| c.checked).count()
+        self.items().filter(|c| c.checked).count()
+    }
+
+    /// All checkbox items (tasks, then tests, then checkpoints)
+    pub fn items(&self) -> impl Iterator<Item = &Checkpoint> {
+        self.tasks
+            .iter()
+            .chain(&self.tests)
+            .chain(&self.checkpoints)
+    }
+
+    /// Get (completed, total) checkbox counts for this step and all nested substeps
+    pub fn rollup_counts(&self) -> (usize, usize) {
+        self.substeps.iter().fold(
+            (self.completed_items(), self.total_items()),
+            |(done, total), substep| {
+                let (d, t) = substep.rollup_counts();
+                (done + d, total + t)
+            },
+        )
+    }
+
+    /// Whether every checkbox item of the step and its substeps is checked
+    pub fn is_complete(&self) -> bool {
+        let (done, total) = self.rollup_counts();
+        total > 0 && done == total
+    }
+
+    /// All substeps at every depth, in document order
+    pub fn all_substeps(&self) -> Vec<&Substep> {
+        let mut out = Vec::new();
+        for substep in &self.substeps {
+            substep.collect_substeps(&mut out);
+        }
+        out
     }
 
     /// Render the step description as markdown (Tasks, Artifacts, Commit Template)
@@ -167,8 +408,7 @@ impl Step {
         if !self.tasks.is_empty() {
             let mut task_lines = vec!["## Tasks".to_string()];
             for task in &self.tasks {
-                let check = if task.checked { "x" } else { " " };
-                task_lines.push(format!("- [{}] {}", check, task.text));
+                task_lines.push(task.render());
             }
             sections.push(task_lines.join("\n"));
         }
@@ -198,8 +438,7 @@ impl Step {
         if !self.tests.is_empty() {
             let mut test_lines = vec!["## Tests".to_string()];
             for test in &self.tests {
-                let check = if test.checked { "x" } else { " " };
-                test_lines.push(format!("- [{}] {}", check, test.text));
+                test_lines.push(test.render());
             }
             sections.push(test_lines.join("\n"));
         }
@@ -208,8 +447,7 @@ impl Step {
         if !self.checkpoints.is_empty() {
             let mut checkpoint_lines = vec!["## Checkpoints".to_string()];
             for checkpoint in &self.checkpoints {
-                let check = if checkpoint.checked { "x" } else { " " };
-                checkpoint_lines.push(format!("- [{}] {}", check, checkpoint.text));
+                checkpoint_lines.push(checkpoint.render());
             }
             sections.push(checkpoint_lines.join("\n"));
         }
@@ -261,19 +499,70 @@ pub struct Substep {
     /// Artifact items (deliverables from this substep)
     #[serde(default)]
     pub artifacts: Vec<String>,
+    /// Nested substeps (e.g., 2.1.1 under 2.1)
+    #[serde(default)]
+    pub substeps: Vec<Substep>,
 }
 
 impl Substep {
     /// Count total checkbox items
+    ///
+    /// Skipped items (`- [-]`) are not counted: they never hold up completion.
     pub fn total_items(&self) -> usize {
-        self.tasks.len() + self.tests.len() + self.checkpoints.len()
+        self.items().filter(|c| !c.is_skipped()).count()
     }
 
     /// Count completed checkbox items
     pub fn completed_items(&self) -> usize {
-        self.tasks.iter().filter(|c| c.checked).count()
-            + self.tests.iter().filter(|c| c.checked).count()
-            + self.checkpoints.iter().filter(|c| c.checked).count()
+        self.items().filter(|c| c.checked).count()
+    }
+
+    /// All checkbox items (tasks, then tests, then checkpoints)
+    pub fn items(&self) -> impl Iterator<Item = &Checkpoint> {
+        self.tasks
+            .iter()
+            .chain(&self.tests)
+            .chain(&self.checkpoints)
+    }
+
+    /// Get (completed, total) checkbox counts for this substep and everything nested in it
+    pub fn rollup_counts(&self) -> (usize, usize) {
+        self.substeps.iter().fold(
+            (self.completed_items(), self.total_items()),
+            |(done, total), substep| {
+                let (d, t) = substep.rollup_counts();
+                (done + d, total + t)
+            },
+        )
+    }
+
+    /// Nesting depth below the top-level step ("2.1" is 1, "2.1.3" is 2)
+    pub fn depth(&self) -> usize {
+        self.number.matches('.').count()
+    }
+
+    /// Number of the enclosing step or substep ("2.1" for "2.1.3")
+    pub fn parent_number(&self) -> &str {
+        self.number
+            .rsplit_once('.')
+            .map_or(self.number.as_str(), |(parent, _)| parent)
+    }
+
+    /// All substeps nested below this one at every depth, in document order
+    pub fn all_substeps(&self) -> Vec<&Substep> {
+        let mut out = Vec::new();
+        for substep in &self.substeps {
+            substep.collect_substeps(&mut out);
+        }
+        out
+    }
+
+    /// Push this substep and all of its descendants, in document order
+    fn collect_substeps<'a>(&'a self, out: &mut Vec<&'a Substep>) {
+        out.push(self);
+        for substep in &self.substeps {
+            substep.collect_substeps(out);
+        }
     }
 
     /// Render the substep description as markdown (Tasks, Artifacts, Commit Template)
@@ -284,8 +573,7 @@ impl Substep {
         if !self.tasks.is_empty() {
             let mut task_lines = vec!["## Tasks".to_string()];
             for task in &self.tasks {
-                let check = if task.checked { "x" } else { " " };
-                task_lines.push(format!("- [{}] {}", check, task.text));
+                task_lines.push(task.render());
             }
             sections.push(task_lines.join("\n"));
         }
@@ -315,8 +603,7 @@ impl Substep {
         if !self.tests.is_empty() {
             let mut test_lines = vec!["## Tests".to_string()];
             for test in &self.tests {
-                let check = if test.checked { "x" } else { " " };
-                test_lines.push(format!("- [{}] {}", check, test.text));
+                test_lines.push(test.render());
             }
             sections.push(test_lines.join("\n"));
         }
@@ -325,8 +612,7 @@ impl Substep {
         if !self.checkpoints.is_empty() {
             let mut checkpoint_lines = vec!["## Checkpoints".to_string()];
             for checkpoint in &self.checkpoints {
-                let check = if checkpoint.checked { "x" } else { " " };
-                checkpoint_lines.push(format!("- [{}] {}", check, checkpoint.text));
+                checkpoint_lines.push(checkpoint.render());
             }
             sections.push(checkpoint_lines.join("\n"));
         }
@@ -336,20 +622,109 @@ impl Substep {
 }
 
 /// A checkbox item (task, test, or checkpoint)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Checkpoint {
     /// Whether the checkbox is checked
     pub checked: bool,
-    /// The text content of the checkbox item
+    /// The text content of the checkbox item (without the reason)
     pub text: String,
     /// Type of checkpoint item
     pub kind: CheckpointKind,
     /// Line number where this item appears
     pub line: usize,
+    /// Skipped, blocked or failed mark on an unchecked item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mark: Option<CheckMark>,
+    /// Reason given after a mark (`- [-] Load test -- no staging cluster`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl Checkpoint {
+    /// A plain checked or open item, with no mark or reason
+    pub fn new(kind: CheckpointKind, text: impl Into<String>, checked: bool, line: usize) -> Self {
+        Self {
+            checked,
+            text: text.into(),
+            kind,
+            line,
+            mark: None,
+            reason: None,
+        }
+    }
+
+    /// Character between the brackets: `x`, ` `, or the mark's marker
+    pub fn marker(&self) -> char {
+        match self.mark {
+            _ if self.checked => 'x',
+            Some(mark) => mark.marker(),
+            None => ' ',
+        }
+    }
+
+    /// Whether the item was deliberately skipped (`- [-]`)
+    pub fn is_skipped(&self) -> bool {
+        self.mark == Some(CheckMark::Skipped)
+    }
+
+    /// Render as a markdown list item, spelling out the mark and reason
+    pub fn render(&self) -> String {
+        let mut line = format!("- [{}] {}", self.marker(), self.text);
+        if let Some(mark) = self.mark {
+            match &self.reason {
+                Some(reason) => line.push_str(&format!(" ({}: {})", mark, reason)),
+                None => line.push_str(&format!(" ({})", mark)),
+            }
+        }
+        line
+    }
+}
+
+/// State of an unchecked checkbox item other than plain open
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckMark {
+    /// `- [-]` deliberately skipped; not counted toward completion
+    Skipped,
+    /// `- [~]` blocked on something outside the step
+    Blocked,
+    /// `- [!]` attempted and failed
+    Failed,
+}
+
+impl CheckMark {
+    /// Parse the character between the brackets
+    pub fn from_marker(marker: &str) -> Option<Self> {
+        match marker {
+            "-" => Some(CheckMark::Skipped),
+            "~" => Some(CheckMark::Blocked),
+            "!" => Some(CheckMark::Failed),
+            _ => None,
+        }
+    }
+
+    /// Character written between the brackets
+    pub fn marker(self) -> char {
+        match self {
+            CheckMark::Skipped => '-',
+            CheckMark::Blocked => '~',
+            CheckMark::Failed => '!',
+        }
+    }
+}
+
+impl std::fmt::Display for CheckMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckMark::Skipped => write!(f, "skipped"),
+            CheckMark::Blocked => write!(f, "blocked"),
+            CheckMark::Failed => write!(f, "failed"),
+        }
+    }
 }
 
 /// Kind of checkpoint item
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 pub enum CheckpointKind {
     /// Task item
     #[default]
@@ -416,13 +791,9 @@ impl Speck {
         let mut total = 0;
 
         for step in &self.steps {
-            done += step.completed_items();
-            total += step.total_items();
-
-            for substep in &step.substeps {
-                done += substep.completed_items();
-                total += substep.total_items();
-            }
+            let (d, t) = step.rollup_counts();
+            done += d;
+            total += t;
         }
 
         (done, total)
@@ -430,42 +801,96 @@ impl Speck {
 
     /// Extract content from a section by its anchor
     /// Returns the markdown content between the heading with {#anchor} and the next same-or-higher level heading
+    ///
+    /// The anchor may name a companion file (`specks-auth-reference.md#t03-error-codes`).
+    /// A plain anchor that is not in this file is looked up in included companions.
     pub fn extract_section_by_anchor(&self, anchor: &str) -> Option<String> {
-        let lines: Vec<&str> = self.raw_content.lines().collect();
-        let anchor_pattern = format!("{{#{}}}", anchor);
-
-        // Find the line with the anchor
-        let start_idx = lines
-            .iter()
-            .position(|line| line.contains(&anchor_pattern))?;
-
-        // Determine the heading level of the anchor line
-        let anchor_line = lines[start_idx];
-        let anchor_level = anchor_line.chars().take_while(|&c| c == '#').count();
-
-        // Find the end of this section (next heading at same or higher level)
-        let mut end_idx = lines.len();
-        for (idx, line) in lines.iter().enumerate().skip(start_idx + 1) {
-            if line.starts_with('#') {
-                let level = line.chars().take_while(|&c| c == '#').count();
-                if level <= anchor_level {
-                    end_idx = idx;
-                    break;
-                }
+        if let Some((file, anchor)) = anchor.split_once('#') {
+            let file = file.trim_start_matches("./");
+            if file.is_empty() {
+                return self.extract_section_by_anchor(anchor);
             }
+            let companion = self.companion(file).filter(|c| c.found)?;
+            return extract_section(&companion.raw_content, anchor);
         }
 
-        // Extract the content between start and end (excluding the heading line itself)
-        if start_idx + 1 < end_idx {
-            let content_lines: Vec<&str> = lines[(start_idx + 1)..end_idx].to_vec();
-            let content = content_lines.join("\n").trim().to_string();
-            if content.is_empty() {
-                None
-            } else {
-                Some(content)
+        extract_section(&self.raw_content, anchor).or_else(|| {
+            self.companions
+                .iter()
+                .filter(|c| c.included && c.found)
+                .find_map(|c| extract_section(&c.raw_content, anchor))
+        })
+    }
+
+    /// Look up a loaded companion file by name
+    pub fn companion(&self, file: &str) -> Option<&Companion> {
+        self.companions.iter().find(|c| c.file == file)
+    }
+
+    /// Every step and substep anchor with its dependencies, in document order
+    ///
+    /// Speck-level dependencies (the `Depends on` metadata row) apply to
+    /// every step.
+    pub fn step_dependencies(&self) -> Vec<(String, Vec<String>)> {
+        let with_speck_deps = |deps: &[String]| -> Vec<String> {
+            deps.iter()
+                .chain(&self.metadata.depends_on)
+                .cloned()
+                .collect()
+        };
+        let mut out = Vec::new();
+        for step in &self.steps {
+            out.push((step.anchor.clone(), with_speck_deps(&step.depends_on)));
+            for substep in step.all_substeps() {
+                out.push((substep.anchor.clone(), with_speck_deps(&substep.depends_on)));
             }
-        } else {
+        }
+        out
+    }
+
+    /// Look up a design decision by ID (e.g., "D01")
+    pub fn decision(&self, id: &str) -> Option<&Decision> {
+        self.decisions.iter().find(|d| d.id == id)
+    }
+
+    /// Look up a labeled artifact by ID (e.g., "T01")
+    pub fn label(&self, id: &str) -> Option<&Label> {
+        self.labels.iter().find(|l| l.id == id)
+    }
+
+    /// Extract the body of a labeled artifact.
+    ///
+    /// Heading labels extend like any heading section. Bold labels extend
+    /// until the next label, heading, or `---` rule.
+    pub fn extract_label(&self, id: &str) -> Option<String> {
+        let label = self.label(id)?;
+        let lines: Vec<&str> = self.raw_content.lines().collect();
+        let start = label.line;
+        if lines.get(start - 1)?.starts_with('#') {
+            return self.extract_section_by_anchor(label.anchor.as_deref()?);
+        }
+
+        let next_label_line = self
+            .labels
+            .iter()
+            .map(|l| l.line)
+            .filter(|&l| l > label.line)
+            .min()
+            .unwrap_or(usize::MAX);
+        let end = lines
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find(|(idx, line)| {
+                idx + 1 >= next_label_line || line.starts_with('#') || line.trim() == "---"
+            })
+            .map_or(lines.len(), |(idx, _)| idx);
+
+        let content = lines[start..end].join("\n").trim().to_string();
+        if content.is_empty() {
             None
+        } else {
+            Some(content)
         }
     }
 
@@ -499,7 +924,10 @@ impl Speck {
 
         let mut lines = vec!["## References".to_string()];
         for decision in &self.decisions {
-            lines.push(format!("- [{}] {}", decision.id, decision.title));
+            lines.push(format!("- [{}] {}", decision.id, decision.summary()));
+            for reason in &decision.rationale {
+                lines.push(format!("  - {}", reason));
+            }
         }
 
         lines.join("\n")
@@ -518,6 +946,46 @@ impl Speck {
     }
 }
 
+/// Extract the section under the heading carrying `{#anchor}` in `content`
+fn extract_section(content: &str, anchor: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let anchor_pattern = format!("{{#{}}}", anchor);
+
+    // Find the line with the anchor
+    let start_idx = lines
+        .iter()
+        .position(|line| line.contains(&anchor_pattern))?;
+
+    // Determine the heading level of the anchor line
+    let anchor_line = lines[start_idx];
+    let anchor_level = anchor_line.chars().take_while(|&c| c == '#').count();
+
+    // Find the end of this section (next heading at same or higher level)
+    let mut end_idx = lines.len();
+    for (idx, line) in lines.iter().enumerate().skip(start_idx + 1) {
+        if line.starts_with('#') {
+            let level = line.chars().take_while(|&c| c == '#').count();
+            if level <= anchor_level {
+                end_idx = idx;
+                break;
+            }
+        }
+    }
+
+    // Extract the content between start and end (excluding the heading line itself)
+    if start_idx + 1 < end_idx {
+        let content_lines: Vec<&str> = lines[(start_idx + 1)..end_idx].to_vec();
+        let content = content_lines.join("\n").trim().to_string();
+        if content.is_empty() {
+            None
+        } else {
+            Some(content)
+        }
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -549,12 +1017,14 @@ mod tests {
                     text: "Task 1".to_string(),
                     kind: CheckpointKind::Task,
                     line: 1,
+                    ..Default::default()
                 },
                 Checkpoint {
                     checked: false,
                     text: "Task 2".to_string(),
                     kind: CheckpointKind::Task,
                     line: 2,
+                    ..Default::default()
                 },
             ],
             tests: vec![Checkpoint {
@@ -562,12 +1032,14 @@ mod tests {
                 text: "Test 1".to_string(),
                 kind: CheckpointKind::Test,
                 line: 3,
+                ..Default::default()
             }],
             checkpoints: vec![Checkpoint {
                 checked: false,
                 text: "Check 1".to_string(),
                 kind: CheckpointKind::Checkpoint,
                 line: 4,
+                ..Default::default()
             }],
             ..Default::default()
         };
@@ -587,12 +1059,14 @@ mod tests {
                     text: "Task 1".to_string(),
                     kind: CheckpointKind::Task,
                     line: 1,
+                    ..Default::default()
                 },
                 Checkpoint {
                     checked: true,
                     text: "Task 2".to_string(),
                     kind: CheckpointKind::Task,
                     line: 2,
+                    ..Default::default()
                 },
             ],
             ..Default::default()
@@ -603,6 +1077,44 @@ mod tests {
         assert_eq!(speck.computed_status(), SpeckStatus::Done);
     }
 
+    #[test]
+    fn test_skipped_items_do_not_block_completion() {
+        let mut speck = Speck::default();
+        speck.metadata.status = Some("active".to_string());
+        speck.steps.push(Step {
+            tests: vec![
+                Checkpoint {
+                    checked: true,
+                    text: "Unit test".to_string(),
+                    kind: CheckpointKind::Test,
+                    line: 1,
+                    ..Default::default()
+                },
+                Checkpoint {
+                    checked: false,
+                    text: "Load test".to_string(),
+                    kind: CheckpointKind::Test,
+                    line: 2,
+                    mark: Some(CheckMark::Skipped),
+                    reason: Some("no staging cluster".to_string()),
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(speck.completion_counts(), (1, 1));
+        assert_eq!(speck.computed_status(), SpeckStatus::Done);
+
+        // Blocked and failed items still count as open
+        speck.steps[0].tests[1].mark = Some(CheckMark::Blocked);
+        assert_eq!(speck.completion_counts(), (1, 2));
+        assert_eq!(speck.computed_status(), SpeckStatus::Active);
+
+        let criteria = speck.steps[0].render_acceptance_criteria();
+        assert!(criteria.contains("- [x] Unit test"));
+        assert!(criteria.contains("- [~] Load test (blocked: no staging cluster)"));
+    }
+
     #[test]
     fn test_step_render_description() {
         let step = Step {
@@ -612,12 +1124,14 @@ mod tests {
                     text: "Task 1".to_string(),
                     kind: CheckpointKind::Task,
                     line: 1,
+                    ..Default::default()
                 },
                 Checkpoint {
                     checked: true,
                     text: "Task 2".to_string(),
                     kind: CheckpointKind::Task,
                     line: 2,
+                    ..Default::default()
                 },
             ],
             artifacts: vec![
@@ -647,6 +1161,7 @@ mod tests {
                 text: "Task 1".to_string(),
                 kind: CheckpointKind::Task,
                 line: 1,
+                ..Default::default()
             }],
             ..Default::default()
         };
@@ -665,6 +1180,7 @@ mod tests {
                 text: "Unit test: retry logic".to_string(),
                 kind: CheckpointKind::Test,
                 line: 1,
+                ..Default::default()
             }],
             checkpoints: vec![
                 Checkpoint {
@@ -672,12 +1188,14 @@ mod tests {
                     text: "cargo test passes".to_string(),
                     kind: CheckpointKind::Checkpoint,
                     line: 2,
+                    ..Default::default()
                 },
                 Checkpoint {
                     checked: false,
                     text: "cargo clippy clean".to_string(),
                     kind: CheckpointKind::Checkpoint,
                     line: 3,
+                    ..Default::default()
                 },
             ],
             ..Default::default()
@@ -726,6 +1244,73 @@ Other content.
         assert!(!criteria.contains("### Next Section"));
     }
 
+    #[test]
+    fn test_speck_extract_label() {
+        let content = r#"### Specification {#specification}
+
+**Spec S01: Schema** {#s01-schema}
+
+Schema body.
+
+**Table T01: Codes** {#t01-codes}
+
+| Code | Meaning |
+|------|---------|
+
+---
+
+#### Risk R01: Slow {#r01-slow}
+
+Risk body.
+
+### Next {#next}
+"#;
+        let speck = Speck {
+            raw_content: content.to_string(),
+            labels: vec![
+                Label {
+                    id: "S01".to_string(),
+                    kind: LabelKind::Spec,
+                    title: "Schema".to_string(),
+                    anchor: Some("s01-schema".to_string()),
+                    line: 3,
+                },
+                Label {
+                    id: "T01".to_string(),
+                    kind: LabelKind::Table,
+                    title: "Codes".to_string(),
+                    anchor: Some("t01-codes".to_string()),
+                    line: 7,
+                },
+                Label {
+                    id: "R01".to_string(),
+                    kind: LabelKind::Risk,
+                    title: "Slow".to_string(),
+                    anchor: Some("r01-slow".to_string()),
+                    line: 14,
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(speck.extract_label("S01").unwrap(), "Schema body.");
+        assert_eq!(
+            speck.extract_label("T01").unwrap(),
+            "| Code | Meaning |\n|------|---------|"
+        );
+        assert_eq!(speck.extract_label("R01").unwrap(), "Risk body.");
+        assert!(speck.extract_label("L01").is_none());
+    }
+
+    #[test]
+    fn test_label_kind_words() {
+        assert_eq!(LabelKind::from_word("Tables"), Some(LabelKind::Table));
+        assert_eq!(LabelKind::from_word("Diagram"), Some(LabelKind::Diagram));
+        assert_eq!(LabelKind::from_word("Decision"), None);
+        assert_eq!(LabelKind::Diagram.prefix(), "Diag");
+        assert_eq!(LabelKind::Concept.to_string(), "Concept");
+    }
+
     #[test]
     fn test_speck_extract_section_by_anchor_missing() {
         let content = "## Phase 1.0: Test {#phase-1}\n";
@@ -827,6 +1412,9 @@ Other.
             status: Some("DECIDED".to_string()),
             anchor: Some("d01-use-rust".to_string()),
             line: 1,
+            statement: Some("Write the CLI in Rust.".to_string()),
+            rationale: vec!["Single static binary".to_string()],
+            ..Default::default()
         });
         speck.decisions.push(Decision {
             id: "D02".to_string(),
@@ -834,11 +1422,14 @@ Other.
             status: Some("DECIDED".to_string()),
             anchor: Some("d02-use-clap".to_string()),
             line: 2,
+            ..Default::default()
         });
 
         let design = speck.render_root_design();
         assert!(design.contains("## References"));
-        assert!(design.contains("- [D01] Use Rust"));
+        assert!(
+            design.contains("- [D01] Use Rust: Write the CLI in Rust.\n  - Single static binary")
+        );
         assert!(design.contains("- [D02] Use clap"));
     }
 }
@@ -7,9 +7,12 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::LazyLock;
 
-use crate::types::{ParseDiagnostic, Speck};
+use crate::companion::{FILE_ANCHOR_REF, external_dependency, is_companion_file_name};
+use crate::graph::StepGraph;
+use crate::types::{Checkpoint, LabelKind, ParseDiagnostic, Speck, Substep};
 
 /// Regex for valid anchor format (only a-z, 0-9, - allowed)
 static VALID_ANCHOR: LazyLock<Regex> =
@@ -38,9 +41,24 @@ static DECISION_CITATION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[D\d{
 static DECISION_ID_CAPTURE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\[(D\d{2,})\]").unwrap());
 
-/// Regex for anchor citations in References (must be in parentheses with # prefix)
-static ANCHOR_CITATION: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"\(#[a-z0-9-]+(,\s*#[a-z0-9-]+)*\)").unwrap());
+/// Regex for anchor citations in References (must be in parentheses with # prefix,
+/// optionally file-qualified: `(specks-auth-reference.md#t03-error-codes)`)
+static ANCHOR_CITATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\((?:[\w./-]+\.md)?#[a-z0-9-]+(,\s*(?:[\w./-]+\.md)?#[a-z0-9-]+)*\)").unwrap()
+});
+
+/// Regex for labeled artifact citations in References lines
+/// Matches "Spec S01", "Tables T21-T25", "Lists L01, L02"
+static LABEL_CITATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(Specs?|Tables?|Lists?|Risks?|Milestones?|Concepts?|Diagrams?)\s+((?:Diag|[STLRMC])\d{2,}(?:\s*[-,]\s*(?:Diag|[STLRMC])\d{2,})*)",
+    )
+    .unwrap()
+});
+
+/// Tokens within a label citation: a range dash, or a prefix + number ID
+static LABEL_ID_TOKEN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(-)|(Diag|[STLRMC])(\d+)").unwrap());
 
 /// Result of validating a speck
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -193,6 +211,90 @@ impl ValidationLevel {
     }
 }
 
+/// Every rule and parse diagnostic, with a short description
+pub const RULES: &[(&str, &str)] = &[
+    ("E001", "Missing required section"),
+    ("E002", "Missing required metadata field"),
+    ("E003", "Invalid metadata Status value"),
+    ("E004", "Step missing References line"),
+    ("E005", "Invalid anchor format"),
+    ("E006", "Duplicate anchor"),
+    ("E010", "Dependency on a non-existent step"),
+    ("E011", "Circular step dependency"),
+    ("E012", "Invalid bead ID format"),
+    ("E017", "Malformed Depends on line"),
+    ("E018", "Malformed References line"),
+    ("E039", "Step depends on its own substep"),
+    ("E045", "Includes entry is not a plain .md file name"),
+    ("W001", "Decision without DECIDED/OPEN status"),
+    ("W002", "Question without resolution status"),
+    ("W003", "Step without checkpoint items"),
+    ("W004", "Step without test items"),
+    ("W005", "Reference to non-existent anchor"),
+    ("W006", "Unfilled placeholder in metadata"),
+    ("W007", "Step without dependencies"),
+    ("W008", "Bead ID present but beads integration disabled"),
+    ("W009", "Step missing Commit line"),
+    ("W010", "Step missing Tasks"),
+    ("W011", "Decision defined but never cited"),
+    ("W012", "Decision cited but not defined"),
+    ("W013", "Anchor referenced but not defined"),
+    ("W014", "Labeled artifact cited but not defined"),
+    ("W015", "Decided decision without rationale"),
+    ("W016", "Included companion file not found"),
+    ("W017", "Dependency implied by another dependency"),
+    ("W018", "Dependency on a later step"),
+    ("W019", "Step not reachable from the first step"),
+    ("R001", "Checked item unchecked since the compared revision"),
+    ("R002", "Step with a bead removed"),
+    ("R003", "Anchor referenced by another speck removed"),
+    ("R004", "Status moved backwards"),
+    ("I001", "Document exceeds recommended size"),
+    ("I003", "Critical path through the step dependencies"),
+    ("P001", "Step header does not match strict format"),
+    (
+        "P002",
+        "Decision/Question header does not match strict format",
+    ),
+    ("P003", "Phase header does not match strict format"),
+    ("P004", "Unrecognized metadata field"),
+    ("P005", "Invalid anchor format"),
+    ("P006", "Structural header inside a code block"),
+    ("P007", "Commit line does not match strict format"),
+    ("P008", "Substep number does not match its parent step"),
+    ("P009", "Malformed or unattached specks-ignore comment"),
+];
+
+/// Short description of a rule code, if it is a known rule
+pub fn rule_description(code: &str) -> Option<&'static str> {
+    RULES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, description)| *description)
+}
+
+/// Per-rule setting from `[specks.validation.rules]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleSetting {
+    /// Never report the rule
+    Off,
+    /// Report the rule at this severity instead of its default
+    Severity(Severity),
+}
+
+impl RuleSetting {
+    /// Parse from string representation ("off", "info", "warning", "error")
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "off" => Some(RuleSetting::Off),
+            "info" => Some(RuleSetting::Severity(Severity::Info)),
+            "warning" | "warn" => Some(RuleSetting::Severity(Severity::Warning)),
+            "error" => Some(RuleSetting::Severity(Severity::Error)),
+            _ => None,
+        }
+    }
+}
+
 /// Validation configuration
 #[derive(Debug, Clone, Default)]
 pub struct ValidationConfig {
@@ -202,6 +304,65 @@ pub struct ValidationConfig {
     pub beads_enabled: bool,
     /// Whether to validate bead IDs (requires beads CLI)
     pub validate_bead_ids: bool,
+    /// Per-rule overrides keyed by code (e.g. "W004")
+    pub rules: HashMap<String, RuleSetting>,
+    /// Project-defined rules from `[[specks.validation.custom]]`
+    pub custom_rules: Vec<CustomRule>,
+}
+
+/// A project-defined validation rule
+#[derive(Debug, Clone)]
+pub struct CustomRule {
+    /// Code reported for this rule (e.g. "C001")
+    pub code: String,
+    /// Severity of its issues
+    pub severity: Severity,
+    /// Message reported for its issues
+    pub message: String,
+    /// What the rule checks
+    pub check: CustomCheck,
+}
+
+/// The check a custom rule performs
+#[derive(Debug, Clone)]
+pub enum CustomCheck {
+    /// The speck must define this anchor
+    RequiredAnchor(String),
+    /// The Plan Metadata table must have a non-empty row with this field name
+    RequiredMetadata(String),
+    /// A step field, where present, must match the pattern
+    StepPattern { field: StepField, pattern: Regex },
+    /// Steps may have at most this many task items
+    MaxTasks(usize),
+}
+
+/// A step field that a custom pattern rule can check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepField {
+    Title,
+    Commit,
+    References,
+    DependsOn,
+    Tasks,
+    Tests,
+    Checkpoints,
+}
+
+impl StepField {
+    /// Parse a field name ("title", "commit", "references", "depends_on",
+    /// "tasks", "tests" or "checkpoints")
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "title" => Some(StepField::Title),
+            "commit" => Some(StepField::Commit),
+            "references" => Some(StepField::References),
+            "depends_on" => Some(StepField::DependsOn),
+            "tasks" => Some(StepField::Tasks),
+            "tests" => Some(StepField::Tests),
+            "checkpoints" => Some(StepField::Checkpoints),
+            _ => None,
+        }
+    }
 }
 
 /// Validate a parsed speck
@@ -247,12 +408,18 @@ pub fn validate_speck_with_config(speck: &Speck, config: &ValidationConfig) -> V
     // E011: Check for circular dependencies
     check_circular_dependencies(speck, &mut result);
 
+    // E039: Check steps do not depend on their own substeps
+    check_dependency_on_descendant(speck, &mut result);
+
     // E017: Check **Depends on:** format (must use anchor refs like #step-N)
     check_depends_on_format(speck, &mut result);
 
     // E018: Check **References:** format (must have [DNN] decision citations)
     check_references_format(speck, &mut result);
 
+    // E045: Check Includes entries are plain .md file names
+    check_includes_file_names(speck, &mut result);
+
     // E012: Check bead ID format (when beads enabled)
     if config.beads_enabled && config.validate_bead_ids {
         check_bead_id_format(speck, &mut result);
@@ -265,8 +432,12 @@ pub fn validate_speck_with_config(speck: &Speck, config: &ValidationConfig) -> V
     // E015: Check step bead exists (when beads enabled)
     // Note: Same as above - format validation only, CLI does existence check
 
+    // Rule overrides can raise a warning or info rule above the level's cutoff,
+    // so every rule runs when any are configured; the level filter comes last
+    let run_all = !config.rules.is_empty();
+
     // === WARNING CHECKS ===
-    if config.level.include_warnings() {
+    if config.level.include_warnings() || run_all {
         // W001: Decisions without DECIDED/OPEN status
         check_decision_status(speck, &mut result);
 
@@ -307,26 +478,243 @@ pub fn validate_speck_with_config(speck: &Speck, config: &ValidationConfig) -> V
 
         // W013: Anchor referenced but not defined (replaces W005)
         check_undefined_referenced_anchors(speck, &anchor_map, &mut result);
+
+        // W014: Labeled artifact (Spec S01, Table T01, ...) cited but not defined
+        check_undefined_cited_labels(speck, &mut result);
+
+        // W015: Decision has no rationale
+        check_decision_rationale(speck, &mut result);
+
+        // W016: Included companion file not found
+        check_included_companions(speck, &mut result);
+
+        // W017-W019: Dependency graph structure
+        check_dependency_graph(speck, &mut result);
     }
 
     // === INFO CHECKS ===
-    if config.level.include_info() {
+    if config.level.include_info() || run_all {
         // I001: Document exceeds recommended size (2000+ lines)
         check_document_size(speck, &mut result);
 
         // I002: Deep dive sections exceed 50% of document
         // Note: This would require parsing deep dive sections, which we don't currently track
         // Skipping for now as it's informational
+
+        // I003: Critical path through the step dependencies
+        check_critical_path(speck, &mut result);
     }
 
+    // Project-defined rules; the level filter applies to them like any other
+    check_custom_rules(speck, &config.custom_rules, &mut result);
+
     // Copy parse diagnostics from speck (filtered by validation level)
     if config.level.include_warnings() {
         result.diagnostics = speck.diagnostics.clone();
+        // Fields a custom rule requires are not unrecognized
+        let required: HashSet<String> = config
+            .custom_rules
+            .iter()
+            .filter_map(|rule| match &rule.check {
+                CustomCheck::RequiredMetadata(field) => Some(field.to_lowercase()),
+                _ => None,
+            })
+            .collect();
+        if !required.is_empty() {
+            result.diagnostics.retain(|d| {
+                d.code != "P004"
+                    || !d
+                        .message
+                        .strip_prefix("Unrecognized metadata field: ")
+                        .is_some_and(|field| required.contains(&field.to_lowercase()))
+            });
+        }
     }
 
+    apply_rule_settings(speck, config, &mut result);
+
     result
 }
 
+/// Apply per-rule overrides and inline `specks-ignore` comments, then drop
+/// issues below the level's cutoff
+pub(crate) fn apply_rule_settings(
+    speck: &Speck,
+    config: &ValidationConfig,
+    result: &mut ValidationResult,
+) {
+    let anchor_lines: HashMap<&str, usize> = speck
+        .anchors
+        .iter()
+        .map(|a| (a.name.as_str(), a.line))
+        .collect();
+    let suppressed = |code: &str, line: Option<usize>| {
+        line.is_some_and(|line| speck.suppressions.iter().any(|s| s.covers(code, line)))
+    };
+
+    result.issues.retain_mut(|issue| {
+        match config.rules.get(&issue.code) {
+            Some(RuleSetting::Off) => return false,
+            Some(RuleSetting::Severity(severity)) => issue.severity = *severity,
+            None => {}
+        }
+
+        let line = issue.line.or_else(|| {
+            let anchor = issue.anchor.as_deref()?.trim_start_matches('#');
+            anchor_lines.get(anchor).copied()
+        });
+        if suppressed(&issue.code, line) {
+            return false;
+        }
+
+        match issue.severity {
+            Severity::Error => true,
+            Severity::Warning => config.level.include_warnings(),
+            Severity::Info => config.level.include_info(),
+        }
+    });
+
+    result.diagnostics.retain(|d| {
+        config.rules.get(&d.code) != Some(&RuleSetting::Off) && !suppressed(&d.code, Some(d.line))
+    });
+
+    result.valid = result.error_count() == 0;
+}
+
+// === CUSTOM RULES ===
+
+/// The fields custom rules read, shared by steps and substeps
+struct StepView<'a> {
+    number: &'a str,
+    title: &'a str,
+    anchor: &'a str,
+    line: usize,
+    commit: Option<&'a str>,
+    references: Option<&'a str>,
+    depends_on: &'a [String],
+    tasks: &'a [Checkpoint],
+    tests: &'a [Checkpoint],
+    checkpoints: &'a [Checkpoint],
+}
+
+/// Every step and substep of a speck, in document order
+fn step_views(speck: &Speck) -> Vec<StepView<'_>> {
+    let mut views = Vec::new();
+    for step in &speck.steps {
+        views.push(StepView {
+            number: &step.number,
+            title: &step.title,
+            anchor: &step.anchor,
+            line: step.line,
+            commit: step.commit_message.as_deref(),
+            references: step.references.as_deref(),
+            depends_on: &step.depends_on,
+            tasks: &step.tasks,
+            tests: &step.tests,
+            checkpoints: &step.checkpoints,
+        });
+        for substep in step.all_substeps() {
+            views.push(StepView {
+                number: &substep.number,
+                title: &substep.title,
+                anchor: &substep.anchor,
+                line: substep.line,
+                commit: substep.commit_message.as_deref(),
+                references: substep.references.as_deref(),
+                depends_on: &substep.depends_on,
+                tasks: &substep.tasks,
+                tests: &substep.tests,
+                checkpoints: &substep.checkpoints,
+            });
+        }
+    }
+    views
+}
+
+/// Run project-defined rules
+fn check_custom_rules(speck: &Speck, rules: &[CustomRule], result: &mut ValidationResult) {
+    if rules.is_empty() {
+        return;
+    }
+    let steps = step_views(speck);
+
+    for rule in rules {
+        let issue = |detail: String| {
+            ValidationIssue::new(
+                &rule.code,
+                rule.severity,
+                format!("{} ({})", rule.message, detail),
+            )
+        };
+
+        match &rule.check {
+            CustomCheck::RequiredAnchor(anchor) => {
+                let anchor = anchor.trim_start_matches('#');
+                if !speck.anchors.iter().any(|a| a.name == anchor) {
+                    result.add_issue(issue(format!("missing #{}", anchor)));
+                }
+            }
+            CustomCheck::RequiredMetadata(field) => {
+                if speck.metadata.field(field).is_none() {
+                    result.add_issue(issue(format!("missing metadata field {}", field)));
+                }
+            }
+            CustomCheck::MaxTasks(max) => {
+                for step in &steps {
+                    if step.tasks.len() > *max {
+                        result.add_issue(
+                            issue(format!(
+                                "Step {} has {} tasks, max {}",
+                                step.number,
+                                step.tasks.len(),
+                                max
+                            ))
+                            .at_line(step.line)
+                            .with_anchor(step.anchor),
+                        );
+                    }
+                }
+            }
+            CustomCheck::StepPattern { field, pattern } => {
+                for step in &steps {
+                    let single = |value: Option<&str>| value.map(|v| vec![(v.to_string(), None)]);
+                    let items = |items: &[Checkpoint]| {
+                        Some(
+                            items
+                                .iter()
+                                .map(|item| (item.text.clone(), Some(item.line)))
+                                .collect(),
+                        )
+                    };
+                    let values: Option<Vec<(String, Option<usize>)>> = match field {
+                        StepField::Title => single(Some(step.title)),
+                        StepField::Commit => single(step.commit),
+                        StepField::References => single(step.references),
+                        StepField::DependsOn => (!step.depends_on.is_empty()).then(|| {
+                            step.depends_on
+                                .iter()
+                                .map(|dep| (format!("#{}", dep), None))
+                                .collect()
+                        }),
+                        StepField::Tasks => items(step.tasks),
+                        StepField::Tests => items(step.tests),
+                        StepField::Checkpoints => items(step.checkpoints),
+                    };
+                    for (value, line) in values.unwrap_or_default() {
+                        if !pattern.is_match(&value) {
+                            result.add_issue(
+                                issue(format!("Step {}: {:?}", step.number, value))
+                                    .at_line(line.unwrap_or(step.line))
+                                    .with_anchor(step.anchor),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 // === ERROR CHECK IMPLEMENTATIONS ===
 
 /// E001: Check for required sections
@@ -424,7 +812,7 @@ fn check_step_references(speck: &Speck, result: &mut ValidationResult) {
         }
 
         // Also check substeps
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if substep.references.is_none() {
                 result.add_issue(
                     ValidationIssue::new(
@@ -496,7 +884,19 @@ fn check_duplicate_anchors(speck: &Speck, result: &mut ValidationResult) {
     }
 }
 
+/// File name of the speck being validated, if it has a path
+fn own_file_name(speck: &Speck) -> Option<&str> {
+    speck
+        .path
+        .as_deref()
+        .and_then(|p| Path::new(p).file_name())
+        .and_then(|n| n.to_str())
+}
+
 /// E010: Check dependency references point to existing step anchors
+///
+/// Cross-speck dependencies (`specks-auth.md#step-3`) must name a step of a
+/// speck that exists next to this one.
 fn check_dependency_references(
     speck: &Speck,
     anchor_map: &HashMap<String, usize>,
@@ -507,89 +907,198 @@ fn check_dependency_references(
         .steps
         .iter()
         .flat_map(|s| {
-            std::iter::once(s.anchor.as_str()).chain(s.substeps.iter().map(|ss| ss.anchor.as_str()))
+            std::iter::once(s.anchor.as_str())
+                .chain(s.all_substeps().into_iter().map(|ss| ss.anchor.as_str()))
         })
         .collect();
+    let own_file = own_file_name(speck);
+
+    // Message for a dependency that does not resolve
+    let missing = |dep: &str| -> Option<String> {
+        let (file, anchor) = match external_dependency(dep) {
+            Some((file, anchor)) if Some(file) != own_file => (Some(file), anchor),
+            Some((_, anchor)) => (None, anchor),
+            None => (None, dep),
+        };
+        match file {
+            None if step_anchors.contains(anchor) || anchor_map.contains_key(anchor) => None,
+            None => Some(format!(
+                "Dependency references non-existent step anchor: {}",
+                anchor
+            )),
+            Some(file) => match speck.companion(file).filter(|c| c.found) {
+                Some(c) if c.dependencies.iter().any(|(a, _)| a == anchor) => None,
+                Some(_) => Some(format!(
+                    "Dependency references non-existent step anchor: {}#{}",
+                    file, anchor
+                )),
+                None => Some(format!(
+                    "Dependency references non-existent step anchor: {}#{} ({} not found)",
+                    file, anchor, file
+                )),
+            },
+        }
+    };
 
     for step in &speck.steps {
         for dep in &step.depends_on {
-            if !step_anchors.contains(dep.as_str()) && !anchor_map.contains_key(dep) {
+            if let Some(message) = missing(dep) {
                 result.add_issue(
-                    ValidationIssue::new(
-                        "E010",
-                        Severity::Error,
-                        format!("Dependency references non-existent step anchor: {}", dep),
-                    )
-                    .at_line(step.line)
-                    .with_anchor(&step.anchor),
+                    ValidationIssue::new("E010", Severity::Error, message)
+                        .at_line(step.line)
+                        .with_anchor(&step.anchor),
                 );
             }
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             for dep in &substep.depends_on {
-                if !step_anchors.contains(dep.as_str()) && !anchor_map.contains_key(dep) {
+                if let Some(message) = missing(dep) {
                     result.add_issue(
-                        ValidationIssue::new(
-                            "E010",
-                            Severity::Error,
-                            format!("Dependency references non-existent step anchor: {}", dep),
-                        )
-                        .at_line(substep.line)
-                        .with_anchor(&substep.anchor),
+                        ValidationIssue::new("E010", Severity::Error, message)
+                            .at_line(substep.line)
+                            .with_anchor(&substep.anchor),
                     );
                 }
             }
         }
     }
+
+    // Speck-level dependencies from the Plan Metadata table (unqualified
+    // ones are E017)
+    for dep in &speck.metadata.depends_on {
+        if external_dependency(dep).is_none() {
+            continue;
+        }
+        if let Some(message) = missing(dep) {
+            result.add_issue(
+                ValidationIssue::new("E010", Severity::Error, message).with_anchor("plan-metadata"),
+            );
+        }
+    }
 }
 
 /// E011: Check for circular dependencies using DFS
+///
+/// The graph spans every speck loaded through cross-speck dependencies:
+/// steps of this speck are plain anchors, steps of other specks are
+/// `file#anchor`. Only cycles that pass through this speck are reported;
+/// the others belong to the specks they are in.
 fn check_circular_dependencies(speck: &Speck, result: &mut ValidationResult) {
-    // Build dependency graph
-    let mut deps: HashMap<&str, Vec<&str>> = HashMap::new();
-
-    for step in &speck.steps {
-        deps.insert(
-            step.anchor.as_str(),
-            step.depends_on.iter().map(|s| s.as_str()).collect(),
-        );
+    let own_file = own_file_name(speck);
+    let qualify = |from: Option<&str>, dep: &str| match external_dependency(dep) {
+        Some((file, anchor)) if Some(file) == own_file => anchor.to_string(),
+        Some(_) => dep.to_string(),
+        None => match from {
+            Some(file) => format!("{}#{}", file, dep),
+            None => dep.to_string(),
+        },
+    };
 
-        for substep in &step.substeps {
-            deps.insert(
-                substep.anchor.as_str(),
-                substep.depends_on.iter().map(|s| s.as_str()).collect(),
-            );
+    // Build dependency graph
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    for (anchor, deps) in speck.step_dependencies() {
+        let deps = deps.iter().map(|dep| qualify(None, dep)).collect();
+        graph.insert(anchor, deps);
+    }
+    for companion in speck.companions.iter().filter(|c| c.found) {
+        for (anchor, deps) in &companion.dependencies {
+            let deps = deps
+                .iter()
+                .map(|dep| qualify(Some(&companion.file), dep))
+                .collect();
+            graph.insert(format!("{}#{}", companion.file, anchor), deps);
         }
     }
+    let deps: HashMap<&str, Vec<&str>> = graph
+        .iter()
+        .map(|(node, deps)| (node.as_str(), deps.iter().map(String::as_str).collect()))
+        .collect();
 
-    // DFS to detect cycles
+    // DFS to detect cycles, starting from this speck's steps
     let mut visited: HashSet<&str> = HashSet::new();
     let mut rec_stack: HashSet<&str> = HashSet::new();
     let mut path: Vec<&str> = Vec::new();
 
-    for start in deps.keys() {
+    let mut starts: Vec<&str> = deps.keys().copied().filter(|n| !n.contains('#')).collect();
+    starts.sort();
+    for start in starts {
         if !visited.contains(start) {
             if let Some(cycle) = detect_cycle(start, &deps, &mut visited, &mut rec_stack, &mut path)
             {
-                result.add_issue(ValidationIssue::new(
-                    "E011",
-                    Severity::Error,
-                    format!("Circular dependency detected: {}", cycle),
-                ));
+                if cycle.iter().any(|node| !node.contains('#')) {
+                    result.add_issue(ValidationIssue::new(
+                        "E011",
+                        Severity::Error,
+                        format!("Circular dependency detected: {}", cycle.join(" -> ")),
+                    ));
+                }
+                rec_stack.clear();
+                path.clear();
+            }
+        }
+    }
+}
+
+/// E039: Check steps do not depend on their own substeps
+///
+/// Substeps implicitly depend on their parent, so a step (or substep) that
+/// waits on one of its own descendants can never start.
+fn check_dependency_on_descendant(speck: &Speck, result: &mut ValidationResult) {
+    let mut check = |number: &str,
+                     anchor: &str,
+                     line: usize,
+                     depends_on: &[String],
+                     descendants: Vec<&Substep>| {
+        for dep in depends_on {
+            if let Some(child) = descendants.iter().find(|d| &d.anchor == dep) {
+                result.add_issue(
+                    ValidationIssue::new(
+                        "E039",
+                        Severity::Error,
+                        format!(
+                            "Step {} depends on its own substep {} (#{})",
+                            number, child.number, dep
+                        ),
+                    )
+                    .at_line(line)
+                    .with_anchor(anchor),
+                );
             }
         }
+    };
+
+    for step in &speck.steps {
+        check(
+            &step.number,
+            &step.anchor,
+            step.line,
+            &step.depends_on,
+            step.all_substeps(),
+        );
+        for substep in step.all_substeps() {
+            check(
+                &substep.number,
+                &substep.anchor,
+                substep.line,
+                &substep.depends_on,
+                substep.all_substeps(),
+            );
+        }
     }
 }
 
 /// Helper for cycle detection
+///
+/// Returns the nodes of the first cycle found, ending with the node it
+/// starts from (`a -> b -> a`).
 fn detect_cycle<'a>(
     node: &'a str,
     deps: &HashMap<&'a str, Vec<&'a str>>,
     visited: &mut HashSet<&'a str>,
     rec_stack: &mut HashSet<&'a str>,
     path: &mut Vec<&'a str>,
-) -> Option<String> {
+) -> Option<Vec<&'a str>> {
     visited.insert(node);
     rec_stack.insert(node);
     path.push(node);
@@ -601,10 +1110,11 @@ fn detect_cycle<'a>(
                     return Some(cycle);
                 }
             } else if rec_stack.contains(neighbor) {
-                // Found a cycle - construct cycle string
+                // Found a cycle - collect its nodes
                 let cycle_start = path.iter().position(|&n| n == neighbor).unwrap();
-                let cycle_nodes: Vec<&str> = path[cycle_start..].to_vec();
-                return Some(format!("{} -> {}", cycle_nodes.join(" -> "), neighbor));
+                let mut cycle_nodes: Vec<&str> = path[cycle_start..].to_vec();
+                cycle_nodes.push(neighbor);
+                return Some(cycle_nodes);
             }
         }
     }
@@ -642,7 +1152,7 @@ fn check_depends_on_format(speck: &Speck, result: &mut ValidationResult) {
         }
 
         // Also check substeps
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             for dep in &substep.depends_on {
                 if !dep.starts_with("step-") && !dep.contains('-') {
                     result.add_issue(
@@ -661,6 +1171,23 @@ fn check_depends_on_format(speck: &Speck, result: &mut ValidationResult) {
             }
         }
     }
+
+    // A whole speck can only wait on steps of other specks
+    for dep in &speck.metadata.depends_on {
+        if external_dependency(dep).is_none() {
+            result.add_issue(
+                ValidationIssue::new(
+                    "E017",
+                    Severity::Error,
+                    format!(
+                        "Invalid speck dependency: '#{}' (must name another speck's step like 'specks-auth.md#step-3')",
+                        dep
+                    ),
+                )
+                .with_anchor("plan-metadata"),
+            );
+        }
+    }
 }
 
 /// E018: Check **References:** format
@@ -715,7 +1242,7 @@ fn check_references_format(speck: &Speck, result: &mut ValidationResult) {
         }
 
         // Also check substeps
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if let Some(refs) = &substep.references {
                 let has_decision_citation = DECISION_CITATION.is_match(refs);
                 let has_anchor_citation = ANCHOR_CITATION.is_match(refs);
@@ -772,7 +1299,7 @@ fn check_bead_id_format(speck: &Speck, result: &mut ValidationResult) {
             }
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if let Some(bead_id) = &substep.bead_id {
                 if !VALID_BEAD_ID.is_match(bead_id) {
                     result.add_issue(
@@ -931,6 +1458,96 @@ fn check_step_dependencies(speck: &Speck, result: &mut ValidationResult) {
     }
 }
 
+/// W017-W019: Dependency graph structure
+///
+/// - W017: a dependency already implied through another dependency
+/// - W018: a dependency on a step that comes later in the speck
+/// - W019: a step whose dependencies never lead back to the first step
+fn check_dependency_graph(speck: &Speck, result: &mut ValidationResult) {
+    let graph = StepGraph::from_speck(speck);
+    let step = |index: usize| graph.nodes[index].step;
+
+    for edge in graph.redundant_edges() {
+        let (from, dep, via) = (step(edge.step), step(edge.dep), step(edge.via));
+        result.add_issue(
+            ValidationIssue::new(
+                "W017",
+                Severity::Warning,
+                format!(
+                    "Step {} dependency on Step {} (#{}) is already implied through Step {}",
+                    from.number, dep.number, dep.anchor, via.number
+                ),
+            )
+            .at_line(from.line)
+            .with_anchor(&from.anchor),
+        );
+    }
+
+    for (from, dep) in graph.later_dependencies() {
+        let (from, dep) = (step(from), step(dep));
+        result.add_issue(
+            ValidationIssue::new(
+                "W018",
+                Severity::Warning,
+                format!(
+                    "Step {} depends on later Step {} (#{})",
+                    from.number, dep.number, dep.anchor
+                ),
+            )
+            .at_line(from.line)
+            .with_anchor(&from.anchor),
+        );
+    }
+
+    for index in graph.unreachable_from_first() {
+        let unreachable = step(index);
+        result.add_issue(
+            ValidationIssue::new(
+                "W019",
+                Severity::Warning,
+                format!(
+                    "Step {} is not reachable from Step {} through its dependencies",
+                    unreachable.number,
+                    step(0).number
+                ),
+            )
+            .at_line(unreachable.line)
+            .with_anchor(&unreachable.anchor),
+        );
+    }
+}
+
+/// I003: Report the critical path through the step dependencies
+fn check_critical_path(speck: &Speck, result: &mut ValidationResult) {
+    if speck.steps.len() < 2 {
+        return;
+    }
+    let graph = StepGraph::from_speck(speck);
+    let Some(path) = graph.critical_path() else {
+        return;
+    };
+
+    let chain: Vec<String> = path
+        .steps
+        .iter()
+        .map(|&i| format!("Step {}", graph.nodes[i].step.number))
+        .collect();
+    let mut message = format!(
+        "Critical path: {} ({} of {} steps",
+        chain.join(" -> "),
+        path.steps.len(),
+        graph.nodes.len()
+    );
+    if path.minutes > 0 {
+        message.push_str(&format!(", {} min estimated", path.minutes));
+    }
+    if path.unestimated > 0 && path.unestimated < path.steps.len() {
+        message.push_str(&format!(", {} without an estimate", path.unestimated));
+    }
+    message.push(')');
+    result.add_issue(ValidationIssue::new("I003", Severity::Info, message));
+}
+
 /// W008: Bead ID present but beads integration not enabled
 fn check_bead_without_integration(speck: &Speck, result: &mut ValidationResult) {
     if speck.metadata.beads_root_id.is_some() {
@@ -957,7 +1574,7 @@ fn check_bead_without_integration(speck: &Speck, result: &mut ValidationResult)
             );
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if substep.bead_id.is_some() {
                 result.add_issue(
                     ValidationIssue::new(
@@ -991,7 +1608,7 @@ fn check_commit_lines(speck: &Speck, result: &mut ValidationResult) {
             );
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if substep.commit_message.is_none() {
                 result.add_issue(
                     ValidationIssue::new(
@@ -1022,7 +1639,7 @@ fn check_step_tasks(speck: &Speck, result: &mut ValidationResult) {
             );
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if substep.tasks.is_empty() {
                 result.add_issue(
                     ValidationIssue::new(
@@ -1061,7 +1678,7 @@ fn check_uncited_decisions(speck: &Speck, result: &mut ValidationResult) {
             }
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if let Some(refs) = &substep.references {
                 for cap in DECISION_ID_CAPTURE.captures_iter(refs) {
                     cited_ids.insert(cap.get(1).unwrap().as_str().to_string());
@@ -1116,7 +1733,7 @@ fn check_undefined_cited_decisions(speck: &Speck, result: &mut ValidationResult)
             }
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if let Some(refs) = &substep.references {
                 for cap in DECISION_ID_CAPTURE.captures_iter(refs) {
                     let cited_id = cap.get(1).unwrap().as_str();
@@ -1141,52 +1758,218 @@ fn check_undefined_cited_decisions(speck: &Speck, result: &mut ValidationResult)
 }
 
 /// W013: Anchor referenced but not defined (replaces W005)
-/// Checks References lines only (not Depends on - those are E010)
+/// Checks References lines only (not Depends on - those are E010).
+/// Plain anchors may be defined in included companions; `file.md#anchor`
+/// references are checked against that file's anchors.
 fn check_undefined_referenced_anchors(
     speck: &Speck,
     anchor_map: &HashMap<String, usize>,
     result: &mut ValidationResult,
 ) {
-    let anchor_ref_pattern = Regex::new(r"#([a-z0-9-]+)").unwrap();
+    let own_file = speck
+        .path
+        .as_deref()
+        .and_then(|p| Path::new(p).file_name())
+        .and_then(|n| n.to_str());
 
     for step in &speck.steps {
-        if let Some(refs) = &step.references {
-            for cap in anchor_ref_pattern.captures_iter(refs) {
-                let ref_anchor = cap.get(1).unwrap().as_str();
-                if !anchor_map.contains_key(ref_anchor) {
+        let mut citing: Vec<(&str, usize, &Option<String>)> =
+            vec![(&step.anchor, step.line, &step.references)];
+        // Check substeps (this was missing in W005)
+        for substep in step.all_substeps() {
+            citing.push((&substep.anchor, substep.line, &substep.references));
+        }
+
+        for (anchor, line, references) in citing {
+            let Some(refs) = references else {
+                continue;
+            };
+            for cap in FILE_ANCHOR_REF.captures_iter(refs) {
+                let file = cap
+                    .get(1)
+                    .map(|m| m.as_str())
+                    .filter(|f| Some(*f) != own_file);
+                let ref_anchor = cap.get(2).unwrap().as_str();
+
+                let message = match file {
+                    None if anchor_map.contains_key(ref_anchor)
+                        || speck
+                            .companions
+                            .iter()
+                            .filter(|c| c.included)
+                            .any(|c| c.anchors.iter().any(|a| a.name == ref_anchor)) =>
+                    {
+                        continue;
+                    }
+                    None => format!("Reference to non-existent anchor: #{}", ref_anchor),
+                    Some(file) => match speck.companion(file).filter(|c| c.found) {
+                        Some(c) if c.anchors.iter().any(|a| a.name == ref_anchor) => continue,
+                        Some(_) => {
+                            format!("Reference to non-existent anchor: {}#{}", file, ref_anchor)
+                        }
+                        None => format!(
+                            "Reference to non-existent anchor: {}#{} ({} not found)",
+                            file, ref_anchor, file
+                        ),
+                    },
+                };
+
+                result.add_issue(
+                    ValidationIssue::new("W013", Severity::Warning, message)
+                        .at_line(line)
+                        .with_anchor(anchor),
+                );
+            }
+        }
+    }
+}
+
+/// E045: Check Includes entries are plain `.md` file names
+///
+/// Companions must sit next to the speck; paths are never loaded.
+fn check_includes_file_names(speck: &Speck, result: &mut ValidationResult) {
+    for file in &speck.metadata.includes {
+        if !is_companion_file_name(file) {
+            result.add_issue(
+                ValidationIssue::new(
+                    "E045",
+                    Severity::Error,
+                    format!(
+                        "Includes entry '{}' must be a .md file name in the speck's directory",
+                        file
+                    ),
+                )
+                .with_anchor("plan-metadata"),
+            );
+        }
+    }
+}
+
+/// W016: Included companion file not found
+fn check_included_companions(speck: &Speck, result: &mut ValidationResult) {
+    for companion in &speck.companions {
+        if companion.included && !companion.found {
+            result.add_issue(ValidationIssue::new(
+                "W016",
+                Severity::Warning,
+                format!("Included companion file not found: {}", companion.file),
+            ));
+        }
+    }
+}
+
+/// W014: Labeled artifact (Spec S01, Table T01, ...) cited but not defined
+fn check_undefined_cited_labels(speck: &Speck, result: &mut ValidationResult) {
+    let defined_ids: HashSet<&str> = speck
+        .labels
+        .iter()
+        .chain(
+            speck
+                .companions
+                .iter()
+                .filter(|c| c.included)
+                .flat_map(|c| c.labels.iter()),
+        )
+        .map(|l| l.id.as_str())
+        .collect();
+
+    for step in &speck.steps {
+        let mut citing: Vec<(&str, &str, usize, &Option<String>)> =
+            vec![(&step.number, &step.anchor, step.line, &step.references)];
+        for substep in step.all_substeps() {
+            citing.push((
+                &substep.number,
+                &substep.anchor,
+                substep.line,
+                &substep.references,
+            ));
+        }
+
+        for (number, anchor, line, references) in citing {
+            let Some(refs) = references else {
+                continue;
+            };
+            for (kind, cited_id) in cited_labels(refs) {
+                if !defined_ids.contains(cited_id.as_str()) {
                     result.add_issue(
                         ValidationIssue::new(
-                            "W013",
+                            "W014",
                             Severity::Warning,
-                            format!("Reference to non-existent anchor: #{}", ref_anchor),
+                            format!(
+                                "Step {} references {} {} which is not defined",
+                                number, kind, cited_id
+                            ),
                         )
-                        .at_line(step.line)
-                        .with_anchor(&step.anchor),
+                        .at_line(line)
+                        .with_anchor(anchor),
                     );
                 }
             }
         }
+    }
+}
 
-        // Check substeps (this was missing in W005)
-        for substep in &step.substeps {
-            if let Some(refs) = &substep.references {
-                for cap in anchor_ref_pattern.captures_iter(refs) {
-                    let ref_anchor = cap.get(1).unwrap().as_str();
-                    if !anchor_map.contains_key(ref_anchor) {
-                        result.add_issue(
-                            ValidationIssue::new(
-                                "W013",
-                                Severity::Warning,
-                                format!("Reference to non-existent anchor: #{}", ref_anchor),
-                            )
-                            .at_line(substep.line)
-                            .with_anchor(&substep.anchor),
-                        );
+/// W015: Decided decision has no rationale (open ones have none yet)
+fn check_decision_rationale(speck: &Speck, result: &mut ValidationResult) {
+    for decision in &speck.decisions {
+        let decided = decision
+            .status
+            .as_deref()
+            .is_some_and(|s| s.eq_ignore_ascii_case("DECIDED"));
+        if decided && decision.rationale.is_empty() {
+            let mut issue = ValidationIssue::new(
+                "W015",
+                Severity::Warning,
+                format!("Decision {} has no rationale", decision.id),
+            )
+            .at_line(decision.line);
+            if let Some(anchor) = &decision.anchor {
+                issue = issue.with_anchor(anchor);
+            }
+            result.add_issue(issue);
+        }
+    }
+}
+
+/// Extract label citations from a References line, expanding ranges
+///
+/// `Spec S15, Tables T21-T23` yields S15, T21, T22, T23.
+pub fn cited_labels(refs: &str) -> Vec<(LabelKind, String)> {
+    let mut cited = Vec::new();
+
+    for cap in LABEL_CITATION.captures_iter(refs) {
+        let Some(kind) = LabelKind::from_word(cap.get(1).unwrap().as_str()) else {
+            continue;
+        };
+        let mut prev: Option<(String, u32, usize)> = None;
+        let mut in_range = false;
+
+        for token in LABEL_ID_TOKEN.captures_iter(cap.get(2).unwrap().as_str()) {
+            if token.get(1).is_some() {
+                in_range = true;
+                continue;
+            }
+            let prefix = token.get(2).unwrap().as_str().to_string();
+            let digits = token.get(3).unwrap().as_str();
+            let number: u32 = digits.parse().unwrap_or(0);
+
+            match prev.take() {
+                Some((prev_prefix, start, width))
+                    if in_range && prev_prefix == prefix && number > start =>
+                {
+                    // Cap expansion so a typo like T01-T9999 stays cheap
+                    for n in (start + 1)..=number.min(start + 100) {
+                        cited.push((kind, format!("{}{:0width$}", prefix, n)));
                     }
                 }
+                _ => cited.push((kind, format!("{}{}", prefix, digits))),
             }
+            prev = Some((prefix, number, digits.len()));
+            in_range = false;
         }
     }
+
+    cited
 }
 
 // === INFO CHECK IMPLEMENTATIONS ===
@@ -1207,6 +1990,7 @@ fn check_document_size(speck: &Speck, result: &mut ValidationResult) {
 mod tests {
     use super::*;
     use crate::parser::parse_speck;
+    use crate::types::{Anchor, Companion};
 
     #[test]
     fn test_validate_minimal_valid_speck() {
@@ -1456,25 +2240,109 @@ Deliverable text.
     }
 
     #[test]
-    fn test_e012_invalid_bead_id() {
+    fn test_e011_circular_dependency_across_levels() {
         let content = r#"## Phase 1.0: Test {#phase-1}
 
-### Plan Metadata {#plan-metadata}
+#### Step 1: First {#step-1}
 
-| Field | Value |
-|------|-------|
-| Owner | Test |
-| Status | draft |
-| Last updated | 2026-02-03 |
-| Beads Root | `invalid bead id` |
-"#;
+**Depends on:** #step-2-1-1
 
-        let speck = parse_speck(content).unwrap();
-        let config = ValidationConfig {
-            beads_enabled: true,
-            validate_bead_ids: true,
-            ..Default::default()
-        };
+**Tasks:**
+- [ ] Task
+
+#### Step 2: Second {#step-2}
+
+**Tasks:**
+- [ ] Task
+
+##### Step 2.1: Nested {#step-2-1}
+
+**Tasks:**
+- [ ] Task
+
+###### Step 2.1.1: Deeper {#step-2-1-1}
+
+**Depends on:** #step-1
+
+**Tasks:**
+- [ ] Task
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let result = validate_speck(&speck);
+
+        let e010_issues: Vec<_> = result.issues.iter().filter(|i| i.code == "E010").collect();
+        assert!(e010_issues.is_empty());
+        let e011_issues: Vec<_> = result.issues.iter().filter(|i| i.code == "E011").collect();
+        assert_eq!(e011_issues.len(), 1);
+    }
+
+    #[test]
+    fn test_e039_step_depends_on_own_substep() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+#### Step 2: Parent {#step-2}
+
+**Depends on:** #step-2-1-1
+
+**Tasks:**
+- [ ] Task
+
+##### Step 2.1: Child {#step-2-1}
+
+**Depends on:** #step-2-1-1
+
+**Tasks:**
+- [ ] Task
+
+###### Step 2.1.1: Grandchild {#step-2-1-1}
+
+**Depends on:** #step-2
+
+**Tasks:**
+- [ ] Task
+
+##### Step 2.2: Sibling {#step-2-2}
+
+**Depends on:** #step-2-1-1
+
+**Tasks:**
+- [ ] Task
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let result = validate_speck(&speck);
+
+        let e039_issues: Vec<_> = result.issues.iter().filter(|i| i.code == "E039").collect();
+        assert_eq!(e039_issues.len(), 2);
+        assert!(
+            e039_issues[0]
+                .message
+                .contains("Step 2 depends on its own substep 2.1.1")
+        );
+        assert!(e039_issues[1].message.contains("Step 2.1 depends"));
+    }
+
+    #[test]
+    fn test_e012_invalid_bead_id() {
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | draft |
+| Last updated | 2026-02-03 |
+| Beads Root | `invalid bead id` |
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let config = ValidationConfig {
+            beads_enabled: true,
+            validate_bead_ids: true,
+            ..Default::default()
+        };
         let result = validate_speck_with_config(&speck, &config);
 
         let e012_issues: Vec<_> = result.issues.iter().filter(|i| i.code == "E012").collect();
@@ -2363,4 +3231,674 @@ Some context here.
             result.issues.iter().filter(|i| i.code == "W013").collect();
         assert_eq!(w013_issues.len(), 0);
     }
+
+    // W014 tests
+    #[test]
+    fn test_w014_undefined_cited_label() {
+        use crate::parser::parse_speck;
+
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | 2026-02-03 |
+
+### Specification {#specification}
+
+**Table T01: Codes** {#t01-codes}
+
+| Code | Meaning |
+|------|---------|
+
+#### Step 0: Test {#step-0}
+
+**Commit:** `feat: add feature`
+
+**References:** Tables T01-T03, Spec S01
+
+**Tasks:**
+- [ ] Task one
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let result = validate_speck(&speck);
+
+        let w014_issues: Vec<&ValidationIssue> =
+            result.issues.iter().filter(|i| i.code == "W014").collect();
+        assert_eq!(w014_issues.len(), 3);
+        assert!(w014_issues[0].message.contains("Table T02"));
+        assert!(w014_issues[1].message.contains("Table T03"));
+        assert!(w014_issues[2].message.contains("Spec S01"));
+    }
+
+    #[test]
+    fn test_w014_defined_labels_no_warning() {
+        use crate::parser::parse_speck;
+
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | 2026-02-03 |
+
+### Specification {#specification}
+
+**Spec S01: Schema** {#s01-schema}
+
+#### Risk R01: Slow startup {#r01-slow-startup}
+
+#### Step 0: Test {#step-0}
+
+**Commit:** `feat: add feature`
+
+**References:** Spec S01, Risk R01, (#specification)
+
+**Tasks:**
+- [ ] Task one
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let result = validate_speck(&speck);
+
+        let w014_issues: Vec<&ValidationIssue> =
+            result.issues.iter().filter(|i| i.code == "W014").collect();
+        assert_eq!(w014_issues.len(), 0);
+    }
+
+    #[test]
+    fn test_cited_labels_expands_ranges() {
+        let cited = cited_labels("Spec S15, Tables T08-T10, Diagram Diag01");
+        let ids: Vec<&str> = cited.iter().map(|(_, id)| id.as_str()).collect();
+        assert_eq!(ids, vec!["S15", "T08", "T09", "T10", "Diag01"]);
+        assert_eq!(cited[1].0, LabelKind::Table);
+    }
+
+    // W015 tests
+    #[test]
+    fn test_w015_decision_without_rationale() {
+        use crate::parser::parse_speck;
+
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Design Decisions {#design-decisions}
+
+#### [D01] Has rationale (DECIDED) {#d01-has}
+
+**Decision:** Do the thing.
+
+**Rationale:** Because it works.
+
+#### [D02] Missing rationale (DECIDED) {#d02-missing}
+
+**Decision:** Do the other thing.
+
+**Implications:**
+- Something changes
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        let result = validate_speck(&speck);
+
+        let w015_issues: Vec<&ValidationIssue> =
+            result.issues.iter().filter(|i| i.code == "W015").collect();
+        assert_eq!(w015_issues.len(), 1);
+        assert!(w015_issues[0].message.contains("D02"));
+        assert_eq!(w015_issues[0].anchor, Some("#d02-missing".to_string()));
+    }
+
+    #[test]
+    fn test_w015_ignores_open_decision() {
+        use crate::parser::parse_speck;
+
+        let content = r#"## Phase 1.0: Test {#phase-1}
+
+### Design Decisions {#design-decisions}
+
+#### [D01] Still undecided (OPEN) {#d01-undecided}
+
+**Decision:** To be decided.
+"#;
+
+        let speck = parse_speck(content).unwrap();
+        assert_eq!(speck.decisions[0].status, Some("OPEN".to_string()));
+        let result = validate_speck(&speck);
+        assert!(!result.issues.iter().any(|i| i.code == "W015"));
+    }
+
+    // W017-W019 and I003 dependency graph tests
+
+    const GRAPH_SPECK: &str = r#"## Phase 1.0: Graph {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Beads:** estimate_minutes=30
+
+#### Step 1: Model {#step-1}
+
+**Depends on:** #step-0
+
+**Beads:** estimate_minutes=60
+
+#### Step 2: Api {#step-2}
+
+**Depends on:** #step-0, #step-1
+
+**Beads:** estimate_minutes=45
+
+#### Step 3: Docs {#step-3}
+
+**Depends on:** #step-4
+
+#### Step 4: Notes {#step-4}
+
+**Depends on:** #step-3-1
+
+##### Step 4.1: Draft {#step-4-1}
+"#;
+
+    #[test]
+    fn test_dependency_graph_warnings() {
+        let speck = parse_speck(GRAPH_SPECK).unwrap();
+        let result = validate_speck(&speck);
+        let messages = |code: &str| -> Vec<String> {
+            result
+                .issues
+                .iter()
+                .filter(|i| i.code == code)
+                .map(|i| i.message.clone())
+                .collect()
+        };
+
+        assert_eq!(
+            messages("W017"),
+            vec!["Step 2 dependency on Step 0 (#step-0) is already implied through Step 1"]
+        );
+        assert_eq!(
+            messages("W018"),
+            vec!["Step 3 depends on later Step 4 (#step-4)"]
+        );
+        // Step 4 depends on an unknown anchor (E010) and is not in the graph's edges
+        assert_eq!(
+            messages("W019"),
+            vec!["Step 3 is not reachable from Step 0 through its dependencies"]
+        );
+        let w017 = result.issues.iter().find(|i| i.code == "W017").unwrap();
+        assert_eq!(w017.anchor.as_deref(), Some("#step-2"));
+
+        // The critical path is informational
+        assert!(messages("I003").is_empty());
+    }
+
+    #[test]
+    fn test_i003_critical_path() {
+        let speck = parse_speck(GRAPH_SPECK).unwrap();
+        let config = ValidationConfig {
+            level: ValidationLevel::Strict,
+            ..Default::default()
+        };
+        let result = validate_speck_with_config(&speck, &config);
+        let i003: Vec<_> = result.issues.iter().filter(|i| i.code == "I003").collect();
+        assert_eq!(i003.len(), 1);
+        assert_eq!(
+            i003[0].message,
+            "Critical path: Step 0 -> Step 1 -> Step 2 (3 of 5 steps, 135 min estimated)"
+        );
+        assert_eq!(i003[0].severity, Severity::Info);
+
+        // Without estimates the longest chain of steps is reported
+        let unestimated = GRAPH_SPECK.replace("**Beads:** estimate_minutes=60\n", "");
+        let speck = parse_speck(&unestimated).unwrap();
+        let result = validate_speck_with_config(&speck, &config);
+        let i003 = result.issues.iter().find(|i| i.code == "I003").unwrap();
+        assert_eq!(
+            i003.message,
+            "Critical path: Step 0 -> Step 1 -> Step 2 (3 of 5 steps, 75 min estimated, 1 without an estimate)"
+        );
+    }
+
+    // W013/W016 companion file tests
+    fn speck_with_companion(references: &str, companion: Companion) -> Speck {
+        let content = format!(
+            r#"## Phase 1.0: Test {{#phase-1}}
+
+#### Step 0: Test {{#step-0}}
+
+**References:** {}
+
+**Tasks:**
+- [ ] Task one
+"#,
+            references
+        );
+        let mut speck = parse_speck(&content).unwrap();
+        speck.path = Some(".specks/specks-auth.md".to_string());
+        speck.companions.push(companion);
+        speck
+    }
+
+    fn w013_messages(speck: &Speck) -> Vec<String> {
+        validate_speck(speck)
+            .issues
+            .into_iter()
+            .filter(|i| i.code == "W013")
+            .map(|i| i.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_w013_resolves_anchors_across_companions() {
+        let reference = Companion {
+            file: "specks-auth-reference.md".to_string(),
+            included: true,
+            found: true,
+            anchors: vec![Anchor {
+                name: "t03-error-codes".to_string(),
+                line: 3,
+            }],
+            ..Default::default()
+        };
+
+        let speck = speck_with_companion(
+            "(#t03-error-codes), specks-auth-reference.md#t03-error-codes, specks-auth.md#step-0",
+            reference.clone(),
+        );
+        assert!(w013_messages(&speck).is_empty());
+
+        let speck = speck_with_companion("specks-auth-reference.md#t04-missing", reference);
+        assert_eq!(
+            w013_messages(&speck),
+            vec!["Reference to non-existent anchor: specks-auth-reference.md#t04-missing"]
+        );
+    }
+
+    #[test]
+    fn test_w013_plain_anchor_not_resolved_by_referenced_only_companion() {
+        let other = Companion {
+            file: "specks-billing.md".to_string(),
+            included: false,
+            found: true,
+            anchors: vec![Anchor {
+                name: "invoices".to_string(),
+                line: 1,
+            }],
+            ..Default::default()
+        };
+        let speck = speck_with_companion("(#invoices), specks-billing.md#invoices", other);
+        assert_eq!(
+            w013_messages(&speck),
+            vec!["Reference to non-existent anchor: #invoices"]
+        );
+    }
+
+    #[test]
+    fn test_w013_and_w016_missing_companion_file() {
+        let missing = Companion {
+            file: "specks-auth-deepdives.md".to_string(),
+            included: true,
+            found: false,
+            ..Default::default()
+        };
+        let speck = speck_with_companion("specks-auth-deepdives.md#flows", missing);
+
+        assert_eq!(
+            w013_messages(&speck),
+            vec![
+                "Reference to non-existent anchor: specks-auth-deepdives.md#flows (specks-auth-deepdives.md not found)"
+            ]
+        );
+        let result = validate_speck(&speck);
+        let w016: Vec<_> = result.issues.iter().filter(|i| i.code == "W016").collect();
+        assert_eq!(w016.len(), 1);
+        assert!(w016[0].message.contains("specks-auth-deepdives.md"));
+    }
+
+    #[test]
+    fn test_e045_includes_must_be_file_names() {
+        let mut speck = speck_with_companion("(#step-0)", Companion::default());
+        speck.companions.clear();
+        speck.metadata.includes = vec![
+            "specks-auth-reference.md".to_string(),
+            "../../etc/passwd".to_string(),
+            "/tmp/notes.md".to_string(),
+        ];
+        let result = validate_speck(&speck);
+        let e045: Vec<_> = result.issues.iter().filter(|i| i.code == "E045").collect();
+        assert_eq!(e045.len(), 2);
+        assert!(e045[0].message.contains("../../etc/passwd"));
+        assert!(e045[1].message.contains("/tmp/notes.md"));
+        assert!(e045.iter().all(|i| i.severity == Severity::Error));
+    }
+
+    const RULES_SPECK: &str = r#"## Phase 1.0: Rules {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | 2026-02-03 |
+
+<!-- specks-ignore W003: integration-only step -->
+#### Step 0: Setup {#step-0}
+
+**Commit:** `chore: setup`
+
+**Tasks:**
+- [ ] Task
+
+##### Step 0.1: Detail {#step-0-1}
+
+**Tasks:**
+- [ ] Detail task
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0
+
+**Commit:** `feat: build`
+
+**Tasks:**
+- [ ] Task
+"#;
+
+    fn codes_for(result: &ValidationResult, code: &str) -> Vec<String> {
+        result
+            .issues
+            .iter()
+            .filter(|i| i.code == code)
+            .map(|i| i.message.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_specks_ignore_suppresses_only_its_section() {
+        let speck = parse_speck(RULES_SPECK).unwrap();
+        let result = validate_speck(&speck);
+        assert_eq!(
+            codes_for(&result, "W003"),
+            vec!["Step 1 has no checkpoint items"]
+        );
+        // Other rules on the suppressed step still fire
+        assert_eq!(codes_for(&result, "W004").len(), 2);
+    }
+
+    #[test]
+    fn test_rule_setting_off_and_severity_override() {
+        let speck = parse_speck(RULES_SPECK).unwrap();
+        let config = ValidationConfig {
+            rules: HashMap::from([
+                ("W004".to_string(), RuleSetting::Off),
+                ("W003".to_string(), RuleSetting::Severity(Severity::Error)),
+            ]),
+            ..Default::default()
+        };
+        let result = validate_speck_with_config(&speck, &config);
+        assert!(codes_for(&result, "W004").is_empty());
+        let w003: Vec<_> = result.issues.iter().filter(|i| i.code == "W003").collect();
+        assert_eq!(w003.len(), 1);
+        assert_eq!(w003[0].severity, Severity::Error);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_rule_override_respects_level() {
+        let speck = parse_speck(RULES_SPECK).unwrap();
+
+        // Promoted to error: reported even at lenient level
+        let config = ValidationConfig {
+            level: ValidationLevel::Lenient,
+            rules: HashMap::from([("W003".to_string(), RuleSetting::Severity(Severity::Error))]),
+            ..Default::default()
+        };
+        let result = validate_speck_with_config(&speck, &config);
+        assert_eq!(codes_for(&result, "W003").len(), 1);
+        assert!(codes_for(&result, "W004").is_empty());
+
+        // Demoted to info: hidden unless info is included
+        let config = ValidationConfig {
+            rules: HashMap::from([("W004".to_string(), RuleSetting::Severity(Severity::Info))]),
+            ..Default::default()
+        };
+        let result = validate_speck_with_config(&speck, &config);
+        assert!(codes_for(&result, "W004").is_empty());
+        let config = ValidationConfig {
+            level: ValidationLevel::Strict,
+            ..config
+        };
+        let result = validate_speck_with_config(&speck, &config);
+        assert!(
+            result
+                .issues
+                .iter()
+                .filter(|i| i.code == "W004")
+                .all(|i| i.severity == Severity::Info)
+        );
+        assert_eq!(codes_for(&result, "W004").len(), 2);
+    }
+
+    #[test]
+    fn test_rule_setting_parse() {
+        assert_eq!(RuleSetting::parse("off"), Some(RuleSetting::Off));
+        assert_eq!(
+            RuleSetting::parse("Warn"),
+            Some(RuleSetting::Severity(Severity::Warning))
+        );
+        assert_eq!(RuleSetting::parse("fatal"), None);
+    }
+
+    fn custom(code: &str, severity: Severity, check: CustomCheck) -> CustomRule {
+        CustomRule {
+            code: code.to_string(),
+            severity,
+            message: format!("{} failed", code),
+            check,
+        }
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let content = RULES_SPECK.replace(
+            "| Status | active |",
+            "| Status | active |\n| Reviewer | |\n| Team | Payments |\n| Sprint | 4 |",
+        );
+        let speck = parse_speck(&content).unwrap();
+        let config = ValidationConfig {
+            custom_rules: vec![
+                custom(
+                    "C001",
+                    Severity::Error,
+                    CustomCheck::RequiredAnchor("#rollback".to_string()),
+                ),
+                custom(
+                    "C002",
+                    Severity::Warning,
+                    CustomCheck::RequiredMetadata("Reviewer".to_string()),
+                ),
+                custom(
+                    "C003",
+                    Severity::Warning,
+                    CustomCheck::RequiredMetadata("team".to_string()),
+                ),
+                custom(
+                    "C004",
+                    Severity::Error,
+                    CustomCheck::StepPattern {
+                        field: StepField::Commit,
+                        pattern: Regex::new(r"^(feat|fix)(\(.+\))?: ").unwrap(),
+                    },
+                ),
+                custom("C005", Severity::Info, CustomCheck::MaxTasks(0)),
+            ],
+            ..Default::default()
+        };
+        let result = validate_speck_with_config(&speck, &config);
+
+        assert_eq!(
+            codes_for(&result, "C001"),
+            vec!["C001 failed (missing #rollback)"]
+        );
+        // An empty row does not satisfy a required field
+        assert_eq!(
+            codes_for(&result, "C002"),
+            vec!["C002 failed (missing metadata field Reviewer)"]
+        );
+        assert!(codes_for(&result, "C003").is_empty());
+        // Substep 0.1 has no Commit line, so only Step 0 is checked and fails
+        let c004: Vec<_> = result.issues.iter().filter(|i| i.code == "C004").collect();
+        assert_eq!(c004.len(), 1);
+        assert_eq!(c004[0].message, "C004 failed (Step 0: \"chore: setup\")");
+        assert_eq!(c004[0].anchor.as_deref(), Some("#step-0"));
+        assert!(!result.valid);
+        // Info rules are dropped at the normal level
+        assert!(codes_for(&result, "C005").is_empty());
+        // Required fields are not reported as unrecognized, other extra rows are
+        let p004: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == "P004")
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(p004, vec!["Unrecognized metadata field: Sprint"]);
+
+        let strict = ValidationConfig {
+            level: ValidationLevel::Strict,
+            ..config
+        };
+        let result = validate_speck_with_config(&speck, &strict);
+        assert_eq!(codes_for(&result, "C005").len(), 3);
+    }
+
+    /// A speck named `specks-{name}.md` with one step per `(anchor, depends on)`
+    fn cross_speck(name: &str, speck_deps: &str, steps: &[(&str, &str)]) -> String {
+        let mut content = format!(
+            "## Phase 1.0: {} {{#phase-1}}\n\n### Plan Metadata {{#plan-metadata}}\n\n| Field | Value |\n|------|-------|\n| Owner | Test |\n| Status | active |\n| Last updated | 2026-02-03 |\n",
+            name
+        );
+        if !speck_deps.is_empty() {
+            content.push_str(&format!("| Depends on | {} |\n", speck_deps));
+        }
+        for (i, (anchor, deps)) in steps.iter().enumerate() {
+            content.push_str(&format!("\n#### Step {}: Work {{#{}}}\n\n", i, anchor));
+            if !deps.is_empty() {
+                content.push_str(&format!("**Depends on:** {}\n\n", deps));
+            }
+            content.push_str("**Tasks:**\n- [ ] Task\n");
+        }
+        content
+    }
+
+    fn load_cross_speck(dir: &Path, name: &str) -> Speck {
+        let path = dir.join(format!("specks-{}.md", name));
+        let mut speck = parse_speck(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        speck.path = Some(path.display().to_string());
+        crate::companion::load_companions(&mut speck, dir);
+        speck
+    }
+
+    #[test]
+    fn test_e010_cross_speck_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let auth = cross_speck("auth", "", &[("step-0", ""), ("step-1", "#step-0")]);
+        std::fs::write(dir.path().join("specks-auth.md"), auth).unwrap();
+        let api = cross_speck(
+            "api",
+            "specks-auth.md#step-0",
+            &[
+                ("step-0", "specks-auth.md#step-1"),
+                ("step-1", "specks-auth.md#step-9, specks-billing.md#step-0"),
+            ],
+        );
+        std::fs::write(dir.path().join("specks-api.md"), api).unwrap();
+
+        let speck = load_cross_speck(dir.path(), "api");
+        assert_eq!(speck.metadata.depends_on, vec!["specks-auth.md#step-0"]);
+        assert!(speck.diagnostics.iter().all(|d| d.code != "P004"));
+        let result = validate_speck(&speck);
+        let e010: Vec<&str> = result
+            .issues
+            .iter()
+            .filter(|i| i.code == "E010")
+            .map(|i| i.message.as_str())
+            .collect();
+        assert_eq!(
+            e010,
+            vec![
+                "Dependency references non-existent step anchor: specks-auth.md#step-9",
+                "Dependency references non-existent step anchor: specks-billing.md#step-0 (specks-billing.md not found)",
+            ]
+        );
+        assert!(result.issues.iter().all(|i| i.code != "E011"));
+    }
+
+    #[test]
+    fn test_e011_cycle_across_specks() {
+        let dir = tempfile::tempdir().unwrap();
+        // api#step-0 -> auth#step-1 -> billing#step-0 -> api#step-0
+        let auth = cross_speck(
+            "auth",
+            "",
+            &[("step-0", ""), ("step-1", "specks-billing.md#step-0")],
+        );
+        let billing = cross_speck("billing", "", &[("step-0", "specks-api.md#step-0")]);
+        let api = cross_speck("api", "", &[("step-0", "specks-auth.md#step-1")]);
+        std::fs::write(dir.path().join("specks-auth.md"), auth).unwrap();
+        std::fs::write(dir.path().join("specks-billing.md"), billing).unwrap();
+        std::fs::write(dir.path().join("specks-api.md"), &api).unwrap();
+
+        let speck = load_cross_speck(dir.path(), "api");
+        // billing is only reachable through auth
+        assert!(speck.companion("specks-billing.md").is_some());
+        let result = validate_speck(&speck);
+        let e011: Vec<&str> = result
+            .issues
+            .iter()
+            .filter(|i| i.code == "E011")
+            .map(|i| i.message.as_str())
+            .collect();
+        assert_eq!(
+            e011,
+            vec![
+                "Circular dependency detected: step-0 -> specks-auth.md#step-1 -> specks-billing.md#step-0 -> step-0"
+            ]
+        );
+
+        // A speck-level dependency closes the same cycle for every step
+        let api = cross_speck("api", "specks-auth.md#step-1", &[("step-0", "")]);
+        std::fs::write(dir.path().join("specks-api.md"), api).unwrap();
+        let result = validate_speck(&load_cross_speck(dir.path(), "api"));
+        assert_eq!(result.issues.iter().filter(|i| i.code == "E011").count(), 1);
+
+        // Breaking the chain removes the cycle; a cycle inside another speck
+        // is not this speck's to report
+        let billing = cross_speck(
+            "billing",
+            "",
+            &[("step-0", "#step-1"), ("step-1", "#step-0")],
+        );
+        std::fs::write(dir.path().join("specks-billing.md"), billing).unwrap();
+        let result = validate_speck(&load_cross_speck(dir.path(), "api"));
+        assert!(result.issues.iter().all(|i| i.code != "E011"));
+    }
+
+    #[test]
+    fn test_e017_speck_dependency_must_name_a_speck() {
+        let content = cross_speck("api", "#step-0", &[("step-0", "")]);
+        let result = validate_speck(&parse_speck(&content).unwrap());
+        let e017: Vec<_> = result.issues.iter().filter(|i| i.code == "E017").collect();
+        assert_eq!(e017.len(), 1);
+        assert!(
+            e017[0]
+                .message
+                .starts_with("Invalid speck dependency: '#step-0'")
+        );
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! These tests validate the parser and validator against fixture files.
 
-use specks_core::{Severity, parse_speck, validate_speck};
+use specks_core::{Severity, SpeckTree, format_speck, parse_speck, validate_speck};
 use std::fs;
 
 const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/fixtures");
@@ -127,6 +127,55 @@ fn test_parser_handles_all_fixtures() {
     }
 }
 
+#[test]
+fn test_syntax_tree_round_trips_all_fixtures() {
+    let valid_dir = format!("{}/valid", FIXTURES_DIR);
+    let invalid_dir = format!("{}/invalid", FIXTURES_DIR);
+
+    for dir in [valid_dir, invalid_dir] {
+        for entry in fs::read_dir(&dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "md") {
+                let content = fs::read_to_string(&path).unwrap();
+                let tree = SpeckTree::parse(&content);
+                assert_eq!(tree.as_str(), content, "round trip changed {:?}", path);
+
+                // Every step the parser sees is addressable in the tree
+                let speck = parse_speck(&content).unwrap();
+                for step in &speck.steps {
+                    assert!(tree.step(&step.anchor).is_some(), "{:?}", path);
+                    for substep in &step.substeps {
+                        assert!(tree.step(&substep.anchor).is_some(), "{:?}", path);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_format_all_fixtures_is_idempotent() {
+    let valid_dir = format!("{}/valid", FIXTURES_DIR);
+    let invalid_dir = format!("{}/invalid", FIXTURES_DIR);
+
+    for dir in [valid_dir, invalid_dir] {
+        for entry in fs::read_dir(&dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "md") {
+                let content = fs::read_to_string(&path).unwrap();
+                let formatted = format_speck(&content)
+                    .unwrap_or_else(|e| panic!("failed to format {:?}: {}", path, e));
+                assert_eq!(
+                    format_speck(&formatted).unwrap(),
+                    formatted,
+                    "formatting {:?} is not idempotent",
+                    path
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn test_valid_complete_fixture() {
     let content = fs::read_to_string(format!("{}/valid/complete.md", FIXTURES_DIR))
@@ -188,6 +237,41 @@ fn test_valid_with_substeps_fixture() {
     );
 }
 
+#[test]
+fn test_valid_nested_substeps_fixture() {
+    let content = fs::read_to_string(format!("{}/valid/nested-substeps.md", FIXTURES_DIR))
+        .expect("Failed to read nested-substeps.md fixture");
+
+    let speck = parse_speck(&content).expect("Failed to parse nested-substeps speck");
+    let result = validate_speck(&speck);
+
+    let errors: Vec<_> = result
+        .issues
+        .iter()
+        .filter(|i| i.severity == Severity::Error)
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "Valid nested-substeps speck should have no errors, got: {:?}",
+        errors
+    );
+    assert!(speck.diagnostics.is_empty());
+
+    let step = &speck.steps[1];
+    let numbers: Vec<&str> = step
+        .all_substeps()
+        .iter()
+        .map(|s| s.number.as_str())
+        .collect();
+    assert_eq!(numbers, vec!["1.1", "1.1.1", "1.1.2", "1.2"]);
+    assert_eq!(step.substeps[0].substeps.len(), 2);
+    assert_eq!(step.substeps[0].substeps[1].depends_on, vec!["step-1-1-1"]);
+
+    assert_eq!(step.substeps[0].rollup_counts(), (2, 3));
+    assert_eq!(step.rollup_counts(), (2, 6));
+    assert_eq!(speck.completion_counts(), (4, 8));
+}
+
 #[test]
 fn test_valid_agent_output_example_fixture() {
     let content = fs::read_to_string(format!("{}/valid/agent-output-example.md", FIXTURES_DIR))
@@ -533,6 +617,7 @@ fn test_full_validation_workflow() {
         "minimal",
         "complete",
         "with-substeps",
+        "nested-substeps",
         "agent-output-example",
         "enrichment-test",
     ];
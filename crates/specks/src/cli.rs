@@ -38,7 +38,7 @@ pub enum Commands {
     /// Creates .specks/ directory with skeleton template and config.
     /// Idempotent: safe to run multiple times (creates only missing files).
     #[command(
-        long_about = "Initialize a specks project in current directory.\n\nCreates:\n  .specks/specks-skeleton.md  Template for new specks\n  .specks/config.toml         Project configuration\n  .specks/specks-implementation-log.md  Implementation progress tracking\n\nIdempotent: if .specks/ already exists, creates only missing files without overwriting.\nWith --force, removes and recreates everything.\nWith --check, performs a lightweight verification of initialization status without side effects."
+        long_about = "Initialize a specks project in current directory.\n\nCreates:\n  .specks/specks-skeleton.md  Template for new specks\n  .specks/config.toml         Project configuration\n  .specks/specks-implementation-log.md  Implementation progress tracking\n  .specks/templates/          Templates for `specks new`\n\nIdempotent: if .specks/ already exists, creates only missing files without overwriting.\nWith --force, removes and recreates everything.\nWith --check, performs a lightweight verification of initialization status without side effects."
     )]
     Init {
         /// Overwrite existing .specks directory
@@ -50,11 +50,42 @@ pub enum Commands {
         check: bool,
     },
 
+    /// Create a new speck from a template
+    ///
+    /// Checks the name against the naming config and fills in metadata.
+    #[command(
+        long_about = "Create a new speck from a template.\n\nThe name is checked against naming.name_pattern in config.toml and the speck\nis written to .specks/<prefix><name>.md. Owner, Last updated and Status are filled in.\n\nTemplates are looked up in .specks/templates/<template>.md, falling back to the\nbuilt-in templates: feature (default), bugfix, refactor, spike.\n\nTemplate variables use {{variable}} syntax:\n  {{name}}    Speck name\n  {{title}}   --title, or the name in title case\n  {{owner}}   --owner, or git user.name\n  {{date}}    Today's date (YYYY-MM-DD)\n  {{status}}  --status (default: draft)\n\nCustom variables are set with --var key=value. The generated speck must pass\nlenient validation; otherwise nothing is written."
+    )]
+    New {
+        /// Speck name (e.g. user-auth creates specks-user-auth.md)
+        name: String,
+
+        /// Template name (feature, bugfix, refactor, spike, or a file in .specks/templates/)
+        #[arg(short, long, default_value = "feature")]
+        template: String,
+
+        /// Speck title (default: name in title case)
+        #[arg(long)]
+        title: Option<String>,
+
+        /// Owner (default: git user.name)
+        #[arg(long)]
+        owner: Option<String>,
+
+        /// Initial status: draft, active or done
+        #[arg(long, default_value = "draft")]
+        status: String,
+
+        /// Extra template variable as key=value (repeatable)
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+    },
+
     /// Validate speck structure against format conventions
     ///
     /// Checks anchors, references, metadata, and step dependencies.
     #[command(
-        long_about = "Validate speck structure against format conventions.\n\nChecks:\n  - Required metadata fields (Owner, Status, Last updated)\n  - Anchor format and uniqueness\n  - Reference validity ([D01], #step-0, etc.)\n  - Step dependency cycles\n  - Cross-reference consistency"
+        long_about = "Validate speck structure against format conventions.\n\nChecks:\n  - Required metadata fields (Owner, Status, Last updated)\n  - Anchor format and uniqueness\n  - Reference validity ([D01], #step-0, etc.)\n  - Step dependency cycles\n  - Cross-reference consistency\n\nUse --fix to apply safe fixes first (step header case and level, anchor names and their references, commit line format, missing commit lines, placeholder Last updated) and print a diff. Add --dry-run to preview without writing.\n\nFor CI, --format sarif writes a SARIF 2.1.0 log, --format junit writes JUnit XML (one testcase per speck and rule), and --format github prints workflow command annotations.\n\nUse --watch to keep running: after every change under .specks/ (debounced, so bursts of writes count once) only new and resolved issues are printed.\n\nFor legacy specks, --write-baseline records the current issues (rule, anchor and a hash of the offending line) in .specks/validation-baseline.json. Later runs hide those issues, fail only on new ones, and list recorded issues that have since been fixed.\n\nUse --changed-since <REF> to validate only the specks that differ from a git ref (committed, staged, unstaged or untracked changes). Each changed speck is also compared with its version at the ref:\n  - R001: a checked item was unchecked (warning)\n  - R002: a step that had a **Bead:** line was removed (error)\n  - R003: an anchor another speck references was removed (error)\n  - R004: the Status moved backwards, e.g. done to active (warning)"
     )]
     Validate {
         /// Speck file to validate (validates all if not specified)
@@ -67,6 +98,45 @@ pub enum Commands {
         /// Validation level: lenient, normal, or strict
         #[arg(long, value_name = "LEVEL")]
         level: Option<String>,
+
+        /// Apply safe fixes before validating and print a diff
+        #[arg(long)]
+        fix: bool,
+
+        /// With --fix, show the diff without writing files
+        #[arg(long, requires = "fix")]
+        dry_run: bool,
+
+        /// Output format: text, json, sarif, junit, or github
+        #[arg(long, value_name = "FORMAT")]
+        format: Option<String>,
+
+        /// Re-validate on every change under .specks/, printing new and resolved issues
+        #[arg(long, conflicts_with = "fix")]
+        watch: bool,
+
+        /// Record current issues in .specks/validation-baseline.json
+        #[arg(long, conflicts_with_all = ["watch", "dry_run"])]
+        write_baseline: bool,
+
+        /// Validate only specks changed since a git ref and report regressions
+        #[arg(long, value_name = "REF", conflicts_with_all = ["file", "watch", "write_baseline"])]
+        changed_since: Option<String>,
+    },
+
+    /// Format specks into canonical form
+    ///
+    /// Normalizes tables, anchors, checkboxes, hints, and blank lines.
+    #[command(
+        long_about = "Format specks into canonical form.\n\nNormalizes:\n  - Plan Metadata table column alignment\n  - Heading anchor placement ({#anchor} at the end, one space before)\n  - **Depends on:** references in document step order\n  - Checkbox spacing and case (- [X] becomes - [x])\n  - **Beads:** hint key order (type, priority, labels, estimate_minutes)\n  - Blank lines around headings, rules, and step blocks\n\nFormatting never changes meaning: if the reformatted speck would parse differently, the file is left untouched and an error is reported.\n\nUse --check in CI to fail (exit 1) when any speck is not formatted, without writing files."
+    )]
+    Fmt {
+        /// Speck file to format (formats all if not specified)
+        file: Option<String>,
+
+        /// Report unformatted specks without writing changes
+        #[arg(long)]
+        check: bool,
     },
 
     /// List all specks with summary information
@@ -85,7 +155,7 @@ pub enum Commands {
     ///
     /// Displays step-by-step progress with task and checkpoint counts.
     #[command(
-        long_about = "Show detailed completion status for a speck.\n\nDisplays:\n  - Overall progress percentage\n  - Per-step completion (tasks, tests, checkpoints)\n  - Substep progress if present\n\nUse -v/--verbose to see individual task and checkpoint items.\nUse --full to include bead-enriched status (bead IDs, commit info, block status)."
+        long_about = "Show detailed completion status for a speck.\n\nDisplays:\n  - Overall progress percentage\n  - Per-step completion (tasks, tests, checkpoints)\n  - Substep progress if present\n\nUse -v/--verbose to see individual task and checkpoint items.\nUse --full to include bead-enriched status (bead IDs, commit info, block status).\nUse --watch to print the completion delta after every change under .specks/."
     )]
     Status {
         /// Speck file to show status for
@@ -98,6 +168,40 @@ pub enum Commands {
         /// Include bead-enriched status (requires beads integration)
         #[arg(long)]
         full: bool,
+
+        /// Print the completion delta after every change under .specks/
+        #[arg(long)]
+        watch: bool,
+    },
+
+    /// Show which steps can be started next
+    ///
+    /// Lists ready and blocked steps and parallel waves, without beads.
+    #[command(
+        long_about = "Show which steps of a speck can be started next.\n\nUses the **Depends on:** lines and checkbox completion, so it works without beads:\n  - Ready: unfinished steps whose dependencies are all complete\n  - Blocked: unfinished steps, with the dependencies they wait on\n    (including steps of other specks)\n  - Waves: unfinished steps grouped into rounds that can run in parallel\n\nA step is complete when every checkbox item in it and its substeps is checked."
+    )]
+    Next {
+        /// Speck file to analyze
+        file: String,
+    },
+
+    /// List labeled artifacts and the steps that cite them
+    ///
+    /// Shows each Spec, Table, List, Risk, Milestone, Concept and Diagram label.
+    #[command(
+        long_about = "List the labeled artifacts of a speck and the steps that cite them.\n\nLabels are bold lines or headings like **Spec S01: Title** {#s01-slug}\nor #### Risk R01: Title {#r01-slug}. A step cites a label in its\n**References:** line (Spec S01, Tables T01-T03).\n\nUse --kind to list one kind of label, and --label to show a single\nlabel with the steps and substeps that cite it."
+    )]
+    Labels {
+        /// Speck file to list labels for
+        file: String,
+
+        /// Only list labels of this kind (spec, table, list, risk, milestone, concept, diagram)
+        #[arg(long)]
+        kind: Option<String>,
+
+        /// Only show the label with this ID (e.g. T01) and the steps citing it
+        #[arg(long)]
+        label: Option<String>,
     },
 
     /// Beads integration commands
@@ -154,6 +258,14 @@ pub enum Commands {
         force: bool,
     },
 
+    /// Run the speck language server over stdio
+    ///
+    /// Diagnostics, navigation, completion, hover and outline for editors.
+    #[command(
+        long_about = "Run the speck language server over stdio.\n\nFeatures:\n  - Diagnostics from the parser and validator, updated as you type\n  - Go to definition for #anchors, #step-N and [D01] references\n  - Find references to an anchor or decision\n  - Anchor completion in **Depends on:** and **References:** lines\n  - Hover text with decision titles and step names\n  - Document outline built from steps and substeps\n\nValidation uses the level and rule settings from the project's .specks/config.toml.\nConfigure your editor to start `specks lsp` for specks-*.md files."
+    )]
+    Lsp,
+
     /// Show version information
     ///
     /// Display package version and optionally build metadata.
@@ -331,6 +443,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_new_command() {
+        let cli = Cli::try_parse_from([
+            "specks",
+            "new",
+            "user-auth",
+            "-t",
+            "bugfix",
+            "--var",
+            "team=core",
+            "--var",
+            "ticket=42",
+        ])
+        .unwrap();
+
+        match cli.command {
+            Some(Commands::New {
+                name,
+                template,
+                title,
+                owner,
+                status,
+                vars,
+            }) => {
+                assert_eq!(name, "user-auth");
+                assert_eq!(template, "bugfix");
+                assert!(title.is_none());
+                assert!(owner.is_none());
+                assert_eq!(status, "draft");
+                assert_eq!(vars, vec!["team=core", "ticket=42"]);
+            }
+            _ => panic!("Expected New command"),
+        }
+    }
+
+    #[test]
+    fn test_fmt_command() {
+        let cli = Cli::try_parse_from(["specks", "fmt"]).unwrap();
+
+        match cli.command {
+            Some(Commands::Fmt { file, check }) => {
+                assert!(file.is_none());
+                assert!(!check);
+            }
+            _ => panic!("Expected Fmt command"),
+        }
+    }
+
+    #[test]
+    fn test_fmt_command_with_check() {
+        let cli = Cli::try_parse_from(["specks", "fmt", "specks-1.md", "--check"]).unwrap();
+
+        match cli.command {
+            Some(Commands::Fmt { file, check }) => {
+                assert_eq!(file, Some("specks-1.md".to_string()));
+                assert!(check);
+            }
+            _ => panic!("Expected Fmt command"),
+        }
+    }
+
     #[test]
     fn test_validate_command() {
         let cli = Cli::try_parse_from(["specks", "validate"]).unwrap();
@@ -340,10 +513,22 @@ mod tests {
                 file,
                 strict,
                 level,
+                fix,
+                dry_run,
+                format,
+                watch,
+                write_baseline,
+                changed_since,
             }) => {
                 assert!(file.is_none());
                 assert!(!strict);
                 assert!(level.is_none());
+                assert!(!fix);
+                assert!(!dry_run);
+                assert!(format.is_none());
+                assert!(!watch);
+                assert!(!write_baseline);
+                assert!(changed_since.is_none());
             }
             _ => panic!("Expected Validate command"),
         }
@@ -358,6 +543,7 @@ mod tests {
                 file,
                 strict,
                 level,
+                ..
             }) => {
                 assert_eq!(file, Some("specks-1.md".to_string()));
                 assert!(!strict);
@@ -376,6 +562,7 @@ mod tests {
                 file,
                 strict,
                 level,
+                ..
             }) => {
                 assert!(file.is_none());
                 assert!(!strict);
@@ -394,6 +581,7 @@ mod tests {
                 file,
                 strict,
                 level,
+                ..
             }) => {
                 assert!(file.is_none());
                 assert!(!strict);
@@ -412,6 +600,7 @@ mod tests {
                 file,
                 strict,
                 level,
+                ..
             }) => {
                 assert!(file.is_none());
                 assert!(strict);
@@ -421,6 +610,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_command_with_fix_dry_run() {
+        let cli = Cli::try_parse_from(["specks", "validate", "--fix", "--dry-run"]).unwrap();
+
+        match cli.command {
+            Some(Commands::Validate { fix, dry_run, .. }) => {
+                assert!(fix);
+                assert!(dry_run);
+            }
+            _ => panic!("Expected Validate command"),
+        }
+
+        // --dry-run only makes sense with --fix
+        assert!(Cli::try_parse_from(["specks", "validate", "--dry-run"]).is_err());
+    }
+
     #[test]
     fn test_list_command() {
         let cli = Cli::try_parse_from(["specks", "list"]).unwrap();
@@ -442,6 +647,7 @@ mod tests {
                 file,
                 verbose,
                 full,
+                ..
             }) => {
                 assert_eq!(file, "specks-1.md");
                 assert!(!verbose);
@@ -451,6 +657,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_next_command() {
+        let cli = Cli::try_parse_from(["specks", "next", "specks-1.md"]).unwrap();
+
+        match cli.command {
+            Some(Commands::Next { file }) => {
+                assert_eq!(file, "specks-1.md");
+            }
+            _ => panic!("Expected Next command"),
+        }
+    }
+
+    #[test]
+    fn test_labels_command() {
+        let cli =
+            Cli::try_parse_from(["specks", "labels", "specks-1.md", "--kind", "risk"]).unwrap();
+
+        match cli.command {
+            Some(Commands::Labels { file, kind, label }) => {
+                assert_eq!(file, "specks-1.md");
+                assert_eq!(kind, Some("risk".to_string()));
+                assert_eq!(label, None);
+            }
+            _ => panic!("Expected Labels command"),
+        }
+    }
+
     #[test]
     fn test_status_command_with_full() {
         let cli = Cli::try_parse_from(["specks", "status", "specks-1.md", "--full"]).unwrap();
@@ -460,6 +693,7 @@ mod tests {
                 file,
                 verbose,
                 full,
+                ..
             }) => {
                 assert_eq!(file, "specks-1.md");
                 assert!(!verbose);
@@ -1,7 +1,8 @@
 //! Implementation of the `specks beads close` command
 
-use specks_core::{BeadsCli, Config, find_project_root};
+use specks_core::{Config, find_project_root};
 
+use crate::commands::beads::open_tracker;
 use crate::commands::log::{LOG_BYTE_THRESHOLD, LOG_LINE_THRESHOLD};
 use crate::output::{BeadsCloseData, JsonIssue, JsonResponse};
 
@@ -22,9 +23,10 @@ pub fn run_close(
 
     // Load config
     let config = Config::load_from_project(&project_root).unwrap_or_default();
-    let bd_path =
-        std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| config.specks.beads.bd_path.clone());
-    let beads = BeadsCli::new(bd_path);
+    let beads = match open_tracker(&config, &project_root) {
+        Ok(beads) => beads,
+        Err(e) => return output_error(json_output, e.code(), &e.to_string(), e.exit_code()),
+    };
 
     // Check if beads CLI is installed
     if !beads.is_installed(None) {
@@ -3,8 +3,9 @@
 use std::fs;
 use std::path::Path;
 
-use specks_core::{BeadsCli, Config, find_project_root, is_valid_bead_id, parse_speck};
+use specks_core::{Config, SpeckTree, find_project_root, is_valid_bead_id, parse_speck};
 
+use crate::commands::beads::open_tracker;
 use crate::output::{JsonIssue, JsonResponse};
 
 /// Link result data for JSON output
@@ -42,9 +43,6 @@ pub fn run_link(
 
     // Load config
     let config = Config::load_from_project(&project_root).unwrap_or_default();
-    let bd_path =
-        std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| config.specks.beads.bd_path.clone());
-
     // Validate bead ID format
     if !is_valid_bead_id(&bead_id) {
         return output_error(
@@ -60,7 +58,20 @@ pub fn run_link(
 
     // If beads validation is enabled, verify bead exists
     if config.specks.beads.enabled && config.specks.beads.validate_bead_ids {
-        let beads = BeadsCli::new(bd_path);
+        let beads = match open_tracker(&config, &project_root) {
+            Ok(beads) => beads,
+            Err(e) => {
+                return output_error(
+                    json_output,
+                    e.code(),
+                    &e.to_string(),
+                    &file,
+                    &step_anchor,
+                    &bead_id,
+                    e.exit_code(),
+                );
+            }
+        };
         if beads.is_installed(None)
             && beads.is_initialized(&project_root)
             && !beads.bead_exists(&bead_id, None)
@@ -136,10 +147,10 @@ pub fn run_link(
         );
     }
 
-    let (step_line, _is_substep) = step_info.unwrap();
-
     // Write bead ID to the step
-    let updated_content = write_bead_to_step(&content, &step_anchor, step_line, &bead_id);
+    let mut tree = SpeckTree::parse(&content);
+    tree.set_step_bead(&step_anchor, &bead_id);
+    let updated_content = tree.into_string();
 
     // Write back to file
     if let Err(e) = fs::write(&path, &updated_content) {
@@ -180,8 +191,8 @@ fn find_step_by_anchor(speck: &specks_core::Speck, anchor: &str) -> Option<(usiz
         if step.anchor == anchor {
             return Some((step.line, false));
         }
-        // Check substeps
-        for substep in &step.substeps {
+        // Check substeps at every depth
+        for substep in step.all_substeps() {
             if substep.anchor == anchor {
                 return Some((substep.line, true));
             }
@@ -190,89 +201,6 @@ fn find_step_by_anchor(speck: &specks_core::Speck, anchor: &str) -> Option<(usiz
     None
 }
 
-/// Write bead ID to a step in content
-fn write_bead_to_step(content: &str, anchor: &str, step_line: usize, bead_id: &str) -> String {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut new_lines: Vec<String> = Vec::new();
-    let bead_line = format!("**Bead:** `{}`", bead_id);
-    let bead_pattern = regex::Regex::new(r"^\*\*Bead:\*\*\s*`[^`]*`").unwrap();
-
-    let mut i = 0;
-    while i < lines.len() {
-        let line = lines[i];
-        new_lines.push(line.to_string());
-
-        // Check if this line contains the step anchor or is at step line
-        if line.contains(&format!("{{#{}}}", anchor)) || (i + 1 == step_line) {
-            let mut found_bead = false;
-            let mut insert_before_commit = None;
-            let mut depends_on_line = None;
-
-            // Look at next lines
-            for (j, next_line) in lines
-                .iter()
-                .enumerate()
-                .take(std::cmp::min(i + 20, lines.len()))
-                .skip(i + 1)
-            {
-                // Track **Depends on:** line
-                if next_line.starts_with("**Depends on:**") {
-                    depends_on_line = Some(j);
-                }
-
-                // Check if bead line already exists
-                if bead_pattern.is_match(next_line) {
-                    // Skip the old bead line, we'll insert new one
-                    found_bead = true;
-                    // Continue from here
-                    i = j;
-                    new_lines.push(bead_line.clone());
-                    break;
-                }
-
-                // Check for **Commit:**
-                if next_line.starts_with("**Commit:**") {
-                    insert_before_commit = Some(j);
-                    break;
-                }
-
-                // Check for next step/section header
-                if next_line.starts_with("####")
-                    || next_line.starts_with("#####")
-                    || next_line.starts_with("---")
-                {
-                    insert_before_commit = Some(j);
-                    break;
-                }
-            }
-
-            if !found_bead {
-                if let Some(pos) = insert_before_commit {
-                    // Copy lines from after step header to insert position
-                    let insert_after = depends_on_line.unwrap_or(i);
-                    for line in lines.iter().take(insert_after + 1).skip(i + 1) {
-                        new_lines.push(line.to_string());
-                    }
-                    // Insert bead line after Depends on (or after header)
-                    new_lines.push(String::new());
-                    new_lines.push(bead_line.clone());
-                    // Continue with remaining lines before Commit
-                    for line in lines.iter().take(pos).skip(insert_after + 1) {
-                        if !bead_pattern.is_match(line) {
-                            new_lines.push(line.to_string());
-                        }
-                    }
-                    i = pos - 1;
-                }
-            }
-        }
-
-        i += 1;
-    }
-
-    new_lines.join("\n")
-}
-
 /// Resolve file path relative to project
 fn resolve_file_path(project_root: &Path, file: &str) -> std::path::PathBuf {
     let path = Path::new(file);
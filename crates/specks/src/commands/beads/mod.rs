@@ -14,7 +14,10 @@ pub mod status;
 pub mod sync;
 pub mod update;
 
+use std::path::Path;
+
 use clap::Subcommand;
+use specks_core::{BeadsCli, Config, FileTracker, GitHubTracker, IssueTracker, SpecksError};
 
 pub use close::run_close;
 pub use inspect::run_inspect;
@@ -24,6 +27,44 @@ pub use status::run_beads_status;
 pub use sync::run_sync;
 pub use update::{run_append_design, run_append_notes, run_update_notes};
 
+/// Issue tracker for a project's `[specks.beads]` config
+///
+/// `tracker = "bd"` (or `"beads"`) uses the beads CLI, with `SPECKS_BD_PATH`
+/// overriding the configured `bd_path`. `tracker = "file"` selects the
+/// built-in tracker under `<project_root>/.specks/tracker/`, and
+/// `tracker = "github"` uses GitHub Issues with a token from `GITHUB_TOKEN`
+/// or `GH_TOKEN`. Any other value is a configuration error.
+pub fn open_tracker(
+    config: &Config,
+    project_root: &Path,
+) -> Result<Box<dyn IssueTracker>, SpecksError> {
+    let beads = &config.specks.beads;
+    match beads.tracker.as_str() {
+        "bd" | "beads" => {
+            let bd_path = std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| beads.bd_path.clone());
+            Ok(Box::new(BeadsCli::new(bd_path)))
+        }
+        "file" => Ok(Box::new(FileTracker::new(
+            project_root,
+            beads.tracker_prefix.clone(),
+        ))),
+        "github" => {
+            let token = std::env::var("GITHUB_TOKEN")
+                .or_else(|_| std::env::var("GH_TOKEN"))
+                .ok();
+            Ok(Box::new(
+                GitHubTracker::new(&beads.github.api_url, &beads.github.repo)
+                    .with_token(token)
+                    .with_milestone_root(beads.github.root == "milestone"),
+            ))
+        }
+        other => Err(SpecksError::Config(format!(
+            "unknown beads tracker '{}' (must be bd, file or github)",
+            other
+        ))),
+    }
+}
+
 /// Beads subcommands
 #[derive(Subcommand, Debug)]
 pub enum BeadsCommands {
@@ -32,7 +73,7 @@ pub enum BeadsCommands {
     /// Creates a root bead for the speck and child beads for each step.
     /// Bead IDs are written back to the speck file.
     #[command(
-        long_about = "Sync speck steps to beads.\n\nCreates:\n  - Root bead (epic) for the speck\n  - Child beads for each execution step\n  - Dependency edges matching **Depends on:** lines\n\nWrites bead IDs back to the speck file:\n  - **Beads Root:** `bd-xxx` in Plan Metadata\n  - **Bead:** `bd-xxx.N` in each step\n\nRe-running sync is idempotent—existing beads are reused."
+        long_about = "Sync speck steps to beads.\n\nCreates:\n  - Root bead (epic) for the speck\n  - Child beads for each execution step\n  - Dependency edges matching **Depends on:** lines\n\nWrites bead IDs back to the speck file:\n  - **Beads Root:** `bd-xxx` in Plan Metadata\n  - **Bead:** `bd-xxx.N` in each step\n\nRe-running sync is idempotent—existing beads are reused.\n\nOrphans (open child beads of the root that no step links to):\n  - report: list them (default)\n  - close: close them\n  - relink: link them to unlinked steps with a similar title"
     )]
     Sync {
         /// Speck file to sync
@@ -53,6 +94,12 @@ pub enum BeadsCommands {
         /// Substep handling mode: none (default) or children
         #[arg(long, default_value = "none")]
         substeps: String,
+
+        /// Open child beads of the root (and of step beads with --substeps
+        /// children) with no matching step: report, close or relink (to
+        /// unlinked steps by title similarity); defaults to config
+        #[arg(long, value_parser = ["report", "close", "relink"])]
+        orphans: Option<String>,
     },
 
     /// Link an existing bead to a step
@@ -91,15 +138,19 @@ pub enum BeadsCommands {
     ///
     /// Marks checkboxes as complete when their associated bead is closed.
     #[command(
-        long_about = "Pull bead completion status to speck checkboxes.\n\nFor each step with a linked bead:\n  - If bead is closed, marks checkpoint items as complete\n  - By default only updates **Checkpoint:** items\n  - Configure pull_checkbox_mode in config.toml for all items\n\nUse --no-overwrite to preserve manually checked items."
+        long_about = "Pull bead completion status to speck checkboxes.\n\nFor each step with a linked bead:\n  - If bead is closed, marks checkpoint items as complete\n  - By default only updates **Checkpoint:** items\n  - Configure pull_checkbox_mode in config.toml for all items\n\nChecked items are never unchecked.\n\nWith --fields, step titles, priorities and labels edited in beads are also\nwritten back to the speck. Each field is compared with its value at the last\nsync or pull (.specks/beads-snapshot.json) to tell which side changed; a\nfield changed on both sides keeps the speck's value and is reported when\npull_warn_on_conflict is set. With --no-overwrite, only fields the speck\nleaves unset are filled in."
     )]
     Pull {
         /// Speck file (pulls all specks if not specified)
         file: Option<String>,
 
-        /// Don't overwrite manually checked items
+        /// With --fields, only fill in fields the speck leaves unset
         #[arg(long)]
         no_overwrite: bool,
+
+        /// Also pull title, priority and label edits from beads
+        #[arg(long)]
+        fields: bool,
     },
 
     /// Close a bead to mark work complete
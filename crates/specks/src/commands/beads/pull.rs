@@ -4,9 +4,12 @@ use std::fs;
 use std::path::Path;
 
 use specks_core::{
-    BeadsCli, Config, find_project_root, find_specks, parse_speck, speck_name_from_path,
+    BeadFields, BeadSnapshot, BeadsHints, CheckMark, Checkpoint, CheckpointKind, Config,
+    FieldChange, IssueTracker, SNAPSHOT_FILE, SnapshotEntry, SpeckTree, find_project_root,
+    find_specks, parse_speck, speck_name_from_path,
 };
 
+use crate::commands::beads::open_tracker;
 use crate::output::{JsonIssue, JsonResponse};
 
 /// Pull result data for JSON output
@@ -14,6 +17,13 @@ use crate::output::{JsonIssue, JsonResponse};
 pub struct PullData {
     pub files: Vec<FilePullResult>,
     pub total_updated: usize,
+    /// Step fields written back from beads (`--fields`)
+    #[serde(skip_serializing_if = "is_zero")]
+    pub total_fields_updated: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
 }
 
 /// Pull result for a single file
@@ -23,12 +33,39 @@ pub struct FilePullResult {
     pub name: String,
     pub checkboxes_updated: usize,
     pub steps_updated: Vec<String>,
+    /// Step fields written back from beads (`--fields`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields_updated: Vec<FieldUpdate>,
+    /// Closed steps that still have blocked or failed items, and fields
+    /// changed on both sides
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// A step field changed in beads and written back to the speck
+#[derive(Debug, serde::Serialize)]
+pub struct FieldUpdate {
+    pub anchor: String,
+    /// "title", "priority" or "labels"
+    pub field: String,
+    pub from: String,
+    pub to: String,
 }
 
 /// Run the beads pull command
+///
+/// Pull only ever checks boxes, so manually checked items are never
+/// overwritten. Skipped, blocked and failed items (`[-]`, `[~]`, `[!]`) are
+/// left as they are.
+///
+/// With `fields`, step titles, priorities and labels edited in beads are
+/// also written back, using the snapshot from the last sync or pull to tell
+/// which side changed. Fields changed on both sides keep the speck's value.
+/// With `no_overwrite`, only fields the speck leaves unset are filled in.
 pub fn run_pull(
     file: Option<String>,
     no_overwrite: bool,
+    fields: bool,
     json_output: bool,
     quiet: bool,
 ) -> Result<i32, String> {
@@ -42,9 +79,10 @@ pub fn run_pull(
 
     // Load config
     let config = Config::load_from_project(&project_root).unwrap_or_default();
-    let bd_path =
-        std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| config.specks.beads.bd_path.clone());
-    let beads = BeadsCli::new(bd_path);
+    let beads = match open_tracker(&config, &project_root) {
+        Ok(beads) => beads,
+        Err(e) => return output_error(json_output, e.code(), &e.to_string(), e.exit_code()),
+    };
 
     // Check if beads CLI is installed
     if !beads.is_installed(None) {
@@ -78,8 +116,20 @@ pub fn run_pull(
         None => find_specks(&project_root).unwrap_or_default(),
     };
 
+    let snapshot_path = project_root.join(".specks").join(SNAPSHOT_FILE);
+    let mut snapshot = if fields {
+        match BeadSnapshot::load(&snapshot_path) {
+            Ok(s) => s,
+            Err(e) => return output_error(json_output, "E002", &e.to_string(), 2),
+        }
+    } else {
+        BeadSnapshot::default()
+    };
+    let loaded_snapshot = snapshot.clone();
+
     let mut all_results: Vec<FilePullResult> = Vec::new();
     let mut total_updated = 0;
+    let mut total_fields_updated = 0;
 
     for path in files {
         let content = match fs::read_to_string(&path) {
@@ -92,10 +142,28 @@ pub fn run_pull(
             Err(_) => continue,
         };
 
-        let (updated_content, checkboxes_updated, steps_updated) =
-            pull_bead_status_to_checkboxes(&speck, &content, &beads, &config, no_overwrite);
+        let (mut updated_content, checkboxes_updated, steps_updated, mut warnings) =
+            pull_bead_status_to_checkboxes(&speck, &content, &*beads, &config);
+
+        // Advance a copy of the snapshot, kept only if the speck is written
+        let mut file_snapshot = snapshot.clone();
+        let mut fields_updated = Vec::new();
+        if fields {
+            let (new_content, updates, conflicts) = pull_bead_fields(
+                &speck,
+                &updated_content,
+                &*beads,
+                &mut file_snapshot,
+                no_overwrite,
+            );
+            updated_content = new_content;
+            fields_updated = updates;
+            if config.specks.beads.pull_warn_on_conflict {
+                warnings.extend(conflicts);
+            }
+        }
 
-        if checkboxes_updated > 0 {
+        if checkboxes_updated > 0 || !fields_updated.is_empty() {
             // Write updated content back to file
             if let Err(e) = fs::write(&path, &updated_content) {
                 if !quiet {
@@ -104,23 +172,34 @@ pub fn run_pull(
                 continue;
             }
         }
+        snapshot = file_snapshot;
 
         let name = speck_name_from_path(&path).unwrap_or_else(|| "unknown".to_string());
         total_updated += checkboxes_updated;
+        total_fields_updated += fields_updated.len();
 
         all_results.push(FilePullResult {
             file: path.to_string_lossy().to_string(),
             name,
             checkboxes_updated,
             steps_updated,
+            fields_updated,
+            warnings,
         });
     }
 
+    if snapshot != loaded_snapshot {
+        if let Err(e) = snapshot.save(&snapshot_path) {
+            return output_error(json_output, "E002", &e.to_string(), 1);
+        }
+    }
+
     // Output results
     if json_output {
         let data = PullData {
             files: all_results,
             total_updated,
+            total_fields_updated,
         };
         let response = JsonResponse::ok("beads pull", data);
         println!("{}", serde_json::to_string_pretty(&response).unwrap());
@@ -135,8 +214,24 @@ pub fn run_pull(
                     println!("  {} - marked complete", step);
                 }
             }
+            if !result.fields_updated.is_empty() {
+                println!(
+                    "{}: {} fields updated",
+                    result.name,
+                    result.fields_updated.len()
+                );
+                for update in &result.fields_updated {
+                    println!(
+                        "  #{} {}: {} -> {}",
+                        update.anchor, update.field, update.from, update.to
+                    );
+                }
+            }
+            for warning in &result.warnings {
+                println!("{}: warning: {}", result.name, warning);
+            }
         }
-        if total_updated == 0 {
+        if total_updated == 0 && total_fields_updated == 0 {
             println!("No checkboxes updated (all in sync)");
         }
     }
@@ -148,13 +243,13 @@ pub fn run_pull(
 fn pull_bead_status_to_checkboxes(
     speck: &specks_core::Speck,
     content: &str,
-    beads: &BeadsCli,
+    beads: &dyn IssueTracker,
     config: &Config,
-    no_overwrite: bool,
-) -> (String, usize, Vec<String>) {
+) -> (String, usize, Vec<String>, Vec<String>) {
     let mut updated_content = content.to_string();
     let mut checkboxes_updated = 0;
     let mut steps_updated: Vec<String> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
 
     let checkbox_mode = &config.specks.beads.pull_checkbox_mode;
 
@@ -163,132 +258,231 @@ fn pull_bead_status_to_checkboxes(
             // Check if bead is complete
             if is_bead_complete(bead_id, beads) {
                 // Update checkboxes for this step
-                let (new_content, count) = mark_step_checkboxes_complete(
-                    &updated_content,
-                    step.line,
-                    &step.anchor,
-                    checkbox_mode,
-                    no_overwrite,
-                );
+                let (new_content, count) =
+                    mark_step_checkboxes_complete(&updated_content, &step.anchor, checkbox_mode);
                 if count > 0 {
                     updated_content = new_content;
                     checkboxes_updated += count;
                     steps_updated.push(format!("Step {}: {}", step.number, step.title));
                 }
+                warnings.extend(unresolved_items_warning(
+                    &step.number,
+                    &step.title,
+                    step.items(),
+                ));
             }
         }
 
-        // Process substeps
-        for substep in &step.substeps {
+        // Process substeps at every depth
+        for substep in step.all_substeps() {
             if let Some(ref bead_id) = substep.bead_id {
                 if is_bead_complete(bead_id, beads) {
                     let (new_content, count) = mark_step_checkboxes_complete(
                         &updated_content,
-                        substep.line,
                         &substep.anchor,
                         checkbox_mode,
-                        no_overwrite,
                     );
                     if count > 0 {
                         updated_content = new_content;
                         checkboxes_updated += count;
                         steps_updated.push(format!("Step {}: {}", substep.number, substep.title));
                     }
+                    warnings.extend(unresolved_items_warning(
+                        &substep.number,
+                        &substep.title,
+                        substep.items(),
+                    ));
                 }
             }
         }
     }
 
-    (updated_content, checkboxes_updated, steps_updated)
+    (updated_content, checkboxes_updated, steps_updated, warnings)
 }
 
-/// Check if a bead is complete (closed)
-fn is_bead_complete(bead_id: &str, beads: &BeadsCli) -> bool {
-    match beads.show(bead_id, None) {
-        Ok(details) => details.status.to_lowercase() == "closed",
-        Err(_) => false,
-    }
-}
-
-/// Mark checkboxes as complete for a step
-fn mark_step_checkboxes_complete(
+/// Pull bead titles, priorities and labels into their steps
+///
+/// Each step is compared three ways: its fields in the speck, on its bead,
+/// and in the snapshot from the last sync or pull. A step seen for the first
+/// time only has its current fields recorded. A field's snapshot only moves
+/// forward once the speck holds the bead's value, so a pull that could not
+/// be written (or was held back by `no_overwrite`, which leaves fields the
+/// speck already sets alone) is tried again next time. Returns the updated
+/// content, the fields written and a warning for each field changed on both
+/// sides.
+fn pull_bead_fields(
+    speck: &specks_core::Speck,
     content: &str,
-    step_line: usize,
-    step_anchor: &str,
-    checkbox_mode: &str,
+    beads: &dyn IssueTracker,
+    snapshot: &mut BeadSnapshot,
     no_overwrite: bool,
-) -> (String, usize) {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut new_lines: Vec<String> = Vec::new();
-    let mut count = 0;
+) -> (String, Vec<FieldUpdate>, Vec<String>) {
+    let mut tree = SpeckTree::parse(content);
+    let mut updates = Vec::new();
+    let mut conflicts = Vec::new();
 
-    let checkbox_pattern = regex::Regex::new(r"^(\s*-\s+)\[ \](.*)$").unwrap();
-    let checked_pattern = regex::Regex::new(r"^(\s*-\s+)\[[xX]\](.*)$").unwrap();
-
-    let mut in_target_step = false;
-    let mut in_checkpoint_section = false;
-    let mut past_step = false;
-
-    for (i, line) in lines.iter().enumerate() {
-        let line_num = i + 1;
-
-        // Check if we've entered the target step
-        if line.contains(&format!("{{#{}}}", step_anchor)) || line_num == step_line {
-            in_target_step = true;
-            past_step = false;
+    // (number, title, anchor, bead, hints) of every step and substep
+    let mut steps: Vec<(&str, &str, &str, &str, Option<&BeadsHints>)> = Vec::new();
+    for step in &speck.steps {
+        if let Some(ref bead_id) = step.bead_id {
+            steps.push((
+                &step.number,
+                &step.title,
+                &step.anchor,
+                bead_id,
+                step.beads_hints.as_ref(),
+            ));
         }
-
-        // Check if we've exited the step (next step header or section divider)
-        if in_target_step
-            && past_step
-            && (line.starts_with("####") || line.starts_with("#####") || line.starts_with("---"))
-        {
-            in_target_step = false;
+        for substep in step.all_substeps() {
+            if let Some(ref bead_id) = substep.bead_id {
+                steps.push((
+                    &substep.number,
+                    &substep.title,
+                    &substep.anchor,
+                    bead_id,
+                    substep.beads_hints.as_ref(),
+                ));
+            }
         }
+    }
 
-        if in_target_step
-            && !line.contains(&format!("{{#{}}}", step_anchor))
-            && line_num != step_line
-        {
-            past_step = true;
-        }
+    for (number, title, anchor, bead_id, hints) in steps {
+        let Ok(details) = beads.show(bead_id, None) else {
+            continue;
+        };
+        let speck_fields = BeadFields::from_step(title, hints);
+        let bead_fields = BeadFields::from_issue(&details);
+
+        let Some(entry) = snapshot.beads.get_mut(bead_id) else {
+            snapshot.beads.insert(
+                bead_id.to_string(),
+                SnapshotEntry::new(speck_fields, bead_fields),
+            );
+            continue;
+        };
+        let merge = entry.merge(&speck_fields, &bead_fields);
+        let overwrite = |has_value: bool| !(no_overwrite && has_value);
+        let step_name = format!("Step {}: {}", number, title);
+        let mut update = |field: &str, from: String, to: String| {
+            updates.push(FieldUpdate {
+                anchor: anchor.to_string(),
+                field: field.to_string(),
+                from,
+                to,
+            })
+        };
+        let mut conflict = |field: &str, speck: String, bead: String| {
+            conflicts.push(format!(
+                "{} - {} changed in both speck ({}) and bead ({}), keeping speck",
+                step_name, field, speck, bead
+            ))
+        };
 
-        // Track section context
-        if in_target_step {
-            if line.starts_with("**Checkpoint:**") || line.starts_with("**Checkpoints:**") {
-                in_checkpoint_section = true;
-            } else if line.starts_with("**Tasks:**") || line.starts_with("**Tests:**") {
-                in_checkpoint_section = checkbox_mode == "all";
-            } else if line.starts_with("**") && !line.starts_with("**Bead:**") {
-                // Other bold sections reset checkpoint context
-                if !line.contains("Checkpoint")
-                    && !line.contains("Tasks")
-                    && !line.contains("Tests")
-                {
-                    in_checkpoint_section = false;
+        match merge.title {
+            FieldChange::Pull(to) if overwrite(!speck_fields.title.is_empty()) => {
+                if tree.set_step_title(anchor, &to) {
+                    entry.settle_title(&to);
+                    update("title", title.to_string(), to);
                 }
             }
+            FieldChange::Converged => entry.settle_title(&bead_fields.title),
+            FieldChange::Conflict { speck, bead } => conflict("title", speck, bead),
+            FieldChange::Pull(_) | FieldChange::Keep => {}
         }
-
-        // Update checkboxes
-        if in_target_step && (in_checkpoint_section || checkbox_mode == "all") {
-            if checkbox_pattern.is_match(line) {
-                // Unchecked checkbox - mark as complete
-                let new_line = checkbox_pattern.replace(line, "$1[x]$2").to_string();
-                new_lines.push(new_line);
-                count += 1;
-                continue;
-            } else if no_overwrite && checked_pattern.is_match(line) {
-                // Already checked and no_overwrite - keep as is
-                new_lines.push(line.to_string());
-                continue;
+        match merge.priority {
+            FieldChange::Pull(to) if overwrite(speck_fields.priority.is_some()) => {
+                let value = to.map(|p| p.to_string());
+                if tree.set_step_hint(anchor, "priority", value.as_deref()) {
+                    entry.settle_priority(to);
+                    update(
+                        "priority",
+                        show_priority(speck_fields.priority),
+                        show_priority(to),
+                    );
+                }
+            }
+            FieldChange::Converged => entry.settle_priority(bead_fields.priority),
+            FieldChange::Conflict { speck, bead } => {
+                conflict("priority", show_priority(speck), show_priority(bead))
+            }
+            FieldChange::Pull(_) | FieldChange::Keep => {}
+        }
+        match merge.labels {
+            FieldChange::Pull(to) if overwrite(!speck_fields.labels.is_empty()) => {
+                let value = (!to.is_empty()).then(|| to.join(","));
+                if tree.set_step_hint(anchor, "labels", value.as_deref()) {
+                    entry.settle_labels(&to);
+                    update(
+                        "labels",
+                        show_labels(&speck_fields.labels),
+                        show_labels(&to),
+                    );
+                }
             }
+            FieldChange::Converged => entry.settle_labels(&bead_fields.labels),
+            FieldChange::Conflict { speck, bead } => {
+                conflict("labels", show_labels(&speck), show_labels(&bead))
+            }
+            FieldChange::Pull(_) | FieldChange::Keep => {}
         }
+    }
 
-        new_lines.push(line.to_string());
+    (tree.into_string(), updates, conflicts)
+}
+
+/// Display form of a step priority
+fn show_priority(priority: Option<i32>) -> String {
+    priority.map_or_else(|| "(none)".to_string(), |p| p.to_string())
+}
+
+/// Display form of step labels
+fn show_labels(labels: &[String]) -> String {
+    if labels.is_empty() {
+        "(none)".to_string()
+    } else {
+        labels.join(",")
     }
+}
 
-    (new_lines.join("\n"), count)
+/// Warning for a closed step that still has blocked or failed items
+fn unresolved_items_warning<'a>(
+    number: &str,
+    title: &str,
+    items: impl Iterator<Item = &'a Checkpoint>,
+) -> Option<String> {
+    let unresolved = items
+        .filter(|item| matches!(item.mark, Some(CheckMark::Blocked | CheckMark::Failed)))
+        .count();
+    (unresolved > 0).then(|| {
+        format!(
+            "Step {}: {} - bead is closed but {} item(s) are still blocked or failed",
+            number, title, unresolved
+        )
+    })
+}
+
+/// Check if a bead is complete (closed)
+fn is_bead_complete(bead_id: &str, beads: &dyn IssueTracker) -> bool {
+    match beads.show(bead_id, None) {
+        Ok(details) => details.status.to_lowercase() == "closed",
+        Err(_) => false,
+    }
+}
+
+/// Mark checkboxes as complete for a step
+///
+/// In "checkpoints" mode only `**Checkpoint:**` items are updated; in "all"
+/// mode every checkbox in the step is. Already-checked boxes are never touched.
+fn mark_step_checkboxes_complete(
+    content: &str,
+    step_anchor: &str,
+    checkbox_mode: &str,
+) -> (String, usize) {
+    let mut tree = SpeckTree::parse(content);
+    let count = tree.check_step_boxes(step_anchor, |section| {
+        checkbox_mode == "all" || section == Some(CheckpointKind::Checkpoint)
+    });
+    (tree.into_string(), count)
 }
 
 /// Resolve file path relative to project
@@ -337,6 +531,7 @@ fn output_error(
             PullData {
                 files: vec![],
                 total_updated: 0,
+                total_fields_updated: 0,
             },
             issues,
         );
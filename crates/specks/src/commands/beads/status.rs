@@ -5,10 +5,11 @@ use std::fs;
 use std::path::Path;
 
 use specks_core::{
-    BeadStatus, BeadsCli, Config, Speck, find_project_root, find_specks, parse_speck,
-    speck_name_from_path,
+    BeadStatus, Config, IssueTracker, Speck, external_dependency, find_project_root, find_specks,
+    parse_speck, resolve_external_step, speck_name_from_path,
 };
 
+use crate::commands::beads::open_tracker;
 use crate::output::{JsonIssue, JsonResponse};
 
 /// Status result data for JSON output
@@ -55,9 +56,10 @@ pub fn run_beads_status(
 
     // Load config
     let config = Config::load_from_project(&project_root).unwrap_or_default();
-    let bd_path =
-        std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| config.specks.beads.bd_path.clone());
-    let beads = BeadsCli::new(bd_path);
+    let beads = match open_tracker(&config, &project_root) {
+        Ok(beads) => beads,
+        Err(e) => return output_error(json_output, e.code(), &e.to_string(), e.exit_code()),
+    };
 
     // Check if beads CLI is installed
     if !beads.is_installed(None) {
@@ -94,7 +96,7 @@ pub fn run_beads_status(
             Err(_) => continue,
         };
 
-        let file_status = get_file_beads_status(&path, &speck, &beads);
+        let file_status = get_file_beads_status(&path, &speck, &*beads);
         all_status.push(file_status);
     }
 
@@ -102,7 +104,7 @@ pub fn run_beads_status(
     if do_pull {
         // Delegate to pull command
         let pull_file = file.clone();
-        crate::commands::beads::pull::run_pull(pull_file, false, json_output, true)?;
+        crate::commands::beads::pull::run_pull(pull_file, false, false, json_output, true)?;
     }
 
     // Output results
@@ -150,7 +152,7 @@ pub fn run_beads_status(
 }
 
 /// Get beads status for a file
-fn get_file_beads_status(path: &Path, speck: &Speck, beads: &BeadsCli) -> FileBeadsStatus {
+fn get_file_beads_status(path: &Path, speck: &Speck, beads: &dyn IssueTracker) -> FileBeadsStatus {
     let name = speck_name_from_path(path).unwrap_or_else(|| "unknown".to_string());
     let file = path.to_string_lossy().to_string();
 
@@ -164,7 +166,7 @@ fn get_file_beads_status(path: &Path, speck: &Speck, beads: &BeadsCli) -> FileBe
             bead_statuses.insert(step.anchor.clone(), (bead_id.clone(), is_complete));
         }
 
-        for substep in &step.substeps {
+        for substep in step.all_substeps() {
             if let Some(ref bead_id) = substep.bead_id {
                 let is_complete = check_bead_complete(bead_id, beads);
                 bead_statuses.insert(substep.anchor.clone(), (bead_id.clone(), is_complete));
@@ -172,6 +174,21 @@ fn get_file_beads_status(path: &Path, speck: &Speck, beads: &BeadsCli) -> FileBe
         }
     }
 
+    // Cross-speck dependencies use the other speck's step beads
+    if let Some(dir) = path.parent() {
+        for (_, deps) in speck.step_dependencies() {
+            for dep in deps {
+                if external_dependency(&dep).is_none() || bead_statuses.contains_key(&dep) {
+                    continue;
+                }
+                if let Some(bead_id) = resolve_external_step(dir, &dep).and_then(|s| s.bead_id) {
+                    let is_complete = check_bead_complete(&bead_id, beads);
+                    bead_statuses.insert(dep, (bead_id, is_complete));
+                }
+            }
+        }
+    }
+
     // Second pass: compute status for each step
     let mut steps_status: Vec<StepBeadsStatus> = Vec::new();
     let mut steps_complete = 0;
@@ -199,8 +216,8 @@ fn get_file_beads_status(path: &Path, speck: &Speck, beads: &BeadsCli) -> FileBe
             blocked_by,
         });
 
-        // Add substeps
-        for substep in &step.substeps {
+        // Add substeps, indented by depth
+        for substep in step.all_substeps() {
             let sub_status = compute_step_status(
                 &substep.anchor,
                 &substep.depends_on,
@@ -216,7 +233,12 @@ fn get_file_beads_status(path: &Path, speck: &Speck, beads: &BeadsCli) -> FileBe
 
             steps_status.push(StepBeadsStatus {
                 anchor: substep.anchor.clone(),
-                title: format!("  Step {}: {}", substep.number, substep.title),
+                title: format!(
+                    "{}Step {}: {}",
+                    "  ".repeat(substep.depth()),
+                    substep.number,
+                    substep.title
+                ),
                 bead_id: substep.bead_id.clone(),
                 status: sub_status.to_string(),
                 blocked_by: sub_blocked_by,
@@ -235,7 +257,7 @@ fn get_file_beads_status(path: &Path, speck: &Speck, beads: &BeadsCli) -> FileBe
 }
 
 /// Check if a bead is complete
-fn check_bead_complete(bead_id: &str, beads: &BeadsCli) -> bool {
+fn check_bead_complete(bead_id: &str, beads: &dyn IssueTracker) -> bool {
     match beads.show(bead_id, None) {
         Ok(details) => details.status.to_lowercase() == "closed",
         Err(_) => false,
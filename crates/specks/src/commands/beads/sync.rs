@@ -1,11 +1,18 @@
 //! Implementation of the `specks beads sync` command (Spec S06)
 
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
-use specks_core::{BeadsCli, Config, Speck, SpecksError, find_project_root, parse_speck};
+use specks_core::snapshot::step_title_from_bead;
+use specks_core::{
+    BeadFields, BeadSnapshot, Config, IssueTracker, SNAPSHOT_FILE, SnapshotEntry, Speck, SpeckTree,
+    SpecksError, external_dependency, find_project_root, load_companions, parse_speck,
+    resolve_external_step,
+};
 
+use crate::commands::beads::open_tracker;
 use crate::output::{JsonIssue, JsonResponse};
 
 /// Sync result data for JSON output
@@ -20,8 +27,25 @@ pub struct SyncData {
     pub enriched: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enrich_errors: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orphans: Vec<OrphanBead>,
 }
 
+/// Open child bead of the root, or of a step bead, that no step links to
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OrphanBead {
+    pub bead_id: String,
+    pub title: String,
+    /// What sync did, or would do on a dry run: "report", "close" or "relink"
+    pub action: String,
+    /// Step the bead is relinked to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step_anchor: Option<String>,
+}
+
+/// Minimum title similarity for relinking an orphan bead to a step
+const RELINK_THRESHOLD: f64 = 0.6;
+
 /// Options for the sync command
 pub struct SyncOptions {
     pub file: String,
@@ -29,6 +53,7 @@ pub struct SyncOptions {
     pub enrich: bool,
     pub prune_deps: bool,
     pub substeps_mode: String,
+    pub orphans: Option<String>,
     pub json_output: bool,
     pub quiet: bool,
 }
@@ -41,6 +66,7 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
         enrich,
         prune_deps,
         substeps_mode,
+        orphans,
         json_output,
         quiet,
     } = opts;
@@ -60,9 +86,26 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
 
     // Load config
     let config = Config::load_from_project(&project_root).unwrap_or_default();
-    let bd_path =
-        std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| config.specks.beads.bd_path.clone());
-    let beads = BeadsCli::new(bd_path);
+    let beads = match open_tracker(&config, &project_root) {
+        Ok(beads) => beads,
+        Err(e) => {
+            return output_error(json_output, e.code(), &e.to_string(), &file, e.exit_code());
+        }
+    };
+
+    let orphans_mode = orphans.unwrap_or_else(|| config.specks.beads.orphans.clone());
+    if !["report", "close", "relink"].contains(&orphans_mode.as_str()) {
+        return output_error(
+            json_output,
+            "E002",
+            &format!(
+                "invalid orphans mode: {} (expected report, close or relink)",
+                orphans_mode
+            ),
+            &file,
+            2,
+        );
+    }
 
     // Check if beads CLI is installed
     if !beads.is_installed(None) {
@@ -113,7 +156,13 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
     };
 
     let speck = match parse_speck(&content) {
-        Ok(s) => s,
+        Ok(mut s) => {
+            s.path = path.to_str().map(|p| p.to_string());
+            if let Some(dir) = path.parent() {
+                load_companions(&mut s, dir);
+            }
+            s
+        }
         Err(e) => {
             return output_error(
                 json_output,
@@ -125,20 +174,29 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
         }
     };
 
+    // Last-synced fields of each bead, for `beads pull --fields`
+    let snapshot_path = project_root.join(".specks").join(SNAPSHOT_FILE);
+    let snapshot = match BeadSnapshot::load(&snapshot_path) {
+        Ok(s) => s,
+        Err(e) => return output_error(json_output, "E002", &e.to_string(), &file, 2),
+    };
+
     // Perform sync
     let ctx = SyncContext {
-        beads: &beads,
+        beads: &*beads,
         config: &config,
         dry_run,
         enrich,
         prune_deps,
         substeps_mode: &substeps_mode,
+        orphans_mode: &orphans_mode,
+        snapshot: RefCell::new(snapshot),
         quiet,
     };
     let result = sync_speck_to_beads(&path, &speck, &content, &ctx);
 
     match result {
-        Ok((root_id, steps_synced, deps_added, updated_content, enrich_errors)) => {
+        Ok((root_id, steps_synced, deps_added, updated_content, enrich_errors, orphans)) => {
             // Write updated content back to file (unless dry run)
             if !dry_run {
                 if let Some(new_content) = updated_content {
@@ -152,6 +210,9 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
                         );
                     }
                 }
+                if let Err(e) = ctx.snapshot.borrow().save(&snapshot_path) {
+                    return output_error(json_output, "E002", &e.to_string(), &file, 1);
+                }
             }
 
             if json_output {
@@ -167,6 +228,7 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
                     } else {
                         Some(enrich_errors.clone())
                     },
+                    orphans,
                 };
                 let response = JsonResponse::ok("beads sync", data);
                 println!("{}", serde_json::to_string_pretty(&response).unwrap());
@@ -191,6 +253,9 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
                         }
                     }
                 }
+                if !orphans.is_empty() {
+                    print_orphans(&orphans, &orphans_mode);
+                }
             }
 
             Ok(0)
@@ -209,23 +274,36 @@ pub fn run_sync(opts: SyncOptions) -> Result<i32, String> {
 
 /// Internal context for sync operations
 struct SyncContext<'a> {
-    beads: &'a BeadsCli,
+    beads: &'a dyn IssueTracker,
     config: &'a Config,
     dry_run: bool,
     enrich: bool,
     prune_deps: bool,
     substeps_mode: &'a str,
+    orphans_mode: &'a str,
+    /// Recorded for each bead created
+    snapshot: RefCell<BeadSnapshot>,
     quiet: bool,
 }
 
 /// Sync a speck to beads
-#[allow(clippy::type_complexity)] // Return tuple includes enrichment errors
+#[allow(clippy::type_complexity)] // Return tuple includes enrichment errors and orphans
 fn sync_speck_to_beads(
-    _path: &Path,
+    path: &Path,
     speck: &Speck,
     content: &str,
     ctx: &SyncContext<'_>,
-) -> Result<(Option<String>, usize, usize, Option<String>, Vec<String>), SpecksError> {
+) -> Result<
+    (
+        Option<String>,
+        usize,
+        usize,
+        Option<String>,
+        Vec<String>,
+        Vec<OrphanBead>,
+    ),
+    SpecksError,
+> {
     let mut updated_content = content.to_string();
     let mut steps_synced = 0;
     let mut deps_added = 0;
@@ -246,7 +324,7 @@ fn sync_speck_to_beads(
             known_ids.push(bead_id.clone());
         }
         if ctx.substeps_mode == "children" {
-            for substep in &step.substeps {
+            for substep in step.all_substeps() {
                 if let Some(ref bead_id) = substep.bead_id {
                     known_ids.push(bead_id.clone());
                 }
@@ -255,7 +333,7 @@ fn sync_speck_to_beads(
     }
 
     // Phase 2: Single batch query to check which beads exist (major performance win)
-    let existing_ids = if ctx.dry_run || known_ids.is_empty() {
+    let mut existing_ids = if known_ids.is_empty() {
         HashSet::new()
     } else {
         ctx.beads.list_by_ids(&known_ids, None).unwrap_or_default()
@@ -270,6 +348,21 @@ fn sync_speck_to_beads(
         &mut updated_content,
     )?;
 
+    // Reconcile child beads of an existing root that no step links to
+    let (orphans, relinked) = if root_created {
+        (Vec::new(), HashMap::new())
+    } else {
+        reconcile_orphans(speck, &root_id, ctx, &existing_ids, &mut updated_content)?
+    };
+    let relinked_speck;
+    let speck = if relinked.is_empty() {
+        speck
+    } else {
+        relinked_speck = with_relinked_beads(speck, &relinked);
+        existing_ids.extend(relinked.values().cloned());
+        &relinked_speck
+    };
+
     // Track newly created beads to avoid double-updates during enrichment
     let mut created_beads: HashSet<String> = HashSet::new();
     if root_created {
@@ -296,12 +389,16 @@ fn sync_speck_to_beads(
         }
         steps_synced += 1;
 
-        // Handle substeps if mode is "children"
+        // Handle substeps if mode is "children"; each nests under its parent's bead
         if ctx.substeps_mode == "children" {
-            for substep in &step.substeps {
+            for (substep, parent_anchor, _) in nested_substeps(step) {
+                let parent_bead_id = anchor_to_bead
+                    .get(parent_anchor)
+                    .cloned()
+                    .unwrap_or_else(|| step_bead_id.clone());
                 let (substep_bead_id, substep_created) = ensure_substep_bead(
                     substep,
-                    &step_bead_id,
+                    &parent_bead_id,
                     speck,
                     ctx,
                     &existing_ids,
@@ -317,16 +414,50 @@ fn sync_speck_to_beads(
         }
     }
 
+    // Cross-speck dependencies point at the other speck's step beads
+    if let Some(dir) = path.parent() {
+        for (_, deps) in speck.step_dependencies() {
+            for dep in deps {
+                if external_dependency(&dep).is_none() || anchor_to_bead.contains_key(&dep) {
+                    continue;
+                }
+                match resolve_external_step(dir, &dep).and_then(|step| step.bead_id) {
+                    Some(dep_bead_id) => {
+                        anchor_to_bead.insert(dep, dep_bead_id);
+                    }
+                    None if !ctx.quiet => {
+                        eprintln!("warning: {} has no bead, sync that speck first", dep);
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+
     // Step 3: Create dependency edges
+    // Speck-level dependencies block the root bead
+    if !speck.metadata.depends_on.is_empty() && (root_created || ctx.prune_deps) {
+        deps_added += sync_dependencies(
+            &root_id,
+            &speck.metadata.depends_on,
+            &anchor_to_bead,
+            ctx.beads,
+            ctx.prune_deps,
+            ctx.dry_run,
+        )?;
+    }
+
     // Optimization: if bead already existed (in existing_ids) and we're not pruning,
     // skip dependency sync entirely - deps were set when bead was first created.
     for step in &speck.steps {
         if let Some(bead_id) = anchor_to_bead.get(&step.anchor) {
-            // Skip if bead already existed and not pruning (deps already set)
+            // Skip if bead already existed and not pruning (deps already set);
+            // relinked beads get their edges from the step they now belong to
             let bead_existed = step
                 .bead_id
                 .as_ref()
-                .is_some_and(|id| existing_ids.contains(id));
+                .is_some_and(|id| existing_ids.contains(id))
+                && !relinked.contains_key(&step.anchor);
             if !bead_existed || ctx.prune_deps {
                 let added = sync_dependencies(
                     bead_id,
@@ -342,7 +473,7 @@ fn sync_speck_to_beads(
 
         // Handle substep dependencies
         if ctx.substeps_mode == "children" {
-            for substep in &step.substeps {
+            for (substep, _, inherited_deps) in nested_substeps(step) {
                 if let Some(bead_id) = anchor_to_bead.get(&substep.anchor) {
                     // Skip if bead already existed and not pruning
                     let bead_existed = substep
@@ -350,9 +481,9 @@ fn sync_speck_to_beads(
                         .as_ref()
                         .is_some_and(|id| existing_ids.contains(id));
                     if !bead_existed || ctx.prune_deps {
-                        // Substeps inherit parent deps if no explicit deps
+                        // Substeps inherit ancestor deps if no explicit deps
                         let deps = if substep.depends_on.is_empty() {
-                            &step.depends_on
+                            inherited_deps
                         } else {
                             &substep.depends_on
                         };
@@ -392,7 +523,7 @@ fn sync_speck_to_beads(
 
             // Enrich substep beads if using children mode
             if ctx.substeps_mode == "children" {
-                for substep in &step.substeps {
+                for substep in step.all_substeps() {
                     if let Some(bead_id) = anchor_to_bead.get(&substep.anchor) {
                         // Skip if just created - already enriched
                         if !created_beads.contains(bead_id) {
@@ -434,9 +565,228 @@ fn sync_speck_to_beads(
             None
         },
         enrich_errors,
+        orphans,
     ))
 }
 
+/// Find open child beads of the root that no step links to and handle them
+/// per the orphans mode
+///
+/// With `--substeps children`, substep beads nest under their parent's bead,
+/// so the children of every existing step and substep bead are scanned too,
+/// and an unlinked substep can be relinked to an orphan under its parent.
+///
+/// Returns the orphans with the action taken, and the step anchors that were
+/// relinked to an orphan bead (whose `**Bead:**` lines are already written
+/// to `content`, which a dry run only previews).
+fn reconcile_orphans(
+    speck: &Speck,
+    root_id: &str,
+    ctx: &SyncContext<'_>,
+    existing_ids: &HashSet<String>,
+    content: &mut String,
+) -> Result<(Vec<OrphanBead>, HashMap<String, String>), SpecksError> {
+    // (anchor, title, bead, parent anchor) of every step sync gives a bead
+    let mut steps: Vec<(&str, &str, Option<&str>, Option<&str>)> = Vec::new();
+    for step in &speck.steps {
+        steps.push((&step.anchor, &step.title, step.bead_id.as_deref(), None));
+        if ctx.substeps_mode == "children" {
+            for (substep, parent_anchor, _) in nested_substeps(step) {
+                steps.push((
+                    &substep.anchor,
+                    &substep.title,
+                    substep.bead_id.as_deref(),
+                    Some(parent_anchor),
+                ));
+            }
+        }
+    }
+    let bead_of = |anchor: &str| {
+        steps
+            .iter()
+            .find(|(a, ..)| *a == anchor)
+            .and_then(|(_, _, bead, _)| *bead)
+            .filter(|id| existing_ids.contains(*id))
+    };
+
+    // (parent bead, child) for the root and every existing bead nesting others
+    let mut parents = vec![root_id];
+    if ctx.substeps_mode == "children" {
+        parents.extend(steps.iter().filter_map(|(anchor, ..)| bead_of(anchor)));
+    }
+    let mut children = Vec::new();
+    for parent in parents {
+        match ctx.beads.list_children_detailed(parent, None) {
+            Ok(list) => children.extend(list.into_iter().map(|child| (parent, child))),
+            Err(e) if !ctx.quiet => {
+                eprintln!("warning: could not list children of {}: {}", parent, e);
+            }
+            Err(_) => {}
+        }
+    }
+
+    let child_ids: HashSet<&str> = children.iter().map(|(_, c)| c.id.as_str()).collect();
+    let mut linked: HashSet<&str> = HashSet::new();
+    for step in &speck.steps {
+        linked.extend(step.bead_id.as_deref());
+        for substep in step.all_substeps() {
+            linked.extend(substep.bead_id.as_deref());
+        }
+    }
+    let orphans: Vec<_> = children
+        .iter()
+        .filter(|(_, c)| c.status != "closed" && !linked.contains(c.id.as_str()))
+        .collect();
+    if orphans.is_empty() {
+        return Ok((Vec::new(), HashMap::new()));
+    }
+
+    // Relink pairs each unlinked step with its most similar orphan under the
+    // bead it nests in, best first
+    let mut relinked: HashMap<String, String> = HashMap::new();
+    if ctx.orphans_mode == "relink" {
+        let mut pairs: Vec<(f64, &str, &str)> = Vec::new();
+        for (anchor, title, bead, parent_anchor) in &steps {
+            if bead.is_some_and(|id| child_ids.contains(id) || existing_ids.contains(id)) {
+                continue;
+            }
+            let Some(parent) = parent_anchor.map_or(Some(root_id), bead_of) else {
+                continue;
+            };
+            for (orphan_parent, orphan) in &orphans {
+                let score = title_similarity(title, &orphan.title);
+                if *orphan_parent == parent && score >= RELINK_THRESHOLD {
+                    pairs.push((score, anchor, orphan.id.as_str()));
+                }
+            }
+        }
+        pairs.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let mut taken: HashSet<&str> = HashSet::new();
+        for (_, anchor, bead_id) in pairs {
+            if relinked.contains_key(anchor) || taken.contains(bead_id) {
+                continue;
+            }
+            taken.insert(bead_id);
+            relinked.insert(anchor.to_string(), bead_id.to_string());
+            write_bead_to_step(content, anchor, bead_id);
+        }
+    }
+
+    let mut result = Vec::new();
+    for (_, orphan) in orphans {
+        let step_anchor = relinked
+            .iter()
+            .find(|(_, id)| **id == orphan.id)
+            .map(|(anchor, _)| anchor.clone());
+        let action = match (&step_anchor, ctx.orphans_mode) {
+            (Some(_), _) => "relink",
+            (None, "close") => "close",
+            _ => "report",
+        };
+        if action == "close" && !ctx.dry_run {
+            ctx.beads
+                .close(&orphan.id, Some("No matching step in speck"), None)?;
+        }
+        result.push(OrphanBead {
+            bead_id: orphan.id.clone(),
+            title: orphan.title.clone(),
+            action: action.to_string(),
+            step_anchor,
+        });
+    }
+    Ok((result, relinked))
+}
+
+/// Copy of `speck` with relinked bead IDs set on their steps and substeps
+fn with_relinked_beads(speck: &Speck, relinked: &HashMap<String, String>) -> Speck {
+    fn relink_substeps(substeps: &mut [specks_core::Substep], relinked: &HashMap<String, String>) {
+        for substep in substeps {
+            if let Some(bead_id) = relinked.get(&substep.anchor) {
+                substep.bead_id = Some(bead_id.clone());
+            }
+            relink_substeps(&mut substep.substeps, relinked);
+        }
+    }
+
+    let mut speck = speck.clone();
+    for step in &mut speck.steps {
+        if let Some(bead_id) = relinked.get(&step.anchor) {
+            step.bead_id = Some(bead_id.clone());
+        }
+        relink_substeps(&mut step.substeps, relinked);
+    }
+    speck
+}
+
+/// Similarity of a step title and a bead title, from 0.0 to 1.0
+///
+/// Ignores case and the `Step N:` prefix sync puts on bead titles, so a
+/// renumbered step still matches its old bead.
+fn title_similarity(a: &str, b: &str) -> f64 {
+    let normalize = |title: &str| {
+        step_title_from_bead(title)
+            .to_lowercase()
+            .chars()
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    // Levenshtein distance, one row at a time
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    1.0 - row[b.len()] as f64 / longest as f64
+}
+
+/// Print the orphan bead table
+fn print_orphans(orphans: &[OrphanBead], mode: &str) {
+    let bead_width = orphans
+        .iter()
+        .map(|o| o.bead_id.len())
+        .max()
+        .unwrap_or(0)
+        .max("BEAD".len());
+    let step_width = orphans
+        .iter()
+        .filter_map(|o| o.step_anchor.as_ref().map(String::len))
+        .max()
+        .unwrap_or(0)
+        .max("STEP".len());
+
+    println!("  Orphan beads: {}", orphans.len());
+    println!(
+        "    {:<bead_width$}  {:<6}  {:<step_width$}  TITLE",
+        "BEAD", "ACTION", "STEP"
+    );
+    for orphan in orphans {
+        println!(
+            "    {:<bead_width$}  {:<6}  {:<step_width$}  {}",
+            orphan.bead_id,
+            orphan.action,
+            orphan.step_anchor.as_deref().unwrap_or("-"),
+            orphan.title
+        );
+    }
+    if mode == "report" {
+        println!("    (re-run with --orphans close or --orphans relink to resolve)");
+    }
+}
+
 /// Ensure root bead exists and return its ID and whether it was newly created
 fn ensure_root_bead(
     speck: &Speck,
@@ -552,6 +902,7 @@ fn ensure_step_bead(
 
     // Write Bead ID to step in content
     write_bead_to_step(content, &step.anchor, &issue.id);
+    record_created_bead(ctx, &issue.id, &step.title, step.beads_hints.as_ref());
 
     Ok((issue.id, true))
 }
@@ -629,16 +980,33 @@ fn ensure_substep_bead(
 
     // Write Bead ID to substep in content
     write_bead_to_step(content, &substep.anchor, &issue.id);
+    record_created_bead(ctx, &issue.id, &substep.title, substep.beads_hints.as_ref());
 
     Ok((issue.id, true))
 }
 
+/// Record a newly created bead's fields as last synced
+fn record_created_bead(
+    ctx: &SyncContext<'_>,
+    bead_id: &str,
+    title: &str,
+    hints: Option<&specks_core::BeadsHints>,
+) {
+    ctx.snapshot.borrow_mut().beads.insert(
+        bead_id.to_string(),
+        SnapshotEntry::new(
+            BeadFields::from_step(title, hints),
+            BeadFields::created(title),
+        ),
+    );
+}
+
 /// Sync dependencies for a bead
 fn sync_dependencies(
     bead_id: &str,
     depends_on: &[String],
     anchor_to_bead: &HashMap<String, String>,
-    beads: &BeadsCli,
+    beads: &dyn IssueTracker,
     prune_deps: bool,
     dry_run: bool,
 ) -> Result<usize, SpecksError> {
@@ -682,45 +1050,9 @@ fn sync_dependencies(
 
 /// Write Beads Root ID to content (in Plan Metadata)
 fn write_beads_root_to_content(content: &mut String, bead_id: &str) {
-    // Check if Beads Root already exists in content
-    let beads_root_pattern = regex::Regex::new(r"\*\*Beads Root:\*\*\s*`[^`]*`").unwrap();
-    let beads_root_line = format!("**Beads Root:** `{}`", bead_id);
-
-    if beads_root_pattern.is_match(content) {
-        // Replace existing
-        *content = beads_root_pattern
-            .replace(content, beads_root_line.as_str())
-            .to_string();
-    } else {
-        // Add after Plan Metadata table
-        // Look for the table end (line starting with | followed by blank line or ---)
-        let lines: Vec<&str> = content.lines().collect();
-        let mut insert_pos = None;
-        let mut in_metadata = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            if line.contains("Plan Metadata") {
-                in_metadata = true;
-            }
-            if in_metadata && line.starts_with('|') && line.contains("Last updated") {
-                // Insert after the table row containing Last updated
-                for (j, next_line) in lines.iter().enumerate().skip(i + 1) {
-                    if !next_line.starts_with('|') {
-                        insert_pos = Some(j);
-                        break;
-                    }
-                }
-                break;
-            }
-        }
-
-        if let Some(pos) = insert_pos {
-            let mut new_lines: Vec<String> = lines.iter().map(|s| s.to_string()).collect();
-            // Insert Beads Root row in table format
-            let table_row = format!("| Beads Root | `{}` |", bead_id);
-            new_lines.insert(pos, table_row);
-            *content = new_lines.join("\n");
-        }
+    let mut tree = SpeckTree::parse(content);
+    if tree.set_beads_root(bead_id) {
+        *content = tree.into_string();
     }
 }
 
@@ -729,66 +1061,10 @@ fn write_beads_root_to_content(content: &mut String, bead_id: &str) {
 /// Uses anchor-based matching only (not line numbers) to avoid issues with
 /// stale line numbers after content modifications.
 fn write_bead_to_step(content: &mut String, anchor: &str, bead_id: &str) {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut new_lines: Vec<String> = Vec::new();
-    let bead_line = format!("**Bead:** `{}`", bead_id);
-    let bead_pattern = regex::Regex::new(r"^\*\*Bead:\*\*\s*`[^`]*`").unwrap();
-
-    let mut i = 0;
-    while i < lines.len() {
-        let line = lines[i];
-        new_lines.push(line.to_string());
-
-        // Only match on anchor - line numbers become stale after edits
-        if line.contains(&format!("{{#{}}}", anchor)) {
-            // Look ahead to find where to insert/update Bead line
-            // Placement: after **Depends on:** if present, before **Commit:**
-            let mut found_bead = false;
-            let mut insert_before = None;
-
-            for j in (i + 1)..std::cmp::min(i + 20, lines.len()) {
-                let next_line = lines[j];
-
-                // Check if bead line already exists
-                if bead_pattern.is_match(next_line) {
-                    // Copy intermediate lines first, then replace the bead
-                    for line in lines.iter().skip(i + 1).take(j - i - 1) {
-                        new_lines.push(line.to_string());
-                    }
-                    new_lines.push(bead_line.clone());
-                    found_bead = true;
-                    i = j;
-                    break;
-                }
-
-                // Check for **Commit:** or next step/section header
-                if next_line.starts_with("**Commit:**")
-                    || next_line.starts_with("####")
-                    || next_line.starts_with("---")
-                {
-                    insert_before = Some(j);
-                    break;
-                }
-            }
-
-            if !found_bead {
-                if let Some(pos) = insert_before {
-                    // Copy lines up to insert position
-                    for line in lines.iter().skip(i + 1).take(pos - i - 1) {
-                        new_lines.push(line.to_string());
-                    }
-                    // Insert bead line
-                    new_lines.push(bead_line.clone());
-                    new_lines.push(String::new()); // Blank line before next section
-                    i = pos - 1;
-                }
-            }
-        }
-
-        i += 1;
+    let mut tree = SpeckTree::parse(content);
+    if tree.set_step_bead(anchor, bead_id) {
+        *content = tree.into_string();
     }
-
-    *content = new_lines.join("\n");
 }
 
 /// Resolve file path relative to project
@@ -817,7 +1093,33 @@ fn resolve_file_path(project_root: &Path, file: &str) -> std::path::PathBuf {
     }
 }
 
-/// Resolve step references and expand decision IDs to titles
+/// Substeps at every depth in document order, each paired with its parent's
+/// anchor and the dependencies it inherits (from the nearest ancestor that
+/// declares any)
+fn nested_substeps(step: &specks_core::Step) -> Vec<(&specks_core::Substep, &str, &[String])> {
+    fn walk<'a>(
+        substeps: &'a [specks_core::Substep],
+        parent_anchor: &'a str,
+        inherited: &'a [String],
+        out: &mut Vec<(&'a specks_core::Substep, &'a str, &'a [String])>,
+    ) {
+        for substep in substeps {
+            out.push((substep, parent_anchor, inherited));
+            let deps = if substep.depends_on.is_empty() {
+                inherited
+            } else {
+                &substep.depends_on
+            };
+            walk(&substep.substeps, &substep.anchor, deps, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&step.substeps, &step.anchor, &step.depends_on, &mut out);
+    out
+}
+
+/// Resolve step references and expand decision IDs to their statements
 fn resolve_step_design(step: &specks_core::Step, speck: &Speck) -> String {
     use regex::Regex;
     use std::sync::LazyLock;
@@ -832,19 +1134,12 @@ fn resolve_step_design(step: &specks_core::Step, speck: &Speck) -> String {
 
     let mut design_lines = vec!["## References".to_string()];
 
-    // Build decision lookup map
-    let decision_map: HashMap<String, String> = speck
-        .decisions
-        .iter()
-        .map(|d| (d.id.clone(), d.title.clone()))
-        .collect();
-
     // Extract decision references and expand them
     let mut decisions = Vec::new();
     for cap in DECISION_REF.captures_iter(references) {
         let id = cap.get(1).unwrap().as_str();
-        if let Some(title) = decision_map.get(id) {
-            decisions.push(format!("- [{}] {}", id, title));
+        if let Some(decision) = speck.decision(id) {
+            decisions.push(format!("- [{}] {}", id, decision.summary()));
         } else {
             // Decision not found, pass through as-is
             decisions.push(format!("- [{}]", id));
@@ -991,6 +1286,7 @@ fn output_error(
                 dry_run: false,
                 enriched: None,
                 enrich_errors: None,
+                orphans: Vec::new(),
             },
             issues,
         );
@@ -1000,3 +1296,77 @@ fn output_error(
     }
     Ok(exit_code)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specks_core::MemoryTracker;
+
+    fn sync_with(
+        tracker: &MemoryTracker,
+        content: &str,
+        dry_run: bool,
+    ) -> (String, Vec<OrphanBead>) {
+        let config = Config::default();
+        let ctx = SyncContext {
+            beads: tracker,
+            config: &config,
+            dry_run,
+            enrich: false,
+            prune_deps: false,
+            substeps_mode: "children",
+            orphans_mode: "relink",
+            snapshot: RefCell::new(BeadSnapshot::default()),
+            quiet: true,
+        };
+        let speck = parse_speck(content).unwrap();
+        let (_, _, _, updated, _, orphans) =
+            sync_speck_to_beads(Path::new("specks-nested.md"), &speck, content, &ctx).unwrap();
+        (updated.unwrap_or_else(|| content.to_string()), orphans)
+    }
+
+    #[test]
+    fn test_dry_run_relinks_orphan_substep_beads() {
+        let tracker = MemoryTracker::default();
+        let content = include_str!("../../../../../tests/fixtures/valid/nested-substeps.md");
+        let (synced, _) = sync_with(&tracker, content, false);
+
+        // Renaming Step 1.2's anchor loses its bead line; its bead stays
+        // open under the Step 1 bead
+        let speck = parse_speck(&synced).unwrap();
+        let queries_bead = speck.steps[1].substeps[1].bead_id.clone().unwrap();
+        let edited = synced
+            .replace("{#step-1-2}", "{#step-queries}")
+            .replace(&format!("**Bead:** `{}`\n", queries_bead), "");
+        assert!(!edited.contains(&queries_bead));
+
+        let created = tracker.issues().len();
+        let (preview, orphans) = sync_with(&tracker, &edited, true);
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].bead_id, queries_bead);
+        assert_eq!(orphans[0].action, "relink");
+        assert_eq!(orphans[0].step_anchor.as_deref(), Some("step-queries"));
+
+        // The dry run plans the relink, not a new bead as well
+        assert!(!preview.contains("bd-dryrun"), "{}", preview);
+        let speck = parse_speck(&preview).unwrap();
+        assert_eq!(
+            speck.steps[1].substeps[1].bead_id.as_deref(),
+            Some(queries_bead.as_str())
+        );
+        assert_eq!(tracker.issues().len(), created);
+    }
+
+    #[test]
+    fn test_title_similarity_ignores_step_prefix() {
+        assert_eq!(
+            title_similarity("Build on Foundation", "Step 4: Build on Foundation"),
+            1.0
+        );
+        assert!(title_similarity("Build on the Foundation", "Step 1: Build on Foundation") > 0.8);
+        assert!(
+            title_similarity("Final Integration", "Step 2: Build on Foundation") < RELINK_THRESHOLD
+        );
+        assert_eq!(title_similarity("", ""), 1.0);
+    }
+}
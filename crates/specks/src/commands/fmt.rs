@@ -0,0 +1,213 @@
+//! Implementation of the `specks fmt` command
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use specks_core::{find_project_root, find_specks, format_speck};
+
+use crate::output::{FmtData, FormattedFile, JsonIssue, JsonResponse};
+
+/// Run the fmt command
+///
+/// With `check`, no files are written and the exit code is 1 if any speck
+/// is not in canonical form.
+pub fn run_fmt(
+    file: Option<String>,
+    check: bool,
+    json_output: bool,
+    quiet: bool,
+) -> Result<i32, String> {
+    let project_root = match find_project_root() {
+        Ok(root) => root,
+        Err(_) => {
+            return output_error(
+                json_output,
+                check,
+                "E009",
+                ".specks directory not initialized",
+                9,
+            );
+        }
+    };
+
+    let files: Vec<PathBuf> = match file {
+        Some(f) => {
+            let path = resolve_file_path(&project_root, &f);
+            if !path.exists() {
+                return output_error(
+                    json_output,
+                    check,
+                    "E002",
+                    &format!("file not found: {}", f),
+                    2,
+                );
+            }
+            vec![path]
+        }
+        None => match find_specks(&project_root) {
+            Ok(specks) => specks,
+            Err(e) => {
+                return output_error(
+                    json_output,
+                    check,
+                    "E009",
+                    &format!("failed to find specks: {}", e),
+                    9,
+                );
+            }
+        },
+    };
+
+    let mut results: Vec<FormattedFile> = Vec::new();
+    let mut issues: Vec<JsonIssue> = Vec::new();
+
+    for path in &files {
+        let relative_path = make_relative_path(&project_root, path);
+
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                issues.push(file_issue(
+                    "E002",
+                    format!("failed to read file: {}", e),
+                    &relative_path,
+                ));
+                continue;
+            }
+        };
+
+        let formatted = match format_speck(&content) {
+            Ok(f) => f,
+            Err(e) => {
+                issues.push(file_issue(e.code(), e.to_string(), &relative_path));
+                continue;
+            }
+        };
+
+        let changed = formatted != content;
+        if changed && !check {
+            if let Err(e) = fs::write(path, &formatted) {
+                issues.push(file_issue(
+                    "E002",
+                    format!("failed to write file: {}", e),
+                    &relative_path,
+                ));
+                continue;
+            }
+        }
+
+        results.push(FormattedFile {
+            path: relative_path,
+            changed,
+        });
+    }
+
+    let unformatted = results.iter().filter(|f| f.changed).count();
+    let failed = !issues.is_empty() || (check && unformatted > 0);
+
+    if json_output {
+        let data = FmtData {
+            check,
+            files: results,
+        };
+        let response = if failed {
+            JsonResponse::error("fmt", data, issues)
+        } else {
+            JsonResponse::ok("fmt", data)
+        };
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else {
+        for issue in &issues {
+            eprintln!(
+                "error: {}: {}",
+                issue.file.as_deref().unwrap_or(""),
+                issue.message
+            );
+        }
+        if !quiet {
+            for f in results.iter().filter(|f| f.changed) {
+                if check {
+                    println!("{}: needs formatting", f.path);
+                } else {
+                    println!("Formatted {}", f.path);
+                }
+            }
+            if unformatted == 0 && issues.is_empty() {
+                println!("All specks already formatted");
+            }
+        }
+    }
+
+    Ok(if failed { 1 } else { 0 })
+}
+
+/// Build a file-scoped error issue
+fn file_issue(code: &str, message: String, file: &str) -> JsonIssue {
+    JsonIssue {
+        code: code.to_string(),
+        severity: "error".to_string(),
+        message,
+        file: Some(file.to_string()),
+        line: None,
+        anchor: None,
+    }
+}
+
+/// Resolve a file path relative to the project
+fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
+    let path = Path::new(file);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else if file.starts_with(".specks/") || file.starts_with(".specks\\") {
+        project_root.join(file)
+    } else if file.starts_with("specks-") {
+        project_root.join(".specks").join(file)
+    } else {
+        let as_is = project_root.join(file);
+        if as_is.exists() {
+            as_is
+        } else {
+            project_root.join(".specks").join(file)
+        }
+    }
+}
+
+/// Make a path relative to the project root
+fn make_relative_path(project_root: &Path, path: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Output an error in JSON or text format
+fn output_error(
+    json_output: bool,
+    check: bool,
+    code: &str,
+    message: &str,
+    exit_code: i32,
+) -> Result<i32, String> {
+    if json_output {
+        let issues = vec![JsonIssue {
+            code: code.to_string(),
+            severity: "error".to_string(),
+            message: message.to_string(),
+            file: None,
+            line: None,
+            anchor: None,
+        }];
+        let response: JsonResponse<FmtData> = JsonResponse::error(
+            "fmt",
+            FmtData {
+                check,
+                files: vec![],
+            },
+            issues,
+        );
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else {
+        eprintln!("error: {}", message);
+    }
+    Ok(exit_code)
+}
@@ -9,6 +9,26 @@ use crate::output::{InitCheckData, InitData, JsonResponse};
 /// Embedded skeleton content
 const SKELETON_CONTENT: &str = include_str!("../../../../.specks/specks-skeleton.md");
 
+/// Embedded speck templates for `specks new`, written to `.specks/templates/`
+pub(crate) const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "feature",
+        include_str!("../../../../.specks/templates/feature.md"),
+    ),
+    (
+        "bugfix",
+        include_str!("../../../../.specks/templates/bugfix.md"),
+    ),
+    (
+        "refactor",
+        include_str!("../../../../.specks/templates/refactor.md"),
+    ),
+    (
+        "spike",
+        include_str!("../../../../.specks/templates/spike.md"),
+    ),
+];
+
 /// Default config.toml content
 const DEFAULT_CONFIG: &str = r#"[specks]
 # Validation strictness: "lenient", "normal", "strict"
@@ -17,6 +37,23 @@ validation_level = "normal"
 # Include info-level messages in validation output
 show_info = false
 
+[specks.validation.rules]
+# Per-rule overrides: "off", "info", "warning" or "error"
+# W004 = "off"
+# W011 = "error"
+
+# Project-defined rules, each with its own code, severity and message.
+# Kinds: required-anchor (anchor), required-metadata (field),
+# step-pattern (field: title, commit, references, depends_on, tasks, tests
+# or checkpoints; pattern: regex) and max-tasks (max).
+# [[specks.validation.custom]]
+# code = "C001"
+# kind = "step-pattern"
+# field = "commit"
+# pattern = '^(feat|fix|chore)(\(.+\))?: '
+# severity = "error"
+# message = "Commit lines must use a conventional prefix"
+
 [specks.naming]
 # Speck file prefix (default: "specks-")
 prefix = "specks-"
@@ -34,6 +71,13 @@ validate_bead_ids = true
 # Path to beads CLI binary (default: "bd" on PATH)
 bd_path = "bd"
 
+# Issue tracker: "bd" (beads CLI), "file" (built-in, stored in .specks/tracker/)
+# or "github" (GitHub Issues, see [specks.beads.github])
+tracker = "bd"
+
+# ID prefix for issues created by the file tracker
+tracker_prefix = "sp"
+
 # Sync behavior defaults (safe, non-destructive)
 prune_deps = false
 
@@ -48,8 +92,19 @@ substeps = "none"
 # - "all": update Tasks/Tests/Checkpoints
 pull_checkbox_mode = "checkpoints"
 
-# Warn when checkboxes and bead status disagree
+# Warn when `beads pull --fields` finds a title, priority or labels
+# changed differently in the speck and in beads (the speck's value is kept)
 pull_warn_on_conflict = true
+
+# Orphan beads (open children of the root with no matching step) on sync:
+# "report" (default), "close" or "relink" (to the step with the most similar title)
+orphans = "report"
+
+# GitHub Issues tracker (tracker = "github"); token from GITHUB_TOKEN or GH_TOKEN
+# [specks.beads.github]
+# api_url = "https://api.github.com"
+# repo = "owner/name"
+# root = "issue"              # Root bead as a tracking "issue" or a "milestone"
 "#;
 
 /// Empty implementation log template
@@ -125,6 +180,8 @@ pub fn run_init(force: bool, check: bool, json_output: bool, quiet: bool) -> Res
             files_created.push("specks-implementation-log.md".to_string());
         }
 
+        files_created.extend(write_templates(specks_dir, false)?);
+
         // Handle .gitignore even in idempotent mode
         ensure_gitignore(quiet)?;
 
@@ -175,13 +232,14 @@ pub fn run_init(force: bool, check: bool, json_output: bool, quiet: bool) -> Res
     fs::write(&log_path, IMPLEMENTATION_LOG_CONTENT)
         .map_err(|e| format!("failed to write specks-implementation-log.md: {}", e))?;
 
-    ensure_gitignore(quiet)?;
-
-    let files_created = vec![
+    let mut files_created = vec![
         "specks-skeleton.md".to_string(),
         "config.toml".to_string(),
         "specks-implementation-log.md".to_string(),
     ];
+    files_created.extend(write_templates(specks_dir, true)?);
+
+    ensure_gitignore(quiet)?;
 
     if json_output {
         let response = JsonResponse::ok(
@@ -194,14 +252,35 @@ pub fn run_init(force: bool, check: bool, json_output: bool, quiet: bool) -> Res
         println!("{}", serde_json::to_string_pretty(&response).unwrap());
     } else if !quiet {
         println!("Initialized specks project in .specks/");
-        println!("  Created: specks-skeleton.md");
-        println!("  Created: config.toml");
-        println!("  Created: specks-implementation-log.md");
+        for f in &files_created {
+            println!("  Created: {}", f);
+        }
     }
 
     Ok(0)
 }
 
+/// Write the built-in templates to `.specks/templates/`
+///
+/// Existing templates are kept unless `overwrite` is set, so customized
+/// templates survive a plain `specks init`. Returns the files written.
+fn write_templates(specks_dir: &Path, overwrite: bool) -> Result<Vec<String>, String> {
+    let templates_dir = specks_dir.join("templates");
+    fs::create_dir_all(&templates_dir)
+        .map_err(|e| format!("failed to create templates directory: {}", e))?;
+
+    let mut written = Vec::new();
+    for (name, content) in TEMPLATES {
+        let path = templates_dir.join(format!("{}.md", name));
+        if overwrite || !path.exists() {
+            fs::write(&path, content)
+                .map_err(|e| format!("failed to write templates/{}.md: {}", name, e))?;
+            written.push(format!("templates/{}.md", name));
+        }
+    }
+    Ok(written)
+}
+
 /// Ensure .specks-worktrees/ is listed in .gitignore
 fn ensure_gitignore(_quiet: bool) -> Result<(), String> {
     let gitignore_path = Path::new(".gitignore");
@@ -284,4 +363,10 @@ mod tests {
         assert_eq!(result, 9, "should return exit code 9");
         // TempDir auto-cleans on drop - no manual cleanup needed
     }
+
+    #[test]
+    fn test_skeleton_is_in_canonical_form() {
+        let formatted = specks_core::format_speck(SKELETON_CONTENT).unwrap();
+        assert_eq!(formatted, SKELETON_CONTENT);
+    }
 }
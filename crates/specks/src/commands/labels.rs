@@ -0,0 +1,320 @@
+//! Implementation of the `specks labels` command
+//!
+//! Lists a speck's labeled artifacts (Spec S01, Table T01, Risk R01, ...)
+//! together with the steps whose References cite them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use specks_core::{
+    LabelKind, Speck, cited_labels, find_project_root, parse_speck, speck_name_from_path,
+};
+
+use crate::output::{JsonIssue, JsonResponse, LabelInfo, LabelsData};
+
+/// Run the labels command
+///
+/// `kind` keeps only labels of one kind ("risk", "Tables", ...); `label`
+/// keeps only the label with that ID, which must be defined.
+pub fn run_labels(
+    file: String,
+    kind: Option<String>,
+    label: Option<String>,
+    json_output: bool,
+    quiet: bool,
+) -> Result<i32, String> {
+    let project_root = match find_project_root() {
+        Ok(root) => root,
+        Err(_) => {
+            return output_error(
+                json_output,
+                "E009",
+                ".specks directory not initialized",
+                &file,
+                9,
+            );
+        }
+    };
+
+    let kind = match kind.as_deref().map(parse_kind) {
+        None => None,
+        Some(Some(kind)) => Some(kind),
+        Some(None) => {
+            return output_error(
+                json_output,
+                "E002",
+                &format!(
+                    "invalid label kind: {} (expected spec, table, list, risk, milestone, concept or diagram)",
+                    kind.unwrap_or_default()
+                ),
+                &file,
+                2,
+            );
+        }
+    };
+
+    let path = resolve_file_path(&project_root, &file);
+    if !path.exists() {
+        return output_error(
+            json_output,
+            "E002",
+            &format!("file not found: {}", file),
+            &file,
+            2,
+        );
+    }
+
+    let content = match fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            return output_error(
+                json_output,
+                "E002",
+                &format!("failed to read file: {}", e),
+                &file,
+                2,
+            );
+        }
+    };
+
+    let speck = match parse_speck(&content) {
+        Ok(s) => s,
+        Err(e) => {
+            return output_error(
+                json_output,
+                "E001",
+                &format!("failed to parse speck: {}", e),
+                &file,
+                1,
+            );
+        }
+    };
+
+    if let Some(id) = &label {
+        if speck.label(id).is_none() {
+            return output_error(
+                json_output,
+                "E002",
+                &format!("label not found: {}", id),
+                &file,
+                2,
+            );
+        }
+    }
+
+    let name = speck_name_from_path(&path).unwrap_or_else(|| file.clone());
+    let data = build_labels_data(&speck, &name, kind, label.as_deref());
+
+    if json_output {
+        let response = JsonResponse::ok("labels", data);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else if !quiet {
+        print!("{}", format_labels_text(&data));
+    }
+
+    Ok(0)
+}
+
+/// Parse a `--kind` value, ignoring case and accepting the plural form
+fn parse_kind(word: &str) -> Option<LabelKind> {
+    let mut chars = word.chars();
+    let capitalized = chars
+        .next()
+        .map(|first| {
+            first
+                .to_uppercase()
+                .chain(chars.flat_map(char::to_lowercase))
+        })
+        .map(|c| c.collect::<String>())?;
+    LabelKind::from_word(&capitalized)
+}
+
+/// List the labels of a speck, keeping those of `kind` or with ID `label`
+fn build_labels_data(
+    speck: &Speck,
+    name: &str,
+    kind: Option<LabelKind>,
+    label: Option<&str>,
+) -> LabelsData {
+    // (anchor, cited label IDs) of every step and substep
+    let mut citations: Vec<(String, Vec<String>)> = Vec::new();
+    for step in &speck.steps {
+        let refs = std::iter::once((&step.anchor, &step.references)).chain(
+            step.all_substeps()
+                .into_iter()
+                .map(|s| (&s.anchor, &s.references)),
+        );
+        for (anchor, references) in refs {
+            if let Some(refs) = references {
+                let ids = cited_labels(refs).into_iter().map(|(_, id)| id).collect();
+                citations.push((format!("#{}", anchor), ids));
+            }
+        }
+    }
+
+    let labels = speck
+        .labels
+        .iter()
+        .filter(|l| kind.is_none_or(|k| l.kind == k))
+        .filter(|l| label.is_none_or(|id| l.id == id))
+        .map(|l| LabelInfo {
+            id: l.id.clone(),
+            kind: l.kind,
+            title: l.title.clone(),
+            anchor: l.anchor.as_ref().map(|a| format!("#{}", a)),
+            line: l.line,
+            cited_by: citations
+                .iter()
+                .filter(|(_, ids)| ids.contains(&l.id))
+                .map(|(anchor, _)| anchor.clone())
+                .collect(),
+        })
+        .collect();
+
+    LabelsData {
+        name: name.to_string(),
+        labels,
+    }
+}
+
+/// Render labels data as text
+fn format_labels_text(data: &LabelsData) -> String {
+    let mut output = format!("{}: {} labels\n", data.name, data.labels.len());
+    for label in &data.labels {
+        let anchor = label
+            .anchor
+            .as_ref()
+            .map(|a| format!(" ({})", a))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "  {} {}: {}{} [line {}]\n",
+            label.kind, label.id, label.title, anchor, label.line
+        ));
+        if !label.cited_by.is_empty() {
+            output.push_str(&format!("    cited by {}\n", label.cited_by.join(", ")));
+        }
+    }
+    output
+}
+
+/// Resolve file path relative to project
+fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
+    let path = Path::new(file);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else if file.starts_with(".specks/") || file.starts_with(".specks\\") {
+        project_root.join(file)
+    } else if file.starts_with("specks-") || file.ends_with(".md") {
+        let filename = if file.starts_with("specks-") && file.ends_with(".md") {
+            file.to_string()
+        } else if file.starts_with("specks-") {
+            format!("{}.md", file)
+        } else {
+            format!("specks-{}.md", file)
+        };
+        project_root.join(".specks").join(filename)
+    } else {
+        let as_is = project_root.join(file);
+        if as_is.exists() {
+            as_is
+        } else {
+            project_root
+                .join(".specks")
+                .join(format!("specks-{}.md", file))
+        }
+    }
+}
+
+/// Output an error in JSON or text format
+fn output_error(
+    json_output: bool,
+    code: &str,
+    message: &str,
+    file: &str,
+    exit_code: i32,
+) -> Result<i32, String> {
+    if json_output {
+        let issues = vec![JsonIssue {
+            code: code.to_string(),
+            severity: "error".to_string(),
+            message: message.to_string(),
+            file: Some(file.to_string()),
+            line: None,
+            anchor: None,
+        }];
+        let response: JsonResponse<LabelsData> = JsonResponse::error(
+            "labels",
+            LabelsData {
+                name: String::new(),
+                labels: vec![],
+            },
+            issues,
+        );
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else {
+        eprintln!("error: {}", message);
+    }
+    Ok(exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECK: &str = r#"## Phase 1.0: Labels {#phase-1}
+
+### Specification {#specification}
+
+**Spec S01: Schema** {#s01-schema}
+
+**Table T01: Codes** {#t01-codes}
+
+**Table T02: Limits** {#t02-limits}
+
+#### Risk R01: Slow {#r01-slow}
+
+### Execution Steps {#execution-steps}
+
+#### Step 0: Setup {#step-0}
+
+**References:** Spec S01, Tables T01-T02
+
+#### Step 1: Api {#step-1}
+
+**References:** Table T02, (#r01-slow)
+
+##### Step 1.1: Limits {#step-1-1}
+
+**References:** Table T02
+"#;
+
+    #[test]
+    fn test_labels_list_and_filter() {
+        let speck = parse_speck(SPECK).unwrap();
+
+        let data = build_labels_data(&speck, "labels", None, None);
+        let ids: Vec<&str> = data.labels.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["S01", "T01", "T02", "R01"]);
+        assert_eq!(data.labels[0].cited_by, vec!["#step-0"]);
+        assert_eq!(
+            data.labels[2].cited_by,
+            vec!["#step-0", "#step-1", "#step-1-1"]
+        );
+        assert!(data.labels[3].cited_by.is_empty());
+
+        let tables = build_labels_data(&speck, "labels", parse_kind("tables"), None);
+        let ids: Vec<&str> = tables.labels.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["T01", "T02"]);
+
+        let one = build_labels_data(&speck, "labels", None, Some("T02"));
+        assert_eq!(one.labels.len(), 1);
+        assert_eq!(one.labels[0].anchor.as_deref(), Some("#t02-limits"));
+
+        let text = format_labels_text(&one);
+        assert!(text.starts_with("labels: 1 labels\n"));
+        assert!(text.contains("    cited by #step-0, #step-1, #step-1-1\n"));
+
+        assert_eq!(parse_kind("RISK"), Some(LabelKind::Risk));
+        assert_eq!(parse_kind("decision"), None);
+    }
+}
@@ -3,8 +3,12 @@
 use std::fs;
 use std::path::Path;
 
-use specks_core::{Speck, find_project_root, find_specks, parse_speck, speck_name_from_path};
+use specks_core::{
+    Speck, find_project_root, find_specks, format_duration, now_unix, parse_speck,
+    speck_name_from_path,
+};
 
+use crate::commands::status::{estimate_summary, logged_completions};
 use crate::output::{JsonIssue, JsonResponse, ListData, Progress, SpeckSummary};
 
 /// Run the list command
@@ -65,7 +69,7 @@ pub fn run_list(
     let mut summaries: Vec<SpeckSummary> = Vec::new();
 
     for path in &speck_files {
-        if let Some(summary) = parse_speck_summary(path) {
+        if let Some(summary) = parse_speck_summary(&project_root, path) {
             // Apply status filter if specified
             if let Some(ref filter) = status_filter {
                 if !summary.status.eq_ignore_ascii_case(filter) {
@@ -91,7 +95,10 @@ pub fn run_list(
 }
 
 /// Parse a speck file and return a summary
-fn parse_speck_summary(path: &Path) -> Option<SpeckSummary> {
+///
+/// Estimates and actual time are rolled up from the speck's step hints and
+/// the implementation log.
+fn parse_speck_summary(project_root: &Path, path: &Path) -> Option<SpeckSummary> {
     let content = fs::read_to_string(path).ok()?;
     let speck = parse_speck(&content).ok()?;
     let name = speck_name_from_path(path)?;
@@ -108,11 +115,21 @@ fn parse_speck_summary(path: &Path) -> Option<SpeckSummary> {
         .clone()
         .unwrap_or_else(|| "unknown".to_string());
 
+    let completions = logged_completions(project_root, &speck, path);
+    let estimates = estimate_summary(&speck, &completions, now_unix())
+        .filter(|e| e.steps.iter().any(|s| s.estimate_minutes.is_some()));
+
     Some(SpeckSummary {
         name,
         status,
         progress: Progress { done, total },
         updated,
+        estimate_minutes: estimates.as_ref().map(|e| e.total_minutes),
+        remaining_minutes: estimates.as_ref().map(|e| e.remaining_minutes),
+        remaining_critical_path_minutes: estimates
+            .as_ref()
+            .map(|e| e.remaining_critical_path_minutes),
+        actual_minutes: estimates.as_ref().and_then(|e| e.actual_minutes),
     })
 }
 
@@ -122,21 +139,44 @@ fn count_checkboxes(speck: &Speck) -> (usize, usize) {
     let mut total = 0;
 
     for step in &speck.steps {
-        total += step.total_items();
-        done += step.completed_items();
-
-        // Count substeps
-        for substep in &step.substeps {
-            total += substep.total_items();
-            done += substep.completed_items();
-        }
+        // Includes substeps at every depth
+        let (step_done, step_total) = step.rollup_counts();
+        done += step_done;
+        total += step_total;
     }
 
     (done, total)
 }
 
 /// Output a formatted table
+///
+/// ESTIMATE and REMAINING columns are added when any speck is estimated;
+/// REMAINING shows the remaining estimate and, when shorter, the remaining
+/// critical path (`5h/3h`).
 fn output_table(summaries: &[SpeckSummary]) {
+    let estimated = summaries.iter().any(|s| s.estimate_minutes.is_some());
+    let estimate_columns = |summary: Option<&SpeckSummary>| -> String {
+        if !estimated {
+            return String::new();
+        }
+        let Some(summary) = summary else {
+            return format!("  {:>10}  {:>10}", "ESTIMATE", "REMAINING");
+        };
+        let (Some(total), Some(remaining)) = (summary.estimate_minutes, summary.remaining_minutes)
+        else {
+            return format!("  {:>10}  {:>10}", "-", "-");
+        };
+        let remaining = match summary.remaining_critical_path_minutes {
+            Some(critical) if critical < remaining => format!(
+                "{}/{}",
+                format_duration(remaining),
+                format_duration(critical)
+            ),
+            _ => format_duration(remaining),
+        };
+        format!("  {:>10}  {:>10}", format_duration(total), remaining)
+    };
+
     // Calculate column widths
     let name_width = summaries
         .iter()
@@ -153,11 +193,12 @@ fn output_table(summaries: &[SpeckSummary]) {
 
     // Print header
     println!(
-        "{:<name_width$}  {:<status_width$}  {:>10}  {:>10}",
+        "{:<name_width$}  {:<status_width$}  {:>10}  {:>10}{}",
         "SPECK",
         "STATUS",
         "PROGRESS",
         "UPDATED",
+        estimate_columns(None),
         name_width = name_width,
         status_width = status_width
     );
@@ -166,11 +207,12 @@ fn output_table(summaries: &[SpeckSummary]) {
     for summary in summaries {
         let progress = format!("{}/{}", summary.progress.done, summary.progress.total);
         println!(
-            "{:<name_width$}  {:<status_width$}  {:>10}  {:>10}",
+            "{:<name_width$}  {:<status_width$}  {:>10}  {:>10}{}",
             summary.name,
             summary.status,
             progress,
             summary.updated,
+            estimate_columns(Some(summary)),
             name_width = name_width,
             status_width = status_width
         );
@@ -4,6 +4,7 @@
 
 use clap::Subcommand;
 use serde_json;
+use specks_core::{format_iso8601, now_unix};
 
 /// Threshold for log rotation by line count (per D01)
 #[allow(dead_code)] // Will be used in step-1 implementation
@@ -55,75 +56,9 @@ pub enum LogCommands {
 
 /// Generate timestamp in YYYY-MM-DD-HHMMSS format for archive filenames
 fn generate_archive_timestamp() -> Result<String, String> {
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    let duration = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| format!("System time error: {}", e))?;
-
-    let secs = duration.as_secs();
-
-    // Convert to date/time components
-    const SECONDS_PER_DAY: u64 = 86400;
-    const DAYS_TO_EPOCH: i64 = 719162; // Days from 0000-01-01 to 1970-01-01
-
-    let days_since_epoch = (secs / SECONDS_PER_DAY) as i64;
-    let seconds_today = secs % SECONDS_PER_DAY;
-
-    let hours = seconds_today / 3600;
-    let minutes = (seconds_today % 3600) / 60;
-    let seconds = seconds_today % 60;
-
-    // Calculate year, month, day
-    let total_days = DAYS_TO_EPOCH + days_since_epoch;
-
-    let mut year = (total_days / 365) as i32;
-    let mut remaining_days = total_days - year_to_days(year);
-
-    while remaining_days < 0 {
-        year -= 1;
-        remaining_days = total_days - year_to_days(year);
-    }
-    while remaining_days >= days_in_year(year) {
-        remaining_days -= days_in_year(year);
-        year += 1;
-    }
-
-    let is_leap = is_leap_year(year);
-    let mut month = 1;
-    let mut day = remaining_days + 1;
-
-    let days_in_months = if is_leap {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
-
-    for (m, &days) in days_in_months.iter().enumerate() {
-        if day <= days as i64 {
-            month = m + 1;
-            break;
-        }
-        day -= days as i64;
-    }
-
-    Ok(format!(
-        "{:04}-{:02}-{:02}-{:02}{:02}{:02}",
-        year, month, day, hours, minutes, seconds
-    ))
-}
-
-fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
-}
-
-fn days_in_year(year: i32) -> i64 {
-    if is_leap_year(year) { 366 } else { 365 }
-}
-
-fn year_to_days(year: i32) -> i64 {
-    let y = year as i64;
-    y * 365 + y / 4 - y / 100 + y / 400
+    // "YYYY-MM-DDTHH:MM:SSZ" -> "YYYY-MM-DD-HHMMSS"
+    let iso = format_iso8601(now_unix());
+    Ok(format!("{}-{}", &iso[..10], iso[11..19].replace(':', "")))
 }
 
 /// Result of a log rotation operation
@@ -297,62 +232,7 @@ pub fn run_log_rotate(
 
 /// Generate ISO 8601 timestamp (YYYY-MM-DDTHH:MM:SSZ)
 fn generate_iso8601_timestamp() -> Result<String, String> {
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    let duration = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| format!("System time error: {}", e))?;
-
-    let secs = duration.as_secs();
-
-    // Convert to date/time components
-    const SECONDS_PER_DAY: u64 = 86400;
-    const DAYS_TO_EPOCH: i64 = 719162; // Days from 0000-01-01 to 1970-01-01
-
-    let days_since_epoch = (secs / SECONDS_PER_DAY) as i64;
-    let seconds_today = secs % SECONDS_PER_DAY;
-
-    let hours = seconds_today / 3600;
-    let minutes = (seconds_today % 3600) / 60;
-    let seconds = seconds_today % 60;
-
-    // Calculate year, month, day
-    let total_days = DAYS_TO_EPOCH + days_since_epoch;
-
-    let mut year = (total_days / 365) as i32;
-    let mut remaining_days = total_days - year_to_days(year);
-
-    while remaining_days < 0 {
-        year -= 1;
-        remaining_days = total_days - year_to_days(year);
-    }
-    while remaining_days >= days_in_year(year) {
-        remaining_days -= days_in_year(year);
-        year += 1;
-    }
-
-    let is_leap = is_leap_year(year);
-    let mut month = 1;
-    let mut day = remaining_days + 1;
-
-    let days_in_months = if is_leap {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
-
-    for (m, &days) in days_in_months.iter().enumerate() {
-        if day <= days as i64 {
-            month = m + 1;
-            break;
-        }
-        day -= days as i64;
-    }
-
-    Ok(format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-        year, month, day, hours, minutes, seconds
-    ))
+    Ok(format_iso8601(now_unix()))
 }
 
 /// Generate YAML frontmatter entry for the implementation log
@@ -0,0 +1,423 @@
+//! Speck navigation: definitions, references, completion, hover and outline
+//!
+//! Everything here works on 0-based line numbers and byte columns; the
+//! server converts to LSP positions.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use specks_core::Speck;
+
+/// Anchor reference, optionally qualified by a file: `#step-1`, `specks-x.md#t01`
+static ANCHOR_TOKEN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:(?:\./)?\b([A-Za-z0-9_][A-Za-z0-9_.-]*\.md))?#([a-z0-9-]+)").unwrap()
+});
+
+/// Decision or question reference: `[D01]`, `[Q02]`
+static DECISION_TOKEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[([DQ]\d+)\]").unwrap());
+
+/// Something a cursor can point at
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    /// `#name` or `file.md#name`
+    Anchor { file: Option<String>, name: String },
+    /// `[D01]` or `[Q01]`
+    Decision(String),
+}
+
+/// A span of text on one line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Where a token is defined
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    /// Companion file name, or None for the document itself
+    pub file: Option<String>,
+    /// 0-based line
+    pub line: usize,
+}
+
+/// A completion candidate
+#[derive(Debug, Clone, PartialEq)]
+pub struct Completion {
+    pub label: String,
+    pub detail: String,
+    pub insert_text: String,
+}
+
+/// An outline entry with nested children
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub name: String,
+    pub detail: String,
+    /// 0-based heading line
+    pub line: usize,
+    /// 0-based last line of the section
+    pub end_line: usize,
+    pub children: Vec<Symbol>,
+}
+
+/// The token under byte column `col` of `line`, if any
+pub fn token_at(line: &str, col: usize) -> Option<Token> {
+    for cap in DECISION_TOKEN.captures_iter(line) {
+        let m = cap.get(0).unwrap();
+        if (m.start()..=m.end()).contains(&col) {
+            return Some(Token::Decision(cap[1].to_string()));
+        }
+    }
+    for cap in ANCHOR_TOKEN.captures_iter(line) {
+        let m = cap.get(0).unwrap();
+        if (m.start()..=m.end()).contains(&col) {
+            return Some(Token::Anchor {
+                file: cap.get(1).map(|f| f.as_str().to_string()),
+                name: cap[2].to_string(),
+            });
+        }
+    }
+    None
+}
+
+/// Where a token is defined
+pub fn definition(speck: &Speck, token: &Token) -> Option<Definition> {
+    match token {
+        Token::Anchor { file: None, name } => {
+            speck
+                .anchors
+                .iter()
+                .find(|a| a.name == *name)
+                .map(|a| Definition {
+                    file: None,
+                    line: a.line - 1,
+                })
+        }
+        Token::Anchor {
+            file: Some(file),
+            name,
+        } => {
+            let own = speck
+                .path
+                .as_deref()
+                .is_some_and(|p| p.ends_with(file.as_str()));
+            if own {
+                return definition(
+                    speck,
+                    &Token::Anchor {
+                        file: None,
+                        name: name.clone(),
+                    },
+                );
+            }
+            let companion = speck.companion(file)?;
+            companion
+                .anchors
+                .iter()
+                .find(|a| a.name == *name)
+                .map(|a| Definition {
+                    file: Some(file.clone()),
+                    line: a.line - 1,
+                })
+        }
+        Token::Decision(id) => {
+            let line = speck
+                .decisions
+                .iter()
+                .find(|d| d.id == *id)
+                .map(|d| d.line)
+                .or_else(|| speck.questions.iter().find(|q| q.id == *id).map(|q| q.line))?;
+            Some(Definition {
+                file: None,
+                line: line - 1,
+            })
+        }
+    }
+}
+
+/// Every occurrence of a token in the document, including its definition
+///
+/// `other.md#name` points into another file and is not a reference to `#name`.
+pub fn references(text: &str, token: &Token) -> Vec<Span> {
+    let mut spans = Vec::new();
+    for (line_idx, line) in text.lines().enumerate() {
+        match token {
+            Token::Anchor { name, .. } => {
+                for cap in ANCHOR_TOKEN.captures_iter(line) {
+                    if cap.get(1).is_none() && cap[2] == **name {
+                        let m = cap.get(0).unwrap();
+                        spans.push(Span {
+                            line: line_idx,
+                            start: m.start(),
+                            end: m.end(),
+                        });
+                    }
+                }
+            }
+            Token::Decision(id) => {
+                for cap in DECISION_TOKEN.captures_iter(line) {
+                    if cap[1] == **id {
+                        let m = cap.get(0).unwrap();
+                        spans.push(Span {
+                            line: line_idx,
+                            start: m.start(),
+                            end: m.end(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    spans
+}
+
+/// Completions for the text before the cursor
+///
+/// Offered only on `**Depends on:**` lines (step anchors) and
+/// `**References:**` lines (decisions and all anchors).
+pub fn completions(speck: &Speck, before_cursor: &str) -> Vec<Completion> {
+    let hash_typed = before_cursor.ends_with('#');
+    let anchor_item = |name: &str, detail: String| Completion {
+        label: format!("#{}", name),
+        detail,
+        insert_text: if hash_typed {
+            name.to_string()
+        } else {
+            format!("#{}", name)
+        },
+    };
+
+    if before_cursor.starts_with("**Depends on:**") {
+        let mut items = Vec::new();
+        for step in &speck.steps {
+            items.push(anchor_item(
+                &step.anchor,
+                format!("Step {}: {}", step.number, step.title),
+            ));
+            for substep in step.all_substeps() {
+                items.push(anchor_item(
+                    &substep.anchor,
+                    format!("Step {}: {}", substep.number, substep.title),
+                ));
+            }
+        }
+        return items;
+    }
+
+    if before_cursor.starts_with("**References:**") {
+        let mut items = Vec::new();
+        if !hash_typed {
+            for decision in &speck.decisions {
+                items.push(Completion {
+                    label: format!("[{}] {}", decision.id, decision.title),
+                    detail: decision.status.clone().unwrap_or_default(),
+                    insert_text: format!("[{}] {}", decision.id, decision.title),
+                });
+            }
+        }
+        for anchor in &speck.anchors {
+            items.push(anchor_item(&anchor.name, format!("line {}", anchor.line)));
+        }
+        return items;
+    }
+
+    Vec::new()
+}
+
+/// Markdown hover text for a token
+pub fn hover(speck: &Speck, token: &Token) -> Option<String> {
+    match token {
+        Token::Decision(id) => {
+            if let Some(decision) = speck.decision(id) {
+                let mut text = format!("**[{}] {}**", decision.id, decision.title);
+                if let Some(status) = &decision.status {
+                    text.push_str(&format!(" ({})", status));
+                }
+                if let Some(statement) = &decision.statement {
+                    text.push_str(&format!("\n\n{}", statement));
+                }
+                return Some(text);
+            }
+            let question = speck.questions.iter().find(|q| q.id == *id)?;
+            let mut text = format!("**[{}] {}**", question.id, question.title);
+            if let Some(resolution) = &question.resolution {
+                text.push_str(&format!(" ({})", resolution));
+            }
+            Some(text)
+        }
+        Token::Anchor { file: None, name } => {
+            for step in &speck.steps {
+                if step.anchor == *name {
+                    return Some(format!("**Step {}: {}**", step.number, step.title));
+                }
+                if let Some(substep) = step.all_substeps().into_iter().find(|s| s.anchor == *name) {
+                    return Some(format!("**Step {}: {}**", substep.number, substep.title));
+                }
+            }
+            let decision = speck
+                .decisions
+                .iter()
+                .find(|d| d.anchor.as_deref() == Some(name))?;
+            hover(speck, &Token::Decision(decision.id.clone()))
+        }
+        Token::Anchor { file: Some(_), .. } => None,
+    }
+}
+
+/// Document outline: the phase's steps with their substeps nested
+pub fn outline(speck: &Speck, line_count: usize) -> Vec<Symbol> {
+    let last_line = line_count.saturating_sub(1);
+    let step_starts: Vec<usize> = speck.steps.iter().map(|s| s.line - 1).collect();
+
+    speck
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| {
+            let end_line = step_starts
+                .get(i + 1)
+                .map(|next| next - 1)
+                .unwrap_or(last_line);
+
+            // Substeps in document order, each ending where the next begins
+            let subs = step.all_substeps();
+            let children = subs
+                .iter()
+                .enumerate()
+                .map(|(j, sub)| Symbol {
+                    name: format!("Step {}: {}", sub.number, sub.title),
+                    detail: format!("#{}", sub.anchor),
+                    line: sub.line - 1,
+                    end_line: subs
+                        .get(j + 1)
+                        .map(|next| next.line - 2)
+                        .unwrap_or(end_line),
+                    children: Vec::new(),
+                })
+                .collect();
+
+            Symbol {
+                name: format!("Step {}: {}", step.number, step.title),
+                detail: format!("#{}", step.anchor),
+                line: step.line - 1,
+                end_line,
+                children,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specks_core::parse_speck;
+
+    const SPECK: &str = r#"## Phase 1.0: Nav {#phase-1}
+
+#### [D01] Use tokens (DECIDED) {#d01-tokens}
+
+**Decision:** Sessions use signed tokens.
+
+#### Step 0: Setup {#step-0}
+
+**References:** [D01] Use tokens, (#d01-tokens)
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0
+
+**References:** specks-other.md#step-0
+
+##### Step 1.1: Part {#step-1-1}
+
+**Depends on:** #step-0
+"#;
+
+    #[test]
+    fn test_token_at() {
+        let line = "**References:** [D01] Use tokens, (#d01-tokens), x.md#t01";
+        assert_eq!(token_at(line, 18), Some(Token::Decision("D01".to_string())));
+        assert_eq!(
+            token_at(line, 38),
+            Some(Token::Anchor {
+                file: None,
+                name: "d01-tokens".to_string()
+            })
+        );
+        assert_eq!(
+            token_at(line, 52),
+            Some(Token::Anchor {
+                file: Some("x.md".to_string()),
+                name: "t01".to_string()
+            })
+        );
+        assert_eq!(token_at(line, 3), None);
+    }
+
+    #[test]
+    fn test_definition_and_references() {
+        let speck = parse_speck(SPECK).unwrap();
+        let step0 = Token::Anchor {
+            file: None,
+            name: "step-0".to_string(),
+        };
+        assert_eq!(
+            definition(&speck, &step0),
+            Some(Definition {
+                file: None,
+                line: 6
+            })
+        );
+        assert_eq!(
+            definition(&speck, &Token::Decision("D01".to_string())).map(|d| d.line),
+            Some(2)
+        );
+
+        // Definition plus two Depends on lines; the cross-file reference is not counted
+        let lines: Vec<usize> = references(SPECK, &step0).iter().map(|s| s.line).collect();
+        assert_eq!(lines, vec![6, 12, 18]);
+    }
+
+    #[test]
+    fn test_completions_by_line_kind() {
+        let speck = parse_speck(SPECK).unwrap();
+        let deps = completions(&speck, "**Depends on:** #");
+        let labels: Vec<&str> = deps.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(labels, vec!["#step-0", "#step-1", "#step-1-1"]);
+        assert_eq!(deps[0].insert_text, "step-0");
+        assert_eq!(deps[0].detail, "Step 0: Setup");
+
+        let refs = completions(&speck, "**References:** ");
+        assert_eq!(refs[0].label, "[D01] Use tokens");
+        assert!(refs.iter().any(|c| c.insert_text == "#d01-tokens"));
+
+        assert!(completions(&speck, "**Tasks:**").is_empty());
+    }
+
+    #[test]
+    fn test_hover_shows_decision_title() {
+        let speck = parse_speck(SPECK).unwrap();
+        assert_eq!(
+            hover(&speck, &Token::Decision("D01".to_string())).unwrap(),
+            "**[D01] Use tokens** (DECIDED)\n\nSessions use signed tokens."
+        );
+        let d01_anchor = Token::Anchor {
+            file: None,
+            name: "d01-tokens".to_string(),
+        };
+        assert!(hover(&speck, &d01_anchor).unwrap().starts_with("**[D01]"));
+    }
+
+    #[test]
+    fn test_outline_nests_substeps() {
+        let speck = parse_speck(SPECK).unwrap();
+        let symbols = outline(&speck, SPECK.lines().count());
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0].name, "Step 0: Setup");
+        assert_eq!((symbols[0].line, symbols[0].end_line), (6, 9));
+        assert_eq!(symbols[1].children[0].name, "Step 1.1: Part");
+        assert_eq!(symbols[1].children[0].end_line, 18);
+    }
+}
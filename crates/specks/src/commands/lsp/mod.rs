@@ -0,0 +1,522 @@
+//! Implementation of the `specks lsp` command
+//!
+//! A language server over stdio. Open speck documents are re-parsed and
+//! validated on every change, and the parser and validator results are
+//! published as diagnostics. Navigation (definition, references, hover,
+//! completion, outline) is in [`analysis`].
+
+mod analysis;
+mod transport;
+
+use std::collections::HashMap;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Value, json};
+use specks_core::{
+    Config, Severity, Speck, ValidationConfig, ValidationLevel, find_project_root_from,
+    load_companions, parse_speck, validate_speck_with_config,
+};
+
+use analysis::{Symbol, Token};
+use transport::{read_message, write_message};
+
+/// JSON-RPC error: message body is not valid JSON
+const PARSE_ERROR: i64 = -32700;
+/// JSON-RPC error: method not found
+const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC error: invalid request parameters
+const INVALID_PARAMS: i64 = -32602;
+/// `window/showMessage` type: error
+const MESSAGE_ERROR: i64 = 1;
+
+/// Run the language server on stdin/stdout until `exit`
+pub fn run_lsp() -> Result<i32, String> {
+    let stdin = io::stdin();
+    let mut reader = BufReader::new(stdin.lock());
+    let mut stdout = io::stdout().lock();
+    let mut server = Server::default();
+
+    loop {
+        let outgoing = match read_message(&mut reader) {
+            Ok(Some(Ok(message))) => server.handle(&message),
+            // The request ID is unknown, so the error goes out with a null ID
+            Ok(Some(Err(e))) => vec![json!({
+                "jsonrpc": "2.0",
+                "id": Value::Null,
+                "error": { "code": PARSE_ERROR, "message": format!("parse error: {}", e) },
+            })],
+            Ok(None) => break,
+            Err(e) => return Err(format!("lsp: failed to read message: {}", e)),
+        };
+        for outgoing in outgoing {
+            write_message(&mut stdout, &outgoing)
+                .map_err(|e| format!("lsp: failed to write message: {}", e))?;
+        }
+        if server.exited {
+            break;
+        }
+    }
+
+    Ok(if server.shutdown_requested { 0 } else { 1 })
+}
+
+/// An open document
+struct Document {
+    text: String,
+    speck: Speck,
+}
+
+/// Language server state
+#[derive(Default)]
+struct Server {
+    documents: HashMap<String, Document>,
+    shutdown_requested: bool,
+    exited: bool,
+    /// Last project config error shown to the user, so it is shown once
+    config_error: Option<String>,
+}
+
+impl Server {
+    /// Handle one incoming message, returning responses and notifications to send
+    fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+
+        let result = match method {
+            "initialize" => Ok(initialize_result()),
+            "initialized" | "$/cancelRequest" | "$/setTrace" => return Vec::new(),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(Value::Null)
+            }
+            "exit" => {
+                self.exited = true;
+                return Vec::new();
+            }
+            "textDocument/didOpen" => {
+                let doc = &params["textDocument"];
+                return self.update(doc["uri"].as_str(), doc["text"].as_str());
+            }
+            "textDocument/didChange" => {
+                // Full sync: the last change holds the whole document
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                return self.update(params["textDocument"]["uri"].as_str(), text);
+            }
+            "textDocument/didClose" => {
+                let Some(uri) = params["textDocument"]["uri"].as_str() else {
+                    return Vec::new();
+                };
+                self.documents.remove(uri);
+                return vec![publish_diagnostics(uri, Vec::new())];
+            }
+            "textDocument/definition" => self.definition(params),
+            "textDocument/references" => self.references(params),
+            "textDocument/completion" => self.completion(params),
+            "textDocument/hover" => self.hover(params),
+            "textDocument/documentSymbol" => self.document_symbols(params),
+            _ => Err((METHOD_NOT_FOUND, format!("method not found: {}", method))),
+        };
+
+        // Notifications never get a response
+        let Some(id) = id else {
+            return Vec::new();
+        };
+        match result {
+            Ok(result) => vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })],
+            Err((code, message)) => vec![json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message },
+            })],
+        }
+    }
+
+    /// Store new document text and publish its diagnostics
+    fn update(&mut self, uri: Option<&str>, text: Option<&str>) -> Vec<Value> {
+        let (Some(uri), Some(text)) = (uri, text) else {
+            return Vec::new();
+        };
+        let path = uri_to_path(uri);
+        let mut speck = parse_speck(text).unwrap_or_default();
+        if let Some(path) = &path {
+            speck.path = Some(path.display().to_string());
+            if let Some(dir) = path.parent() {
+                load_companions(&mut speck, dir);
+            }
+        }
+
+        let (config, config_error) = validation_config(path.as_deref());
+        let diagnostics = diagnostics(&speck, text, &config);
+        self.documents.insert(
+            uri.to_string(),
+            Document {
+                text: text.to_string(),
+                speck,
+            },
+        );
+
+        let mut out = Vec::new();
+        if config_error != self.config_error {
+            if let Some(message) = &config_error {
+                out.push(show_message(MESSAGE_ERROR, &format!("specks: {}", message)));
+            }
+            self.config_error = config_error;
+        }
+        out.push(publish_diagnostics(uri, diagnostics));
+        out
+    }
+
+    /// The open document and the token under the request's position
+    fn document_and_token(&self, params: &Value) -> Result<(&Document, Option<Token>), RpcError> {
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .ok_or((INVALID_PARAMS, "missing textDocument.uri".to_string()))?;
+        let doc = self
+            .documents
+            .get(uri)
+            .ok_or((INVALID_PARAMS, format!("document not open: {}", uri)))?;
+        let (line, col) = position(&doc.text, &params["position"]);
+        let token = doc
+            .text
+            .lines()
+            .nth(line)
+            .and_then(|text| analysis::token_at(text, col));
+        Ok((doc, token))
+    }
+
+    fn definition(&self, params: &Value) -> Result<Value, RpcError> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let (doc, token) = self.document_and_token(params)?;
+        let Some(def) = token.and_then(|t| analysis::definition(&doc.speck, &t)) else {
+            return Ok(Value::Null);
+        };
+        let target = match &def.file {
+            None => uri.to_string(),
+            Some(file) => sibling_uri(uri, file),
+        };
+        Ok(json!({ "uri": target, "range": line_range(def.line, 0, 0) }))
+    }
+
+    fn references(&self, params: &Value) -> Result<Value, RpcError> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let (doc, token) = self.document_and_token(params)?;
+        let Some(token) = token else {
+            return Ok(json!([]));
+        };
+        let include_declaration = params["context"]["includeDeclaration"]
+            .as_bool()
+            .unwrap_or(true);
+        let declaration = analysis::definition(&doc.speck, &token)
+            .filter(|d| d.file.is_none())
+            .map(|d| d.line);
+        let lines: Vec<&str> = doc.text.lines().collect();
+
+        let locations: Vec<Value> = analysis::references(&doc.text, &token)
+            .into_iter()
+            .filter(|span| include_declaration || Some(span.line) != declaration)
+            .map(|span| {
+                let text = lines[span.line];
+                json!({
+                    "uri": uri,
+                    "range": line_range(
+                        span.line,
+                        utf16_len(&text[..span.start]),
+                        utf16_len(&text[..span.end]),
+                    ),
+                })
+            })
+            .collect();
+        Ok(json!(locations))
+    }
+
+    fn completion(&self, params: &Value) -> Result<Value, RpcError> {
+        let (doc, _) = self.document_and_token(params)?;
+        let (line, col) = position(&doc.text, &params["position"]);
+        let before = doc
+            .text
+            .lines()
+            .nth(line)
+            .map(|text| &text[..col.min(text.len())])
+            .unwrap_or_default();
+
+        let items: Vec<Value> = analysis::completions(&doc.speck, before)
+            .into_iter()
+            .map(|c| {
+                json!({
+                    "label": c.label,
+                    "kind": 18, // Reference
+                    "detail": c.detail,
+                    "insertText": c.insert_text,
+                })
+            })
+            .collect();
+        Ok(json!({ "isIncomplete": false, "items": items }))
+    }
+
+    fn hover(&self, params: &Value) -> Result<Value, RpcError> {
+        let (doc, token) = self.document_and_token(params)?;
+        let Some(text) = token.and_then(|t| analysis::hover(&doc.speck, &t)) else {
+            return Ok(Value::Null);
+        };
+        Ok(json!({ "contents": { "kind": "markdown", "value": text } }))
+    }
+
+    fn document_symbols(&self, params: &Value) -> Result<Value, RpcError> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(doc) = self.documents.get(uri) else {
+            return Ok(json!([]));
+        };
+        let lines: Vec<&str> = doc.text.lines().collect();
+        let symbols = analysis::outline(&doc.speck, lines.len());
+        Ok(json!(
+            symbols
+                .iter()
+                .map(|s| symbol_json(s, &lines))
+                .collect::<Vec<_>>()
+        ))
+    }
+}
+
+/// Error code and message for a failed request
+type RpcError = (i64, String);
+
+/// Server capabilities
+fn initialize_result() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": 1, // Full
+            "definitionProvider": true,
+            "referencesProvider": true,
+            "hoverProvider": true,
+            "documentSymbolProvider": true,
+            "completionProvider": { "triggerCharacters": ["#", "["] },
+        },
+        "serverInfo": { "name": "specks", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+/// Validation settings from the project containing `path`, or the defaults
+///
+/// A config file that cannot be loaded, invalid rule settings and invalid
+/// custom rules are left out, and the error is returned alongside so it can
+/// be shown to the user.
+fn validation_config(path: Option<&Path>) -> (ValidationConfig, Option<String>) {
+    let mut error = None;
+    let config = path
+        .and_then(|p| p.parent())
+        .and_then(|dir| find_project_root_from(dir.to_path_buf()).ok())
+        .map(|root| Config::load_from_project(&root))
+        .transpose()
+        .unwrap_or_else(|e| {
+            error = Some(e.to_string());
+            None
+        })
+        .unwrap_or_default();
+    let validation = &config.specks.validation;
+    let rules = validation.rule_settings().unwrap_or_else(|e| {
+        error = Some(e.to_string());
+        Default::default()
+    });
+    let custom_rules = validation.custom_rules().unwrap_or_else(|e| {
+        error.get_or_insert(e.to_string());
+        Vec::new()
+    });
+    let config = ValidationConfig {
+        level: ValidationLevel::parse(&config.specks.validation_level),
+        rules,
+        custom_rules,
+        ..Default::default()
+    };
+    (config, error)
+}
+
+/// LSP diagnostics for a parsed speck
+fn diagnostics(speck: &Speck, text: &str, config: &ValidationConfig) -> Vec<Value> {
+    let result = validate_speck_with_config(speck, config);
+    let lines: Vec<&str> = text.lines().collect();
+    let range_for = |line: usize| {
+        let line = line.saturating_sub(1);
+        let len = lines.get(line).map(|l| utf16_len(l)).unwrap_or(0);
+        line_range(line, 0, len)
+    };
+
+    let mut out = Vec::new();
+    for issue in &result.issues {
+        let line = issue.line.or_else(|| {
+            let anchor = issue.anchor.as_deref()?.trim_start_matches('#');
+            speck
+                .anchors
+                .iter()
+                .find(|a| a.name == anchor)
+                .map(|a| a.line)
+        });
+        out.push(json!({
+            "range": range_for(line.unwrap_or(1)),
+            "severity": match issue.severity {
+                Severity::Error => 1,
+                Severity::Warning => 2,
+                Severity::Info => 3,
+            },
+            "code": issue.code,
+            "source": "specks",
+            "message": issue.message,
+        }));
+    }
+    for diagnostic in &result.diagnostics {
+        let message = match &diagnostic.suggestion {
+            Some(suggestion) => format!("{}\n{}", diagnostic.message, suggestion),
+            None => diagnostic.message.clone(),
+        };
+        out.push(json!({
+            "range": range_for(diagnostic.line),
+            "severity": 2,
+            "code": diagnostic.code,
+            "source": "specks",
+            "message": message,
+        }));
+    }
+    out
+}
+
+/// `textDocument/publishDiagnostics` notification
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+/// `window/showMessage` notification
+fn show_message(kind: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "window/showMessage",
+        "params": { "type": kind, "message": message },
+    })
+}
+
+/// DocumentSymbol for an outline entry
+fn symbol_json(symbol: &Symbol, lines: &[&str]) -> Value {
+    let end_len = lines
+        .get(symbol.end_line)
+        .map(|l| utf16_len(l))
+        .unwrap_or(0);
+    let heading_len = lines.get(symbol.line).map(|l| utf16_len(l)).unwrap_or(0);
+    json!({
+        "name": symbol.name,
+        "detail": symbol.detail,
+        "kind": 3, // Namespace
+        "range": {
+            "start": { "line": symbol.line, "character": 0 },
+            "end": { "line": symbol.end_line, "character": end_len },
+        },
+        "selectionRange": line_range(symbol.line, 0, heading_len),
+        "children": symbol
+            .children
+            .iter()
+            .map(|child| symbol_json(child, lines))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// A range within one line (UTF-16 columns)
+fn line_range(line: usize, start: usize, end: usize) -> Value {
+    json!({
+        "start": { "line": line, "character": start },
+        "end": { "line": line, "character": end },
+    })
+}
+
+/// (line, byte column) for an LSP position, whose column counts UTF-16 units
+fn position(text: &str, position: &Value) -> (usize, usize) {
+    let line = position["line"].as_u64().unwrap_or(0) as usize;
+    let character = position["character"].as_u64().unwrap_or(0) as usize;
+    let Some(text) = text.lines().nth(line) else {
+        return (line, 0);
+    };
+
+    let mut units = 0;
+    for (byte, c) in text.char_indices() {
+        if units >= character {
+            return (line, byte);
+        }
+        units += c.len_utf16();
+    }
+    (line, text.len())
+}
+
+/// Length of a string in UTF-16 code units
+fn utf16_len(s: &str) -> usize {
+    s.chars().map(char::len_utf16).sum()
+}
+
+/// Local path for a `file://` URI
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut iter = path.bytes();
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex: Vec<u8> = iter.by_ref().take(2).collect();
+            let decoded = std::str::from_utf8(&hex)
+                .ok()
+                .and_then(|h| u8::from_str_radix(h, 16).ok())?;
+            bytes.push(decoded);
+        } else {
+            bytes.push(b);
+        }
+    }
+    Some(PathBuf::from(String::from_utf8(bytes).ok()?))
+}
+
+/// URI of another file in the same directory as `uri`
+fn sibling_uri(uri: &str, file: &str) -> String {
+    match uri.rfind('/') {
+        Some(slash) => format!("{}/{}", &uri[..slash], file),
+        None => file.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_counts_utf16_units() {
+        let text = "a\n\u{1F600}#step-0\n";
+        let pos = json!({ "line": 1, "character": 2 });
+        assert_eq!(position(text, &pos), (1, 4));
+        assert_eq!(utf16_len("\u{1F600}#"), 3);
+    }
+
+    #[test]
+    fn test_uri_helpers() {
+        assert_eq!(
+            uri_to_path("file:///tmp/my%20specks/specks-a.md"),
+            Some(PathBuf::from("/tmp/my specks/specks-a.md"))
+        );
+        assert_eq!(uri_to_path("untitled:1"), None);
+        assert_eq!(
+            sibling_uri("file:///p/.specks/specks-a.md", "specks-b.md"),
+            "file:///p/.specks/specks-b.md"
+        );
+    }
+
+    #[test]
+    fn test_unknown_request_and_notification() {
+        let mut server = Server::default();
+        let out = server.handle(&json!({ "jsonrpc": "2.0", "id": 7, "method": "foo/bar" }));
+        assert_eq!(out[0]["error"]["code"], METHOD_NOT_FOUND);
+        assert!(
+            server
+                .handle(&json!({ "jsonrpc": "2.0", "method": "foo/bar" }))
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! LSP base protocol: `Content-Length` framed JSON-RPC messages
+
+use std::io::{self, BufRead, Read, Write};
+
+use serde_json::Value;
+
+/// Largest message body read, so a bogus header cannot exhaust memory
+pub const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+/// A message that was received but could not be used
+#[derive(Debug, thiserror::Error)]
+pub enum MessageError {
+    /// `Content-Length` is not a number; the body cannot be found
+    #[error("invalid Content-Length {0:?}")]
+    InvalidLength(String),
+    /// `Content-Length` is over [`MAX_CONTENT_LENGTH`]; the body was skipped
+    #[error("Content-Length {0} exceeds the {MAX_CONTENT_LENGTH} byte limit")]
+    TooLong(usize),
+    /// The body is not valid JSON
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// Read one message; `None` at end of input
+///
+/// A body that is not valid JSON or too long is returned as `Some(Err(_))`:
+/// its framing was intact, so the next message can still be read. After an
+/// unparsable `Content-Length`, reading resumes at the next complete header.
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Result<Value, MessageError>>> {
+    let mut content_length: Option<usize> = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                match value.trim().parse() {
+                    Ok(length) => content_length = Some(length),
+                    Err(_) => {
+                        return Ok(Some(Err(MessageError::InvalidLength(
+                            value.trim().to_string(),
+                        ))));
+                    }
+                }
+            }
+        }
+    }
+
+    let content_length = content_length.unwrap_or(0);
+    if content_length > MAX_CONTENT_LENGTH {
+        let skipped = io::copy(
+            &mut (&mut *reader).take(content_length as u64),
+            &mut io::sink(),
+        )?;
+        if skipped < content_length as u64 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        return Ok(Some(Err(MessageError::TooLong(content_length))));
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Some(
+        serde_json::from_slice(&body).map_err(MessageError::from),
+    ))
+}
+
+/// Write one message
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_string(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip() {
+        let mut buf = Vec::new();
+        write_message(&mut buf, &json!({ "id": 1, "method": "x" })).unwrap();
+        write_message(&mut buf, &json!({ "id": 2 })).unwrap();
+
+        let mut reader = io::Cursor::new(buf);
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap()["method"],
+            "x"
+        );
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap()["id"],
+            2
+        );
+        assert!(read_message(&mut reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_invalid_body_does_not_end_input() {
+        let mut buf = b"Content-Length: 5\r\n\r\n{oops".to_vec();
+        write_message(&mut buf, &json!({ "id": 3 })).unwrap();
+
+        let mut reader = io::Cursor::new(buf);
+        assert!(read_message(&mut reader).unwrap().unwrap().is_err());
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap()["id"],
+            3
+        );
+    }
+
+    #[test]
+    fn test_invalid_length_is_a_parse_error() {
+        let mut buf = b"Content-Length: five\r\n\r\n".to_vec();
+        write_message(&mut buf, &json!({ "id": 4 })).unwrap();
+
+        let mut reader = io::Cursor::new(buf);
+        assert!(matches!(
+            read_message(&mut reader).unwrap().unwrap(),
+            Err(MessageError::InvalidLength(value)) if value == "five"
+        ));
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap()["id"],
+            4
+        );
+    }
+
+    #[test]
+    fn test_oversized_body_is_skipped_without_allocating() {
+        let length = MAX_CONTENT_LENGTH + 1;
+        let mut buf = format!("Content-Length: {}\r\n\r\n", length).into_bytes();
+        buf.resize(buf.len() + length, b' ');
+        write_message(&mut buf, &json!({ "id": 5 })).unwrap();
+
+        let mut reader = io::Cursor::new(buf);
+        assert!(matches!(
+            read_message(&mut reader).unwrap().unwrap(),
+            Err(MessageError::TooLong(n)) if n == length
+        ));
+        assert_eq!(
+            read_message(&mut reader).unwrap().unwrap().unwrap()["id"],
+            5
+        );
+
+        // A claimed length far past the input ends it instead of aborting
+        let mut reader = io::Cursor::new(b"Content-Length: 99999999999\r\n\r\n{}".to_vec());
+        assert!(read_message(&mut reader).is_err());
+    }
+}
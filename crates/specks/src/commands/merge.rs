@@ -7,9 +7,11 @@
 //! - Remote: Has origin remote → merge PR via `gh pr merge --squash`
 //! - Local: No remote → `git merge --squash` directly
 
+use crate::commands::beads::open_tracker;
 use serde::{Deserialize, Serialize};
 use specks_core::{
-    BeadsCli, Step, derive_speck_slug, find_worktree_by_speck, parse_speck, remove_worktree,
+    Config, IssueTracker, Step, derive_speck_slug, find_worktree_by_speck, parse_speck,
+    remove_worktree,
 };
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -213,7 +215,11 @@ fn check_worktree_dirty(wt_path: &Path) -> Result<Option<String>, String> {
 
 /// P1 preflight check: warn if beads/steps are not all complete.
 /// Returns None if all complete, beads unavailable, or beads not configured.
-fn check_bead_completion(repo_root: &Path, speck_path: &Path) -> Option<String> {
+fn check_bead_completion(
+    repo_root: &Path,
+    speck_path: &Path,
+    beads: &dyn IssueTracker,
+) -> Option<String> {
     // Read and parse the speck file
     let full_path = repo_root.join(speck_path);
     let content = std::fs::read_to_string(&full_path).ok()?;
@@ -226,8 +232,7 @@ fn check_bead_completion(repo_root: &Path, speck_path: &Path) -> Option<String>
         return None; // No beads configured, skip check silently
     }
 
-    // Check bead completion via BeadsCli
-    let beads = BeadsCli::default();
+    // Check bead completion via the tracker
     if !beads.is_installed(None) {
         return None; // bd not installed, skip silently
     }
@@ -438,8 +443,14 @@ fn run_preflight_checks(
     }
 
     // P1: Bead completion check (warning only)
-    if let Some(warning) = check_bead_completion(repo_root, speck_path) {
-        warnings.push(warning);
+    let config = Config::load_from_project(repo_root).unwrap_or_default();
+    match open_tracker(&config, repo_root) {
+        Ok(tracker) => {
+            if let Some(warning) = check_bead_completion(repo_root, speck_path, &*tracker) {
+                warnings.push(warning);
+            }
+        }
+        Err(e) => warnings.push(format!("Could not check bead completion: {}", e)),
     }
 
     // P2: Branch divergence and infrastructure diff (dry-run only)
@@ -1546,6 +1557,7 @@ pub fn run_merge(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use specks_core::BeadsCli;
     use std::fs;
     use std::process::Command;
 
@@ -2413,7 +2425,11 @@ mod tests {
         )
         .unwrap();
 
-        let result = check_bead_completion(temp_path, Path::new(".specks/specks-test.md"));
+        let result = check_bead_completion(
+            temp_path,
+            Path::new(".specks/specks-test.md"),
+            &BeadsCli::default(),
+        );
         assert!(result.is_none(), "No beads configured should return None");
     }
 
@@ -2433,7 +2449,11 @@ mod tests {
         .unwrap();
 
         // When bd is not installed, should return None (skip silently)
-        let result = check_bead_completion(temp_path, Path::new(".specks/specks-test.md"));
+        let result = check_bead_completion(
+            temp_path,
+            Path::new(".specks/specks-test.md"),
+            &BeadsCli::default(),
+        );
         // Result depends on whether bd is installed in the test environment
         // If bd is not installed: None (silently skipped)
         // If bd is installed but bead doesn't exist: None (show fails, treated as incomplete)
@@ -2441,6 +2461,53 @@ mod tests {
         let _ = result;
     }
 
+    #[test]
+    fn test_check_bead_completion_counts_open_beads() {
+        use specks_core::MemoryTracker;
+        use tempfile::TempDir;
+        let temp_dir = TempDir::new().unwrap();
+        let temp_path = temp_dir.path();
+
+        let tracker = MemoryTracker::default();
+        let root = tracker
+            .create("Root", None, None, None, None, None, None, None, None)
+            .unwrap();
+        for title in ["Step 0", "Step 1"] {
+            tracker
+                .create(
+                    title,
+                    None,
+                    Some(&root.id),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        tracker.close("mem-1.1", None, None).unwrap();
+
+        let specks_dir = temp_path.join(".specks");
+        fs::create_dir_all(&specks_dir).unwrap();
+        fs::write(
+            specks_dir.join("specks-test.md"),
+            "## Phase 1 {#phase-1}\n\n---\n\n### 1.0.4 Execution Steps {#execution-steps}\n\n#### Step 0: One {#step-0}\n\n**Bead:** `mem-1.1`\n\n**Tasks:**\n- [ ] Do a thing\n\n#### Step 1: Two {#step-1}\n\n**Bead:** `mem-1.2`\n\n**Tasks:**\n- [ ] Do another\n",
+        )
+        .unwrap();
+
+        let speck_path = Path::new(".specks/specks-test.md");
+        assert_eq!(
+            check_bead_completion(temp_path, speck_path, &tracker).as_deref(),
+            Some(
+                "1 of 2 steps incomplete. Run 'specks beads status .specks/specks-test.md' to review."
+            )
+        );
+        tracker.close("mem-1.2", None, None).unwrap();
+        assert!(check_bead_completion(temp_path, speck_path, &tracker).is_none());
+    }
+
     #[test]
     fn test_check_bead_completion_missing_file_returns_none() {
         use tempfile::TempDir;
@@ -2448,7 +2515,11 @@ mod tests {
         let temp_path = temp_dir.path();
 
         // Speck file does not exist
-        let result = check_bead_completion(temp_path, Path::new(".specks/specks-nonexistent.md"));
+        let result = check_bead_completion(
+            temp_path,
+            Path::new(".specks/specks-nonexistent.md"),
+            &BeadsCli::default(),
+        );
         assert!(result.is_none(), "Missing speck file should return None");
     }
 
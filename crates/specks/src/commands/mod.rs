@@ -2,10 +2,15 @@
 
 pub mod beads;
 pub mod doctor;
+pub mod fmt;
 pub mod init;
+pub mod labels;
 pub mod list;
 pub mod log;
+pub mod lsp;
 pub mod merge;
+pub mod new;
+pub mod next;
 pub mod status;
 pub mod step_commit;
 pub mod step_publish;
@@ -18,14 +23,19 @@ pub use beads::{
     run_link, run_pull, run_sync, run_update_notes,
 };
 pub use doctor::run_doctor;
+pub use fmt::run_fmt;
 pub use init::run_init;
+pub use labels::run_labels;
 pub use list::run_list;
 pub use log::{LogCommands, run_log_prepend, run_log_rotate};
+pub use lsp::run_lsp;
 pub use merge::run_merge;
+pub use new::{NewOptions, run_new};
+pub use next::run_next;
 pub use status::run_status;
 pub use step_commit::run_step_commit;
 pub use step_publish::run_step_publish;
-pub use validate::run_validate;
+pub use validate::{ValidateOptions, run_validate};
 pub use version::run_version;
 pub use worktree::{
     WorktreeCommands, run_worktree_cleanup, run_worktree_create, run_worktree_list,
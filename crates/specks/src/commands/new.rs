@@ -0,0 +1,277 @@
+//! Implementation of the `specks new` command
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use specks_core::{
+    Config, SpecksError, ValidationConfig, ValidationLevel, find_project_root, format_speck,
+    now_iso8601, parse_speck, render_template, title_from_name, validate_speck_with_config,
+};
+
+use crate::commands::init::TEMPLATES;
+use crate::output::{JsonIssue, JsonResponse, NewData};
+
+/// Options for `specks new`
+pub struct NewOptions {
+    pub name: String,
+    pub template: String,
+    pub title: Option<String>,
+    pub owner: Option<String>,
+    pub status: String,
+    pub vars: Vec<String>,
+    pub json_output: bool,
+    pub quiet: bool,
+}
+
+/// Run the new command
+///
+/// The generated speck is validated at lenient level before it is written,
+/// so a broken template never leaves a half-valid speck behind. It is written
+/// in `specks fmt` form, since substituted values change column widths.
+pub fn run_new(opts: NewOptions) -> Result<i32, String> {
+    let json_output = opts.json_output;
+
+    let project_root = match find_project_root() {
+        Ok(root) => root,
+        Err(_) => {
+            return output_error(json_output, "E009", ".specks directory not initialized", 9);
+        }
+    };
+
+    let config = match Config::load_from_project(&project_root) {
+        Ok(c) => c,
+        Err(e) => return output_specks_error(json_output, &e),
+    };
+    let naming = &config.specks.naming;
+
+    if let Err(e) = naming.validate_name(&opts.name) {
+        return output_specks_error(json_output, &e);
+    }
+
+    let file_name = naming.file_name(&opts.name);
+    let path = project_root.join(".specks").join(&file_name);
+    let relative_path = format!(".specks/{}", file_name);
+    if path.exists() {
+        return output_specks_error(
+            json_output,
+            &SpecksError::SpeckAlreadyExists {
+                path: relative_path,
+            },
+        );
+    }
+
+    let template = match load_template(&project_root, &opts.template) {
+        Ok(t) => t,
+        Err(e) => return output_specks_error(json_output, &e),
+    };
+
+    let mut vars = BTreeMap::new();
+    vars.insert("name".to_string(), opts.name.clone());
+    vars.insert(
+        "title".to_string(),
+        opts.title
+            .clone()
+            .unwrap_or_else(|| title_from_name(&opts.name)),
+    );
+    vars.insert(
+        "owner".to_string(),
+        opts.owner.clone().unwrap_or_else(default_owner),
+    );
+    vars.insert("date".to_string(), now_iso8601()[..10].to_string());
+    vars.insert("status".to_string(), opts.status.to_lowercase());
+    for var in &opts.vars {
+        let Some((key, value)) = var
+            .split_once('=')
+            .filter(|(key, _)| !key.trim().is_empty())
+        else {
+            return output_error(
+                json_output,
+                "E002",
+                &format!("invalid --var {:?} (expected key=value)", var),
+                2,
+            );
+        };
+        vars.insert(key.trim().to_string(), value.to_string());
+    }
+
+    let content = match render_template(&template, &vars) {
+        Ok(c) => format_speck(&c).unwrap_or(c),
+        Err(e) => return output_specks_error(json_output, &e),
+    };
+
+    // The result must be a valid speck before anything is written
+    let speck = parse_speck(&content).map_err(|e| e.to_string())?;
+    let validation_config = ValidationConfig {
+        level: ValidationLevel::Lenient,
+        ..Default::default()
+    };
+    let result = validate_speck_with_config(&speck, &validation_config);
+    if !result.valid {
+        let issues: Vec<JsonIssue> = result
+            .issues
+            .iter()
+            .map(|i| JsonIssue::from(i).with_file(&relative_path))
+            .collect();
+        if json_output {
+            let response =
+                JsonResponse::error("new", new_data(&relative_path, &opts, false), issues);
+            println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        } else {
+            eprintln!(
+                "error: template '{}' does not produce a valid speck:",
+                opts.template
+            );
+            for issue in &issues {
+                match issue.line {
+                    Some(line) => eprintln!("  {} line {}: {}", issue.code, line, issue.message),
+                    None => eprintln!("  {}: {}", issue.code, issue.message),
+                }
+            }
+        }
+        return Ok(1);
+    }
+
+    fs::write(&path, &content).map_err(|e| format!("failed to write {}: {}", file_name, e))?;
+
+    if json_output {
+        let response = JsonResponse::ok("new", new_data(&relative_path, &opts, true));
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else if !opts.quiet {
+        println!(
+            "Created {} from template '{}'",
+            relative_path, opts.template
+        );
+    }
+
+    Ok(0)
+}
+
+/// Load a template by name: `.specks/templates/<name>.md`, then the built-ins
+fn load_template(project_root: &Path, name: &str) -> Result<String, SpecksError> {
+    let path = project_root
+        .join(".specks")
+        .join("templates")
+        .join(format!("{}.md", name));
+    if let Ok(content) = fs::read_to_string(&path) {
+        return Ok(content);
+    }
+    TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, content)| content.to_string())
+        .ok_or_else(|| SpecksError::TemplateNotFound {
+            name: name.to_string(),
+        })
+}
+
+/// Owner for a new speck: git user.name, then $USER
+fn default_owner() -> String {
+    Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| std::env::var("USER").ok().filter(|u| !u.is_empty()))
+        .unwrap_or_else(|| "TBD".to_string())
+}
+
+/// Build the JSON data payload
+fn new_data(path: &str, opts: &NewOptions, created: bool) -> NewData {
+    NewData {
+        path: path.to_string(),
+        name: opts.name.clone(),
+        template: opts.template.clone(),
+        created,
+    }
+}
+
+/// Output a core error with its code and exit code
+fn output_specks_error(json_output: bool, error: &SpecksError) -> Result<i32, String> {
+    output_error(
+        json_output,
+        error.code(),
+        &error.to_string(),
+        error.exit_code(),
+    )
+}
+
+/// Output an error in JSON or text format
+fn output_error(
+    json_output: bool,
+    code: &str,
+    message: &str,
+    exit_code: i32,
+) -> Result<i32, String> {
+    if json_output {
+        let issues = vec![JsonIssue {
+            code: code.to_string(),
+            severity: "error".to_string(),
+            message: message.to_string(),
+            file: None,
+            line: None,
+            anchor: None,
+        }];
+        let response: JsonResponse<NewData> = JsonResponse::error(
+            "new",
+            NewData {
+                path: String::new(),
+                name: String::new(),
+                template: String::new(),
+                created: false,
+            },
+            issues,
+        );
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else {
+        eprintln!("error: {}", message);
+    }
+    Ok(exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_templates_pass_lenient_validation() {
+        let vars: BTreeMap<String, String> = [
+            ("name", "user-auth"),
+            ("title", "User Auth"),
+            ("owner", "Test Owner"),
+            ("date", "2026-02-03"),
+            ("status", "draft"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        for (name, template) in TEMPLATES {
+            assert_eq!(
+                format_speck(template).unwrap(),
+                *template,
+                "template {} is not in canonical form",
+                name
+            );
+            let content = render_template(template, &vars).unwrap();
+            let speck = parse_speck(&content).unwrap();
+            let result = validate_speck_with_config(
+                &speck,
+                &ValidationConfig {
+                    level: ValidationLevel::Lenient,
+                    ..Default::default()
+                },
+            );
+            assert!(
+                result.valid,
+                "template {} has errors: {:?}",
+                name, result.issues
+            );
+            assert_eq!(speck.metadata.owner.as_deref(), Some("Test Owner"));
+        }
+    }
+}
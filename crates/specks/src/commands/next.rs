@@ -0,0 +1,344 @@
+//! Implementation of the `specks next` command
+//!
+//! Works out which steps can be started from the `**Depends on:**` graph and
+//! checkbox completion alone, so it needs no beads backend.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use specks_core::{Speck, StepGraph, find_project_root, parse_speck, speck_name_from_path};
+
+use crate::commands::status::external_blockers;
+use crate::output::{JsonIssue, JsonResponse, NextData, NextStep};
+
+/// Run the next command
+pub fn run_next(file: String, json_output: bool, quiet: bool) -> Result<i32, String> {
+    let project_root = match find_project_root() {
+        Ok(root) => root,
+        Err(_) => {
+            return output_error(
+                json_output,
+                "E009",
+                ".specks directory not initialized",
+                &file,
+                9,
+            );
+        }
+    };
+
+    let path = resolve_file_path(&project_root, &file);
+    if !path.exists() {
+        return output_error(
+            json_output,
+            "E002",
+            &format!("file not found: {}", file),
+            &file,
+            2,
+        );
+    }
+
+    let content = match fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            return output_error(
+                json_output,
+                "E002",
+                &format!("failed to read file: {}", e),
+                &file,
+                2,
+            );
+        }
+    };
+
+    let speck = match parse_speck(&content) {
+        Ok(s) => s,
+        Err(e) => {
+            return output_error(
+                json_output,
+                "E001",
+                &format!("failed to parse speck: {}", e),
+                &file,
+                1,
+            );
+        }
+    };
+
+    let name = speck_name_from_path(&path).unwrap_or_else(|| file.clone());
+    let data = build_next_data(&speck, &name, path.parent());
+
+    if json_output {
+        let response = JsonResponse::ok("next", data);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else if !quiet {
+        print!("{}", format_next_text(&data));
+    }
+
+    Ok(0)
+}
+
+/// Classify the unfinished steps of a speck as ready or blocked and group
+/// them into waves; `dir` is where other specks' steps are looked up
+fn build_next_data(speck: &Speck, name: &str, dir: Option<&Path>) -> NextData {
+    let graph = StepGraph::from_speck(speck);
+    let complete: Vec<bool> = speck.steps.iter().map(|s| s.is_complete()).collect();
+    let external = dir
+        .map(|dir| external_blockers(speck, dir))
+        .unwrap_or_default();
+
+    let mut ready = Vec::new();
+    let mut blocked = Vec::new();
+    let mut waiting = vec![false; graph.nodes.len()];
+    for (index, node) in graph.nodes.iter().enumerate() {
+        if complete[index] {
+            continue;
+        }
+        let step = node.step;
+        let own_anchors: Vec<String> = std::iter::once(&step.anchor)
+            .chain(step.all_substeps().into_iter().map(|s| &s.anchor))
+            .map(|a| format!("#{}", a))
+            .collect();
+
+        let mut blocked_by: Vec<String> = node
+            .deps
+            .iter()
+            .filter(|&&dep| !complete[dep])
+            .map(|&dep| format!("#{}", graph.nodes[dep].step.anchor))
+            .collect();
+        for blocker in &external {
+            let applies = blocker
+                .anchor
+                .as_ref()
+                .is_none_or(|a| own_anchors.contains(a));
+            if applies {
+                waiting[index] = true;
+                if !blocked_by.contains(&blocker.dependency) {
+                    blocked_by.push(blocker.dependency.clone());
+                }
+            }
+        }
+
+        let entry = NextStep {
+            anchor: format!("#{}", step.anchor),
+            number: step.number.clone(),
+            title: step.title.clone(),
+            blocked_by,
+        };
+        if entry.blocked_by.is_empty() {
+            ready.push(entry);
+        } else {
+            blocked.push(entry);
+        }
+    }
+
+    let waves = graph
+        .waves(&complete, &waiting)
+        .into_iter()
+        .map(|wave| {
+            wave.into_iter()
+                .map(|i| format!("#{}", graph.nodes[i].step.anchor))
+                .collect()
+        })
+        .collect();
+
+    NextData {
+        name: name.to_string(),
+        complete: complete.iter().filter(|&&c| c).count(),
+        total: speck.steps.len(),
+        ready,
+        blocked,
+        waves,
+    }
+}
+
+/// Render next data as text
+fn format_next_text(data: &NextData) -> String {
+    let mut output = format!(
+        "{}: {} ready, {} blocked, {}/{} steps complete\n",
+        data.name,
+        data.ready.len(),
+        data.blocked.len(),
+        data.complete,
+        data.total
+    );
+    if data.ready.is_empty() && data.blocked.is_empty() {
+        return output;
+    }
+
+    let step_label =
+        |step: &NextStep| format!("Step {}: {} ({})", step.number, step.title, step.anchor);
+    if !data.ready.is_empty() {
+        output.push_str("\nReady:\n");
+        for step in &data.ready {
+            output.push_str(&format!("  {}\n", step_label(step)));
+        }
+    }
+    if !data.blocked.is_empty() {
+        output.push_str("\nBlocked:\n");
+        for step in &data.blocked {
+            output.push_str(&format!(
+                "  {} <- waiting on {}\n",
+                step_label(step),
+                step.blocked_by.join(", ")
+            ));
+        }
+    }
+    if !data.waves.is_empty() {
+        output.push_str("\nWaves:\n");
+        for (index, wave) in data.waves.iter().enumerate() {
+            let steps = if wave.is_empty() {
+                "(waiting on other specks)".to_string()
+            } else {
+                wave.join(", ")
+            };
+            output.push_str(&format!("  {}: {}\n", index + 1, steps));
+        }
+    }
+    output
+}
+
+/// Resolve file path relative to project
+fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
+    let path = Path::new(file);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else if file.starts_with(".specks/") || file.starts_with(".specks\\") {
+        project_root.join(file)
+    } else if file.starts_with("specks-") || file.ends_with(".md") {
+        let filename = if file.starts_with("specks-") && file.ends_with(".md") {
+            file.to_string()
+        } else if file.starts_with("specks-") {
+            format!("{}.md", file)
+        } else {
+            format!("specks-{}.md", file)
+        };
+        project_root.join(".specks").join(filename)
+    } else {
+        let as_is = project_root.join(file);
+        if as_is.exists() {
+            as_is
+        } else {
+            project_root
+                .join(".specks")
+                .join(format!("specks-{}.md", file))
+        }
+    }
+}
+
+/// Output an error in JSON or text format
+fn output_error(
+    json_output: bool,
+    code: &str,
+    message: &str,
+    file: &str,
+    exit_code: i32,
+) -> Result<i32, String> {
+    if json_output {
+        let issues = vec![JsonIssue {
+            code: code.to_string(),
+            severity: "error".to_string(),
+            message: message.to_string(),
+            file: Some(file.to_string()),
+            line: None,
+            anchor: None,
+        }];
+        let response: JsonResponse<NextData> = JsonResponse::error(
+            "next",
+            NextData {
+                name: String::new(),
+                complete: 0,
+                total: 0,
+                ready: vec![],
+                blocked: vec![],
+                waves: vec![],
+            },
+            issues,
+        );
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else {
+        eprintln!("error: {}", message);
+    }
+    Ok(exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECK: &str = r#"## Phase 1.0: Next {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Tasks:**
+- [x] Create module
+
+#### Step 1: Model {#step-1}
+
+**Depends on:** #step-0
+
+**Tasks:**
+- [ ] Add model
+
+#### Step 2: Api {#step-2}
+
+**Depends on:** #step-1
+
+**Tasks:**
+- [ ] Add endpoints
+
+#### Step 3: Docs {#step-3}
+
+**Depends on:** #step-0, specks-auth.md#step-1
+
+**Tasks:**
+- [ ] Write docs
+
+#### Step 4: Cli {#step-4}
+
+**Depends on:** #step-0
+
+**Tasks:**
+- [ ] Add command
+"#;
+
+    #[test]
+    fn test_next_ready_blocked_and_waves() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(
+            temp.path().join("specks-auth.md"),
+            "## Phase 1.0: Auth {#phase-1}\n\n#### Step 1: Tokens {#step-1}\n\n**Tasks:**\n- [ ] Issue tokens\n",
+        )
+        .unwrap();
+
+        let speck = parse_speck(SPECK).unwrap();
+        let data = build_next_data(&speck, "next", Some(temp.path()));
+
+        assert_eq!((data.complete, data.total), (1, 5));
+        let anchors = |steps: &[NextStep]| -> Vec<String> {
+            steps.iter().map(|s| s.anchor.clone()).collect()
+        };
+        assert_eq!(anchors(&data.ready), vec!["#step-1", "#step-4"]);
+        assert_eq!(anchors(&data.blocked), vec!["#step-2", "#step-3"]);
+        assert_eq!(data.blocked[0].blocked_by, vec!["#step-1"]);
+        assert_eq!(data.blocked[1].blocked_by, vec!["specks-auth.md#step-1"]);
+        assert_eq!(
+            data.waves,
+            vec![vec!["#step-1", "#step-4"], vec!["#step-2", "#step-3"]]
+        );
+        assert!(!data.waves[0].iter().any(|a| *a == "#step-3"));
+
+        let text = format_next_text(&data);
+        assert!(text.starts_with("next: 2 ready, 2 blocked, 1/5 steps complete\n"));
+        assert!(text.contains("  Step 2: Api (#step-2) <- waiting on #step-1\n"));
+        assert!(text.contains("  1: #step-1, #step-4\n"));
+
+        // Once the other speck's step is done, Step 3 is ready
+        fs::write(
+            temp.path().join("specks-auth.md"),
+            "## Phase 1.0: Auth {#phase-1}\n\n#### Step 1: Tokens {#step-1}\n\n**Tasks:**\n- [x] Issue tokens\n",
+        )
+        .unwrap();
+        let data = build_next_data(&speck, "next", Some(temp.path()));
+        assert_eq!(anchors(&data.ready), vec!["#step-1", "#step-3", "#step-4"]);
+        assert_eq!(data.waves[0], vec!["#step-1", "#step-3", "#step-4"]);
+    }
+}
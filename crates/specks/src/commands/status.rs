@@ -5,17 +5,82 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use specks_core::{
-    BeadsCli, IssueDetails, Speck, find_project_root, parse_close_reason, parse_speck,
-    speck_name_from_path,
+    CheckMark, Checkpoint, Config, IssueDetails, IssueTracker, Speck, Substep, bead_completions,
+    external_dependency, find_project_root, format_duration, format_iso8601, log_completions,
+    now_iso8601, now_unix, parse_close_reason, parse_log_entries, parse_speck,
+    resolve_external_step, rollup_estimates, speck_name_from_path,
 };
 
+use crate::commands::beads::open_tracker;
 use crate::output::{
-    BeadStepStatus, JsonIssue, JsonResponse, Progress, StatusData, StepInfo, StepStatus,
-    SubstepStatus,
+    BeadStepStatus, EstimateSummary, ExternalBlocker, JsonIssue, JsonResponse, MarkedItem,
+    Progress, StatusData, StepEstimate, StepInfo, StepStatus, SubstepStatus,
 };
+use crate::watch::{DEFAULT_DEBOUNCE, Watcher};
 
 /// Run the status command
+///
+/// With `watch`, the status is printed once and then the completion delta
+/// is printed after every change under `.specks/`, until interrupted.
 pub fn run_status(
+    file: String,
+    verbose: bool,
+    full: bool,
+    watch: bool,
+    json_output: bool,
+    quiet: bool,
+) -> Result<i32, String> {
+    if watch && json_output {
+        let issues = vec![JsonIssue {
+            code: "E002".to_string(),
+            severity: "error".to_string(),
+            message: "--watch only supports text output".to_string(),
+            file: None,
+            line: None,
+            anchor: None,
+        }];
+        let response: JsonResponse<StatusData> =
+            JsonResponse::error("status", empty_status_data(), issues);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        return Ok(2);
+    }
+
+    let code = show_status(file.clone(), verbose, full, json_output, quiet)?;
+    if !watch || code != 0 {
+        return Ok(code);
+    }
+    let project_root = find_project_root().map_err(|e| e.to_string())?;
+    watch_status(&project_root, &file)
+}
+
+/// Status data with no content, for error responses
+fn empty_status_data() -> StatusData {
+    StatusData {
+        name: String::new(),
+        status: String::new(),
+        progress: Progress { done: 0, total: 0 },
+        steps: vec![],
+        all_steps: None,
+        completed_steps: None,
+        remaining_steps: None,
+        next_step: None,
+        bead_mapping: None,
+        dependencies: None,
+        mode: None,
+        speck: None,
+        phase_title: None,
+        total_step_count: None,
+        completed_step_count: None,
+        ready_step_count: None,
+        blocked_step_count: None,
+        bead_steps: None,
+        external_blockers: vec![],
+        estimates: None,
+    }
+}
+
+/// Print the status of a speck once
+fn show_status(
     file: String,
     verbose: bool,
     full: bool,
@@ -57,6 +122,8 @@ pub fn run_status(
                         ready_step_count: None,
                         blocked_step_count: None,
                         bead_steps: None,
+                        external_blockers: vec![],
+                        estimates: None,
                     },
                     issues,
                 );
@@ -102,6 +169,8 @@ pub fn run_status(
                     ready_step_count: None,
                     blocked_step_count: None,
                     bead_steps: None,
+                    external_blockers: vec![],
+                    estimates: None,
                 },
                 issues,
             );
@@ -147,6 +216,8 @@ pub fn run_status(
                         ready_step_count: None,
                         blocked_step_count: None,
                         bead_steps: None,
+                        external_blockers: vec![],
+                        estimates: None,
                     },
                     issues,
                 );
@@ -192,6 +263,8 @@ pub fn run_status(
                         ready_step_count: None,
                         blocked_step_count: None,
                         bead_steps: None,
+                        external_blockers: vec![],
+                        estimates: None,
                     },
                     issues,
                 );
@@ -205,26 +278,59 @@ pub fn run_status(
 
     let name = speck_name_from_path(&path).unwrap_or_else(|| file.clone());
 
+    // Unfinished steps of other specks this one waits on
+    let blockers = path
+        .parent()
+        .map(|dir| external_blockers(&speck, dir))
+        .unwrap_or_default();
+
+    // Step completion times from the implementation log
+    let completions = logged_completions(&project_root, &speck, &path);
+    let estimates = estimate_summary(&speck, &completions, now_unix());
+
     // Check if beads integration is available
     if let Some(ref root_id) = speck.metadata.beads_root_id {
         // Try beads path
-        let beads_cli = BeadsCli::default();
+        let config = Config::load_from_project(&project_root).unwrap_or_default();
+        let tracker = match open_tracker(&config, &project_root) {
+            Ok(tracker) if tracker.is_installed(None) => Ok(tracker),
+            Ok(_) => Err("beads CLI not found".to_string()),
+            Err(e) => Err(e.to_string()),
+        };
 
-        if !beads_cli.is_installed(None) {
-            eprintln!("warning: beads CLI not found, falling back to checkbox mode");
-            // Fall back to checkbox mode
-            let status_data = build_checkbox_status_data(&speck, &name);
-            if json_output {
-                let response = JsonResponse::ok("status", status_data);
-                println!("{}", serde_json::to_string_pretty(&response).unwrap());
-            } else {
-                output_text(&status_data, &speck, verbose);
+        let tracker = match tracker {
+            Ok(tracker) => tracker,
+            Err(reason) => {
+                eprintln!("warning: {}, falling back to checkbox mode", reason);
+                // Fall back to checkbox mode
+                let mut status_data = build_checkbox_status_data(&speck, &name);
+                status_data.external_blockers = blockers;
+                status_data.estimates = estimates;
+                if json_output {
+                    let response = JsonResponse::ok("status", status_data);
+                    println!("{}", serde_json::to_string_pretty(&response).unwrap());
+                } else {
+                    output_text(&status_data, &speck, verbose);
+                }
+                return Ok(0);
             }
-            return Ok(0);
-        }
+        };
 
-        match build_beads_status_data(&speck, &name, &file, root_id, &beads_cli) {
-            Ok((status_data, details_map)) => {
+        match build_beads_status_data(&speck, &name, &file, root_id, &*tracker) {
+            Ok((mut status_data, details_map)) => {
+                status_data.external_blockers = blockers;
+
+                // Bead close times count as completions too
+                let closed_at: HashMap<String, String> = details_map
+                    .values()
+                    .filter_map(|d| Some((d.id.clone(), d.closed_at.clone()?)))
+                    .collect();
+                let mut completions = completions;
+                for (anchor, time) in bead_completions(&speck, &closed_at) {
+                    let latest = completions.entry(anchor).or_insert(time);
+                    *latest = (*latest).max(time);
+                }
+                status_data.estimates = estimate_summary(&speck, &completions, now_unix());
                 if json_output {
                     let response = JsonResponse::ok("status", status_data);
                     println!("{}", serde_json::to_string_pretty(&response).unwrap());
@@ -239,7 +345,9 @@ pub fn run_status(
                     e
                 );
                 // Fall back to checkbox mode
-                let status_data = build_checkbox_status_data(&speck, &name);
+                let mut status_data = build_checkbox_status_data(&speck, &name);
+                status_data.external_blockers = blockers;
+                status_data.estimates = estimates;
                 if json_output {
                     let response = JsonResponse::ok("status", status_data);
                     println!("{}", serde_json::to_string_pretty(&response).unwrap());
@@ -251,7 +359,9 @@ pub fn run_status(
         }
     } else {
         // No beads_root_id, use checkbox mode
-        let status_data = build_checkbox_status_data(&speck, &name);
+        let mut status_data = build_checkbox_status_data(&speck, &name);
+        status_data.external_blockers = blockers;
+        status_data.estimates = estimates;
         if json_output {
             let response = JsonResponse::ok("status", status_data);
             println!("{}", serde_json::to_string_pretty(&response).unwrap());
@@ -262,6 +372,117 @@ pub fn run_status(
     }
 }
 
+/// Print the completion delta of a speck on each change under `.specks/`
+///
+/// Progress is counted from checkboxes, since bead state does not live
+/// under `.specks/`. Runs until interrupted.
+fn watch_status(project_root: &Path, file: &str) -> Result<i32, String> {
+    let path = resolve_file_path(project_root, file);
+    let name = speck_name_from_path(&path).unwrap_or_else(|| file.to_string());
+    let load = || -> Result<StatusData, String> {
+        let content =
+            fs::read_to_string(&path).map_err(|e| format!("failed to read file: {}", e))?;
+        let speck = parse_speck(&content).map_err(|e| format!("failed to parse speck: {}", e))?;
+        Ok(build_checkbox_status_data(&speck, &name))
+    };
+
+    let mut watcher = Watcher::new(&project_root.join(".specks"), DEFAULT_DEBOUNCE);
+    println!();
+    println!("Watching .specks/ for changes (Ctrl-C to stop)");
+    let mut previous = load()?;
+
+    loop {
+        watcher.wait_for_change();
+        let current = match load() {
+            Ok(data) => data,
+            Err(message) => {
+                println!("[{}] error: {}", &now_iso8601()[11..19], message);
+                continue;
+            }
+        };
+        println!(
+            "[{}] {}",
+            &now_iso8601()[11..19],
+            progress_line(&previous, &current)
+        );
+        for line in step_deltas(&previous, &current) {
+            println!("  {}", line);
+        }
+        previous = current;
+    }
+}
+
+/// Percentage of completed items
+fn percent(progress: &Progress) -> usize {
+    if progress.total > 0 {
+        (progress.done as f64 / progress.total as f64 * 100.0) as usize
+    } else {
+        0
+    }
+}
+
+/// Summary line of the overall progress change
+fn progress_line(before: &StatusData, after: &StatusData) -> String {
+    let (b, a) = (&before.progress, &after.progress);
+    if (b.done, b.total) == (a.done, a.total) {
+        format!(
+            "{}.md: no progress change ({}/{} tasks, {}%)",
+            after.name,
+            a.done,
+            a.total,
+            percent(a)
+        )
+    } else {
+        format!(
+            "{}.md: {}/{} -> {}/{} tasks ({}% -> {}%)",
+            after.name,
+            b.done,
+            b.total,
+            a.done,
+            a.total,
+            percent(b),
+            percent(a)
+        )
+    }
+}
+
+/// One line per step whose progress changed, plus a Status change
+fn step_deltas(before: &StatusData, after: &StatusData) -> Vec<String> {
+    let counts = |step: &StepStatus| {
+        step.rollup
+            .as_ref()
+            .map_or((step.done, step.total), |r| (r.done, r.total))
+    };
+    let mut lines = Vec::new();
+    if before.status != after.status {
+        lines.push(format!("Status: {} -> {}", before.status, after.status));
+    }
+    for step in &after.steps {
+        let (done, total) = counts(step);
+        match before.steps.iter().find(|s| s.anchor == step.anchor) {
+            Some(old) => {
+                let (old_done, old_total) = counts(old);
+                if (old_done, old_total) != (done, total) {
+                    lines.push(format!(
+                        "{} {}: {}/{} -> {}/{}",
+                        step.anchor, step.title, old_done, old_total, done, total
+                    ));
+                }
+            }
+            None => lines.push(format!(
+                "{} {}: added ({}/{})",
+                step.anchor, step.title, done, total
+            )),
+        }
+    }
+    for old in &before.steps {
+        if !after.steps.iter().any(|s| s.anchor == old.anchor) {
+            lines.push(format!("{} {}: removed", old.anchor, old.title));
+        }
+    }
+    lines
+}
+
 /// Resolve a file path relative to the project
 fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
     let path = Path::new(file);
@@ -293,6 +514,37 @@ fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
     }
 }
 
+/// Collect the skipped, blocked and failed items of a step or substep
+fn marked_items<'a>(items: impl Iterator<Item = &'a Checkpoint>) -> Vec<MarkedItem> {
+    items
+        .filter_map(|item| {
+            item.mark.map(|mark| MarkedItem {
+                state: mark,
+                text: item.text.clone(),
+                reason: item.reason.clone(),
+                line: item.line,
+            })
+        })
+        .collect()
+}
+
+/// Build status for a substep and everything nested under it
+fn build_substep_status(substep: &Substep) -> SubstepStatus {
+    let (rollup_done, rollup_total) = substep.rollup_counts();
+    SubstepStatus {
+        title: substep.title.clone(),
+        anchor: format!("#{}", substep.anchor),
+        done: substep.completed_items(),
+        total: substep.total_items(),
+        marked: marked_items(substep.items()),
+        rollup: (!substep.substeps.is_empty()).then_some(Progress {
+            done: rollup_done,
+            total: rollup_total,
+        }),
+        substeps: substep.substeps.iter().map(build_substep_status).collect(),
+    }
+}
+
 /// Build status data from a parsed speck using checkbox counting (fallback mode)
 fn build_checkbox_status_data(speck: &Speck, name: &str) -> StatusData {
     let mut total_done = 0;
@@ -302,35 +554,21 @@ fn build_checkbox_status_data(speck: &Speck, name: &str) -> StatusData {
         .steps
         .iter()
         .map(|step| {
-            let step_done = step.completed_items();
-            let step_total = step.total_items();
-            total_done += step_done;
-            total_items += step_total;
-
-            let substeps: Vec<SubstepStatus> = step
-                .substeps
-                .iter()
-                .map(|substep| {
-                    let sub_done = substep.completed_items();
-                    let sub_total = substep.total_items();
-                    total_done += sub_done;
-                    total_items += sub_total;
-
-                    SubstepStatus {
-                        title: substep.title.clone(),
-                        anchor: format!("#{}", substep.anchor),
-                        done: sub_done,
-                        total: sub_total,
-                    }
-                })
-                .collect();
+            let (rollup_done, rollup_total) = step.rollup_counts();
+            total_done += rollup_done;
+            total_items += rollup_total;
 
             StepStatus {
                 title: step.title.clone(),
                 anchor: format!("#{}", step.anchor),
-                done: step_done,
-                total: step_total,
-                substeps,
+                done: step.completed_items(),
+                total: step.total_items(),
+                marked: marked_items(step.items()),
+                rollup: (!step.substeps.is_empty()).then_some(Progress {
+                    done: rollup_done,
+                    total: rollup_total,
+                }),
+                substeps: step.substeps.iter().map(build_substep_status).collect(),
             }
         })
         .collect();
@@ -357,11 +595,7 @@ fn build_checkbox_status_data(speck: &Speck, name: &str) -> StatusData {
     let completed_steps: Vec<StepInfo> = speck
         .steps
         .iter()
-        .filter(|step| {
-            let step_done = step.completed_items();
-            let step_total = step.total_items();
-            step_total > 0 && step_done == step_total
-        })
+        .filter(|step| step.is_complete())
         .map(|step| StepInfo {
             anchor: format!("#{}", step.anchor),
             title: step.title.clone(),
@@ -402,7 +636,10 @@ fn build_checkbox_status_data(speck: &Speck, name: &str) -> StatusData {
             let deps = step
                 .depends_on
                 .iter()
-                .map(|dep| format!("#{}", dep))
+                .map(|dep| match external_dependency(dep) {
+                    Some(_) => dep.clone(),
+                    None => format!("#{}", dep),
+                })
                 .collect();
             (format!("#{}", step.anchor), deps)
         })
@@ -430,9 +667,209 @@ fn build_checkbox_status_data(speck: &Speck, name: &str) -> StatusData {
         ready_step_count: None,
         blocked_step_count: None,
         bead_steps: None,
+        external_blockers: vec![],
+        estimates: None,
     }
 }
 
+/// Unfinished steps of other specks (in `dir`) that the speck or its steps
+/// depend on; dependencies that cannot be resolved are listed as blockers too
+pub(crate) fn external_blockers(speck: &Speck, dir: &Path) -> Vec<ExternalBlocker> {
+    let mut blockers = Vec::new();
+    let mut check = |anchor: Option<&str>, number: Option<&str>, deps: &[String]| {
+        for dep in deps.iter().filter(|d| external_dependency(d).is_some()) {
+            let external = resolve_external_step(dir, dep);
+            if external.as_ref().is_some_and(|e| e.is_complete()) {
+                continue;
+            }
+            blockers.push(ExternalBlocker {
+                anchor: anchor.map(|a| format!("#{}", a)),
+                number: number.map(str::to_string),
+                dependency: dep.clone(),
+                title: external
+                    .as_ref()
+                    .map(|e| format!("Step {}: {}", e.number, e.title)),
+                done: external.as_ref().map_or(0, |e| e.done),
+                total: external.as_ref().map_or(0, |e| e.total),
+            });
+        }
+    };
+
+    check(None, None, &speck.metadata.depends_on);
+    for step in &speck.steps {
+        check(Some(&step.anchor), Some(&step.number), &step.depends_on);
+        for substep in step.all_substeps() {
+            check(
+                Some(&substep.anchor),
+                Some(&substep.number),
+                &substep.depends_on,
+            );
+        }
+    }
+    blockers
+}
+
+/// Completion times of a speck's steps from the implementation log, keyed
+/// by step anchor (without `#`); empty if there is no log
+pub(crate) fn logged_completions(
+    project_root: &Path,
+    speck: &Speck,
+    path: &Path,
+) -> HashMap<String, i64> {
+    let log_path = project_root
+        .join(".specks")
+        .join("specks-implementation-log.md");
+    let Ok(content) = fs::read_to_string(log_path) else {
+        return HashMap::new();
+    };
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    log_completions(speck, file_name, &parse_log_entries(&content))
+}
+
+/// Estimate rollups and actual time of a speck; none if no step has an
+/// estimate or a completion time
+pub(crate) fn estimate_summary(
+    speck: &Speck,
+    completions: &HashMap<String, i64>,
+    now: i64,
+) -> Option<EstimateSummary> {
+    let rollup = rollup_estimates(speck, completions);
+    if !rollup.has_estimates() && completions.is_empty() {
+        return None;
+    }
+
+    let anchors = |steps: &[usize]| -> Vec<String> {
+        steps
+            .iter()
+            .map(|&i| format!("#{}", rollup.steps[i].anchor))
+            .collect()
+    };
+    let (critical_path, critical_path_minutes) = rollup
+        .critical_path
+        .as_ref()
+        .map_or((vec![], 0), |p| (anchors(&p.steps), p.minutes));
+    let (remaining_critical_path, remaining_critical_path_minutes) = rollup
+        .remaining_critical_path
+        .as_ref()
+        .map_or((vec![], 0), |p| (anchors(&p.steps), p.minutes));
+    let actual = rollup.actual_vs_estimate();
+
+    Some(EstimateSummary {
+        total_minutes: rollup.total_minutes,
+        remaining_minutes: rollup.remaining_minutes,
+        unestimated_steps: rollup.unestimated_steps,
+        critical_path,
+        critical_path_minutes,
+        remaining_critical_path,
+        remaining_critical_path_minutes,
+        actual_minutes: actual.map(|(a, _)| a),
+        actual_estimate_minutes: actual.map(|(_, e)| e),
+        pace: rollup.pace().map(|p| (p * 100.0).round() / 100.0),
+        eta: rollup.eta(now).map(format_iso8601),
+        steps: rollup
+            .steps
+            .iter()
+            .map(|step| StepEstimate {
+                anchor: format!("#{}", step.anchor),
+                estimate_minutes: step.estimate_minutes,
+                done: step.done,
+                completed_at: step.completed_at.map(format_iso8601),
+                actual_minutes: step.actual_minutes,
+            })
+            .collect(),
+    })
+}
+
+/// Estimate and actual time of a step for its status line (`  (est 1h,
+/// actual 1h 30m)`), empty if neither is known
+fn step_estimate_suffix(estimates: Option<&EstimateSummary>, anchor: &str) -> String {
+    let Some(step) = estimates.and_then(|e| e.steps.iter().find(|s| s.anchor == anchor)) else {
+        return String::new();
+    };
+    let parts: Vec<String> = [
+        step.estimate_minutes
+            .map(|m| format!("est {}", format_duration(m))),
+        step.actual_minutes
+            .map(|m| format!("actual {}", format_duration(m))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("  ({})", parts.join(", "))
+    }
+}
+
+/// Text lines summarizing estimates, actual time and ETA
+fn format_estimates(estimates: &EstimateSummary) -> String {
+    let mut output = format!(
+        "Estimates: {} total, {} remaining",
+        format_duration(estimates.total_minutes),
+        format_duration(estimates.remaining_minutes)
+    );
+    match estimates.unestimated_steps {
+        0 => {}
+        1 => output.push_str(" (1 step without an estimate)"),
+        n => output.push_str(&format!(" ({} steps without an estimate)", n)),
+    }
+    output.push('\n');
+
+    if estimates.critical_path.len() > 1 {
+        output.push_str(&format!(
+            "Critical path: {} ({}, {} remaining)\n",
+            estimates.critical_path.join(" -> "),
+            format_duration(estimates.critical_path_minutes),
+            format_duration(estimates.remaining_critical_path_minutes)
+        ));
+    }
+    if let (Some(actual), Some(estimated)) =
+        (estimates.actual_minutes, estimates.actual_estimate_minutes)
+    {
+        let pace = estimates
+            .pace
+            .map(|p| format!(" (pace {:.2})", p))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "Actual: {} against {} estimated{}\n",
+            format_duration(actual),
+            format_duration(estimated),
+            pace
+        ));
+    }
+    if let Some(ref eta) = estimates.eta {
+        output.push_str(&format!("ETA: {}\n", eta));
+    }
+    output
+}
+
+/// Text lines listing external blockers, empty if there are none
+fn format_external_blockers(blockers: &[ExternalBlocker]) -> String {
+    if blockers.is_empty() {
+        return String::new();
+    }
+    let mut output = String::from("External blockers:\n");
+    for blocker in blockers {
+        let waiting = match &blocker.number {
+            Some(number) => format!("Step {}", number),
+            None => "Speck".to_string(),
+        };
+        let detail = match &blocker.title {
+            Some(title) => format!("{}, {}/{}", title, blocker.done, blocker.total),
+            None => "not found".to_string(),
+        };
+        output.push_str(&format!(
+            "  {} waits on {} ({})\n",
+            waiting, blocker.dependency, detail
+        ));
+    }
+    output
+}
+
 /// Classify steps based on bead data (pure function for testability)
 fn classify_steps(
     speck: &Speck,
@@ -594,15 +1031,15 @@ fn build_beads_status_data(
     name: &str,
     file_path: &str,
     root_id: &str,
-    beads_cli: &BeadsCli,
+    tracker: &dyn IssueTracker,
 ) -> Result<(StatusData, HashMap<String, IssueDetails>), String> {
     // Query all child beads with details
-    let children = beads_cli
+    let children = tracker
         .list_children_detailed(root_id, None)
         .map_err(|e| format!("failed to query bead children: {}", e))?;
 
     // Query ready beads
-    let ready_beads = beads_cli
+    let ready_beads = tracker
         .ready(Some(root_id), None)
         .map_err(|e| format!("failed to query ready beads: {}", e))?;
 
@@ -653,6 +1090,8 @@ fn build_beads_status_data(
         ready_step_count: Some(ready_count),
         blocked_step_count: Some(blocked_count),
         bead_steps: Some(bead_steps),
+        external_blockers: vec![],
+        estimates: None,
     };
 
     Ok((status_data, details_map))
@@ -696,8 +1135,12 @@ fn format_beads_text(
             let status_label = step.bead_status.as_deref().unwrap_or("pending");
 
             output.push_str(&format!(
-                "Step {}: {}   {} {}\n",
-                step.number, step.title, indicator, status_label
+                "Step {}: {}   {} {}{}\n",
+                step.number,
+                step.title,
+                indicator,
+                status_label,
+                step_estimate_suffix(data.estimates.as_ref(), &step.anchor)
             ));
 
             // Show close reason for completed steps
@@ -767,6 +1210,16 @@ fn format_beads_text(
         }
     }
 
+    let blockers = format_external_blockers(&data.external_blockers);
+    if !blockers.is_empty() {
+        output.push('\n');
+        output.push_str(&blockers);
+    }
+    if let Some(ref estimates) = data.estimates {
+        output.push('\n');
+        output.push_str(&format_estimates(estimates));
+    }
+
     output
 }
 
@@ -780,6 +1233,58 @@ fn output_beads_text(
     print!("{}", format_beads_text(data, full, details_map));
 }
 
+/// Box shown for a step: failed and blocked items win over progress
+fn check_symbol(done: usize, total: usize, marked: &[MarkedItem]) -> &'static str {
+    let has = |wanted: fn(CheckMark) -> bool| marked.iter().any(|m| wanted(m.state));
+    if has(|mark| matches!(mark, CheckMark::Failed)) {
+        "[!]"
+    } else if has(|mark| matches!(mark, CheckMark::Blocked)) {
+        "[~]"
+    } else if total > 0 && done == total {
+        "[x]"
+    } else {
+        "[ ]"
+    }
+}
+
+/// Print skipped, blocked and failed items with their reasons
+fn print_marked_text(marked: &[MarkedItem], depth: usize) {
+    let indent = "  ".repeat(depth);
+    for item in marked {
+        match &item.reason {
+            Some(reason) => println!("{}  {}: {} ({})", indent, item.state, item.text, reason),
+            None => println!("{}  {}: {}", indent, item.state, item.text),
+        }
+    }
+}
+
+/// Format a checkbox item for verbose output, including any reason
+fn format_item(item: &Checkpoint) -> String {
+    match &item.reason {
+        Some(reason) => format!("[{}] {} ({})", item.marker(), item.text, reason),
+        None => format!("[{}] {}", item.marker(), item.text),
+    }
+}
+
+/// Print substep lines, indented by nesting depth, with rolled-up progress
+fn print_substeps_text(statuses: &[SubstepStatus], substeps: &[Substep], depth: usize) {
+    for (status, substep) in statuses.iter().zip(substeps) {
+        let (done, total) = status
+            .rollup
+            .as_ref()
+            .map_or((status.done, status.total), |r| (r.done, r.total));
+        let check = check_symbol(done, total, &status.marked);
+        let indent = "  ".repeat(depth);
+        let width = 40usize.saturating_sub(2 * depth);
+        println!(
+            "{}Step {}: {:<width$} {} {}/{}",
+            indent, substep.number, status.title, check, done, total
+        );
+        print_marked_text(&status.marked, depth + 1);
+        print_substeps_text(&status.substeps, &substep.substeps, depth + 1);
+    }
+}
+
 /// Output status in text format
 fn output_text(data: &StatusData, speck: &Speck, verbose: bool) {
     // Calculate percentage
@@ -796,31 +1301,27 @@ fn output_text(data: &StatusData, speck: &Speck, verbose: bool) {
     println!();
 
     for (i, step) in data.steps.iter().enumerate() {
-        let check = if step.total > 0 && step.done == step.total {
-            "[x]"
-        } else {
-            "[ ]"
-        };
+        let (done, total) = step
+            .rollup
+            .as_ref()
+            .map_or((step.done, step.total), |r| (r.done, r.total));
+        let check = check_symbol(done, total, &step.marked);
 
-        let progress = format!("{}/{}", step.done, step.total);
+        let progress = format!("{}/{}", done, total);
         println!(
-            "Step {}: {:<40} {} {}",
-            speck.steps[i].number, step.title, check, progress
+            "Step {}: {:<40} {} {}{}",
+            speck.steps[i].number,
+            step.title,
+            check,
+            progress,
+            step_estimate_suffix(data.estimates.as_ref(), &step.anchor)
         );
+        if !verbose {
+            print_marked_text(&step.marked, 1);
+        }
 
         // Show substeps
-        for (j, substep) in step.substeps.iter().enumerate() {
-            let sub_check = if substep.total > 0 && substep.done == substep.total {
-                "[x]"
-            } else {
-                "[ ]"
-            };
-            let sub_progress = format!("{}/{}", substep.done, substep.total);
-            println!(
-                "  Step {}: {:<38} {} {}",
-                speck.steps[i].substeps[j].number, substep.title, sub_check, sub_progress
-            );
-        }
+        print_substeps_text(&step.substeps, &speck.steps[i].substeps, 1);
 
         // Verbose mode: show individual tasks
         if verbose {
@@ -828,22 +1329,19 @@ fn output_text(data: &StatusData, speck: &Speck, verbose: bool) {
             if !step_data.tasks.is_empty() {
                 println!("    Tasks:");
                 for task in &step_data.tasks {
-                    let check = if task.checked { "[x]" } else { "[ ]" };
-                    println!("      {} {}", check, task.text);
+                    println!("      {}", format_item(task));
                 }
             }
             if !step_data.tests.is_empty() {
                 println!("    Tests:");
                 for test in &step_data.tests {
-                    let check = if test.checked { "[x]" } else { "[ ]" };
-                    println!("      {} {}", check, test.text);
+                    println!("      {}", format_item(test));
                 }
             }
             if !step_data.checkpoints.is_empty() {
                 println!("    Checkpoints:");
                 for checkpoint in &step_data.checkpoints {
-                    let check = if checkpoint.checked { "[x]" } else { "[ ]" };
-                    println!("      {} {}", check, checkpoint.text);
+                    println!("      {}", format_item(checkpoint));
                 }
             }
             if let Some(ref refs) = step_data.references {
@@ -853,11 +1351,36 @@ fn output_text(data: &StatusData, speck: &Speck, verbose: bool) {
         }
     }
 
+    let blockers = format_external_blockers(&data.external_blockers);
+    if !blockers.is_empty() {
+        println!();
+        print!("{}", blockers);
+    }
+    if let Some(ref estimates) = data.estimates {
+        println!();
+        print!("{}", format_estimates(estimates));
+    }
+
     println!();
-    println!(
+    let mut summary = format!(
         "Total: {}/{} tasks complete",
         data.progress.done, data.progress.total
     );
+    for mark in [CheckMark::Skipped, CheckMark::Blocked, CheckMark::Failed] {
+        let count = speck
+            .steps
+            .iter()
+            .flat_map(|step| {
+                step.items()
+                    .chain(step.all_substeps().into_iter().flat_map(|s| s.items()))
+            })
+            .filter(|item| item.mark == Some(mark))
+            .count();
+        if count > 0 {
+            summary.push_str(&format!(", {} {}", count, mark));
+        }
+    }
+    println!("{}", summary);
 }
 
 #[cfg(test)]
@@ -880,13 +1403,19 @@ mod tests {
                 tracking: None,
                 last_updated: None,
                 beads_root_id: None,
+                includes: vec![],
+                depends_on: vec![],
+                extra: Default::default(),
             },
             anchors: vec![],
             decisions: vec![],
             questions: vec![],
+            labels: vec![],
             steps,
             raw_content: String::new(),
             diagnostics: vec![],
+            companions: vec![],
+            suppressions: vec![],
         }
     }
 
@@ -925,12 +1454,14 @@ mod tests {
                     kind: specks_core::CheckpointKind::Task,
                     checked: false,
                     line: 21,
+                    ..Default::default()
                 },
                 specks_core::Checkpoint {
                     text: "task 2".to_string(),
                     kind: specks_core::CheckpointKind::Task,
                     checked: false,
                     line: 22,
+                    ..Default::default()
                 },
             ],
             tests: vec![specks_core::Checkpoint {
@@ -938,12 +1469,14 @@ mod tests {
                 kind: specks_core::CheckpointKind::Test,
                 checked: false,
                 line: 23,
+                ..Default::default()
             }],
             checkpoints: vec![specks_core::Checkpoint {
                 text: "checkpoint 1".to_string(),
                 kind: specks_core::CheckpointKind::Checkpoint,
                 checked: false,
                 line: 24,
+                ..Default::default()
             }],
             artifacts: vec![],
             depends_on: vec![],
@@ -983,6 +1516,8 @@ mod tests {
             acceptance_criteria: None,
             notes: None,
             close_reason: Some("Committed: abc123d -- feat: setup".to_string()),
+            closed_at: None,
+            labels: vec![],
             metadata: None,
         };
 
@@ -999,6 +1534,8 @@ mod tests {
             acceptance_criteria: None,
             notes: None,
             close_reason: None,
+            closed_at: None,
+            labels: vec![],
             metadata: None,
         };
 
@@ -1018,6 +1555,8 @@ mod tests {
             acceptance_criteria: None,
             notes: None,
             close_reason: None,
+            closed_at: None,
+            labels: vec![],
             metadata: None,
         };
 
@@ -1047,6 +1586,8 @@ mod tests {
             ready_step_count: Some(1),
             blocked_step_count: Some(1),
             bead_steps: Some(bead_steps),
+            external_blockers: vec![],
+            estimates: None,
         };
 
         let response = JsonResponse::ok("status", status_data);
@@ -1118,6 +1659,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_beads_status_from_memory_tracker() {
+        use specks_core::MemoryTracker;
+
+        let tracker = MemoryTracker::default();
+        let root = tracker
+            .create("Root", None, None, None, None, None, None, None, None)
+            .unwrap();
+        for title in ["Setup", "Build", "Ship"] {
+            tracker
+                .create(
+                    title,
+                    None,
+                    Some(&root.id),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        tracker.dep_add("mem-1.2", "mem-1.1", None).unwrap();
+        tracker.dep_add("mem-1.3", "mem-1.2", None).unwrap();
+        tracker
+            .close("mem-1.1", Some("Committed: abc123 -- feat: setup"), None)
+            .unwrap();
+
+        let speck = parse_speck(
+            r#"## Phase 1.0: Memory {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Bead:** `mem-1.1`
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0
+
+**Bead:** `mem-1.2`
+
+#### Step 2: Ship {#step-2}
+
+**Depends on:** #step-1
+
+**Bead:** `mem-1.3`
+"#,
+        )
+        .unwrap();
+
+        let (data, details) =
+            build_beads_status_data(&speck, "memory", "specks-memory.md", &root.id, &tracker)
+                .unwrap();
+        assert_eq!(data.completed_step_count, Some(1));
+        assert_eq!(data.ready_step_count, Some(1));
+        assert_eq!(data.blocked_step_count, Some(1));
+        let steps = data.bead_steps.unwrap();
+        assert_eq!(steps[0].commit_hash.as_deref(), Some("abc123"));
+        assert_eq!(steps[2].blocked_by, Some(vec!["#step-1".to_string()]));
+        assert!(details["#step-0"].closed_at.is_some());
+    }
+
     #[test]
     fn test_golden_fallback_status_json() {
         // Build a minimal speck inline
@@ -1176,6 +1780,268 @@ Last updated: 2026-02-01
         assert_eq!(actual_steps[0]["total"], 4);
     }
 
+    #[test]
+    fn test_checkbox_status_rolls_up_nested_substeps() {
+        let speck_content = r#"## Phase 1.0: Nested {#phase-1}
+
+#### Step 1: Parent {#step-1}
+
+**Tasks:**
+- [x] Parent task
+
+##### Step 1.1: Child {#step-1-1}
+
+**Tasks:**
+- [x] Child task
+
+###### Step 1.1.1: Grandchild {#step-1-1-1}
+
+**Tasks:**
+- [ ] Grandchild task
+"#;
+
+        let speck = parse_speck(speck_content).unwrap();
+        let status_data = build_checkbox_status_data(&speck, "nested");
+
+        assert_eq!(status_data.progress.done, 2);
+        assert_eq!(status_data.progress.total, 3);
+
+        let step = &status_data.steps[0];
+        assert_eq!((step.done, step.total), (1, 1));
+        let rollup = step.rollup.as_ref().unwrap();
+        assert_eq!((rollup.done, rollup.total), (2, 3));
+
+        let child = &step.substeps[0];
+        assert_eq!(child.rollup.as_ref().unwrap().total, 2);
+        assert_eq!(child.substeps[0].anchor, "#step-1-1-1");
+        assert!(child.substeps[0].rollup.is_none());
+
+        // Own tasks are all checked, but the grandchild is not
+        assert!(status_data.completed_steps.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_watch_completion_delta() {
+        let before = r#"## Phase 1.0: Watch {#phase-1}
+
+| Field | Value |
+|------|-------|
+| Status | draft |
+
+#### Step 0: Setup {#step-0}
+
+**Tasks:**
+- [ ] One
+- [ ] Two
+
+#### Step 1: Build {#step-1}
+
+**Tasks:**
+- [ ] Three
+"#;
+        let after = before
+            .replace("| draft |", "| active |")
+            .replace("- [ ] One", "- [x] One")
+            .replace(
+                "#### Step 1: Build {#step-1}\n\n**Tasks:**\n- [ ] Three\n",
+                "",
+            )
+            + "\n#### Step 2: Ship {#step-2}\n\n**Tasks:**\n- [x] Four\n";
+
+        let before = build_checkbox_status_data(&parse_speck(before).unwrap(), "w");
+        let after = build_checkbox_status_data(&parse_speck(&after).unwrap(), "w");
+
+        assert_eq!(
+            progress_line(&before, &after),
+            "w.md: 0/3 -> 2/3 tasks (0% -> 66%)"
+        );
+        assert_eq!(
+            step_deltas(&before, &after),
+            vec![
+                "Status: draft -> active",
+                "#step-0 Setup: 0/2 -> 1/2",
+                "#step-2 Ship: added (1/1)",
+                "#step-1 Build: removed",
+            ]
+        );
+        assert_eq!(
+            progress_line(&after, &after),
+            "w.md: no progress change (2/3 tasks, 66%)"
+        );
+        assert!(step_deltas(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_checkbox_status_reports_marked_items() {
+        let speck_content = r#"## Phase 1.0: Marks {#phase-1}
+
+#### Step 0: Done but skipped {#step-0}
+
+**Tests:**
+- [x] Unit test
+- [-] Load test -- no staging cluster
+
+#### Step 1: Stuck {#step-1}
+
+**Tasks:**
+- [~] Deploy -- waiting on credentials
+- [!] Migrate
+"#;
+
+        let speck = parse_speck(speck_content).unwrap();
+        let status_data = build_checkbox_status_data(&speck, "marks");
+
+        assert_eq!(status_data.progress.done, 1);
+        assert_eq!(status_data.progress.total, 3);
+
+        let skipped = &status_data.steps[0];
+        assert_eq!((skipped.done, skipped.total), (1, 1));
+        assert_eq!(skipped.marked[0].state, CheckMark::Skipped);
+        assert_eq!(
+            skipped.marked[0].reason.as_deref(),
+            Some("no staging cluster")
+        );
+        assert_eq!(check_symbol(1, 1, &skipped.marked), "[x]");
+
+        let stuck = &status_data.steps[1];
+        assert_eq!(stuck.marked.len(), 2);
+        assert_eq!(check_symbol(0, 2, &stuck.marked), "[!]");
+        assert_eq!(check_symbol(0, 2, &stuck.marked[..1]), "[~]");
+
+        let completed = status_data.completed_steps.unwrap();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].anchor, "#step-0");
+    }
+
+    #[test]
+    fn test_external_blockers() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(
+            temp.path().join("specks-auth.md"),
+            r#"## Phase 1.0: Auth {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Tasks:**
+- [x] Create module
+
+#### Step 1: Tokens {#step-1}
+
+**Tasks:**
+- [x] Issue tokens
+- [ ] Refresh tokens
+"#,
+        )
+        .unwrap();
+
+        let speck_content = r#"## Phase 1.0: Api {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Depends on | specks-auth.md#step-0 |
+
+#### Step 0: Endpoints {#step-0}
+
+**Depends on:** specks-auth.md#step-1, specks-billing.md#step-0
+
+**Tasks:**
+- [ ] Add endpoints
+"#;
+        let speck = parse_speck(speck_content).unwrap();
+        let blockers = external_blockers(&speck, temp.path());
+
+        // The speck-level dependency is complete and not listed
+        assert_eq!(blockers.len(), 2);
+        assert_eq!(blockers[0].anchor.as_deref(), Some("#step-0"));
+        assert_eq!(blockers[0].dependency, "specks-auth.md#step-1");
+        assert_eq!(blockers[0].title.as_deref(), Some("Step 1: Tokens"));
+        assert_eq!((blockers[0].done, blockers[0].total), (1, 2));
+        assert!(blockers[1].title.is_none());
+
+        assert_eq!(
+            format_external_blockers(&blockers),
+            "External blockers:\n  \
+             Step 0 waits on specks-auth.md#step-1 (Step 1: Tokens, 1/2)\n  \
+             Step 0 waits on specks-billing.md#step-0 (not found)\n"
+        );
+
+        // Dependencies keep their file in the step dependency map
+        let status_data = build_checkbox_status_data(&speck, "api");
+        assert_eq!(
+            status_data.dependencies.unwrap()["#step-0"],
+            vec!["specks-auth.md#step-1", "specks-billing.md#step-0"]
+        );
+    }
+
+    #[test]
+    fn test_estimate_summary() {
+        let speck_content = r#"## Phase 1.0: Estimates {#phase-1}
+
+#### Step 0: Setup {#step-0}
+
+**Beads:** estimate_minutes=30
+
+**Tasks:**
+- [x] Create module
+
+#### Step 1: Model {#step-1}
+
+**Depends on:** #step-0
+
+**Beads:** estimate_minutes=60
+
+**Tasks:**
+- [x] Add model
+
+#### Step 2: Api {#step-2}
+
+**Depends on:** #step-1
+
+**Beads:** estimate_minutes=90
+
+**Tasks:**
+- [ ] Add endpoints
+"#;
+        let speck = parse_speck(speck_content).unwrap();
+        assert!(estimate_summary(&speck, &HashMap::new(), 0).is_some());
+
+        let completions = HashMap::from([
+            ("step-0".to_string(), 3600),
+            ("step-1".to_string(), 3600 + 90 * 60),
+        ]);
+        let estimates = estimate_summary(&speck, &completions, 0).unwrap();
+        assert_eq!(estimates.total_minutes, 180);
+        assert_eq!(estimates.remaining_minutes, 90);
+        assert_eq!(estimates.remaining_critical_path, vec!["#step-2"]);
+        assert_eq!(estimates.actual_minutes, Some(90));
+        assert_eq!(estimates.pace, Some(1.5));
+        assert_eq!(estimates.eta.as_deref(), Some("1970-01-01T02:15:00Z"));
+        assert_eq!(
+            estimates.steps[0].completed_at.as_deref(),
+            Some("1970-01-01T01:00:00Z")
+        );
+
+        assert_eq!(
+            format_estimates(&estimates),
+            "Estimates: 3h total, 1h 30m remaining\n\
+             Critical path: #step-0 -> #step-1 -> #step-2 (3h, 1h 30m remaining)\n\
+             Actual: 1h 30m against 1h estimated (pace 1.50)\n\
+             ETA: 1970-01-01T02:15:00Z\n"
+        );
+        assert_eq!(
+            step_estimate_suffix(Some(&estimates), "#step-1"),
+            "  (est 1h, actual 1h 30m)"
+        );
+        assert_eq!(step_estimate_suffix(None, "#step-1"), "");
+
+        // Nothing estimated or logged: no summary at all
+        let plain =
+            parse_speck(&speck_content.replace("**Beads:** estimate_minutes", "x")).unwrap();
+        assert!(estimate_summary(&plain, &HashMap::new(), 0).is_none());
+    }
+
     #[test]
     fn test_full_text_output_has_section_headers() {
         // Build minimal StatusData with one complete bead_step
@@ -1213,6 +2079,8 @@ Last updated: 2026-02-01
             ready_step_count: Some(0),
             blocked_step_count: Some(0),
             bead_steps: Some(vec![bead_step]),
+            external_blockers: vec![],
+            estimates: None,
         };
 
         // Build details_map with content
@@ -1232,6 +2100,8 @@ Last updated: 2026-02-01
                 acceptance_criteria: Some("Test acceptance".to_string()),
                 notes: Some("Test notes".to_string()),
                 close_reason: Some("Committed: abc123 -- feat: test".to_string()),
+                closed_at: None,
+                labels: vec![],
                 metadata: None,
             },
         );
@@ -2,8 +2,10 @@
 //!
 //! Atomically performs log rotation, prepend, git commit, and bead close.
 
+use crate::commands::beads::open_tracker;
 use crate::commands::log::{log_prepend_inner, log_rotate_inner};
 use crate::output::{JsonResponse, StepCommitData};
+use specks_core::IssueTracker;
 use std::path::Path;
 use std::process::Command;
 
@@ -181,16 +183,23 @@ fn close_bead_in_worktree(
     bead_id: &str,
     reason: Option<&str>,
 ) -> Result<(bool, Vec<String>), String> {
-    use specks_core::{BeadsCli, Config};
+    use specks_core::Config;
 
     // Load config from worktree
     let config = Config::load_from_project(worktree_path).unwrap_or_default();
-    let bd_path =
-        std::env::var("SPECKS_BD_PATH").unwrap_or_else(|_| config.specks.beads.bd_path.clone());
-
-    let beads = BeadsCli::new(bd_path);
+    let tracker = open_tracker(&config, worktree_path).map_err(|e| e.to_string())?;
+    close_bead(&*tracker, worktree_path, bead_id, reason)
+}
 
-    // Check if beads CLI is installed (from worktree context)
+/// Close a bead through the tracker, run from the worktree so it finds its
+/// state; failures are returned as warnings since the commit already happened
+fn close_bead(
+    beads: &dyn IssueTracker,
+    worktree_path: &Path,
+    bead_id: &str,
+    reason: Option<&str>,
+) -> Result<(bool, Vec<String>), String> {
+    // Check if the tracker is reachable (from worktree context)
     if !beads.is_installed(Some(worktree_path)) {
         return Ok((
             false,
@@ -198,7 +207,7 @@ fn close_bead_in_worktree(
         ));
     }
 
-    // Close bead via BeadsCli with working_dir so bd finds .beads/
+    // Close bead with working_dir so bd finds .beads/
     match beads.close(bead_id, reason, Some(worktree_path)) {
         Ok(_) => Ok((true, vec![])),
         Err(e) => Ok((false, vec![format!("Bead close failed: {}", e)])),
@@ -281,3 +290,30 @@ fn find_orphaned_changes(worktree_path: &Path) -> Result<Vec<String>, String> {
 
     Ok(orphaned)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specks_core::MemoryTracker;
+
+    #[test]
+    fn test_close_bead_reports_failures_as_warnings() {
+        let tracker = MemoryTracker::default();
+        let issue = tracker
+            .create("Step 0", None, None, None, None, None, None, None, None)
+            .unwrap();
+        let worktree = Path::new(".");
+
+        let (closed, warnings) =
+            close_bead(&tracker, worktree, &issue.id, Some("Committed: abc -- x")).unwrap();
+        assert!(closed);
+        assert!(warnings.is_empty());
+        let details = tracker.show(&issue.id, None).unwrap();
+        assert_eq!(details.status, "closed");
+        assert_eq!(details.close_reason.as_deref(), Some("Committed: abc -- x"));
+
+        let (closed, warnings) = close_bead(&tracker, worktree, "mem-9", None).unwrap();
+        assert!(!closed);
+        assert!(warnings[0].starts_with("Bead close failed:"));
+    }
+}
@@ -1,23 +1,125 @@
 //! Implementation of the `specks validate` command (Spec S02)
 
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use specks_core::{
-    Config, Severity, ValidationConfig, ValidationLevel, ValidationResult, find_project_root,
-    find_specks, parse_speck, speck_name_from_path, validate_speck_with_config,
+    BASELINE_FILE, Baseline, Config, Severity, SpecksError, ValidationConfig, ValidationLevel,
+    ValidationResult, check_regressions, cross_file_refs, find_project_root, find_specks,
+    fix_speck, is_reserved_file, load_companions, now_iso8601, parse_speck, speck_name_from_path,
+    unified_diff, validate_speck_with_config,
 };
 
-use crate::output::{JsonDiagnostic, JsonIssue, JsonResponse, ValidateData, ValidatedFile};
+use crate::output::{
+    BaselineSummary, FixedFile, JsonDiagnostic, JsonFix, JsonIssue, JsonResponse, ValidateData,
+    ValidatedFile,
+};
+use crate::report::{self, ReportFormat};
+use crate::watch::{DEFAULT_DEBOUNCE, Watcher, map_delta};
+
+/// Options for `specks validate`
+pub struct ValidateOptions {
+    pub file: Option<String>,
+    pub strict: bool,
+    pub level: Option<String>,
+    /// Apply safe fixes before validating
+    pub fix: bool,
+    /// With `fix`, show the fixes without writing them
+    pub dry_run: bool,
+    /// Output format: text, json, sarif, junit or github
+    pub format: Option<String>,
+    /// Re-validate on every change under `.specks/`
+    pub watch: bool,
+    /// Record current issues in the validation baseline instead of failing
+    pub write_baseline: bool,
+    /// Validate only specks changed since this git ref, and report regressions
+    pub changed_since: Option<String>,
+    pub json_output: bool,
+    pub quiet: bool,
+}
 
 /// Run the validate command
-pub fn run_validate(
-    file: Option<String>,
-    strict: bool,
-    level_arg: Option<String>,
-    json_output: bool,
-    quiet: bool,
-) -> Result<i32, String> {
+///
+/// With `fix`, safe fixes are applied before validating and the remaining
+/// issues are reported; `dry_run` shows the fixes without writing them.
+/// With `watch`, the specks are validated again after every change under
+/// `.specks/` and only new and resolved issues are printed.
+///
+/// Issues recorded in `.specks/validation-baseline.json` are hidden and do
+/// not fail the run; recorded issues that no longer occur are reported as
+/// fixed. `write_baseline` records the current issues there instead.
+///
+/// With `changed_since`, only specks that differ from that git ref are
+/// validated, and each is also compared with its version at the ref for
+/// semantic regressions (R001-R004).
+pub fn run_validate(opts: ValidateOptions) -> Result<i32, String> {
+    let ValidateOptions {
+        file,
+        strict,
+        level: level_arg,
+        fix,
+        dry_run,
+        format,
+        watch,
+        write_baseline,
+        changed_since,
+        json_output,
+        quiet,
+    } = opts;
+
+    let format = match format {
+        None if json_output => ReportFormat::Json,
+        None => ReportFormat::Text,
+        Some(f) => match ReportFormat::parse(&f) {
+            Some(format) => format,
+            None => {
+                let message = format!(
+                    "invalid format '{}': must be text, json, sarif, junit, or github",
+                    f
+                );
+                if json_output {
+                    let issues = vec![JsonIssue {
+                        code: "E002".to_string(),
+                        severity: "error".to_string(),
+                        message: message.clone(),
+                        file: None,
+                        line: None,
+                        anchor: None,
+                    }];
+                    let response: JsonResponse<ValidateData> =
+                        JsonResponse::error("validate", ValidateData::default(), issues);
+                    println!("{}", serde_json::to_string_pretty(&response).unwrap());
+                } else {
+                    eprintln!("error: {}", message);
+                }
+                return Ok(2);
+            }
+        },
+    };
+    let json_output = format == ReportFormat::Json;
+
+    if watch && format != ReportFormat::Text {
+        let message = "--watch only supports text output".to_string();
+        if json_output {
+            let issues = vec![JsonIssue {
+                code: "E002".to_string(),
+                severity: "error".to_string(),
+                message: message.clone(),
+                file: None,
+                line: None,
+                anchor: None,
+            }];
+            let response: JsonResponse<ValidateData> =
+                JsonResponse::error("validate", ValidateData::default(), issues);
+            println!("{}", serde_json::to_string_pretty(&response).unwrap());
+        } else {
+            eprintln!("error: {}", message);
+        }
+        return Ok(2);
+    }
+
     // Find project root
     let project_root = match find_project_root() {
         Ok(root) => root,
@@ -85,13 +187,42 @@ pub fn run_validate(
         ValidationLevel::parse(&config.specks.validation_level)
     };
 
+    let validation = &config.specks.validation;
+    let rules = validation
+        .rule_settings()
+        .and_then(|rules| Ok((rules, validation.custom_rules()?)));
+    let (rules, custom_rules) = match rules {
+        Ok(rules) => rules,
+        Err(e) => {
+            let message = e.to_string();
+            if json_output {
+                let issues = vec![JsonIssue {
+                    code: e.code().to_string(),
+                    severity: "error".to_string(),
+                    message: message.clone(),
+                    file: Some(".specks/config.toml".to_string()),
+                    line: None,
+                    anchor: None,
+                }];
+                let response: JsonResponse<ValidateData> =
+                    JsonResponse::error("validate", ValidateData::default(), issues);
+                println!("{}", serde_json::to_string_pretty(&response).unwrap());
+            } else {
+                eprintln!("error: {}", message);
+            }
+            return Ok(e.exit_code());
+        }
+    };
+
     let validation_config = ValidationConfig {
         level,
+        rules,
+        custom_rules,
         ..Default::default()
     };
 
     // Get files to validate
-    let files_to_validate = match file {
+    let mut files_to_validate = match file.clone() {
         Some(f) => {
             // Single file validation
             let path = resolve_file_path(&project_root, &f);
@@ -155,8 +286,69 @@ pub fn run_validate(
         }
     };
 
+    // Specks as they were at the --changed-since ref, keyed by current path
+    let mut previous: HashMap<PathBuf, String> = HashMap::new();
+    let mut referenced_by: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    if let Some(rev) = &changed_since {
+        let changed = match changed_files(&project_root, rev) {
+            Ok(changed) => changed,
+            Err(message) => {
+                if json_output {
+                    let issues = vec![JsonIssue {
+                        code: "E002".to_string(),
+                        severity: "error".to_string(),
+                        message: message.clone(),
+                        file: None,
+                        line: None,
+                        anchor: None,
+                    }];
+                    let response: JsonResponse<ValidateData> =
+                        JsonResponse::error("validate", ValidateData::default(), issues);
+                    println!("{}", serde_json::to_string_pretty(&response).unwrap());
+                } else {
+                    eprintln!("error: {}", message);
+                }
+                return Ok(2);
+            }
+        };
+
+        files_to_validate.retain(|path| changed.contains(&make_relative_path(&project_root, path)));
+        // Deleted specks are still compared with their old version
+        for relative_path in &changed {
+            let path = project_root.join(relative_path);
+            let is_speck = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| {
+                    name.starts_with("specks-") && name.ends_with(".md") && !is_reserved_file(name)
+                });
+            if is_speck && !path.exists() {
+                files_to_validate.push(path);
+            }
+        }
+        files_to_validate.sort();
+
+        for path in &files_to_validate {
+            let relative_path = make_relative_path(&project_root, path);
+            if let Some(content) = git_show(&project_root, rev, &relative_path) {
+                previous.insert(path.clone(), content);
+            }
+        }
+        referenced_by = cross_references(&project_root);
+    }
+
+    if watch {
+        return Ok(watch_validate(
+            &project_root,
+            file.as_deref(),
+            &validation_config,
+        ));
+    }
+
     if files_to_validate.is_empty() {
-        if json_output {
+        if !matches!(format, ReportFormat::Text | ReportFormat::Json) {
+            output_report(format, &[], &validation_config);
+        } else if json_output {
             let response = JsonResponse::ok(
                 "validate",
                 ValidateData {
@@ -166,31 +358,312 @@ pub fn run_validate(
             );
             println!("{}", serde_json::to_string_pretty(&response).unwrap());
         } else if !quiet {
-            println!("No speck files found to validate");
+            match &changed_since {
+                Some(rev) => println!("No specks changed since {}", rev),
+                None => println!("No speck files found to validate"),
+            }
         }
         return Ok(0);
     }
 
     let mut all_results: Vec<(PathBuf, ValidationResult)> = Vec::new();
-    let mut has_errors = false;
+    let mut contents: Vec<String> = Vec::new();
+    let mut fixed_files: Vec<FixedFile> = Vec::new();
 
     for path in &files_to_validate {
-        let result = validate_file(path, &validation_config);
-        if !result.valid {
-            has_errors = true;
+        let (content, mut result) = match fs::read_to_string(path) {
+            Ok(content) if fix => {
+                let relative_path = make_relative_path(&project_root, path);
+                match fix_file(path, &relative_path, &content, dry_run) {
+                    Ok((content, fixed)) => {
+                        if let Some(fixed) = fixed {
+                            fixed_files.push(fixed);
+                        }
+                        let result = validate_content(path, &content, &validation_config);
+                        (content, result)
+                    }
+                    // The file on disk is unchanged, so report on what it holds
+                    Err(e) => {
+                        let mut result = validate_content(path, &content, &validation_config);
+                        result.add_issue(specks_core::ValidationIssue::new(
+                            "E002",
+                            Severity::Error,
+                            format!("failed to write fixes: {}", e),
+                        ));
+                        (content, result)
+                    }
+                }
+            }
+            Ok(content) => {
+                let result = validate_content(path, &content, &validation_config);
+                (content, result)
+            }
+            // Deleted since the --changed-since ref
+            Err(_) if !path.exists() && previous.contains_key(path) => {
+                (String::new(), ValidationResult::new())
+            }
+            Err(e) => (String::new(), read_error_result(&e)),
+        };
+        if let Some(old_content) = previous.get(path) {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let refs = referenced_by.get(&name).map(Vec::as_slice).unwrap_or(&[]);
+            add_regressions(
+                path,
+                old_content,
+                &content,
+                refs,
+                &validation_config,
+                &mut result,
+            );
         }
+        contents.push(content);
         all_results.push((path.clone(), result));
     }
 
-    if json_output {
-        output_json(&project_root, &all_results, has_errors);
-    } else if !quiet {
-        output_text(&project_root, &all_results);
+    let baseline_path = project_root.join(".specks").join(BASELINE_FILE);
+    let baseline = match Baseline::load(&baseline_path) {
+        Ok(baseline) => baseline,
+        Err(e) => return Ok(output_baseline_error(&e, &baseline_path, json_output)),
+    };
+
+    if write_baseline {
+        // A single file updates only its own entries
+        let mut baseline = match (&file, baseline) {
+            (Some(_), Some(baseline)) => baseline,
+            _ => Baseline::default(),
+        };
+        let mut recorded = 0;
+        for ((path, result), content) in all_results.iter().zip(&contents) {
+            recorded += result.issues.len() + result.diagnostics.len();
+            baseline.record(&make_relative_path(&project_root, path), content, result);
+        }
+        if let Err(e) = baseline.save(&baseline_path) {
+            return Ok(output_baseline_error(&e, &baseline_path, json_output));
+        }
+        let summary = BaselineSummary {
+            path: make_relative_path(&project_root, &baseline_path),
+            written: true,
+            baselined: recorded,
+            fixed: vec![],
+        };
+        if json_output {
+            output_json(
+                &project_root,
+                &all_results,
+                fixed_files,
+                false,
+                Some(summary),
+            );
+        } else if !quiet {
+            output_fixes(&fixed_files, dry_run);
+            println!(
+                "Recorded {} issue{} from {} file{} in {}",
+                recorded,
+                if recorded == 1 { "" } else { "s" },
+                all_results.len(),
+                if all_results.len() == 1 { "" } else { "s" },
+                summary.path
+            );
+        }
+        return Ok(0);
+    }
+
+    let baseline_summary = baseline.map(|baseline| {
+        let mut summary = BaselineSummary {
+            path: make_relative_path(&project_root, &baseline_path),
+            written: false,
+            baselined: 0,
+            fixed: vec![],
+        };
+        let mut validated = Vec::new();
+        for ((path, result), content) in all_results.iter_mut().zip(&contents) {
+            let relative_path = make_relative_path(&project_root, path);
+            let matched = baseline.apply(&relative_path, content, result);
+            summary.baselined += matched.baselined;
+            summary.fixed.extend(matched.fixed);
+            validated.push(relative_path);
+        }
+        if file.is_none() && changed_since.is_none() {
+            // Recorded specks that no longer exist
+            summary.fixed.extend(baseline.entries_outside(&validated));
+        }
+        summary
+    });
+    let has_errors = all_results.iter().any(|(_, result)| !result.valid);
+
+    match format {
+        ReportFormat::Json => output_json(
+            &project_root,
+            &all_results,
+            fixed_files,
+            has_errors,
+            baseline_summary,
+        ),
+        ReportFormat::Text => {
+            if !quiet {
+                output_fixes(&fixed_files, dry_run);
+                output_text(&project_root, &all_results);
+                if let Some(summary) = &baseline_summary {
+                    output_baseline_text(summary);
+                }
+            }
+        }
+        _ => {
+            let results: Vec<(String, ValidationResult)> = all_results
+                .into_iter()
+                .map(|(path, result)| (make_relative_path(&project_root, &path), result))
+                .collect();
+            output_report(format, &results, &validation_config);
+        }
     }
 
     Ok(if has_errors { 1 } else { 0 })
 }
 
+/// Identity of an issue across watch cycles
+///
+/// Line numbers are left out so that edits above an issue do not report it
+/// as resolved and new again.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct IssueKey {
+    file: String,
+    code: String,
+    severity: &'static str,
+    message: String,
+    anchor: Option<String>,
+}
+
+/// All issues and diagnostics of a validation run, with their lines
+fn issue_map(
+    project_root: &Path,
+    results: &[(PathBuf, ValidationResult)],
+) -> BTreeMap<IssueKey, Option<usize>> {
+    let mut map = BTreeMap::new();
+    for (path, result) in results {
+        let file = make_relative_path(project_root, path);
+        for issue in &result.issues {
+            let key = IssueKey {
+                file: file.clone(),
+                code: issue.code.clone(),
+                severity: match issue.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Info => "info",
+                },
+                message: issue.message.clone(),
+                anchor: issue.anchor.clone(),
+            };
+            map.insert(key, issue.line);
+        }
+        for diagnostic in &result.diagnostics {
+            let key = IssueKey {
+                file: file.clone(),
+                code: diagnostic.code.clone(),
+                severity: "warning",
+                message: diagnostic.message.clone(),
+                anchor: None,
+            };
+            map.insert(key, Some(diagnostic.line));
+        }
+    }
+    map
+}
+
+/// Format one watched issue as `error[E005] path line 7: message`
+fn format_watched_issue(key: &IssueKey, line: Option<usize>) -> String {
+    let location = match line {
+        Some(line) => format!("{} line {}", key.file, line),
+        None => key.file.clone(),
+    };
+    format!(
+        "{}[{}] {}: {}",
+        key.severity, key.code, location, key.message
+    )
+}
+
+/// Validate the given file (or every speck) on each change under `.specks/`
+///
+/// The first run prints the full report; later runs print only new and
+/// resolved issues followed by the current totals. Runs until interrupted.
+fn watch_validate(project_root: &Path, file: Option<&str>, config: &ValidationConfig) -> i32 {
+    let files = |root: &Path| match file {
+        Some(f) => vec![resolve_file_path(root, f)],
+        None => find_specks(root).unwrap_or_default(),
+    };
+    let baseline_path = project_root.join(".specks").join(BASELINE_FILE);
+    let validate_all = |paths: &[PathBuf]| -> Vec<(PathBuf, ValidationResult)> {
+        // Reloaded every run, since the baseline lives under .specks/ too
+        let baseline = Baseline::load(&baseline_path).ok().flatten();
+        paths
+            .iter()
+            .map(|path| {
+                let result = match fs::read_to_string(path) {
+                    Ok(content) => {
+                        let mut result = validate_content(path, &content, config);
+                        if let Some(baseline) = &baseline {
+                            let relative_path = make_relative_path(project_root, path);
+                            baseline.apply(&relative_path, &content, &mut result);
+                        }
+                        result
+                    }
+                    Err(e) => read_error_result(&e),
+                };
+                (path.clone(), result)
+            })
+            .collect()
+    };
+
+    let specks_dir = project_root.join(".specks");
+    let mut watcher = Watcher::new(&specks_dir, DEFAULT_DEBOUNCE);
+    let results = validate_all(&files(project_root));
+    output_text(project_root, &results);
+    println!("Watching .specks/ for changes (Ctrl-C to stop)");
+    let mut previous = issue_map(project_root, &results);
+
+    loop {
+        let changed = watcher.wait_for_change();
+        let results = validate_all(&files(project_root));
+        let current = issue_map(project_root, &results);
+
+        let changed_names: Vec<String> = changed
+            .iter()
+            .map(|path| make_relative_path(project_root, path))
+            .collect();
+        println!(
+            "[{}] changed: {}",
+            &now_iso8601()[11..19],
+            changed_names.join(", ")
+        );
+
+        let (new, resolved) = map_delta(&previous, &current);
+        if new.is_empty() && resolved.is_empty() {
+            println!("  no new or resolved issues");
+        }
+        for (key, line) in &new {
+            println!("  + {}", format_watched_issue(key, **line));
+        }
+        for (key, line) in &resolved {
+            println!("  - {}", format_watched_issue(key, **line));
+        }
+
+        let errors = current.keys().filter(|k| k.severity == "error").count();
+        println!(
+            "  {} new, {} resolved; {} error{}, {} other issue{}",
+            new.len(),
+            resolved.len(),
+            errors,
+            if errors == 1 { "" } else { "s" },
+            current.len() - errors,
+            if current.len() - errors == 1 { "" } else { "s" }
+        );
+        println!();
+        previous = current;
+    }
+}
+
 /// Resolve a file path relative to the project
 fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
     let path = Path::new(file);
@@ -213,26 +686,208 @@ fn resolve_file_path(project_root: &Path, file: &str) -> PathBuf {
     }
 }
 
-/// Validate a single file
-fn validate_file(path: &Path, config: &ValidationConfig) -> ValidationResult {
-    // Read the file
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(e) => {
-            let mut result = ValidationResult::new();
-            result.add_issue(specks_core::ValidationIssue::new(
-                "E002",
-                Severity::Error,
-                format!("failed to read file: {}", e),
-            ));
-            return result;
+/// Apply safe fixes to a file's content, writing it back unless `dry_run`
+///
+/// Returns the content to validate and, if anything changed, the fix report.
+/// Fails if the fixed content cannot be written.
+fn fix_file(
+    path: &Path,
+    relative_path: &str,
+    content: &str,
+    dry_run: bool,
+) -> std::io::Result<(String, Option<FixedFile>)> {
+    let today = &now_iso8601()[..10];
+    let result = match fix_speck(content, today) {
+        Ok(result) if result.changed() => result,
+        _ => return Ok((content.to_string(), None)),
+    };
+
+    if !dry_run {
+        fs::write(path, &result.content)?;
+    }
+    let fixed = FixedFile {
+        path: relative_path.to_string(),
+        written: !dry_run,
+        fixes: result
+            .fixes
+            .iter()
+            .map(|f| JsonFix {
+                code: f.code.clone(),
+                line: f.line,
+                message: f.message.clone(),
+            })
+            .collect(),
+        diff: unified_diff(relative_path, content, &result.content),
+    };
+    Ok((result.content, Some(fixed)))
+}
+
+/// Speck files under `.specks/` that differ from `rev`: changed, added,
+/// deleted and untracked, as project-root-relative paths
+fn changed_files(project_root: &Path, rev: &str) -> Result<Vec<String>, String> {
+    let verified = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{}^{{commit}}", rev))
+        .output()
+        .map_err(|e| format!("failed to run git: {}", e))?;
+    if !verified.status.success() {
+        return Err(format!("unknown git ref '{}'", rev));
+    }
+
+    let git_lines = |args: &[&str]| -> Result<Vec<String>, String> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(project_root)
+            .args(args)
+            .output()
+            .map_err(|e| format!("failed to run git: {}", e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("git {} failed: {}", args[0], stderr.trim()));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.to_string())
+            .collect())
+    };
+
+    let mut changed = git_lines(&["diff", "--name-only", "--relative", rev, "--", ".specks"])?;
+    changed.extend(git_lines(&[
+        "ls-files",
+        "--others",
+        "--exclude-standard",
+        "--",
+        ".specks",
+    ])?);
+    changed.retain(|path| path.ends_with(".md"));
+    changed.sort();
+    changed.dedup();
+    Ok(changed)
+}
+
+/// Content of a project-root-relative file at `rev`, if it existed there
+fn git_show(project_root: &Path, rev: &str, relative_path: &str) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .arg("show")
+        .arg(format!("{}:./{}", rev, relative_path))
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Cross-file anchor references between the current specks, keyed by the
+/// referenced file name, as `(anchor, referencing file)` pairs
+fn cross_references(project_root: &Path) -> HashMap<String, Vec<(String, String)>> {
+    let mut refs: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    for path in find_specks(project_root).unwrap_or_default() {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        for (file, anchor) in cross_file_refs(&content) {
+            if file != name {
+                refs.entry(file).or_default().push((anchor, name.clone()));
+            }
         }
+    }
+    refs
+}
+
+/// Compare a speck with its content at the `--changed-since` ref
+fn add_regressions(
+    path: &Path,
+    old_content: &str,
+    content: &str,
+    referenced_by: &[(String, String)],
+    config: &ValidationConfig,
+    result: &mut ValidationResult,
+) {
+    let (Ok(old), Ok(mut new)) = (parse_speck(old_content), parse_speck(content)) else {
+        return;
     };
+    if let Some(dir) = path.parent() {
+        load_companions(&mut new, dir);
+    }
+    check_regressions(&old, &new, referenced_by, config, result);
+}
+
+/// Result for a file that could not be read
+fn read_error_result(e: &std::io::Error) -> ValidationResult {
+    let mut result = ValidationResult::new();
+    result.add_issue(specks_core::ValidationIssue::new(
+        "E002",
+        Severity::Error,
+        format!("failed to read file: {}", e),
+    ));
+    result
+}
+
+/// Report a baseline that cannot be read, parsed or written
+fn output_baseline_error(e: &SpecksError, path: &Path, json_output: bool) -> i32 {
+    let message = e.to_string();
+    if json_output {
+        let issues = vec![JsonIssue {
+            code: e.code().to_string(),
+            severity: "error".to_string(),
+            message,
+            file: Some(format!(".specks/{}", BASELINE_FILE)),
+            line: None,
+            anchor: None,
+        }];
+        let response: JsonResponse<ValidateData> =
+            JsonResponse::error("validate", ValidateData::default(), issues);
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    } else {
+        eprintln!("error: {} ({})", message, path.display());
+    }
+    e.exit_code()
+}
+
+/// Print how many issues the baseline hid and which recorded ones are fixed
+fn output_baseline_text(summary: &BaselineSummary) {
+    if summary.baselined > 0 {
+        println!(
+            "{} known issue{} hidden by {}",
+            summary.baselined,
+            if summary.baselined == 1 { "" } else { "s" },
+            summary.path
+        );
+    }
+    if !summary.fixed.is_empty() {
+        println!("\nFixed since baseline (run `specks validate --write-baseline` to update it):");
+        for entry in &summary.fixed {
+            let anchor = entry
+                .anchor
+                .as_ref()
+                .map(|a| format!(" {}", a))
+                .unwrap_or_default();
+            println!(
+                "  {} {}{}: {}",
+                entry.code, entry.file, anchor, entry.message
+            );
+        }
+    }
+}
 
+/// Validate the content of a single file
+fn validate_content(path: &Path, content: &str, config: &ValidationConfig) -> ValidationResult {
     // Parse the speck
-    let speck = match parse_speck(&content) {
+    let speck = match parse_speck(content) {
         Ok(mut s) => {
             s.path = path.to_str().map(|s| s.to_string());
+            if let Some(dir) = path.parent() {
+                load_companions(&mut s, dir);
+            }
             s
         }
         Err(e) => {
@@ -250,8 +905,54 @@ fn validate_file(path: &Path, config: &ValidationConfig) -> ValidationResult {
     validate_speck_with_config(&speck, config)
 }
 
+/// Print results as a SARIF, JUnit or GitHub annotation report
+fn output_report(
+    format: ReportFormat,
+    results: &[(String, ValidationResult)],
+    config: &ValidationConfig,
+) {
+    match format {
+        ReportFormat::Sarif => println!("{}", report::sarif(results)),
+        ReportFormat::Junit => print!("{}", report::junit(results, &config.rules)),
+        ReportFormat::Github => print!("{}", report::github(results)),
+        ReportFormat::Text | ReportFormat::Json => {}
+    }
+}
+
+/// Print the diff and a summary for each fixed file
+fn output_fixes(fixed_files: &[FixedFile], dry_run: bool) {
+    for fixed in fixed_files {
+        print!("{}", fixed.diff);
+        let count = fixed.fixes.len();
+        let verb = if dry_run {
+            "Would fix"
+        } else if fixed.written {
+            "Fixed"
+        } else {
+            "Could not write fixes for"
+        };
+        println!(
+            "{} {} issue{} in {}",
+            verb,
+            count,
+            if count == 1 { "" } else { "s" },
+            fixed.path
+        );
+        for fix in &fixed.fixes {
+            println!("  {} line {}: {}", fix.code, fix.line, fix.message);
+        }
+        println!();
+    }
+}
+
 /// Output results in JSON format
-fn output_json(project_root: &Path, results: &[(PathBuf, ValidationResult)], has_errors: bool) {
+fn output_json(
+    project_root: &Path,
+    results: &[(PathBuf, ValidationResult)],
+    fixed: Vec<FixedFile>,
+    has_errors: bool,
+    baseline: Option<BaselineSummary>,
+) {
     let mut files = Vec::new();
     let mut all_issues = Vec::new();
     let mut all_diagnostics = Vec::new();
@@ -283,6 +984,8 @@ fn output_json(project_root: &Path, results: &[(PathBuf, ValidationResult)], has
             ValidateData {
                 files,
                 diagnostics: all_diagnostics,
+                fixed,
+                baseline,
             },
             all_issues,
         )
@@ -292,6 +995,8 @@ fn output_json(project_root: &Path, results: &[(PathBuf, ValidationResult)], has
             ValidateData {
                 files,
                 diagnostics: all_diagnostics,
+                fixed,
+                baseline,
             },
             all_issues,
         )
@@ -422,3 +1127,21 @@ fn make_relative_path(project_root: &Path, path: &Path) -> String {
         .to_string_lossy()
         .replace('\\', "/")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_file_reports_write_errors() {
+        let temp = tempfile::tempdir().unwrap();
+        let content = "#### step 0: Setup {#step-0}\n\n**Commit:** `feat: setup`\n";
+        let path = temp.path().join("missing").join("specks-x.md");
+
+        let (fixed, report) = fix_file(&path, "specks-x.md", content, true).unwrap();
+        assert!(fixed.contains("#### Step 0: Setup {#step-0}"));
+        assert!(!report.unwrap().written);
+
+        assert!(fix_file(&path, "specks-x.md", content, false).is_err());
+    }
+}
@@ -386,14 +386,41 @@ pub fn run_worktree_create_with_root(
         };
 
         // Validate with normal level
+        let rules = specks_core::Config::load_from_project(&repo_root).and_then(|config| {
+            let validation = &config.specks.validation;
+            Ok((validation.rule_settings()?, validation.custom_rules()?))
+        });
+        let (rules, custom_rules) = match rules {
+            Ok(rules) => rules,
+            Err(e) => {
+                if json_output {
+                    let error_data = serde_json::json!({
+                        "error": format!("Invalid config: {}", e),
+                    });
+                    println!("{}", error_data);
+                } else if !quiet {
+                    eprintln!("error: Invalid config: {}", e);
+                }
+                return Ok(e.exit_code());
+            }
+        };
         let validation_config = specks_core::validator::ValidationConfig {
             level: ValidationLevel::Normal,
             beads_enabled: false,
             validate_bead_ids: false,
+            rules,
+            custom_rules,
         };
-        let validation_result =
+        let mut validation_result =
             specks_core::validate_speck_with_config(&parsed_speck, &validation_config);
 
+        // Issues recorded in the validation baseline do not block creation
+        let baseline_path = repo_root.join(".specks").join(specks_core::BASELINE_FILE);
+        if let Ok(Some(baseline)) = specks_core::Baseline::load(&baseline_path) {
+            let relative_path = speck.trim_start_matches("./").replace('\\', "/");
+            baseline.apply(&relative_path, &speck_content, &mut validation_result);
+        }
+
         // Check for validation errors or diagnostics
         if !validation_result.valid || !validation_result.diagnostics.is_empty() {
             if json_output {
@@ -448,6 +475,9 @@ pub fn run_worktree_create_with_root(
                 eprintln!("\nFix validation issues before creating worktree.");
                 eprintln!("Run: specks validate {}", speck);
                 eprintln!("Or use --skip-validation to bypass this check.");
+                eprintln!(
+                    "To accept existing issues, record them: specks validate --write-baseline"
+                );
             }
             return Ok(8); // Exit code 8: Validation failed
         }
@@ -611,9 +641,11 @@ pub fn run_worktree_create_with_root(
 
             // Query bd ready to get ready_steps (only if root_bead_id is available)
             let ready_steps: Option<Vec<String>> = if let Some(ref root_id) = root_bead_id {
-                use specks_core::beads::BeadsCli;
-                let bd = BeadsCli::default();
-                match bd.ready(Some(root_id), None) {
+                let config =
+                    specks_core::Config::load_from_project(&worktree_path).unwrap_or_default();
+                let ready = crate::commands::beads::open_tracker(&config, &worktree_path)
+                    .and_then(|bd| bd.ready(Some(root_id), Some(&worktree_path)));
+                match ready {
                     Ok(ready_beads) => {
                         // Map bead IDs to step anchors using bead_mapping
                         if let Some(ref mapping) = bead_mapping {
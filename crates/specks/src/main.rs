@@ -3,7 +3,9 @@
 mod cli;
 mod commands;
 mod output;
+mod report;
 mod splash;
+mod watch;
 
 use std::process::ExitCode;
 
@@ -17,20 +19,61 @@ fn main() -> ExitCode {
         Some(Commands::Init { force, check }) => {
             commands::run_init(force, check, cli.json, cli.quiet)
         }
+        Some(Commands::New {
+            name,
+            template,
+            title,
+            owner,
+            status,
+            vars,
+        }) => commands::run_new(commands::NewOptions {
+            name,
+            template,
+            title,
+            owner,
+            status,
+            vars,
+            json_output: cli.json,
+            quiet: cli.quiet,
+        }),
         Some(Commands::Validate {
             file,
             strict,
             level,
-        }) => commands::run_validate(file, strict, level, cli.json, cli.quiet),
+            fix,
+            dry_run,
+            format,
+            watch,
+            write_baseline,
+            changed_since,
+        }) => commands::run_validate(commands::ValidateOptions {
+            file,
+            strict,
+            level,
+            fix,
+            dry_run,
+            format,
+            watch,
+            write_baseline,
+            changed_since,
+            json_output: cli.json,
+            quiet: cli.quiet,
+        }),
+        Some(Commands::Fmt { file, check }) => commands::run_fmt(file, check, cli.json, cli.quiet),
         Some(Commands::List { status }) => commands::run_list(status, cli.json, cli.quiet),
         Some(Commands::Status {
             file,
             verbose,
             full,
+            watch,
         }) => {
             // Use verbose flag from subcommand, or global verbose
             let verbose = verbose || cli.verbose;
-            commands::run_status(file, verbose, full, cli.json, cli.quiet)
+            commands::run_status(file, verbose, full, watch, cli.json, cli.quiet)
+        }
+        Some(Commands::Next { file }) => commands::run_next(file, cli.json, cli.quiet),
+        Some(Commands::Labels { file, kind, label }) => {
+            commands::run_labels(file, kind, label, cli.json, cli.quiet)
         }
         Some(Commands::Beads(beads_cmd)) => match beads_cmd {
             BeadsCommands::Sync {
@@ -39,12 +82,14 @@ fn main() -> ExitCode {
                 enrich,
                 prune_deps,
                 substeps,
+                orphans,
             } => commands::run_sync(commands::beads::sync::SyncOptions {
                 file,
                 dry_run,
                 enrich,
                 prune_deps,
                 substeps_mode: substeps,
+                orphans,
                 json_output: cli.json,
                 quiet: cli.quiet,
             }),
@@ -56,9 +101,11 @@ fn main() -> ExitCode {
             BeadsCommands::Status { file, pull } => {
                 commands::run_beads_status(file, pull, cli.json, cli.quiet)
             }
-            BeadsCommands::Pull { file, no_overwrite } => {
-                commands::run_pull(file, no_overwrite, cli.json, cli.quiet)
-            }
+            BeadsCommands::Pull {
+                file,
+                no_overwrite,
+                fields,
+            } => commands::run_pull(file, no_overwrite, fields, cli.json, cli.quiet),
             BeadsCommands::Close { bead_id, reason } => {
                 commands::run_close(bead_id, reason, cli.json, cli.quiet)
             }
@@ -143,6 +190,7 @@ fn main() -> ExitCode {
             } => commands::run_log_prepend(None, step, speck, summary, bead, cli.json, cli.quiet),
         },
         Some(Commands::Doctor) => commands::run_doctor(cli.json, cli.quiet),
+        Some(Commands::Lsp) => commands::run_lsp(),
         Some(Commands::Version { verbose }) => commands::run_version(verbose, cli.json, cli.quiet),
         Some(Commands::StepCommit {
             worktree,
@@ -1,7 +1,7 @@
 //! JSON output formatting per Spec S05
 
 use serde::{Deserialize, Serialize};
-use specks_core::{Severity, ValidationIssue};
+use specks_core::{CheckMark, LabelKind, Severity, ValidationIssue};
 
 const SCHEMA_VERSION: &str = "1";
 
@@ -110,6 +110,19 @@ pub struct InitData {
     pub files_created: Vec<String>,
 }
 
+/// Data payload for new command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewData {
+    /// Project-root-relative path of the speck
+    pub path: String,
+    /// Speck name (without prefix or extension)
+    pub name: String,
+    /// Template used
+    pub template: String,
+    /// Whether the file was written
+    pub created: bool,
+}
+
 /// Data payload for init --check command
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InitCheckData {
@@ -162,6 +175,50 @@ pub struct ValidateData {
     /// Parse diagnostics (P-codes) across all validated files
     #[serde(default)]
     pub diagnostics: Vec<JsonDiagnostic>,
+    /// Files changed (or, with --dry-run, that would change) by --fix
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixed: Vec<FixedFile>,
+    /// Baseline written (--write-baseline) or applied to this run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<BaselineSummary>,
+}
+
+/// How the validation baseline was used
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineSummary {
+    /// Project-root-relative path of the baseline file
+    pub path: String,
+    /// Whether this run wrote the baseline
+    pub written: bool,
+    /// Entries written, or issues hidden because they are recorded
+    pub baselined: usize,
+    /// Recorded issues that no longer occur
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixed: Vec<specks_core::BaselineEntry>,
+}
+
+/// A file changed by `validate --fix`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedFile {
+    /// Project-root-relative path
+    pub path: String,
+    /// Whether the fixes were written (false for --dry-run)
+    pub written: bool,
+    /// Fixes applied
+    pub fixes: Vec<JsonFix>,
+    /// Unified diff of the change
+    pub diff: String,
+}
+
+/// A single applied fix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonFix {
+    /// Diagnostic or rule code resolved (e.g., "P001")
+    pub code: String,
+    /// Line number in the original file
+    pub line: usize,
+    /// What was changed
+    pub message: String,
 }
 
 /// A validated file entry
@@ -179,6 +236,24 @@ pub struct ValidatedFile {
     pub diagnostic_count: usize,
 }
 
+/// Data payload for fmt command
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FmtData {
+    /// Whether this was a --check run (no files written)
+    pub check: bool,
+    /// Files examined
+    pub files: Vec<FormattedFile>,
+}
+
+/// A formatted file entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormattedFile {
+    /// Project-root-relative path
+    pub path: String,
+    /// Whether the file was (or, with --check, would be) changed
+    pub changed: bool,
+}
+
 /// Data payload for list command
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListData {
@@ -197,6 +272,18 @@ pub struct SpeckSummary {
     pub progress: Progress,
     /// Last updated date
     pub updated: String,
+    /// Sum of all step estimates, in minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<u32>,
+    /// Sum of the estimates of unfinished steps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_minutes: Option<u32>,
+    /// Estimated length of the critical path through the unfinished steps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_critical_path_minutes: Option<u32>,
+    /// Actual time logged against estimated steps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_minutes: Option<u32>,
 }
 
 /// Progress tracking
@@ -261,6 +348,12 @@ pub struct StatusData {
     /// Bead-enriched step status (for --full)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bead_steps: Option<Vec<BeadStepStatus>>,
+    /// Unfinished steps of other specks that this speck waits on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_blockers: Vec<ExternalBlocker>,
+    /// Estimate rollups and actual time; none if nothing is estimated or logged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimates: Option<EstimateSummary>,
 }
 
 /// Status of a single step
@@ -274,6 +367,12 @@ pub struct StepStatus {
     pub done: usize,
     /// Total number of items
     pub total: usize,
+    /// Skipped, blocked and failed items, with their reasons
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub marked: Vec<MarkedItem>,
+    /// Counts including all nested substeps (only when there are substeps)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<Progress>,
     /// Substeps (if any)
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub substeps: Vec<SubstepStatus>,
@@ -290,6 +389,162 @@ pub struct SubstepStatus {
     pub done: usize,
     /// Total number of items
     pub total: usize,
+    /// Skipped, blocked and failed items, with their reasons
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub marked: Vec<MarkedItem>,
+    /// Counts including all nested substeps (only when there are substeps)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<Progress>,
+    /// Nested substeps (if any)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub substeps: Vec<SubstepStatus>,
+}
+
+/// A checkbox item marked skipped (`[-]`), blocked (`[~]`) or failed (`[!]`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkedItem {
+    /// Serialized as "skipped", "blocked" or "failed"
+    pub state: CheckMark,
+    /// Item text
+    pub text: String,
+    /// Reason given in the speck, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Line number in the speck
+    pub line: usize,
+}
+
+/// A step of another speck that blocks this one (`specks-auth.md#step-3`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalBlocker {
+    /// Anchor (with #) of the waiting step; none when the whole speck waits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// Number of the waiting step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    /// The dependency as written (`specks-auth.md#step-3`)
+    pub dependency: String,
+    /// Title of the blocking step (`Step 3: Tokens`); none if it was not found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Completed items of the blocking step
+    pub done: usize,
+    /// Total items of the blocking step
+    pub total: usize,
+}
+
+/// Estimated and actual time of a speck, in minutes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateSummary {
+    /// Sum of all step estimates
+    pub total_minutes: u32,
+    /// Sum of the estimates of unfinished steps
+    pub remaining_minutes: u32,
+    /// Steps without an estimate
+    pub unestimated_steps: usize,
+    /// Anchors (with #) of the critical path through all steps
+    pub critical_path: Vec<String>,
+    /// Estimated length of the critical path
+    pub critical_path_minutes: u32,
+    /// Anchors (with #) of the critical path through the unfinished steps
+    pub remaining_critical_path: Vec<String>,
+    /// Estimated length of the remaining critical path
+    pub remaining_critical_path_minutes: u32,
+    /// Actual time of the completed steps that have an estimate and a known start
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_minutes: Option<u32>,
+    /// Estimates of the same steps as `actual_minutes`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_estimate_minutes: Option<u32>,
+    /// Actual over estimated time (above 1.0 means slower than estimated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pace: Option<f64>,
+    /// Expected completion time (ISO 8601) from the remaining critical path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eta: Option<String>,
+    /// Per step, in document order
+    pub steps: Vec<StepEstimate>,
+}
+
+/// Estimated and actual time of a single step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepEstimate {
+    /// Step anchor (with #)
+    pub anchor: String,
+    /// Estimate in minutes, including substeps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<u32>,
+    /// Whether the step is complete
+    pub done: bool,
+    /// Completion time (ISO 8601) from the implementation log or bead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// Minutes since the previous completed step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual_minutes: Option<u32>,
+}
+
+/// Data payload for next command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NextData {
+    /// Speck name
+    pub name: String,
+    /// Steps with every item checked
+    pub complete: usize,
+    /// Total steps
+    pub total: usize,
+    /// Steps that can be started now
+    pub ready: Vec<NextStep>,
+    /// Unfinished steps waiting on other steps
+    pub blocked: Vec<NextStep>,
+    /// Unfinished steps (anchors with #) grouped into waves that can run in
+    /// parallel, the first wave first; steps waiting on another speck are
+    /// never in the first wave
+    pub waves: Vec<Vec<String>>,
+}
+
+/// A step listed by the next command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NextStep {
+    /// Step anchor (with #)
+    pub anchor: String,
+    /// Step number
+    pub number: String,
+    /// Step title
+    pub title: String,
+    /// Unfinished dependencies: anchors (with #) of this speck's steps, or
+    /// other specks' steps as written (`specks-auth.md#step-3`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+}
+
+/// Data payload for labels command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelsData {
+    /// Speck name
+    pub name: String,
+    /// Labeled artifacts in document order, after filtering
+    pub labels: Vec<LabelInfo>,
+}
+
+/// A labeled artifact listed by the labels command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelInfo {
+    /// Label ID (e.g., "S01")
+    pub id: String,
+    /// Kind of artifact ("spec", "table", ...)
+    pub kind: LabelKind,
+    /// Label title
+    pub title: String,
+    /// Anchor (with #) if the label has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// Line number
+    pub line: usize,
+    /// Anchors (with #) of the steps and substeps whose References cite it
+    #[serde(default)]
+    pub cited_by: Vec<String>,
 }
 
 /// Lightweight step information for extended status queries
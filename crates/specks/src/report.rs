@@ -0,0 +1,354 @@
+//! CI report formats for `specks validate`
+//!
+//! - SARIF 2.1.0, for code-scanning UIs
+//! - JUnit XML, one testsuite per speck and one testcase per rule
+//! - GitHub workflow commands (`::error file=…,line=…::message`)
+//!
+//! Parse diagnostics (P-codes) are reported as warnings, as in text output.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde_json::json;
+use specks_core::{RULES, RuleSetting, Severity, ValidationResult, rule_description};
+
+/// Output format for `specks validate --format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Sarif,
+    Junit,
+    Github,
+}
+
+impl ReportFormat {
+    /// Parse a `--format` value
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Some(ReportFormat::Text),
+            "json" => Some(ReportFormat::Json),
+            "sarif" => Some(ReportFormat::Sarif),
+            "junit" => Some(ReportFormat::Junit),
+            "github" => Some(ReportFormat::Github),
+            _ => None,
+        }
+    }
+}
+
+/// A finding from either the validator or the parser
+struct Finding<'a> {
+    code: &'a str,
+    level: Severity,
+    message: &'a str,
+    line: Option<usize>,
+}
+
+/// Issues and diagnostics of one result, in reporting order
+fn findings(result: &ValidationResult) -> Vec<Finding<'_>> {
+    let issues = result.issues.iter().map(|i| Finding {
+        code: &i.code,
+        level: i.severity,
+        message: &i.message,
+        line: i.line,
+    });
+    let diagnostics = result.diagnostics.iter().map(|d| Finding {
+        code: &d.code,
+        level: Severity::Warning,
+        message: &d.message,
+        line: Some(d.line),
+    });
+    issues.chain(diagnostics).collect()
+}
+
+/// Render results as a SARIF 2.1.0 log
+pub fn sarif(results: &[(String, ValidationResult)]) -> String {
+    let mut rules: Vec<serde_json::Value> = RULES
+        .iter()
+        .map(|(code, description)| {
+            json!({ "id": code, "shortDescription": { "text": description } })
+        })
+        .collect();
+    let mut extra: BTreeSet<&str> = BTreeSet::new();
+
+    let mut sarif_results = Vec::new();
+    for (path, result) in results {
+        for finding in findings(result) {
+            if rule_description(finding.code).is_none() {
+                extra.insert(finding.code);
+            }
+            let mut location = json!({ "artifactLocation": { "uri": path } });
+            if let Some(line) = finding.line {
+                location["region"] = json!({ "startLine": line });
+            }
+            sarif_results.push(json!({
+                "ruleId": finding.code,
+                "level": match finding.level {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Info => "note",
+                },
+                "message": { "text": finding.message },
+                "locations": [{ "physicalLocation": location }],
+            }));
+        }
+    }
+    rules.extend(extra.into_iter().map(|code| json!({ "id": code })));
+
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "specks",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": sarif_results,
+        }],
+    });
+    serde_json::to_string_pretty(&log).unwrap()
+}
+
+/// Render results as JUnit XML
+///
+/// Errors fail a testcase; warnings and info are listed in its
+/// `system-out`. Rules turned off in config are marked skipped.
+pub fn junit(
+    results: &[(String, ValidationResult)],
+    rule_settings: &HashMap<String, RuleSetting>,
+) -> String {
+    let mut suites = String::new();
+    let (mut total_tests, mut total_failures) = (0, 0);
+
+    for (path, result) in results {
+        let findings = findings(result);
+        let mut codes: Vec<&str> = RULES.iter().map(|(code, _)| *code).collect();
+        for finding in &findings {
+            if !codes.contains(&finding.code) {
+                codes.push(finding.code);
+            }
+        }
+
+        let (mut failures, mut skipped) = (0, 0);
+        let mut cases = String::new();
+        for code in &codes {
+            let name = match rule_description(code) {
+                Some(description) => format!("{}: {}", code, description),
+                None => code.to_string(),
+            };
+            cases.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                xml_escape(path),
+                xml_escape(&name)
+            ));
+
+            let matching: Vec<&Finding> = findings.iter().filter(|f| f.code == *code).collect();
+            if rule_settings.get(*code) == Some(&RuleSetting::Off) {
+                skipped += 1;
+                cases.push_str(">\n      <skipped message=\"rule is off\"/>\n    </testcase>\n");
+                continue;
+            }
+            if matching.is_empty() {
+                cases.push_str("/>\n");
+                continue;
+            }
+
+            cases.push_str(">\n");
+            let errors: Vec<&&Finding> = matching
+                .iter()
+                .filter(|f| f.level == Severity::Error)
+                .collect();
+            if !errors.is_empty() {
+                failures += 1;
+                cases.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    code,
+                    xml_escape(errors[0].message),
+                    xml_escape(&finding_lines(errors.into_iter().copied()))
+                ));
+            }
+            let others: Vec<&&Finding> = matching
+                .iter()
+                .filter(|f| f.level != Severity::Error)
+                .collect();
+            if !others.is_empty() {
+                cases.push_str(&format!(
+                    "      <system-out>{}</system-out>\n",
+                    xml_escape(&finding_lines(others.into_iter().copied()))
+                ));
+            }
+            cases.push_str("    </testcase>\n");
+        }
+
+        total_tests += codes.len();
+        total_failures += failures;
+        suites.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n{}  </testsuite>\n",
+            xml_escape(path),
+            codes.len(),
+            failures,
+            skipped,
+            cases
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"specks validate\" tests=\"{}\" failures=\"{}\">\n{}</testsuites>\n",
+        total_tests, total_failures, suites
+    )
+}
+
+/// One `line N: message` row per finding
+fn finding_lines<'a>(findings: impl Iterator<Item = &'a Finding<'a>>) -> String {
+    findings
+        .map(|f| match f.line {
+            Some(line) => format!("line {}: {}", line, f.message),
+            None => f.message.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render results as GitHub workflow command annotations
+pub fn github(results: &[(String, ValidationResult)]) -> String {
+    let mut out = String::new();
+    for (path, result) in results {
+        for finding in findings(result) {
+            let command = match finding.level {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "notice",
+            };
+            let mut properties = format!("file={}", github_property(path));
+            if let Some(line) = finding.line {
+                properties.push_str(&format!(",line={}", line));
+            }
+            properties.push_str(&format!(",title={}", github_property(finding.code)));
+            out.push_str(&format!(
+                "::{} {}::{}\n",
+                command,
+                properties,
+                github_data(finding.message)
+            ));
+        }
+    }
+    out
+}
+
+/// Escape the message part of a workflow command
+fn github_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command
+fn github_property(s: &str) -> String {
+    github_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Escape text for XML attributes and content
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use specks_core::{ParseDiagnostic, ValidationIssue};
+
+    fn sample() -> Vec<(String, ValidationResult)> {
+        let mut result = ValidationResult::new();
+        result.add_issue(
+            ValidationIssue::new("E005", Severity::Error, "Invalid anchor <x>".to_string())
+                .at_line(7),
+        );
+        result.add_issue(ValidationIssue::new(
+            "W006",
+            Severity::Warning,
+            "Unfilled placeholder in metadata: Owner contains <name>".to_string(),
+        ));
+        result.diagnostics.push(ParseDiagnostic {
+            code: "P001".to_string(),
+            message: "Step header does not match strict format".to_string(),
+            line: 12,
+            suggestion: None,
+        });
+        vec![(".specks/specks-a.md".to_string(), result)]
+    }
+
+    #[test]
+    fn test_report_format_parse() {
+        assert_eq!(ReportFormat::parse("SARIF"), Some(ReportFormat::Sarif));
+        assert_eq!(ReportFormat::parse("xml"), None);
+    }
+
+    #[test]
+    fn test_sarif_results_and_rules() {
+        let log: serde_json::Value = serde_json::from_str(&sarif(&sample())).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert!(
+            run["tool"]["driver"]["rules"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|r| r["id"] == "P009")
+        );
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["ruleId"], "E005");
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], ".specks/specks-a.md");
+        assert_eq!(location["region"]["startLine"], 7);
+        // No line: no region
+        assert!(results[1]["locations"][0]["physicalLocation"]["region"].is_null());
+        assert_eq!(results[2]["ruleId"], "P001");
+        assert_eq!(results[2]["level"], "warning");
+    }
+
+    #[test]
+    fn test_junit_testcase_per_rule() {
+        let settings = HashMap::from([("W004".to_string(), RuleSetting::Off)]);
+        let xml = junit(&sample(), &settings);
+        let tests = RULES.len();
+        assert!(xml.contains(&format!(
+            "<testsuite name=\".specks/specks-a.md\" tests=\"{}\" failures=\"1\" skipped=\"1\">",
+            tests
+        )));
+        assert!(xml.contains(
+            "<failure type=\"E005\" message=\"Invalid anchor &lt;x&gt;\">line 7: Invalid anchor &lt;x&gt;</failure>"
+        ));
+        assert!(xml.contains(
+            "<testcase classname=\".specks/specks-a.md\" name=\"W004: Step without test items\">\n      <skipped"
+        ));
+        assert!(xml.contains(
+            "<system-out>line 12: Step header does not match strict format</system-out>"
+        ));
+        assert!(xml.contains("name=\"W003: Step without checkpoint items\"/>"));
+    }
+
+    #[test]
+    fn test_github_annotations() {
+        let out = github(&sample());
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "::error file=.specks/specks-a.md,line=7,title=E005::Invalid anchor <x>",
+                "::warning file=.specks/specks-a.md,title=W006::Unfilled placeholder in metadata: Owner contains <name>",
+                "::warning file=.specks/specks-a.md,line=12,title=P001::Step header does not match strict format",
+            ]
+        );
+        assert_eq!(github_property("a,b:c"), "a%2Cb%3Ac");
+        assert_eq!(github_data("50%\nmore"), "50%25%0Amore");
+    }
+}
@@ -0,0 +1,181 @@
+//! Polling file watcher for `--watch` modes
+//!
+//! Watches a directory tree by comparing modification times and sizes, so no
+//! platform notification API is needed. A change is reported only once the
+//! tree has been quiet for the debounce interval, which collapses the bursts
+//! of writes agents make while editing a speck.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the tree is scanned
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long the tree must stay unchanged before a change is reported
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Modification time and size of every file under the watched directory
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+/// Watches a directory tree for changes
+pub struct Watcher {
+    root: PathBuf,
+    debounce: Duration,
+    snapshot: Snapshot,
+}
+
+impl Watcher {
+    /// Start watching `root`; the current state is the baseline
+    pub fn new(root: &Path, debounce: Duration) -> Self {
+        Watcher {
+            root: root.to_path_buf(),
+            debounce,
+            snapshot: scan(root),
+        }
+    }
+
+    /// Block until the tree changes and then stays quiet for the debounce
+    /// interval. Returns the files that were added, removed or modified.
+    pub fn wait_for_change(&mut self) -> Vec<PathBuf> {
+        let mut current = loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = scan(&self.root);
+            if current != self.snapshot {
+                break current;
+            }
+        };
+
+        let mut quiet_since = Instant::now();
+        while quiet_since.elapsed() < self.debounce {
+            thread::sleep(POLL_INTERVAL);
+            let next = scan(&self.root);
+            if next != current {
+                current = next;
+                quiet_since = Instant::now();
+            }
+        }
+
+        let changed = changed_paths(&self.snapshot, &current);
+        self.snapshot = current;
+        changed
+    }
+}
+
+/// Record every file under `root`; unreadable entries are skipped
+fn scan(root: &Path) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                snapshot.insert(entry.path(), (metadata.modified().ok(), metadata.len()));
+            }
+        }
+    }
+    snapshot
+}
+
+/// Paths that differ between two snapshots
+fn changed_paths(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let paths: BTreeSet<&PathBuf> = before.keys().chain(after.keys()).collect();
+    paths
+        .into_iter()
+        .filter(|path| before.get(*path) != after.get(*path))
+        .cloned()
+        .collect()
+}
+
+/// Entries that differ between two maps, borrowed from one of them
+pub type Entries<'a, K, V> = Vec<(&'a K, &'a V)>;
+
+/// Entries only in `after` (new) and entries only in `before` (resolved)
+///
+/// Entries are matched by key; values (such as line numbers) are carried
+/// along for display but do not make an entry new.
+pub fn map_delta<'a, K: Ord, V>(
+    before: &'a BTreeMap<K, V>,
+    after: &'a BTreeMap<K, V>,
+) -> (Entries<'a, K, V>, Entries<'a, K, V>) {
+    (
+        after
+            .iter()
+            .filter(|(k, _)| !before.contains_key(k))
+            .collect(),
+        before
+            .iter()
+            .filter(|(k, _)| !after.contains_key(k))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_paths() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(temp.path().join("a.md"), "a").unwrap();
+        fs::create_dir(temp.path().join("sub")).unwrap();
+        fs::write(temp.path().join("sub").join("b.md"), "b").unwrap();
+        let before = scan(temp.path());
+        assert_eq!(before.len(), 2);
+
+        fs::write(temp.path().join("sub").join("b.md"), "bb").unwrap();
+        fs::remove_file(temp.path().join("a.md")).unwrap();
+        fs::write(temp.path().join("c.md"), "c").unwrap();
+        let after = scan(temp.path());
+
+        let changed = changed_paths(&before, &after);
+        assert_eq!(
+            changed,
+            vec![
+                temp.path().join("a.md"),
+                temp.path().join("c.md"),
+                temp.path().join("sub").join("b.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wait_for_change_debounces_bursts() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("specks-a.md");
+        fs::write(&path, "0").unwrap();
+        let mut watcher = Watcher::new(temp.path(), Duration::from_millis(300));
+
+        let writer_path = path.clone();
+        let writer = thread::spawn(move || {
+            for i in 1..=5 {
+                thread::sleep(Duration::from_millis(50));
+                fs::write(&writer_path, "x".repeat(i)).unwrap();
+            }
+        });
+
+        let changed = watcher.wait_for_change();
+        writer.join().unwrap();
+        assert_eq!(changed, vec![path.clone()]);
+        // The whole burst was absorbed into one change
+        assert_eq!(watcher.snapshot[&path].1, 5);
+    }
+
+    #[test]
+    fn test_map_delta_ignores_value_changes() {
+        let before = BTreeMap::from([("a", 1), ("b", 2)]);
+        let after = BTreeMap::from([("b", 7), ("c", 3)]);
+        let (added, resolved) = map_delta(&before, &after);
+        assert_eq!(added, vec![(&"c", &3)]);
+        assert_eq!(resolved, vec![(&"a", &1)]);
+    }
+}
@@ -512,6 +512,58 @@ fn test_beads_sync_creates_dependency_edges() {
     );
 }
 
+#[test]
+fn test_beads_sync_children_mode_nests_substep_beads() {
+    let temp = setup_test_project();
+    let temp_state = tempfile::tempdir().expect("failed to create temp state dir");
+
+    let mut fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    fixture.pop(); // crates
+    fixture.pop(); // specks root
+    fixture.push("tests/fixtures/valid/nested-substeps.md");
+    let content = fs::read_to_string(&fixture).expect("failed to read nested fixture");
+    create_test_speck(&temp, "nested", &content);
+
+    let output = Command::new(specks_binary())
+        .env("SPECKS_BD_PATH", bd_fake_path())
+        .env("SPECKS_BD_STATE", temp_state.path())
+        .args([
+            "beads",
+            "sync",
+            "specks-nested.md",
+            "--substeps",
+            "children",
+            "--json",
+        ])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks beads sync");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "sync should succeed: {}", stdout);
+
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("should be valid JSON");
+    assert_eq!(json["data"]["steps_synced"], 6);
+
+    // Every step and substep gets a bead line, and Step 1.1.1 is a
+    // grandchild of the Step 1 bead
+    let speck = specks_core::parse_speck(
+        &fs::read_to_string(temp.path().join(".specks/specks-nested.md")).unwrap(),
+    )
+    .unwrap();
+    let step_bead = speck.steps[1].bead_id.clone().expect("step 1 bead");
+    let child_bead = speck.steps[1].substeps[0]
+        .bead_id
+        .clone()
+        .expect("1.1 bead");
+    let grandchild_bead = speck.steps[1].substeps[0].substeps[0]
+        .bead_id
+        .clone()
+        .expect("1.1.1 bead");
+    assert!(child_bead.starts_with(&format!("{}.", step_bead)));
+    assert!(grandchild_bead.starts_with(&format!("{}.", child_bead)));
+}
+
 // =============================================================================
 // Beads status integration tests
 // =============================================================================
@@ -640,6 +692,63 @@ fn test_beads_pull_updates_checkboxes() {
     );
 }
 
+#[test]
+fn test_beads_pull_leaves_marked_checkboxes_and_warns() {
+    let temp = setup_test_project();
+    let temp_state = tempfile::tempdir().expect("failed to create temp state dir");
+    let speck = SINGLE_STEP_SPECK.replace(
+        "- [ ] Build passes\n",
+        "- [ ] Build passes\n- [-] Load test -- no staging cluster\n- [!] Fuzz test\n",
+    );
+    create_test_speck(&temp, "test", &speck);
+
+    Command::new(specks_binary())
+        .env("SPECKS_BD_PATH", bd_fake_path())
+        .env("SPECKS_BD_STATE", temp_state.path())
+        .args(["beads", "sync", "specks-test.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run sync");
+
+    let issues_content =
+        fs::read_to_string(temp_state.path().join("issues.json")).expect("failed to read issues");
+    let issues: serde_json::Value = serde_json::from_str(&issues_content).unwrap();
+    let step_bead_id = issues
+        .as_object()
+        .unwrap()
+        .keys()
+        .find(|k| k.contains('.'))
+        .expect("should have a step bead");
+
+    Command::new(bd_fake_path())
+        .env("SPECKS_BD_STATE", temp_state.path())
+        .args(["close", step_bead_id])
+        .output()
+        .expect("failed to close bead");
+
+    let output = Command::new(specks_binary())
+        .env("SPECKS_BD_PATH", bd_fake_path())
+        .env("SPECKS_BD_STATE", temp_state.path())
+        .args(["beads", "pull", "specks-test.md", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run pull");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "pull should succeed: {}", stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("should be valid JSON");
+    assert_eq!(json["data"]["total_updated"], 1);
+    let warnings = json["data"]["files"][0]["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].as_str().unwrap().contains("1 item(s)"));
+
+    let speck_content = fs::read_to_string(temp.path().join(".specks/specks-test.md"))
+        .expect("failed to read speck");
+    assert!(speck_content.contains("- [x] Build passes"));
+    assert!(speck_content.contains("- [-] Load test -- no staging cluster"));
+    assert!(speck_content.contains("- [!] Fuzz test"));
+}
+
 // =============================================================================
 // Full workflow integration test (as documented in README)
 // =============================================================================
@@ -1316,3 +1425,356 @@ fn test_revision_loop_update_notes_overwrites() {
         "notes should contain new coder content"
     );
 }
+
+// =============================================================================
+// Built-in file tracker
+// =============================================================================
+
+/// Create an initialized project that uses the built-in file tracker
+fn setup_file_tracker_project() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let output = Command::new(specks_binary())
+        .arg("init")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks init");
+    assert!(output.status.success());
+
+    let config_path = temp.path().join(".specks/config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace("tracker = \"bd\"", "tracker = \"file\""),
+    )
+    .unwrap();
+    temp
+}
+
+/// Run specks with `--json` output and no bd available, asserting success
+fn run_specks_json(temp: &tempfile::TempDir, args: &[&str]) -> serde_json::Value {
+    let output = Command::new(specks_binary())
+        .env("SPECKS_BD_PATH", "/nonexistent/bd")
+        .args(args)
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks");
+    assert!(
+        output.status.success(),
+        "{:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stdout)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_file_tracker_workflow_without_bd() {
+    let temp = setup_file_tracker_project();
+    create_test_speck(&temp, "offline", MULTI_STEP_SPECK);
+    let specks = |args: &[&str]| run_specks_json(&temp, args);
+
+    let sync = specks(&["beads", "sync", "specks-offline.md", "--json"]);
+    assert_eq!(sync["data"]["steps_synced"], 3);
+    let root_id = sync["data"]["root_bead_id"].as_str().unwrap().to_string();
+    assert_eq!(root_id, "sp-1");
+    assert!(temp.path().join(".specks/tracker/sp-1.json").is_file());
+    assert!(temp.path().join(".specks/tracker/sp-1.3.json").is_file());
+
+    // Re-running sync reuses the stored issues
+    specks(&["beads", "sync", "specks-offline.md", "--json"]);
+    assert!(!temp.path().join(".specks/tracker/sp-2.json").exists());
+
+    let status = specks(&["beads", "status", "specks-offline.md", "--json"]);
+    let steps = status["data"]["files"][0]["steps"].as_array().unwrap();
+    let step = |steps: &[serde_json::Value], anchor: &str| {
+        steps
+            .iter()
+            .find(|s| s["anchor"] == anchor)
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(step(steps, "step-0")["status"], "ready");
+    assert_eq!(step(steps, "step-1")["status"], "blocked");
+    let bead_id = step(steps, "step-0")["bead_id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    specks(&["beads", "close", &bead_id, "--reason", "Done", "--json"]);
+    let stored = fs::read_to_string(
+        temp.path()
+            .join(format!(".specks/tracker/{}.json", bead_id)),
+    )
+    .unwrap();
+    assert!(stored.contains("\"close_reason\": \"Done\""));
+
+    let status = specks(&["beads", "status", "specks-offline.md", "--json"]);
+    let steps = status["data"]["files"][0]["steps"].as_array().unwrap();
+    assert_eq!(step(steps, "step-0")["status"], "complete");
+    assert_eq!(step(steps, "step-1")["status"], "ready");
+
+    let pull = specks(&["beads", "pull", "specks-offline.md", "--json"]);
+    assert!(pull["data"]["total_updated"].as_u64().unwrap() >= 1);
+    let speck = fs::read_to_string(temp.path().join(".specks/specks-offline.md")).unwrap();
+    assert!(speck.contains("[x] Base works"));
+}
+
+#[test]
+fn test_unknown_tracker_is_a_config_error() {
+    let temp = setup_file_tracker_project();
+    create_test_speck(&temp, "typo", MULTI_STEP_SPECK);
+    let config_path = temp.path().join(".specks/config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace("tracker = \"file\"", "tracker = \"fiel\""),
+    )
+    .unwrap();
+
+    let output = Command::new(specks_binary())
+        .env("SPECKS_BD_PATH", bd_fake_path())
+        .args(["beads", "sync", "specks-typo.md", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks");
+    assert_eq!(output.status.code(), Some(4));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["issues"][0]["code"], "E004");
+    assert!(
+        json["issues"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("unknown beads tracker 'fiel'")
+    );
+}
+
+#[test]
+fn test_beads_sync_reconciles_orphan_beads() {
+    let temp = setup_file_tracker_project();
+    create_test_speck(&temp, "orphans", MULTI_STEP_SPECK);
+    let speck_path = temp.path().join(".specks/specks-orphans.md");
+    let specks = |args: &[&str]| run_specks_json(&temp, args);
+    specks(&["beads", "sync", "specks-orphans.md", "--json"]);
+
+    // Drop Step 2, and rename Step 1's anchor and title, losing its bead line
+    let synced = fs::read_to_string(&speck_path).unwrap();
+    let step_2 = synced.find("#### Step 2:").unwrap();
+    let deliverables = synced.find("### 1.0.6").unwrap();
+    let edited = format!("{}{}", &synced[..step_2], &synced[deliverables..])
+        .replace(
+            "#### Step 1: Build on Foundation {#step-1}",
+            "#### Step 1: Build on the Foundation {#step-build}",
+        )
+        .replace("**Bead:** `sp-1.2`\n", "");
+    assert!(!edited.contains("sp-1.2"));
+    fs::write(&speck_path, &edited).unwrap();
+
+    let orphans = |sync: &serde_json::Value| {
+        sync["data"]["orphans"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .iter()
+            .map(|o| {
+                format!(
+                    "{} {} {}",
+                    o["bead_id"].as_str().unwrap(),
+                    o["action"].as_str().unwrap(),
+                    o["step_anchor"].as_str().unwrap_or("-")
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Default mode only reports
+    let sync = specks(&["beads", "sync", "specks-orphans.md", "--dry-run", "--json"]);
+    assert_eq!(orphans(&sync), ["sp-1.2 report -", "sp-1.3 report -"]);
+
+    // Dry-run relink shows the plan without touching the speck
+    let sync = specks(&[
+        "beads",
+        "sync",
+        "specks-orphans.md",
+        "--dry-run",
+        "--orphans",
+        "relink",
+        "--json",
+    ]);
+    assert_eq!(
+        orphans(&sync),
+        ["sp-1.2 relink step-build", "sp-1.3 report -"]
+    );
+    assert_eq!(fs::read_to_string(&speck_path).unwrap(), edited);
+
+    let sync = specks(&[
+        "beads",
+        "sync",
+        "specks-orphans.md",
+        "--orphans",
+        "relink",
+        "--json",
+    ]);
+    assert_eq!(
+        orphans(&sync),
+        ["sp-1.2 relink step-build", "sp-1.3 report -"]
+    );
+    assert!(fs::read_to_string(&speck_path).unwrap().contains("sp-1.2"));
+    assert!(!temp.path().join(".specks/tracker/sp-1.4.json").exists());
+
+    let sync = specks(&[
+        "beads",
+        "sync",
+        "specks-orphans.md",
+        "--orphans",
+        "close",
+        "--json",
+    ]);
+    assert_eq!(orphans(&sync), ["sp-1.3 close -"]);
+    let closed = specks(&["beads", "inspect", "sp-1.3", "--json"]);
+    assert_eq!(closed["data"]["status"], "closed");
+
+    let sync = specks(&["beads", "sync", "specks-orphans.md", "--json"]);
+    assert!(orphans(&sync).is_empty());
+}
+
+#[test]
+fn test_beads_pull_fields_three_way() {
+    let temp = setup_file_tracker_project();
+    create_test_speck(&temp, "fields", MULTI_STEP_SPECK);
+    let speck_path = temp.path().join(".specks/specks-fields.md");
+    let specks = |args: &[&str]| run_specks_json(&temp, args);
+    specks(&["beads", "sync", "specks-fields.md", "--json"]);
+    assert!(temp.path().join(".specks/beads-snapshot.json").is_file());
+
+    let edit_bead = |id: &str, edit: &dyn Fn(&mut serde_json::Value)| {
+        let path = temp.path().join(format!(".specks/tracker/{}.json", id));
+        let mut issue: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        edit(&mut issue);
+        fs::write(&path, issue.to_string()).unwrap();
+    };
+
+    // Step 0 is edited only in beads; Step 1's title on both sides
+    edit_bead("sp-1.1", &|issue| {
+        issue["title"] = "Step 0: Lay the Foundation".into();
+        issue["priority"] = 1.into();
+        issue["labels"] = serde_json::json!(["infra", "core"]);
+    });
+    edit_bead("sp-1.2", &|issue| {
+        issue["title"] = "Step 1: Build Upward".into();
+    });
+    let speck = fs::read_to_string(&speck_path).unwrap().replace(
+        "#### Step 1: Build on Foundation {#step-1}",
+        "#### Step 1: Build Sideways {#step-1}",
+    );
+    fs::write(&speck_path, speck).unwrap();
+
+    // Without --fields only checkboxes are pulled
+    specks(&["beads", "pull", "specks-fields.md", "--json"]);
+    assert!(
+        fs::read_to_string(&speck_path)
+            .unwrap()
+            .contains("#### Step 0: Foundation {#step-0}")
+    );
+
+    let pull = specks(&["beads", "pull", "specks-fields.md", "--fields", "--json"]);
+    assert_eq!(pull["data"]["total_fields_updated"], 3);
+    let result = &pull["data"]["files"][0];
+    let updates: Vec<String> = result["fields_updated"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|u| {
+            format!(
+                "{} {} {} -> {}",
+                u["anchor"].as_str().unwrap(),
+                u["field"].as_str().unwrap(),
+                u["from"].as_str().unwrap(),
+                u["to"].as_str().unwrap()
+            )
+        })
+        .collect();
+    assert_eq!(
+        updates,
+        [
+            "step-0 title Foundation -> Lay the Foundation",
+            "step-0 priority (none) -> 1",
+            "step-0 labels (none) -> core,infra",
+        ]
+    );
+    let warnings = result["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].as_str().unwrap().contains("Build Upward"));
+
+    let speck = fs::read_to_string(&speck_path).unwrap();
+    assert!(speck.contains("#### Step 0: Lay the Foundation {#step-0}"));
+    assert!(speck.contains("**Beads:** priority=1, labels=core,infra"));
+    assert!(speck.contains("#### Step 1: Build Sideways {#step-1}"));
+
+    // Pulled fields are now in sync; the conflict stays until resolved
+    let pull = specks(&["beads", "pull", "specks-fields.md", "--fields", "--json"]);
+    assert!(pull["data"]["total_fields_updated"].is_null());
+    assert_eq!(pull["data"]["files"][0]["warnings"][0], warnings[0]);
+
+    // With warnings off, the conflict is silently kept
+    let config_path = temp.path().join(".specks/config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace(
+            "pull_warn_on_conflict = true",
+            "pull_warn_on_conflict = false",
+        ),
+    )
+    .unwrap();
+    let pull = specks(&["beads", "pull", "specks-fields.md", "--fields", "--json"]);
+    assert!(pull["data"]["files"][0]["warnings"].is_null());
+    assert!(
+        fs::read_to_string(&speck_path)
+            .unwrap()
+            .contains("#### Step 1: Build Sideways {#step-1}")
+    );
+}
+
+#[test]
+fn test_beads_pull_fields_no_overwrite() {
+    let temp = setup_file_tracker_project();
+    create_test_speck(&temp, "fields", MULTI_STEP_SPECK);
+    let speck_path = temp.path().join(".specks/specks-fields.md");
+    let specks = |args: &[&str]| run_specks_json(&temp, args);
+    specks(&["beads", "sync", "specks-fields.md", "--json"]);
+
+    let bead_path = temp.path().join(".specks/tracker/sp-1.1.json");
+    let mut issue: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&bead_path).unwrap()).unwrap();
+    issue["title"] = "Step 0: Lay the Foundation".into();
+    issue["priority"] = 1.into();
+    fs::write(&bead_path, issue.to_string()).unwrap();
+
+    // The step has no priority yet, but it does have a title
+    let pull = specks(&[
+        "beads",
+        "pull",
+        "specks-fields.md",
+        "--fields",
+        "--no-overwrite",
+        "--json",
+    ]);
+    assert_eq!(pull["data"]["total_fields_updated"], 1);
+    assert_eq!(
+        pull["data"]["files"][0]["fields_updated"][0]["field"],
+        "priority"
+    );
+    let speck = fs::read_to_string(&speck_path).unwrap();
+    assert!(speck.contains("#### Step 0: Foundation {#step-0}"));
+    assert!(speck.contains("**Beads:** priority=1"));
+
+    // The held-back title is still pulled later
+    let pull = specks(&["beads", "pull", "specks-fields.md", "--fields", "--json"]);
+    assert_eq!(pull["data"]["total_fields_updated"], 1);
+    assert!(
+        fs::read_to_string(&speck_path)
+            .unwrap()
+            .contains("#### Step 0: Lay the Foundation {#step-0}")
+    );
+}
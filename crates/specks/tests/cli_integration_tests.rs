@@ -141,6 +141,16 @@ fn test_init_creates_expected_files() {
         specks_dir.join("specks-implementation-log.md").is_file(),
         "implementation log should exist"
     );
+    for template in ["feature", "bugfix", "refactor", "spike"] {
+        assert!(
+            specks_dir
+                .join("templates")
+                .join(format!("{}.md", template))
+                .is_file(),
+            "{} template should exist",
+            template
+        );
+    }
 }
 
 #[test]
@@ -310,6 +320,56 @@ fn test_list_shows_specks() {
     assert!(stdout.contains("active"), "output should contain status");
 }
 
+#[test]
+fn test_included_companion_resolves_references_and_is_not_listed() {
+    let temp = setup_test_project();
+    let main = MINIMAL_SPECK
+        .replace(
+            "| Last updated | 2026-02-04 |",
+            "| Last updated | 2026-02-04 |\n| Includes | specks-test-reference.md |",
+        )
+        .replace(
+            "(#context)",
+            "(#context), specks-test-reference.md#error-codes",
+        );
+    create_test_speck(&temp, "test", &main);
+    create_test_speck(
+        &temp,
+        "test-reference",
+        "## Test Reference {#test-reference}\n\n### Error codes {#error-codes}\n\nCodes.\n",
+    );
+
+    let output = Command::new(specks_binary())
+        .args(["validate", "specks-test.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks validate");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "validate should succeed: {}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("W013"),
+        "companion anchor should resolve: {}",
+        stdout
+    );
+
+    let output = Command::new(specks_binary())
+        .arg("list")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks list");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "list should succeed");
+    assert!(
+        !stdout.contains("test-reference"),
+        "companion should not be listed: {}",
+        stdout
+    );
+}
+
 #[test]
 fn test_status_shows_step_breakdown() {
     let temp = setup_test_project();
@@ -329,6 +389,183 @@ fn test_status_shows_step_breakdown() {
     assert!(stdout.contains("Total:"), "output should contain total");
 }
 
+#[test]
+fn test_cross_speck_dependencies() {
+    let temp = setup_test_project();
+    create_test_speck(&temp, "test", MINIMAL_SPECK);
+    let api = MINIMAL_SPECK.replace(
+        "**Commit:** `feat: setup`",
+        "**Depends on:** specks-test.md#step-0\n\n**Commit:** `feat: setup`",
+    );
+    create_test_speck(&temp, "api", &api);
+
+    let output = Command::new(specks_binary())
+        .arg("status")
+        .arg("specks-api.md")
+        .arg("--json")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks status");
+    assert!(output.status.success(), "status should succeed");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("should be valid JSON");
+    let blockers = &json["data"]["external_blockers"];
+    assert_eq!(blockers.as_array().unwrap().len(), 1);
+    assert_eq!(blockers[0]["anchor"], "#step-0");
+    assert_eq!(blockers[0]["dependency"], "specks-test.md#step-0");
+    assert_eq!(blockers[0]["title"], "Step 0: Setup");
+
+    // A dependency back onto specks-api.md closes a cycle across both specks
+    let test = MINIMAL_SPECK.replace(
+        "**Commit:** `feat: setup`",
+        "**Depends on:** specks-api.md#step-0\n\n**Commit:** `feat: setup`",
+    );
+    create_test_speck(&temp, "test", &test);
+    let output = Command::new(specks_binary())
+        .arg("validate")
+        .arg("specks-api.md")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks validate");
+    assert!(
+        !output.status.success(),
+        "validate should fail on the cycle"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Circular dependency detected: step-0 -> specks-test.md#step-0 -> step-0"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_next_lists_ready_steps() {
+    let temp = setup_test_project();
+    let speck = MINIMAL_SPECK.replace(
+        "---\n\n### 1.0.6 Deliverables",
+        "#### Step 1: Build {#step-1}\n\n**Depends on:** #step-0\n\n**Tasks:**\n- [ ] Build it\n\n---\n\n### 1.0.6 Deliverables",
+    );
+    create_test_speck(&temp, "test", &speck);
+
+    let output = Command::new(specks_binary())
+        .arg("next")
+        .arg("specks-test.md")
+        .arg("--json")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks next");
+    assert!(output.status.success(), "next should succeed");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("should be valid JSON");
+    assert_eq!(json["command"], "next");
+    assert_eq!(json["data"]["ready"][0]["anchor"], "#step-0");
+    assert_eq!(json["data"]["blocked"][0]["anchor"], "#step-1");
+    assert_eq!(json["data"]["blocked"][0]["blocked_by"][0], "#step-0");
+    assert_eq!(
+        json["data"]["waves"],
+        serde_json::json!([["#step-0"], ["#step-1"]])
+    );
+
+    let output = Command::new(specks_binary())
+        .arg("next")
+        .arg("specks-missing.md")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks next");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_status_and_list_roll_up_estimates() {
+    let temp = setup_test_project();
+    let speck = MINIMAL_SPECK
+        .replace(
+            "**Commit:** `feat: setup`",
+            "**Commit:** `feat: setup`\n\n**Beads:** estimate_minutes=30",
+        )
+        .replace(
+            "---\n\n### 1.0.6 Deliverables",
+            "#### Step 1: Build {#step-1}\n\n**Depends on:** #step-0\n\n\
+             **Beads:** estimate_minutes=60\n\n**Tasks:**\n- [ ] Build it\n\n\
+             #### Step 2: Ship {#step-2}\n\n**Depends on:** #step-1\n\n\
+             **Beads:** estimate_minutes=120\n\n**Tasks:**\n- [ ] Ship it\n\n\
+             ---\n\n### 1.0.6 Deliverables",
+        );
+    create_test_speck(&temp, "test", &speck);
+
+    // Step 0 and Step 1 were logged 45 minutes apart
+    let log_path = temp.path().join(".specks/specks-implementation-log.md");
+    let mut log = fs::read_to_string(&log_path).unwrap();
+    for (step, date) in [
+        ("step-1", "2026-02-13T10:45:00Z"),
+        ("step-0", "2026-02-13T10:00:00Z"),
+    ] {
+        log.push_str(&format!(
+            "\n---\nstep: #{step}\ndate: {date}\n---\n\n## #{step}: Done\n\n\
+             **Files changed:**\n- .specks/specks-test.md\n\n---\n"
+        ));
+    }
+    fs::write(&log_path, log).unwrap();
+
+    let output = Command::new(specks_binary())
+        .args(["status", "specks-test.md", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks status");
+    assert!(output.status.success(), "status should succeed");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("should be valid JSON");
+    let estimates = &json["data"]["estimates"];
+    assert_eq!(estimates["total_minutes"], 210);
+    assert_eq!(estimates["remaining_minutes"], 120);
+    assert_eq!(
+        estimates["critical_path"],
+        serde_json::json!(["#step-0", "#step-1", "#step-2"])
+    );
+    assert_eq!(estimates["remaining_critical_path_minutes"], 120);
+    assert_eq!(estimates["actual_minutes"], 45);
+    assert_eq!(estimates["actual_estimate_minutes"], 60);
+    assert_eq!(estimates["pace"], 0.75);
+    assert!(estimates["eta"].is_string());
+    assert_eq!(
+        estimates["steps"][0]["completed_at"],
+        "2026-02-13T10:00:00Z"
+    );
+
+    let output = Command::new(specks_binary())
+        .arg("status")
+        .arg("specks-test.md")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks status");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Estimates: 3h 30m total, 2h remaining"));
+    assert!(stdout.contains("Actual: 45m against 1h estimated (pace 0.75)"));
+    assert!(stdout.contains("(est 1h, actual 45m)"));
+
+    let output = Command::new(specks_binary())
+        .args(["list", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks list");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("should be valid JSON");
+    let summary = &json["data"]["specks"][0];
+    assert_eq!(summary["estimate_minutes"], 210);
+    assert_eq!(summary["remaining_minutes"], 120);
+    assert_eq!(summary["actual_minutes"], 45);
+
+    let output = Command::new(specks_binary())
+        .arg("list")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks list");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("ESTIMATE"));
+    assert!(stdout.contains("3h 30m"));
+}
+
 #[test]
 fn test_json_output_init() {
     let temp = tempfile::tempdir().expect("failed to create temp dir");
@@ -832,3 +1069,740 @@ fn test_validate_invalid_level_rejected_json() {
         serde_json::to_string_pretty(&json).unwrap()
     );
 }
+
+#[test]
+fn test_validate_rule_config_and_inline_ignore() {
+    let temp = setup_test_project();
+    let no_tests = MINIMAL_SPECK.replace("**Tests:**\n- [ ] Unit test\n\n", "");
+    create_test_speck(&temp, "rules", &no_tests);
+
+    let config_path = temp.path().join(".specks").join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace("# W004 = \"off\"", "W004 = \"error\""),
+    )
+    .unwrap();
+
+    let run = || {
+        Command::new(specks_binary())
+            .args(["validate", "specks-rules.md", "--json"])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks validate")
+    };
+
+    // W004 promoted to an error fails validation
+    let output = run();
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = json["issues"].as_array().unwrap();
+    assert!(
+        issues
+            .iter()
+            .any(|i| i["code"] == "W004" && i["severity"] == "error"),
+        "expected W004 error: {}",
+        json
+    );
+
+    // An inline suppression on the step silences it
+    create_test_speck(
+        &temp,
+        "rules",
+        &no_tests.replace(
+            "#### Step 0: Setup",
+            "<!-- specks-ignore W004: docs-only step -->\n#### Step 0: Setup",
+        ),
+    );
+    let output = run();
+    assert!(
+        output.status.success(),
+        "stdout:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    // Invalid settings are a config error
+    fs::write(
+        &config_path,
+        config.replace("# W004 = \"off\"", "W004 = \"loud\""),
+    )
+    .unwrap();
+    let output = run();
+    assert_eq!(output.status.code(), Some(4));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["issues"][0]["code"], "E004");
+}
+
+#[test]
+fn test_validate_fix_dry_run_then_fix() {
+    let temp = setup_test_project();
+    let broken = MINIMAL_SPECK
+        .replace(
+            "#### Step 0: Setup {#step-0}",
+            "### step 0: Setup {#Step_0}",
+        )
+        .replace("**Commit:** `feat: setup`", "Commit: feat: setup")
+        .replace("(#context)", "(#context, #Step_0)");
+    create_test_speck(&temp, "fixme", &broken);
+    let path = temp.path().join(".specks").join("specks-fixme.md");
+
+    let output = Command::new(specks_binary())
+        .args(["validate", "specks-fixme.md", "--fix", "--dry-run"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks validate --fix --dry-run");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("--- a/.specks/specks-fixme.md"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("+### Step 0: Setup {#step-0}"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Would fix 3 issues"), "{}", stdout);
+    assert_eq!(fs::read_to_string(&path).unwrap(), broken);
+
+    let output = Command::new(specks_binary())
+        .args(["validate", "specks-fixme.md", "--fix", "--json"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks validate --fix");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let fixed = &json["data"]["fixed"][0];
+    assert_eq!(fixed["written"], true);
+    let codes: Vec<&str> = fixed["fixes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(codes, vec!["P005", "P001", "P007"]);
+    assert!(json["data"]["diagnostics"].as_array().unwrap().is_empty());
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("**Commit:** `feat: setup`"));
+    assert!(content.contains("(#context, #step-0)"));
+}
+
+#[test]
+fn test_validate_ci_formats() {
+    let temp = setup_test_project();
+    let broken = MINIMAL_SPECK
+        .replace("| Status | active |", "| Status | bogus |")
+        .replace(
+            "#### Step 0: Setup {#step-0}",
+            "#### step 0: Setup {#step-0}",
+        );
+    create_test_speck(&temp, "ci", &broken);
+
+    let run = |format: &str| {
+        Command::new(specks_binary())
+            .args(["validate", "specks-ci.md", "--format", format])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks validate --format")
+    };
+
+    let output = run("github");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("::error file=.specks/specks-ci.md,title=E003::Invalid metadata Status"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("::warning file=.specks/specks-ci.md,line=43,title=P001::"),
+        "{}",
+        stdout
+    );
+
+    let output = run("sarif");
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    assert!(
+        results
+            .iter()
+            .any(|r| r["ruleId"] == "E003" && r["level"] == "error")
+    );
+
+    let output = run("junit");
+    let xml = String::from_utf8_lossy(&output.stdout);
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains("<testsuite name=\".specks/specks-ci.md\""));
+    assert!(xml.contains("<failure type=\"E003\""));
+
+    let output = run("yaml");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid format"));
+}
+
+#[test]
+fn test_fmt_check_reports_and_fmt_rewrites() {
+    let temp = setup_test_project();
+    let messy = MINIMAL_SPECK
+        .replace("- [ ]", "-  [ ]")
+        .replace("\n---\n", "\n\n\n---\n");
+    create_test_speck(&temp, "messy", &messy);
+
+    let output = Command::new(specks_binary())
+        .args(["fmt", "--check", "specks-messy.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks fmt --check");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "check should fail: {}",
+        stdout
+    );
+    assert!(stdout.contains("needs formatting"), "stdout: {}", stdout);
+
+    let speck_path = temp.path().join(".specks/specks-messy.md");
+    assert_eq!(fs::read_to_string(&speck_path).unwrap(), messy);
+
+    let output = Command::new(specks_binary())
+        .args(["fmt", "specks-messy.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks fmt");
+    assert!(output.status.success());
+    let formatted = fs::read_to_string(&speck_path).unwrap();
+    assert_ne!(formatted, messy);
+    assert!(!formatted.contains("-  [ ]"));
+
+    let output = Command::new(specks_binary())
+        .args(["fmt", "--check", "--json", "specks-messy.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks fmt --check --json");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["command"], "fmt");
+    assert_eq!(json["data"]["check"], true);
+    assert_eq!(json["data"]["files"][0]["changed"], false);
+}
+
+#[test]
+fn test_new_creates_valid_speck_from_each_template() {
+    let temp = setup_test_project();
+
+    for template in ["feature", "bugfix", "refactor", "spike"] {
+        let name = format!("{}-work", template);
+        let output = Command::new(specks_binary())
+            .args(["new", &name, "--template", template, "--owner", "Jo Dev"])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks new");
+        assert!(
+            output.status.success(),
+            "new should succeed for {}: {}",
+            template,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let content = fs::read_to_string(
+            temp.path()
+                .join(".specks")
+                .join(format!("specks-{}.md", name)),
+        )
+        .expect("speck should be written");
+        assert!(content.contains("| Owner             | Jo Dev     |"));
+        assert!(content.contains("| Status            | draft      |"));
+        assert!(!content.contains("{{"), "all variables substituted");
+
+        let output = Command::new(specks_binary())
+            .args([
+                "validate",
+                &format!("specks-{}.md", name),
+                "--level",
+                "lenient",
+            ])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks validate");
+        assert!(
+            output.status.success(),
+            "{} speck should validate: {}",
+            template,
+            String::from_utf8_lossy(&output.stdout)
+        );
+
+        let output = Command::new(specks_binary())
+            .args(["fmt", &format!("specks-{}.md", name), "--check"])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks fmt");
+        assert!(
+            output.status.success(),
+            "{} speck should already be formatted: {}",
+            template,
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+}
+
+#[test]
+fn test_new_rejects_bad_name_and_existing_speck() {
+    let temp = setup_test_project();
+
+    let output = Command::new(specks_binary())
+        .args(["--json", "new", "Bad Name"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks new");
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    assert_eq!(json["issues"][0]["code"], "E040");
+
+    create_test_speck(&temp, "taken", MINIMAL_SPECK);
+    let output = Command::new(specks_binary())
+        .args(["new", "taken"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks new");
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("E043"));
+}
+
+#[test]
+fn test_new_custom_template_variables() {
+    let temp = setup_test_project();
+    let template = MINIMAL_SPECK
+        .replace(
+            "Phase 1.0: Test Feature",
+            "Phase 1.0: {{title}} for {{team}}",
+        )
+        .replace("| Owner | Test |", "| Owner | {{owner}} |")
+        .replace("| Status | active |", "| Status | {{status}} |")
+        .replace(
+            "| Last updated | 2026-02-04 |",
+            "| Last updated | {{date}} |",
+        );
+    fs::write(temp.path().join(".specks/templates/team.md"), template).unwrap();
+
+    // Unset variables are reported and nothing is written
+    let output = Command::new(specks_binary())
+        .args(["new", "billing", "--template", "team"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks new");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("team"));
+    assert!(!temp.path().join(".specks/specks-billing.md").exists());
+
+    let output = Command::new(specks_binary())
+        .args([
+            "new",
+            "billing",
+            "--template",
+            "team",
+            "--var",
+            "team=Payments",
+            "--status",
+            "active",
+        ])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run specks new");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let content = fs::read_to_string(temp.path().join(".specks/specks-billing.md")).unwrap();
+    assert!(content.contains("## Phase 1.0: Billing for Payments {#phase-1}"));
+    assert!(content.contains("| Status            | active     |"));
+
+    // A --var without a key is a usage error
+    for var in ["team", " =Payments"] {
+        let output = Command::new(specks_binary())
+            .args([
+                "--json",
+                "new",
+                "ledger",
+                "--template",
+                "team",
+                "--var",
+                var,
+            ])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks new");
+        assert_eq!(output.status.code(), Some(2));
+        let json: serde_json::Value =
+            serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+        assert_eq!(json["issues"][0]["code"], "E002");
+    }
+    assert!(!temp.path().join(".specks/specks-ledger.md").exists());
+}
+
+/// Read stdout lines of a watching child until one contains `needle`
+fn wait_for_line(
+    lines: &std::sync::mpsc::Receiver<String>,
+    needle: &str,
+    seen: &mut Vec<String>,
+) -> String {
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(15);
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        match lines.recv_timeout(remaining) {
+            Ok(line) => {
+                seen.push(line.clone());
+                if line.contains(needle) {
+                    return line;
+                }
+            }
+            Err(_) => panic!(
+                "timed out waiting for {:?}; output:\n{}",
+                needle,
+                seen.join("\n")
+            ),
+        }
+    }
+}
+
+/// Spawn specks with piped stdout, forwarding lines through a channel
+fn spawn_watching(
+    temp: &tempfile::TempDir,
+    args: &[&str],
+) -> (std::process::Child, std::sync::mpsc::Receiver<String>) {
+    use std::io::BufRead;
+
+    let mut child = Command::new(specks_binary())
+        .args(args)
+        .current_dir(temp.path())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .expect("failed to spawn specks");
+    let stdout = std::io::BufReader::new(child.stdout.take().unwrap());
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for line in stdout.lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    (child, receiver)
+}
+
+#[test]
+fn test_validate_and_status_watch() {
+    let temp = setup_test_project();
+    create_test_speck(&temp, "w", MINIMAL_SPECK);
+    let path = temp.path().join(".specks").join("specks-w.md");
+
+    let (mut child, lines) = spawn_watching(&temp, &["validate", "specks-w.md", "--watch"]);
+    let mut seen = Vec::new();
+    wait_for_line(&lines, "Watching .specks/", &mut seen);
+
+    // A burst of writes is reported once
+    let broken = MINIMAL_SPECK.replace("| Status | active |", "| Status | bogus |");
+    for _ in 0..3 {
+        fs::write(&path, &broken).unwrap();
+    }
+    wait_for_line(&lines, "changed: .specks/specks-w.md", &mut seen);
+    let new = wait_for_line(&lines, "+ error[E003]", &mut seen);
+    assert!(
+        new.contains(".specks/specks-w.md: Invalid metadata Status"),
+        "{}",
+        new
+    );
+    wait_for_line(&lines, "1 new, 0 resolved", &mut seen);
+
+    fs::write(&path, MINIMAL_SPECK).unwrap();
+    wait_for_line(&lines, "- error[E003]", &mut seen);
+    wait_for_line(&lines, "0 new, 1 resolved", &mut seen);
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(
+        seen.iter().filter(|l| l.contains("changed:")).count(),
+        2,
+        "{}",
+        seen.join("\n")
+    );
+
+    let (mut child, lines) = spawn_watching(&temp, &["status", "specks-w.md", "--watch"]);
+    let mut seen = Vec::new();
+    wait_for_line(&lines, "Watching .specks/", &mut seen);
+    fs::write(
+        &path,
+        MINIMAL_SPECK.replace("- [ ] Add tests", "- [x] Add tests"),
+    )
+    .unwrap();
+    let delta = wait_for_line(&lines, "w.md: 2/5 -> 3/5 tasks", &mut seen);
+    assert!(delta.ends_with("(40% -> 60%)"), "{}", delta);
+    wait_for_line(&lines, "#step-0 Setup: 2/5 -> 3/5", &mut seen);
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    // Watch mode is text only
+    let output = Command::new(specks_binary())
+        .args(["validate", "--watch", "--format", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_validate_baseline() {
+    let temp = setup_test_project();
+    let legacy = MINIMAL_SPECK.replace("| Status | active |", "| Status | bogus |");
+    create_test_speck(&temp, "legacy", &legacy);
+    let path = temp.path().join(".specks").join("specks-legacy.md");
+    let baseline_path = temp.path().join(".specks").join("validation-baseline.json");
+
+    let validate = |args: &[&str]| {
+        Command::new(specks_binary())
+            .arg("validate")
+            .args(args)
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks validate")
+    };
+
+    assert_eq!(validate(&[]).status.code(), Some(1));
+
+    let output = validate(&["--write-baseline"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("in .specks/validation-baseline.json"),
+        "{}",
+        stdout
+    );
+    let baseline: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&baseline_path).unwrap()).unwrap();
+    let entry = &baseline["entries"][0];
+    assert_eq!(entry["file"], ".specks/specks-legacy.md");
+    assert_eq!(entry["code"], "E003");
+    assert!(entry.get("line").is_none());
+
+    // Recorded issues no longer fail, even after lines move
+    fs::write(
+        &path,
+        legacy.replace("**Purpose:**", "Intro paragraph.\n\n**Purpose:**"),
+    )
+    .unwrap();
+    let output = validate(&[]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("1 known issue hidden"));
+
+    // A new error fails the run and is the only one reported
+    fs::write(
+        &path,
+        legacy.replace(
+            "**Commit:** `feat: setup`",
+            "**Depends on:** #nowhere\n\n**Commit:** `feat: setup`",
+        ),
+    )
+    .unwrap();
+    let output = validate(&["--json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let codes: Vec<&str> = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i["code"].as_str().unwrap())
+        .collect();
+    assert!(!codes.is_empty());
+    assert!(!codes.contains(&"E003"), "{:?}", codes);
+    assert_eq!(json["data"]["baseline"]["baselined"], 1);
+
+    // Fixing a recorded issue is reported
+    fs::write(&path, MINIMAL_SPECK).unwrap();
+    let output = validate(&["--json"]);
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["data"]["baseline"]["fixed"][0]["code"], "E003");
+    let stdout = String::from_utf8_lossy(&validate(&[]).stdout).to_string();
+    assert!(
+        stdout.contains("Fixed since baseline")
+            && stdout.contains("E003 .specks/specks-legacy.md: Invalid metadata Status"),
+        "{}",
+        stdout
+    );
+
+    // Fixed issues on a step show its anchor once
+    fs::write(
+        &path,
+        MINIMAL_SPECK.replace("**Tests:**\n- [ ] Unit test\n\n", ""),
+    )
+    .unwrap();
+    assert_eq!(validate(&["--write-baseline"]).status.code(), Some(0));
+    fs::write(&path, MINIMAL_SPECK).unwrap();
+    let stdout = String::from_utf8_lossy(&validate(&[]).stdout).to_string();
+    assert!(
+        stdout.contains("  W004 .specks/specks-legacy.md #step-0: Step 0 has no test items"),
+        "{}",
+        stdout
+    );
+
+    // A corrupt baseline is a configuration-class error
+    fs::write(&baseline_path, "not json").unwrap();
+    let output = validate(&[]);
+    assert_eq!(output.status.code(), Some(4));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("E044"));
+}
+
+#[test]
+fn test_validate_custom_rules() {
+    let temp = setup_test_project();
+    create_test_speck(
+        &temp,
+        "custom",
+        &MINIMAL_SPECK.replace("`feat: setup`", "`update setup`"),
+    );
+
+    let config_path = temp.path().join(".specks").join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}\n{}",
+            config,
+            r#"[[specks.validation.custom]]
+code = "C001"
+kind = "step-pattern"
+field = "commit"
+pattern = '^(feat|fix|chore)(\(.+\))?: '
+severity = "error"
+message = "Commit lines must use a conventional prefix"
+
+[[specks.validation.custom]]
+code = "C002"
+kind = "required-metadata"
+field = "Reviewer"
+message = "Name a reviewer"
+"#
+        ),
+    )
+    .unwrap();
+
+    let run = || {
+        Command::new(specks_binary())
+            .args(["validate", "specks-custom.md", "--json"])
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks validate")
+    };
+
+    let output = run();
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = json["issues"].as_array().unwrap();
+    let c001 = issues.iter().find(|i| i["code"] == "C001").unwrap();
+    assert_eq!(c001["severity"], "error");
+    assert_eq!(c001["anchor"], "#step-0");
+    assert_eq!(
+        c001["message"],
+        "Commit lines must use a conventional prefix (Step 0: \"update setup\")"
+    );
+    let c002 = issues.iter().find(|i| i["code"] == "C002").unwrap();
+    assert_eq!(c002["severity"], "warning");
+
+    // Custom codes take per-rule overrides like built-in ones
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace("# W004 = \"off\"", "C001 = \"off\""),
+    )
+    .unwrap();
+    let output = run();
+    assert_eq!(output.status.code(), Some(0));
+
+    // A malformed rule is a configuration error
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(&config_path, config.replace("'^(feat", "'^((feat")).unwrap();
+    let output = run();
+    assert_eq!(output.status.code(), Some(4));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["issues"][0]["code"], "E004");
+}
+
+#[test]
+fn test_validate_changed_since() {
+    let temp = setup_test_project();
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "-b", "main"]);
+    git(&["config", "user.name", "Test User"]);
+    git(&["config", "user.email", "test@example.com"]);
+
+    let step_1 = "#### Step 1: Tokens {#step-1}\n\n**Depends on:** #step-0\n\n**Bead:** `bd-abc.2`\n\n**Commit:** `feat: tokens`\n\n**References:** [D01] Test Decision\n\n**Tasks:**\n- [x] Issue tokens\n\n---\n\n";
+    let auth = MINIMAL_SPECK
+        .replace("| Status | active |", "| Status | done |")
+        .replace(
+            "### 1.0.6 Deliverables",
+            &format!("{}### 1.0.6 Deliverables", step_1),
+        );
+    create_test_speck(&temp, "auth", &auth);
+    let other = MINIMAL_SPECK.replace("(#context)", "(#context), specks-auth.md#step-1");
+    create_test_speck(&temp, "other", &other);
+    git(&["add", "."]);
+    git(&["commit", "-m", "Add specks"]);
+
+    let validate = |args: &[&str]| {
+        Command::new(specks_binary())
+            .arg("validate")
+            .args(args)
+            .current_dir(temp.path())
+            .output()
+            .expect("failed to run specks validate")
+    };
+
+    let output = validate(&["--changed-since", "HEAD"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("No specks changed since HEAD"));
+
+    // Uncheck an item, drop the beaded and referenced step, reopen the speck
+    let edited = auth
+        .replace("- [x] Create project", "- [ ] Create project")
+        .replace(step_1, "")
+        .replace("| Status | done |", "| Status | active |");
+    fs::write(temp.path().join(".specks").join("specks-auth.md"), edited).unwrap();
+
+    let output = validate(&["--changed-since", "HEAD", "--json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let files = json["data"]["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["path"], ".specks/specks-auth.md");
+    let mut codes: Vec<&str> = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i["code"].as_str().unwrap())
+        .filter(|code| code.starts_with('R'))
+        .collect();
+    codes.sort();
+    assert_eq!(codes, vec!["R001", "R002", "R003", "R004"]);
+
+    // Text output names the referencing speck
+    let output = validate(&["--changed-since", "HEAD"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Anchor #step-1 was removed but is referenced by specks-other.md"),
+        "{}",
+        stdout
+    );
+
+    let output = validate(&["--changed-since", "no-such-ref"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown git ref 'no-such-ref'"));
+}
@@ -0,0 +1,351 @@
+//! Integration tests for `specks lsp`, driven by a scripted LSP client
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use serde_json::{Value, json};
+
+/// Get the path to the specks binary
+fn specks_binary() -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.pop(); // crates
+    path.pop(); // specks root
+    path.push("target");
+    path.push("debug");
+    path.push("specks");
+    path
+}
+
+const SPECK: &str = r#"## Phase 1.0: Auth {#phase-1}
+
+### Plan Metadata {#plan-metadata}
+
+| Field | Value |
+|------|-------|
+| Owner | Test |
+| Status | active |
+| Last updated | 2026-02-04 |
+
+#### [D01] Use tokens (DECIDED) {#d01-tokens}
+
+**Decision:** Sessions use signed tokens.
+
+#### Step 0: Setup {#step-0}
+
+**Commit:** `feat: setup`
+
+**References:** [D01] Use tokens
+
+**Tasks:**
+- [ ] Task
+
+#### Step 1: Build {#step-1}
+
+**Depends on:** #step-0
+
+**Commit:** `feat: build`
+
+**References:** [D01] Use tokens, (#step-0)
+
+**Tasks:**
+- [ ] Task
+"#;
+
+/// A minimal LSP client speaking to a `specks lsp` child process
+struct Client {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: i64,
+    notifications: Vec<Value>,
+}
+
+impl Client {
+    fn start() -> Self {
+        let mut child = Command::new(specks_binary())
+            .arg("lsp")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to start specks lsp");
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        Client {
+            child,
+            stdin,
+            stdout,
+            next_id: 1,
+            notifications: Vec::new(),
+        }
+    }
+
+    fn send(&mut self, message: Value) {
+        let body = message.to_string();
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = 0;
+        loop {
+            let mut header = String::new();
+            self.stdout.read_line(&mut header).unwrap();
+            let header = header.trim();
+            if header.is_empty() {
+                break;
+            }
+            if let Some(value) = header.strip_prefix("Content-Length:") {
+                length = value.trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; length];
+        self.stdout.read_exact(&mut body).unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    /// Send a request and wait for its response, keeping notifications
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        loop {
+            let message = self.receive();
+            if message["id"] == id {
+                return message;
+            }
+            self.notifications.push(message);
+        }
+    }
+
+    /// Wait for the next publishDiagnostics notification
+    fn diagnostics(&mut self) -> Vec<Value> {
+        loop {
+            let message = if self.notifications.is_empty() {
+                self.receive()
+            } else {
+                self.notifications.remove(0)
+            };
+            if message["method"] == "textDocument/publishDiagnostics" {
+                return message["params"]["diagnostics"].as_array().unwrap().clone();
+            }
+        }
+    }
+}
+
+fn position(uri: &str, line: usize, character: usize) -> Value {
+    json!({
+        "textDocument": { "uri": uri },
+        "position": { "line": line, "character": character },
+    })
+}
+
+#[test]
+fn test_lsp_scripted_session() {
+    let temp = tempfile::tempdir().unwrap();
+    let output = Command::new(specks_binary())
+        .arg("init")
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Promote W004 so the document has an error to report
+    let config_path = temp.path().join(".specks").join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace("# W004 = \"off\"", "W004 = \"error\""),
+    )
+    .unwrap();
+
+    let path = temp.path().join(".specks").join("specks-auth.md");
+    fs::write(&path, SPECK).unwrap();
+    let uri = format!("file://{}", path.display());
+
+    let mut client = Client::start();
+    let init = client.request("initialize", json!({ "capabilities": {} }));
+    let capabilities = &init["result"]["capabilities"];
+    assert_eq!(capabilities["definitionProvider"], true);
+    assert_eq!(capabilities["documentSymbolProvider"], true);
+    client.notify("initialized", json!({}));
+
+    client.notify(
+        "textDocument/didOpen",
+        json!({ "textDocument": {
+            "uri": uri, "languageId": "markdown", "version": 1, "text": SPECK,
+        }}),
+    );
+    let diagnostics = client.diagnostics();
+    let w004: Vec<&Value> = diagnostics.iter().filter(|d| d["code"] == "W004").collect();
+    assert_eq!(w004.len(), 2, "{:?}", diagnostics);
+    assert_eq!(w004[0]["severity"], 1);
+    assert_eq!(w004[0]["range"]["start"]["line"], 14);
+
+    // Go to definition: #step-0 on the Depends on line
+    let def = client.request("textDocument/definition", position(&uri, 25, 18));
+    assert_eq!(def["result"]["uri"], uri.as_str());
+    assert_eq!(def["result"]["range"]["start"]["line"], 14);
+
+    // [D01] resolves to the decision heading
+    let def = client.request("textDocument/definition", position(&uri, 18, 17));
+    assert_eq!(def["result"]["range"]["start"]["line"], 10);
+
+    // References to #step-0: heading, Depends on and References lines
+    let refs = client.request(
+        "textDocument/references",
+        json!({
+            "textDocument": { "uri": uri },
+            "position": { "line": 14, "character": 24 },
+            "context": { "includeDeclaration": true },
+        }),
+    );
+    let lines: Vec<u64> = refs["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|l| l["range"]["start"]["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(lines, vec![14, 25, 29]);
+
+    // Hover shows the decision title and statement
+    let hover = client.request("textDocument/hover", position(&uri, 29, 17));
+    let text = hover["result"]["contents"]["value"].as_str().unwrap();
+    assert!(
+        text.starts_with("**[D01] Use tokens** (DECIDED)"),
+        "{}",
+        text
+    );
+
+    // Outline
+    let symbols = client.request(
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": uri } }),
+    );
+    let names: Vec<&str> = symbols["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["Step 0: Setup", "Step 1: Build"]);
+
+    // Typing "#" after Depends on: offers step anchors
+    let edited = SPECK.replace("**Depends on:** #step-0", "**Depends on:** #");
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": uri, "version": 2 },
+            "contentChanges": [{ "text": edited }],
+        }),
+    );
+    client.diagnostics();
+    let completion = client.request("textDocument/completion", position(&uri, 25, 17));
+    let items = completion["result"]["items"].as_array().unwrap();
+    assert_eq!(items[0]["label"], "#step-0");
+    assert_eq!(items[0]["insertText"], "step-0");
+
+    // Fixing the document clears the W004 errors
+    let fixed = SPECK.replace("- [ ] Task\n", "- [ ] Task\n\n**Tests:**\n- [ ] Test\n");
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": uri, "version": 3 },
+            "contentChanges": [{ "text": fixed }],
+        }),
+    );
+    let diagnostics = client.diagnostics();
+    assert!(
+        diagnostics.iter().all(|d| d["code"] != "W004"),
+        "{:?}",
+        diagnostics
+    );
+
+    // A body that is not JSON gets a parse error, and the session goes on
+    write!(client.stdin, "Content-Length: 5\r\n\r\n{{oops").unwrap();
+    client.stdin.flush().unwrap();
+    let error = client.receive();
+    assert_eq!(error["error"]["code"], -32700);
+    assert!(error["id"].is_null());
+
+    let shutdown = client.request("shutdown", Value::Null);
+    assert!(shutdown["result"].is_null());
+    client.notify("exit", Value::Null);
+    let status = client.child.wait().unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn test_lsp_shows_invalid_custom_rules() {
+    let temp = tempfile::tempdir().unwrap();
+    let output = Command::new(specks_binary())
+        .arg("init")
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let config_path = temp.path().join(".specks").join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}\n{}",
+            config,
+            r#"[[specks.validation.custom]]
+code = "C001"
+kind = "step-pattern"
+field = "commit"
+pattern = '^((feat'
+message = "Commit lines must use a conventional prefix"
+"#
+        ),
+    )
+    .unwrap();
+
+    let path = temp.path().join(".specks").join("specks-auth.md");
+    fs::write(&path, SPECK).unwrap();
+    let uri = format!("file://{}", path.display());
+
+    let mut client = Client::start();
+    client.request("initialize", json!({ "capabilities": {} }));
+    client.notify(
+        "textDocument/didOpen",
+        json!({ "textDocument": {
+            "uri": uri, "languageId": "markdown", "version": 1, "text": SPECK,
+        }}),
+    );
+
+    let message = client.receive();
+    assert_eq!(message["method"], "window/showMessage");
+    assert_eq!(message["params"]["type"], 1);
+    let text = message["params"]["message"].as_str().unwrap();
+    assert!(text.contains("invalid custom rule C001"), "{}", text);
+    // Documents are still validated with the remaining settings
+    assert_eq!(
+        client.receive()["method"],
+        "textDocument/publishDiagnostics"
+    );
+
+    // The same error is not shown again on every change
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": uri, "version": 2 },
+            "contentChanges": [{ "text": SPECK }],
+        }),
+    );
+    assert_eq!(
+        client.receive()["method"],
+        "textDocument/publishDiagnostics"
+    );
+
+    client.request("shutdown", Value::Null);
+    client.notify("exit", Value::Null);
+    assert!(client.child.wait().unwrap().success());
+}
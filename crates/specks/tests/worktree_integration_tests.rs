@@ -684,6 +684,65 @@ Test decision.
     }
 }
 
+#[test]
+#[serial_test::serial]
+fn test_worktree_create_rejects_invalid_custom_rules() {
+    let temp = setup_test_git_repo();
+    create_test_speck(&temp, "valid", MINIMAL_SPECK);
+
+    let config_path = temp.path().join(".specks").join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}\n{}",
+            config,
+            r#"[[specks.validation.custom]]
+code = "C001"
+kind = "step-pattern"
+field = "commit"
+pattern = '^((feat'
+message = "Commit lines must use a conventional prefix"
+"#
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(specks_binary())
+        .args(["worktree", "create", ".specks/specks-valid.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run worktree create");
+
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("invalid custom rule C001"),
+        "stderr should name the bad rule: {}",
+        stderr
+    );
+    assert!(!temp.path().join(".specks-worktrees").exists());
+
+    // A rule missing a required key makes the whole config unreadable
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replace(
+            "message = \"Commit lines must use a conventional prefix\"\n",
+            "",
+        ),
+    )
+    .unwrap();
+    let output = Command::new(specks_binary())
+        .args(["worktree", "create", ".specks/specks-valid.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to run worktree create");
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("failed to parse config file"), "{}", stderr);
+}
+
 #[test]
 #[serial_test::serial]
 fn test_worktree_create_blocks_speck_with_diagnostics() {